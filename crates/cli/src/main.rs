@@ -22,8 +22,20 @@ use stateql_dialect_sqlite::SqliteDialect;
 mod error_presentation;
 
 const RUNTIME_ERROR_EXIT_CODE: i32 = 1;
+#[cfg(feature = "mysql")]
+const MYSQL_GRANT_ACCOUNTS_KEY: &str = "mysql.grant_accounts";
 #[cfg(feature = "postgres")]
 const POSTGRES_SSLMODE_KEY: &str = "postgres.sslmode";
+#[cfg(feature = "mssql")]
+const MSSQL_ENCRYPT_KEY: &str = "mssql.encrypt";
+#[cfg(feature = "mssql")]
+const MSSQL_CA_CERT_KEY: &str = "mssql.ca_cert";
+#[cfg(feature = "mssql")]
+const MSSQL_TRUST_SERVER_CERTIFICATE_KEY: &str = "mssql.trust_server_certificate";
+#[cfg(feature = "sqlite")]
+const SQLITE_ATTACH_KEY_PREFIX: &str = "sqlite.attach.";
+#[cfg(feature = "sqlite")]
+const SQLITE_SAFE_APPLY_KEY: &str = "sqlite.safe_apply";
 
 #[derive(Parser, Debug)]
 #[command(name = "stateql")]
@@ -100,6 +112,10 @@ struct MysqlArgs {
     connection: TcpConnectionArgs,
     #[arg(long)]
     socket: Option<String>,
+    #[arg(long)]
+    enable_column_reorder: bool,
+    #[arg(long = "grant-account", value_name = "USER@HOST")]
+    grant_accounts: Vec<String>,
     #[arg(value_name = "DATABASE")]
     database: String,
 }
@@ -120,6 +136,10 @@ struct PostgresArgs {
 struct SqliteArgs {
     #[command(flatten)]
     mode: ModeArgs,
+    #[arg(long = "attach", value_name = "SCHEMA=PATH", value_parser = parse_attachment)]
+    attachments: Vec<(String, String)>,
+    #[arg(long, value_name = "MODE", value_parser = ["backup", "rehearse"])]
+    safe_apply: Option<String>,
     #[arg(value_name = "DATABASE")]
     database: String,
 }
@@ -130,6 +150,12 @@ struct MssqlArgs {
     mode: ModeArgs,
     #[command(flatten)]
     connection: TcpConnectionArgs,
+    #[arg(long, value_name = "MODE", value_parser = ["off", "on", "required"])]
+    encrypt: Option<String>,
+    #[arg(long = "ca-cert", value_name = "PATH")]
+    ca_cert: Option<String>,
+    #[arg(long = "trust-server-certificate", conflicts_with = "ca_cert")]
+    trust_server_certificate: bool,
     #[arg(value_name = "DATABASE")]
     database: String,
 }
@@ -158,10 +184,19 @@ fn run(cli: Cli) -> CliResult<()> {
                 mode,
                 connection,
                 socket,
+                enable_column_reorder,
+                grant_accounts,
                 database,
             } = args;
-            let config = connection_config(connection, database, socket, BTreeMap::new());
-            run_with_dialect(&MysqlDialect, config, mode)
+            let mut extra = BTreeMap::new();
+            if !grant_accounts.is_empty() {
+                extra.insert(
+                    MYSQL_GRANT_ACCOUNTS_KEY.to_string(),
+                    grant_accounts.join(","),
+                );
+            }
+            let config = connection_config(connection, database, socket, extra);
+            run_with_dialect(&MysqlDialect, config, mode, enable_column_reorder)
         }
         #[cfg(feature = "postgres")]
         DialectCommand::Postgres(args) => {
@@ -176,11 +211,23 @@ fn run(cli: Cli) -> CliResult<()> {
                 extra.insert(POSTGRES_SSLMODE_KEY.to_string(), sslmode);
             }
             let config = connection_config(connection, database, None, extra);
-            run_with_dialect(&PostgresDialect, config, mode)
+            run_with_dialect(&PostgresDialect, config, mode, false)
         }
         #[cfg(feature = "sqlite")]
         DialectCommand::Sqlite(args) => {
-            let SqliteArgs { mode, database } = args;
+            let SqliteArgs {
+                mode,
+                attachments,
+                safe_apply,
+                database,
+            } = args;
+            let mut extra: BTreeMap<_, _> = attachments
+                .into_iter()
+                .map(|(schema, path)| (format!("{SQLITE_ATTACH_KEY_PREFIX}{schema}"), path))
+                .collect();
+            if let Some(safe_apply) = safe_apply {
+                extra.insert(SQLITE_SAFE_APPLY_KEY.to_string(), safe_apply);
+            }
             let config = ConnectionConfig {
                 host: None,
                 port: None,
@@ -188,19 +235,35 @@ fn run(cli: Cli) -> CliResult<()> {
                 password: None,
                 database,
                 socket: None,
-                extra: BTreeMap::new(),
+                extra,
             };
-            run_with_dialect(&SqliteDialect, config, mode)
+            run_with_dialect(&SqliteDialect, config, mode, false)
         }
         #[cfg(feature = "mssql")]
         DialectCommand::Mssql(args) => {
             let MssqlArgs {
                 mode,
                 connection,
+                encrypt,
+                ca_cert,
+                trust_server_certificate,
                 database,
             } = args;
-            let config = connection_config(connection, database, None, BTreeMap::new());
-            run_with_dialect(&MssqlDialect, config, mode)
+            let mut extra = BTreeMap::new();
+            if let Some(encrypt) = encrypt {
+                extra.insert(MSSQL_ENCRYPT_KEY.to_string(), encrypt);
+            }
+            if let Some(ca_cert) = ca_cert {
+                extra.insert(MSSQL_CA_CERT_KEY.to_string(), ca_cert);
+            }
+            if trust_server_certificate {
+                extra.insert(
+                    MSSQL_TRUST_SERVER_CERTIFICATE_KEY.to_string(),
+                    "true".to_string(),
+                );
+            }
+            let config = connection_config(connection, database, None, extra);
+            run_with_dialect(&MssqlDialect, config, mode, false)
         }
         #[cfg(not(any(
             feature = "mysql",
@@ -212,6 +275,16 @@ fn run(cli: Cli) -> CliResult<()> {
     }
 }
 
+#[cfg(feature = "sqlite")]
+fn parse_attachment(raw: &str) -> Result<(String, String), String> {
+    match raw.split_once('=') {
+        Some((schema, path)) if !schema.is_empty() && !path.is_empty() => {
+            Ok((schema.to_string(), path.to_string()))
+        }
+        _ => Err(format!("expected SCHEMA=PATH, got `{raw}`")),
+    }
+}
+
 fn connection_config(
     connection: TcpConnectionArgs,
     database: String,
@@ -233,6 +306,7 @@ fn run_with_dialect(
     dialect: &dyn Dialect,
     connection_config: ConnectionConfig,
     mode_args: ModeArgs,
+    enable_column_reorder: bool,
 ) -> CliResult<()> {
     let explicit_mode = mode_args.explicit_mode();
     let desired_sql = if explicit_mode == Some(Mode::Export) {
@@ -258,6 +332,7 @@ fn run_with_dialect(
         OrchestratorOptions {
             mode,
             enable_drop: mode_args.enable_drop,
+            enable_column_reorder,
         },
     )?;
 
@@ -20,6 +20,7 @@ fn mysql_help_lists_common_and_mysql_specific_connection_flags() {
     assert!(stdout.contains("--user"));
     assert!(stdout.contains("--password"));
     assert!(stdout.contains("--socket"));
+    assert!(stdout.contains("--grant-account"));
     assert!(stdout.contains("<DATABASE>"));
 }
 
@@ -48,6 +49,8 @@ fn sqlite_help_uses_database_path_and_excludes_network_flags() {
 
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(stdout.contains("<DATABASE>"));
+    assert!(stdout.contains("--attach <SCHEMA=PATH>"));
+    assert!(stdout.contains("--safe-apply <MODE>"));
     assert!(!stdout.contains("--host"));
     assert!(!stdout.contains("--port"));
     assert!(!stdout.contains("--user"));
@@ -55,3 +58,18 @@ fn sqlite_help_uses_database_path_and_excludes_network_flags() {
     assert!(!stdout.contains("--socket"));
     assert!(!stdout.contains("--sslmode"));
 }
+
+#[cfg(feature = "mssql")]
+#[test]
+fn mssql_help_lists_common_and_tls_flags() {
+    let output = run_stateql(&["mssql", "--help"]);
+
+    assert_eq!(output.status.code(), Some(0));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("--host"));
+    assert!(stdout.contains("--encrypt <MODE>"));
+    assert!(stdout.contains("--ca-cert <PATH>"));
+    assert!(stdout.contains("--trust-server-certificate"));
+    assert!(stdout.contains("<DATABASE>"));
+}
@@ -1,4 +1,4 @@
-use crate::{Result, Version};
+use crate::{Result, Statement, StatementContext, Version};
 
 const COMMIT_SQL: &str = "COMMIT";
 const ROLLBACK_SQL: &str = "ROLLBACK";
@@ -14,6 +14,25 @@ pub trait DatabaseAdapter: Send {
     fn begin(&mut self) -> Result<Transaction<'_>>;
     fn schema_search_path(&self) -> Vec<String>;
     fn server_version(&self) -> Result<Version>;
+
+    /// Runs the SQL of a `Statement` along with its context. Adapters that treat some
+    /// steps specially (SQLite's foreign key handling around a table rebuild) recognize
+    /// them by the context rather than the SQL text.
+    fn execute_with_context(&self, sql: &str, _context: Option<&StatementContext>) -> Result<()> {
+        self.execute(sql)
+    }
+
+    /// Called before an apply hands `statements` to the executor, so the adapter can
+    /// guard the plan as a whole (take a backup, rehearse it on a copy).
+    fn prepare_apply(&mut self, _statements: &[Statement]) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with the executor's outcome once the plan has run; the adapter may undo
+    /// a failed plan and report how in the returned error.
+    fn finish_apply(&mut self, outcome: Result<()>) -> Result<()> {
+        outcome
+    }
 }
 
 /// RAII transaction handle.
@@ -37,6 +56,14 @@ impl<'a> Transaction<'a> {
         self.adapter.execute(sql)
     }
 
+    pub fn execute_with_context(
+        &mut self,
+        sql: &str,
+        context: Option<&StatementContext>,
+    ) -> Result<()> {
+        self.adapter.execute_with_context(sql, context)
+    }
+
     pub fn commit(mut self) -> Result<()> {
         self.adapter.execute(COMMIT_SQL)?;
         self.committed = true;
@@ -1,7 +1,10 @@
-use crate::{DiffError, Ident, QualifiedName, Result, SchemaObject};
+use crate::{
+    DiffError, Ident, ParseError, QualifiedName, Result, SchemaObject, SourceLocation, Value,
+};
 
 const RENAMED_KEYWORD: &str = "@renamed";
 const RENAME_ALIAS_KEYWORD: &str = "@rename";
+const BACKFILL_KEYWORD: &str = "@backfill";
 
 /// Extracts rename annotations from SQL comments before parser invocation.
 pub struct AnnotationExtractor;
@@ -17,6 +20,16 @@ pub struct RenameAnnotation {
     pub deprecated_alias: bool,
 }
 
+/// A backfill annotation captured from SQL comments: `-- @backfill <expr>` fills a new
+/// column for the rows already in its table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackfillAnnotation {
+    /// 1-based source line number in the original SQL.
+    pub line: usize,
+    /// The rest of the comment, as written.
+    pub expr: String,
+}
+
 /// Source-line attachment point for a parsed schema object.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AnnotationAttachment {
@@ -70,6 +83,46 @@ impl AnnotationExtractor {
 
         Ok((cleaned_sql, annotations))
     }
+
+    /// Extracts `@backfill` annotations, each the whole of its line comment.
+    ///
+    /// The returned SQL preserves original line boundaries.
+    pub fn extract_backfills(sql: &str) -> Result<(String, Vec<BackfillAnnotation>)> {
+        let mut cleaned_sql = String::with_capacity(sql.len());
+        let mut annotations = Vec::new();
+
+        for (line_index, raw_line) in sql.split_inclusive('\n').enumerate() {
+            let (line, line_ending) = split_line_ending(raw_line);
+            let mut cleaned_line = line;
+
+            if let Some(comment_start) = find_line_comment_start(line)
+                && let Some(expr) = line[comment_start + 2..]
+                    .trim()
+                    .strip_prefix(BACKFILL_KEYWORD)
+                    .filter(|expr| {
+                        expr.is_empty() || expr.starts_with(|ch: char| ch.is_ascii_whitespace())
+                    })
+            {
+                let annotation = BackfillAnnotation {
+                    line: line_index + 1,
+                    expr: expr.trim().to_string(),
+                };
+                if annotation.expr.is_empty() {
+                    return Err(backfill_annotation_error(
+                        &annotation,
+                        "annotation @backfill needs an expression",
+                    ));
+                }
+                annotations.push(annotation);
+                cleaned_line = &line[..comment_start + 2];
+            }
+
+            cleaned_sql.push_str(cleaned_line);
+            cleaned_sql.push_str(line_ending);
+        }
+
+        Ok((cleaned_sql, annotations))
+    }
 }
 
 /// Attaches rename annotations to parsed schema objects.
@@ -96,6 +149,51 @@ pub fn attach_annotations(
     Ok(())
 }
 
+/// Stores each backfill annotation under `extra_key` on the column defined on its line.
+///
+/// The function is fail-fast: if any annotation has no column to attach to, no objects
+/// are mutated and an error is returned.
+pub fn attach_backfills(
+    objects: &mut [SchemaObject],
+    annotations: &[BackfillAnnotation],
+    attachments: &[AnnotationAttachment],
+    extra_key: &str,
+) -> Result<()> {
+    let mut ops = Vec::with_capacity(annotations.len());
+
+    for annotation in annotations {
+        let target = attachments
+            .iter()
+            .find(|attachment| {
+                attachment.line == annotation.line
+                    && matches!(attachment.target, AnnotationTarget::TableColumn { .. })
+            })
+            .and_then(|attachment| match &attachment.target {
+                AnnotationTarget::TableColumn { table, column } => {
+                    find_table_column_index(objects, table, column)
+                }
+                _ => None,
+            });
+        let Some((object_index, column_index)) = target else {
+            return Err(backfill_annotation_error(
+                annotation,
+                "annotation @backfill is not on a column definition",
+            ));
+        };
+        ops.push((object_index, column_index, annotation.expr.clone()));
+    }
+
+    for (object_index, column_index, expr) in ops {
+        if let SchemaObject::Table(table) = &mut objects[object_index] {
+            table.columns[column_index]
+                .extra
+                .insert(extra_key.to_string(), Value::String(expr));
+        }
+    }
+
+    Ok(())
+}
+
 struct ParsedAnnotation {
     start: usize,
     end: usize,
@@ -532,6 +630,19 @@ fn orphan_annotation_error(annotation: &RenameAnnotation) -> crate::Error {
     .into()
 }
 
+fn backfill_annotation_error(annotation: &BackfillAnnotation, message: &str) -> crate::Error {
+    ParseError::StatementConversion {
+        statement_index: 0,
+        source_sql: format!("-- {BACKFILL_KEYWORD} {}", annotation.expr),
+        source_location: Some(SourceLocation {
+            line: annotation.line,
+            column: None,
+        }),
+        source: message.into(),
+    }
+    .into()
+}
+
 fn format_ident_for_annotation(ident: &Ident) -> String {
     if ident.quoted {
         format!("\"{}\"", ident.value.replace('\"', "\"\""))
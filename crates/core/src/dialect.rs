@@ -1,6 +1,6 @@
 use crate::{
     ConnectionConfig, DatabaseAdapter, DiffOp, Ident, Result, SchemaObject, Statement,
-    diff::{DEFAULT_EQUIVALENCE_POLICY, EquivalencePolicy},
+    diff::{DEFAULT_EQUIVALENCE_POLICY, EquivalencePolicy, PartitionChangeStrategy},
 };
 
 /// Dialect contract for parse, normalize, diff planning, and connection lifecycle.
@@ -126,6 +126,18 @@ pub trait Dialect: Send + Sync {
     fn equivalence_policy(&self) -> &'static dyn EquivalencePolicy {
         &DEFAULT_EQUIVALENCE_POLICY
     }
+    fn partition_change_strategy(&self) -> PartitionChangeStrategy {
+        PartitionChangeStrategy::DropAndAdd
+    }
+    fn requires_table_definitions(&self) -> bool {
+        false
+    }
+    fn diffs_table_constraints(&self) -> bool {
+        false
+    }
+    fn diffed_table_options(&self) -> &'static [&'static str] {
+        &[]
+    }
     fn quote_ident(&self, ident: &Ident) -> String;
     fn batch_separator(&self) -> &str {
         ""
@@ -1,23 +1,30 @@
+mod column_order;
 pub mod compare;
 mod compare_remaining;
 mod constraint_pairing;
 mod cycle;
 mod enable_drop;
 pub mod engine;
+mod enum_labels;
 mod name_resolution;
 mod partition;
 pub mod policy;
 mod privilege;
 mod rename;
+mod table_constraints;
+mod table_definition;
+mod table_options;
 pub mod types;
 mod view_rebuild;
 
 pub use compare::DiffEngine;
-pub use enable_drop::{DiffDiagnostics, DiffOutcome, SkippedOpDiagnostic, SkippedOpKind};
+pub use enable_drop::{
+    DiffDiagnostics, DiffOutcome, DiffWarning, SkippedOpDiagnostic, SkippedOpKind,
+};
 pub use policy::{
     DEFAULT_EQUIVALENCE_POLICY, DefaultEquivalencePolicy, DiffConfig, EquivalencePolicy,
-    EquivalencePolicyContractError, custom_types_equivalent, exprs_equivalent,
-    verify_equivalence_policy_contract,
+    EquivalencePolicyContractError, PartitionChangeStrategy, custom_types_equivalent,
+    exprs_equivalent, verify_equivalence_policy_contract,
 };
 pub use types::{
     ColumnChange, DiffOp, DomainChange, SequenceChange, TypeChange,
@@ -0,0 +1,209 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{
+    enum_labels::{LabelChange, classify_label_change},
+    name_resolution::IdentKey,
+};
+use crate::{Column, ColumnChange, ColumnPosition, DiffConfig, DiffOp, QualifiedName};
+
+/// Rewrites the column ops emitted for `table` (starting at `column_ops_start`) so that
+/// the surviving columns end up in the desired order.
+///
+/// Added columns are positioned relative to their desired predecessor, and existing
+/// columns outside the longest already-ordered run are moved with a full column
+/// redefinition, because MySQL's `MODIFY COLUMN ... AFTER` restates the whole column.
+pub(super) fn diff_column_order(
+    table: &QualifiedName,
+    desired_columns: &[Column],
+    current_columns: &[Column],
+    column_ops_start: usize,
+    config: &DiffConfig,
+    ops: &mut Vec<DiffOp>,
+) {
+    let mut renamed = BTreeMap::new();
+    let mut dropped = BTreeSet::new();
+    let mut added = BTreeSet::new();
+    for op in &ops[column_ops_start..] {
+        match op {
+            DiffOp::RenameColumn { from, to, .. } => {
+                renamed.insert(IdentKey::from(from), IdentKey::from(to));
+            }
+            DiffOp::DropColumn { column, .. } => {
+                dropped.insert(IdentKey::from(column));
+            }
+            DiffOp::AddColumn { column, .. } => {
+                added.insert(IdentKey::from(&column.name));
+            }
+            _ => {}
+        }
+    }
+
+    let mut order = current_columns
+        .iter()
+        .map(|column| IdentKey::from(&column.name))
+        .filter(|key| !dropped.contains(key))
+        .map(|key| renamed.get(&key).cloned().unwrap_or(key))
+        .collect::<Vec<_>>();
+
+    position_added_columns(
+        table,
+        desired_columns,
+        &added,
+        &mut order,
+        column_ops_start,
+        ops,
+    );
+
+    let current_positions = desired_columns
+        .iter()
+        .map(|column| {
+            let key = IdentKey::from(&column.name);
+            order.iter().position(|candidate| *candidate == key)
+        })
+        .collect::<Option<Vec<_>>>();
+    let Some(current_positions) = current_positions else {
+        return;
+    };
+
+    let in_place = longest_increasing_run(&current_positions);
+    for (index, column) in desired_columns.iter().enumerate() {
+        if in_place.contains(&index) {
+            continue;
+        }
+
+        let column_key = IdentKey::from(&column.name);
+        let mut cursor = column_ops_start;
+        while cursor < ops.len() {
+            let is_superseded = matches!(
+                &ops[cursor],
+                DiffOp::AlterColumn { column: altered, .. }
+                    if IdentKey::from(altered) == column_key
+            );
+            if is_superseded {
+                ops.remove(cursor);
+            } else {
+                cursor += 1;
+            }
+        }
+
+        let mut redefined = column.clone();
+        // A label removal held back by enable_drop must not sneak in through the redefinition.
+        if !config.enable_drop
+            && let Some(current) = matching_current_column(column, current_columns)
+            && matches!(
+                classify_label_change(&column.data_type, &current.data_type),
+                Some((_, LabelChange::Remove(_)))
+            )
+        {
+            redefined.data_type = current.data_type.clone();
+        }
+
+        ops.push(DiffOp::AlterColumn {
+            table: table.clone(),
+            column: column.name.clone(),
+            changes: full_redefinition(&redefined, desired_predecessor(desired_columns, index)),
+        });
+    }
+}
+
+fn position_added_columns(
+    table: &QualifiedName,
+    desired_columns: &[Column],
+    added: &BTreeSet<IdentKey>,
+    order: &mut Vec<IdentKey>,
+    column_ops_start: usize,
+    ops: &mut [DiffOp],
+) {
+    let slots = (column_ops_start..ops.len())
+        .filter(|index| matches!(ops[*index], DiffOp::AddColumn { .. }))
+        .collect::<Vec<_>>();
+    let added_in_desired_order = desired_columns
+        .iter()
+        .enumerate()
+        .filter(|(_, column)| added.contains(&IdentKey::from(&column.name)));
+
+    for (slot, (index, column)) in slots.into_iter().zip(added_in_desired_order) {
+        let position = desired_predecessor(desired_columns, index);
+        let insert_at = match &position {
+            ColumnPosition::First => 0,
+            ColumnPosition::After(predecessor) => {
+                let predecessor = IdentKey::from(predecessor);
+                order
+                    .iter()
+                    .position(|candidate| *candidate == predecessor)
+                    .map_or(order.len(), |found| found + 1)
+            }
+        };
+        order.insert(insert_at, IdentKey::from(&column.name));
+
+        ops[slot] = DiffOp::AddColumn {
+            table: table.clone(),
+            column: Box::new(column.clone()),
+            position: Some(position),
+        };
+    }
+}
+
+fn matching_current_column<'a>(
+    desired: &Column,
+    current_columns: &'a [Column],
+) -> Option<&'a Column> {
+    let desired_key = IdentKey::from(&desired.name);
+    let renamed_from = desired.renamed_from.as_ref().map(IdentKey::from);
+    current_columns
+        .iter()
+        .find(|column| IdentKey::from(&column.name) == desired_key)
+        .or_else(|| {
+            current_columns
+                .iter()
+                .find(|column| Some(IdentKey::from(&column.name)) == renamed_from)
+        })
+}
+
+fn desired_predecessor(desired_columns: &[Column], index: usize) -> ColumnPosition {
+    match index.checked_sub(1) {
+        Some(previous) => ColumnPosition::After(desired_columns[previous].name.clone()),
+        None => ColumnPosition::First,
+    }
+}
+
+fn full_redefinition(column: &Column, position: ColumnPosition) -> Vec<ColumnChange> {
+    vec![
+        ColumnChange::SetType(column.data_type.clone()),
+        ColumnChange::SetNotNull(column.not_null),
+        ColumnChange::SetDefault(column.default.clone()),
+        ColumnChange::SetIdentity(column.identity.clone()),
+        ColumnChange::SetGenerated(column.generated.clone()),
+        ColumnChange::SetCollation(column.collation.clone()),
+        ColumnChange::SetPosition(position),
+    ]
+}
+
+/// Returns the indexes of the longest strictly increasing subsequence of `positions`,
+/// i.e. the largest set of columns that are already in the desired relative order.
+/// Ties keep the run ending latest, so the column that visibly jumped is the one moved.
+fn longest_increasing_run(positions: &[usize]) -> BTreeSet<usize> {
+    let mut lengths = vec![1usize; positions.len()];
+    let mut previous = vec![None; positions.len()];
+
+    for right in 0..positions.len() {
+        for left in 0..right {
+            if positions[left] < positions[right] && lengths[left] + 1 > lengths[right] {
+                lengths[right] = lengths[left] + 1;
+                previous[right] = Some(left);
+            }
+        }
+    }
+
+    let mut kept = BTreeSet::new();
+    let mut cursor = lengths
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, length)| **length)
+        .map(|(index, _)| index);
+    while let Some(index) = cursor {
+        kept.insert(index);
+        cursor = previous[index];
+    }
+    kept
+}
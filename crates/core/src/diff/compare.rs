@@ -1,16 +1,23 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use super::{
+    column_order::diff_column_order,
     compare_remaining::{compare_remaining_objects, validate_sequence_invariant},
     constraint_pairing::check_drop_add_keys_match,
     cycle::{apply_create_cycle_fallback, drop_fk_ops_for_drop_table_cycles},
-    enable_drop::{DiffDiagnostics, DiffOutcome},
+    enable_drop::{DiffDiagnostics, DiffOutcome, DiffWarning},
+    enum_labels::{LabelChange, classify_label_change},
     name_resolution::{
         IdentKey, IndexLookupKey, IndexOwnerKey, QualifiedNameKey, resolve_index_match,
         resolve_qualified_name_match,
     },
     partition::diff_partition,
     rename::{index_renamed_from, indexes_equivalent_for_rename, resolve_rename_match},
+    table_constraints::{diff_foreign_keys, diff_primary_key},
+    table_definition::{
+        DeclaredIndexes, attach_dependents, declared_by_table, push_table_definition,
+    },
+    table_options::{diff_table_options, requires_recreate},
 };
 use crate::{
     CheckConstraint, Column, ColumnChange, DataType, DiffConfig, DiffError, DiffOp, Ident,
@@ -33,8 +40,7 @@ impl DiffEngine {
         current: &[SchemaObject],
         config: &DiffConfig,
     ) -> Result<Vec<DiffOp>> {
-        let compare_ops = self.compare_objects(desired, current, config)?;
-        self.resolve_and_order(compare_ops, config)
+        Ok(self.diff_with_warnings(desired, current, config)?.0)
     }
 
     pub fn diff_with_diagnostics(
@@ -43,8 +49,8 @@ impl DiffEngine {
         current: &[SchemaObject],
         config: &DiffConfig,
     ) -> Result<DiffOutcome> {
-        let ops = self.diff(desired, current, config)?;
-        let diagnostics = if config.enable_drop {
+        let (ops, warnings) = self.diff_with_warnings(desired, current, config)?;
+        let mut diagnostics = if config.enable_drop {
             DiffDiagnostics::default()
         } else {
             let mut with_drop_enabled = config.clone();
@@ -52,15 +58,28 @@ impl DiffEngine {
             let full_ops = self.diff(desired, current, &with_drop_enabled)?;
             DiffDiagnostics::from_enable_drop(&full_ops, &ops)
         };
+        diagnostics.warnings = warnings;
 
         Ok(DiffOutcome::new(ops, diagnostics))
     }
 
+    fn diff_with_warnings(
+        &self,
+        desired: &[SchemaObject],
+        current: &[SchemaObject],
+        config: &DiffConfig,
+    ) -> Result<(Vec<DiffOp>, Vec<DiffWarning>)> {
+        let mut warnings = Vec::new();
+        let compare_ops = self.compare_objects(desired, current, config, &mut warnings)?;
+        Ok((self.resolve_and_order(compare_ops, config)?, warnings))
+    }
+
     fn compare_objects(
         &self,
         desired: &[SchemaObject],
         current: &[SchemaObject],
         config: &DiffConfig,
+        warnings: &mut Vec<DiffWarning>,
     ) -> Result<Vec<DiffOp>> {
         validate_sequence_invariant(desired, "desired")?;
         validate_sequence_invariant(current, "current")?;
@@ -72,14 +91,22 @@ impl DiffEngine {
         validate_index_owners(&current_objects, "current")?;
 
         let mut ops = Vec::new();
-        self.compare_tables(&desired_objects, &current_objects, config, &mut ops);
+        self.compare_tables(
+            &desired_objects,
+            &current_objects,
+            config,
+            &mut ops,
+            warnings,
+        );
+        // Indexes a table declares itself only change along with its definition.
         self.compare_indexes(
-            &desired_objects.indexes,
-            &current_objects.indexes,
+            &desired_objects.standalone_indexes(),
+            &current_objects.standalone_indexes(),
             config,
             &mut ops,
         )?;
         compare_remaining_objects(desired, current, config, &mut ops)?;
+        attach_dependents(current, &mut ops);
         Ok(ops)
     }
 
@@ -94,6 +121,7 @@ impl DiffEngine {
         current: &ObjectBuckets<'_>,
         config: &DiffConfig,
         ops: &mut Vec<DiffOp>,
+        warnings: &mut Vec<DiffWarning>,
     ) {
         let mut matched_current = BTreeSet::new();
 
@@ -101,7 +129,18 @@ impl DiffEngine {
             let table_key = QualifiedNameKey::from(&desired_table.name);
             if let Some(current_table) = current.tables.get(&table_key) {
                 matched_current.insert(table_key.clone());
-                self.compare_table(desired_table, current_table, config, ops);
+                let declared = DeclaredIndexes {
+                    desired: desired.declared_indexes(&desired_table.name),
+                    current: current.declared_indexes(&current_table.name),
+                };
+                self.compare_table(
+                    desired_table,
+                    current_table,
+                    &declared,
+                    config,
+                    ops,
+                    warnings,
+                );
                 continue;
             }
 
@@ -112,7 +151,18 @@ impl DiffEngine {
                 &config.schema_search_path,
             ) {
                 matched_current.insert((*matched_key).clone());
-                self.compare_table(desired_table, current_table, config, ops);
+                let declared = DeclaredIndexes {
+                    desired: desired.declared_indexes(&desired_table.name),
+                    current: current.declared_indexes(&current_table.name),
+                };
+                self.compare_table(
+                    desired_table,
+                    current_table,
+                    &declared,
+                    config,
+                    ops,
+                    warnings,
+                );
                 continue;
             }
 
@@ -121,11 +171,25 @@ impl DiffEngine {
                 resolve_rename_match(renamed_from.as_ref(), &current.tables, &matched_current)
             {
                 matched_current.insert((*from_key).clone());
-                ops.push(DiffOp::RenameTable {
-                    from: current_table.name.clone(),
-                    to: desired_table.name.clone(),
-                });
-                self.compare_table(desired_table, current_table, config, ops);
+                // A recreated table is dropped under its current name instead.
+                if !requires_recreate(&desired_table.options, &current_table.options) {
+                    ops.push(DiffOp::RenameTable {
+                        from: current_table.name.clone(),
+                        to: desired_table.name.clone(),
+                    });
+                }
+                let declared = DeclaredIndexes {
+                    desired: desired.declared_indexes(&desired_table.name),
+                    current: current.declared_indexes(&current_table.name),
+                };
+                self.compare_table(
+                    desired_table,
+                    current_table,
+                    &declared,
+                    config,
+                    ops,
+                    warnings,
+                );
             } else {
                 ops.push(DiffOp::CreateTable((*desired_table).clone()));
             }
@@ -153,16 +217,54 @@ impl DiffEngine {
         &self,
         desired: &Table,
         current: &Table,
+        declared: &DeclaredIndexes<'_>,
         config: &DiffConfig,
         ops: &mut Vec<DiffOp>,
+        warnings: &mut Vec<DiffWarning>,
     ) {
+        if requires_recreate(&desired.options, &current.options) {
+            if config.enable_drop {
+                ops.push(DiffOp::DropTable(current.name.clone()));
+                ops.push(DiffOp::CreateTable(desired.clone()));
+            }
+            return;
+        }
+
+        let column_ops_start = ops.len();
         self.compare_columns(
             &desired.name,
             &desired.columns,
             &current.columns,
             config,
             ops,
+            warnings,
         );
+        if config.enable_column_reorder {
+            diff_column_order(
+                &desired.name,
+                &desired.columns,
+                &current.columns,
+                column_ops_start,
+                config,
+                ops,
+            );
+        }
+        if config.diff_table_constraints {
+            diff_primary_key(
+                &desired.name,
+                desired.primary_key.as_ref(),
+                current.primary_key.as_ref(),
+                config,
+                ops,
+            );
+            diff_foreign_keys(
+                &desired.name,
+                &desired.foreign_keys,
+                &current.foreign_keys,
+                config,
+                ops,
+            );
+        }
         self.compare_checks(&desired.name, &desired.checks, &current.checks, config, ops);
         diff_partition(
             &desired.name,
@@ -171,6 +273,14 @@ impl DiffEngine {
             config,
             ops,
         );
+        diff_table_options(
+            &desired.name,
+            &desired.options,
+            &current.options,
+            config,
+            ops,
+        );
+        push_table_definition(desired, current, declared, config, column_ops_start, ops);
     }
 
     fn compare_columns(
@@ -180,6 +290,7 @@ impl DiffEngine {
         current_columns: &[Column],
         config: &DiffConfig,
         ops: &mut Vec<DiffOp>,
+        warnings: &mut Vec<DiffWarning>,
     ) {
         let current_by_name = map_columns_by_name(current_columns);
         let desired_by_name = map_columns_by_name(desired_columns);
@@ -188,7 +299,8 @@ impl DiffEngine {
         for (column_key, desired_column) in &desired_by_name {
             if let Some(current_column) = current_by_name.get(column_key) {
                 matched_current.insert((*column_key).clone());
-                let changes = column_changes(desired_column, current_column, config);
+                let changes =
+                    column_changes(table, desired_column, current_column, config, warnings);
                 if !changes.is_empty() {
                     ops.push(DiffOp::AlterColumn {
                         table: table.clone(),
@@ -210,7 +322,8 @@ impl DiffEngine {
                     to: desired_column.name.clone(),
                 });
 
-                let changes = column_changes(desired_column, current_column, config);
+                let changes =
+                    column_changes(table, desired_column, current_column, config, warnings);
                 if !changes.is_empty() {
                     ops.push(DiffOp::AlterColumn {
                         table: table.clone(),
@@ -441,10 +554,52 @@ fn index_name(index: &IndexDef) -> Result<Ident> {
     Ok(name.clone())
 }
 
-fn column_changes(desired: &Column, current: &Column, config: &DiffConfig) -> Vec<ColumnChange> {
+fn column_changes(
+    table: &QualifiedName,
+    desired: &Column,
+    current: &Column,
+    config: &DiffConfig,
+    warnings: &mut Vec<DiffWarning>,
+) -> Vec<ColumnChange> {
     let mut changes = Vec::new();
 
-    if !data_types_equivalent(&desired.data_type, &current.data_type, config) {
+    if let Some((kind, label_change)) =
+        classify_label_change(&desired.data_type, &current.data_type)
+    {
+        let target = format!(
+            "{}.{}",
+            display_qualified_name(table),
+            display_ident(&desired.name)
+        );
+        match label_change {
+            LabelChange::Append => {
+                changes.push(ColumnChange::SetType(desired.data_type.clone()));
+            }
+            LabelChange::Reorder => {
+                changes.push(ColumnChange::SetType(desired.data_type.clone()));
+                warnings.push(DiffWarning {
+                    target,
+                    message: format!("{kind} labels are reordered; the table will be rewritten"),
+                });
+            }
+            LabelChange::Remove(removed) if config.enable_drop => {
+                changes.push(ColumnChange::SetType(desired.data_type.clone()));
+                warnings.push(DiffWarning {
+                    target,
+                    message: format!(
+                        "{kind} labels {} are removed; the table will be rewritten and rows holding them lose their value",
+                        removed
+                            .iter()
+                            .map(|label| format!("'{label}'"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                });
+            }
+            // Removing labels is destructive, so it waits for enable_drop like other drops.
+            LabelChange::Remove(_) => {}
+        }
+    } else if !data_types_equivalent(&desired.data_type, &current.data_type, config) {
         changes.push(ColumnChange::SetType(desired.data_type.clone()));
     }
 
@@ -569,6 +724,23 @@ struct ObjectBuckets<'a> {
 }
 
 impl<'a> ObjectBuckets<'a> {
+    fn standalone_indexes(&self) -> Vec<&'a IndexDef> {
+        self.indexes
+            .iter()
+            .copied()
+            .filter(|index| !declared_by_table(index))
+            .collect()
+    }
+
+    fn declared_indexes(&self, table: &QualifiedName) -> Vec<&'a IndexDef> {
+        let owner = IndexOwnerKey::Table(QualifiedNameKey::from(table));
+        self.indexes
+            .iter()
+            .copied()
+            .filter(|index| declared_by_table(index) && IndexOwnerKey::from(&index.owner) == owner)
+            .collect()
+    }
+
     fn from_schema(objects: &'a [SchemaObject]) -> Result<Self> {
         let mut tables = BTreeMap::new();
         let mut table_order = Vec::new();
@@ -592,15 +764,18 @@ impl<'a> ObjectBuckets<'a> {
                     indexes.push(index);
                 }
                 SchemaObject::Sequence(_)
+                | SchemaObject::Synonym(_)
                 | SchemaObject::Trigger(_)
                 | SchemaObject::Function(_)
+                | SchemaObject::Event(_)
                 | SchemaObject::Type(_)
                 | SchemaObject::Domain(_)
                 | SchemaObject::Extension(_)
                 | SchemaObject::Schema(_)
                 | SchemaObject::Comment(_)
                 | SchemaObject::Privilege(_)
-                | SchemaObject::Policy(_) => {}
+                | SchemaObject::Policy(_)
+                | SchemaObject::Pragma(_) => {}
             }
         }
 
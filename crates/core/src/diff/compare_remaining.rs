@@ -5,9 +5,10 @@ use super::{
     view_rebuild::build_view_rebuild_plan,
 };
 use crate::{
-    CheckConstraint, Comment, DiffConfig, DiffError, DiffOp, Domain, DomainChange, Extension,
-    Function, Ident, MaterializedView, Policy, Privilege, QualifiedName, Result, SchemaDef,
-    SchemaObject, Sequence, SequenceChange, Table, Trigger, TypeChange, TypeDef, TypeKind, View,
+    CheckConstraint, Comment, DiffConfig, DiffError, DiffOp, Domain, DomainChange, Event,
+    Extension, Function, Ident, MaterializedView, Policy, Pragma, Privilege, QualifiedName, Result,
+    SchemaDef, SchemaObject, Sequence, SequenceChange, Synonym, Table, Trigger, TypeChange,
+    TypeDef, TypeKind, View, value_total_eq,
 };
 
 pub(crate) fn compare_remaining_objects(
@@ -33,6 +34,10 @@ pub(crate) fn compare_remaining_objects(
     let current_sequences = collect_sequences(current);
     compare_sequences(&desired_sequences, &current_sequences, config, ops);
 
+    let desired_synonyms = collect_synonyms(desired);
+    let current_synonyms = collect_synonyms(current);
+    compare_synonyms(&desired_synonyms, &current_synonyms, config, ops);
+
     let desired_triggers = collect_triggers(desired);
     let current_triggers = collect_triggers(current);
     compare_triggers(&desired_triggers, &current_triggers, config, ops);
@@ -41,6 +46,10 @@ pub(crate) fn compare_remaining_objects(
     let current_functions = collect_functions(current);
     compare_functions(&desired_functions, &current_functions, config, ops);
 
+    let desired_events = collect_events(desired);
+    let current_events = collect_events(current);
+    compare_events(&desired_events, &current_events, config, ops);
+
     let desired_types = collect_types(desired);
     let current_types = collect_types(current);
     compare_types(&desired_types, &current_types, config, ops);
@@ -69,6 +78,8 @@ pub(crate) fn compare_remaining_objects(
     let current_policies = collect_policies(current);
     compare_policies(&desired_policies, &current_policies, config, ops);
 
+    compare_pragmas(&collect_pragmas(desired), &collect_pragmas(current), ops);
+
     Ok(())
 }
 
@@ -170,6 +181,26 @@ fn collect_functions(objects: &[SchemaObject]) -> Vec<&Function> {
         .collect()
 }
 
+fn collect_events(objects: &[SchemaObject]) -> Vec<&Event> {
+    objects
+        .iter()
+        .filter_map(|object| match object {
+            SchemaObject::Event(event) => Some(event),
+            _ => None,
+        })
+        .collect()
+}
+
+fn collect_synonyms(objects: &[SchemaObject]) -> Vec<&Synonym> {
+    objects
+        .iter()
+        .filter_map(|object| match object {
+            SchemaObject::Synonym(synonym) => Some(synonym),
+            _ => None,
+        })
+        .collect()
+}
+
 fn collect_types(objects: &[SchemaObject]) -> Vec<&TypeDef> {
     objects
         .iter()
@@ -200,6 +231,16 @@ fn collect_extensions(objects: &[SchemaObject]) -> Vec<&Extension> {
         .collect()
 }
 
+fn collect_pragmas(objects: &[SchemaObject]) -> Vec<&Pragma> {
+    objects
+        .iter()
+        .filter_map(|object| match object {
+            SchemaObject::Pragma(pragma) => Some(pragma),
+            _ => None,
+        })
+        .collect()
+}
+
 fn collect_schemas(objects: &[SchemaObject]) -> Vec<&SchemaDef> {
     objects
         .iter()
@@ -369,6 +410,82 @@ fn compare_sequences(
     }
 }
 
+/// A synonym cannot be altered, so a changed target recreates it.
+fn compare_synonyms(
+    desired: &[&Synonym],
+    current: &[&Synonym],
+    config: &DiffConfig,
+    ops: &mut Vec<DiffOp>,
+) {
+    for desired_synonym in desired.iter().copied() {
+        match current
+            .iter()
+            .copied()
+            .find(|candidate| candidate.name == desired_synonym.name)
+        {
+            Some(current_synonym) => {
+                if desired_synonym != current_synonym {
+                    if config.enable_drop {
+                        ops.push(DiffOp::DropSynonym(current_synonym.name.clone()));
+                    }
+                    ops.push(DiffOp::CreateSynonym(desired_synonym.clone()));
+                }
+            }
+            None => ops.push(DiffOp::CreateSynonym(desired_synonym.clone())),
+        }
+    }
+
+    if config.enable_drop {
+        for current_synonym in current.iter().copied() {
+            let missing_in_desired = desired
+                .iter()
+                .copied()
+                .all(|candidate| candidate.name != current_synonym.name);
+            if missing_in_desired {
+                ops.push(DiffOp::DropSynonym(current_synonym.name.clone()));
+            }
+        }
+    }
+}
+
+/// A changed event is recreated, as routines are.
+fn compare_events(
+    desired: &[&Event],
+    current: &[&Event],
+    config: &DiffConfig,
+    ops: &mut Vec<DiffOp>,
+) {
+    for desired_event in desired.iter().copied() {
+        match current
+            .iter()
+            .copied()
+            .find(|candidate| candidate.name == desired_event.name)
+        {
+            Some(current_event) => {
+                if desired_event != current_event {
+                    if config.enable_drop {
+                        ops.push(DiffOp::DropEvent(current_event.name.clone()));
+                    }
+                    ops.push(DiffOp::CreateEvent(desired_event.clone()));
+                }
+            }
+            None => ops.push(DiffOp::CreateEvent(desired_event.clone())),
+        }
+    }
+
+    if config.enable_drop {
+        for current_event in current.iter().copied() {
+            let missing_in_desired = desired
+                .iter()
+                .copied()
+                .all(|candidate| candidate.name != current_event.name);
+            if missing_in_desired {
+                ops.push(DiffOp::DropEvent(current_event.name.clone()));
+            }
+        }
+    }
+}
+
 fn compare_triggers(
     desired: &[&Trigger],
     current: &[&Trigger],
@@ -412,6 +529,13 @@ fn compare_triggers(
     }
 }
 
+fn drop_function(function: &Function) -> DiffOp {
+    DiffOp::DropFunction {
+        name: function.name.clone(),
+        procedure: function.return_type.is_none(),
+    }
+}
+
 fn compare_functions(
     desired: &[&Function],
     current: &[&Function],
@@ -427,7 +551,7 @@ fn compare_functions(
             Some(current_function) => {
                 if desired_function != current_function {
                     if config.enable_drop {
-                        ops.push(DiffOp::DropFunction(current_function.name.clone()));
+                        ops.push(drop_function(current_function));
                     }
                     ops.push(DiffOp::CreateFunction(desired_function.clone()));
                 }
@@ -443,7 +567,7 @@ fn compare_functions(
                 .copied()
                 .all(|candidate| candidate.name != current_function.name);
             if missing_in_desired {
-                ops.push(DiffOp::DropFunction(current_function.name.clone()));
+                ops.push(drop_function(current_function));
             }
         }
     }
@@ -590,6 +714,21 @@ fn compare_extensions(
     }
 }
 
+/// Only pragmas the desired schema declares are managed; a pragma it leaves out keeps
+/// whatever value the database has.
+fn compare_pragmas(desired: &[&Pragma], current: &[&Pragma], ops: &mut Vec<DiffOp>) {
+    for desired_pragma in desired.iter().copied() {
+        let desired_key = QualifiedNameKey::from(&desired_pragma.name);
+        let unchanged = current.iter().copied().any(|current_pragma| {
+            QualifiedNameKey::from(&current_pragma.name) == desired_key
+                && value_total_eq(&current_pragma.value, &desired_pragma.value)
+        });
+        if !unchanged {
+            ops.push(DiffOp::SetPragma(desired_pragma.clone()));
+        }
+    }
+}
+
 fn compare_schemas(
     desired: &[&SchemaDef],
     current: &[&SchemaDef],
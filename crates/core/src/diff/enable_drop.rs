@@ -1,4 +1,4 @@
-use crate::DiffOp;
+use crate::{ColumnChange, DataType, DiffOp};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DiffOutcome {
@@ -16,6 +16,7 @@ impl DiffOutcome {
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct DiffDiagnostics {
     pub skipped_ops: Vec<SkippedOpDiagnostic>,
+    pub warnings: Vec<DiffWarning>,
 }
 
 impl DiffDiagnostics {
@@ -43,15 +44,26 @@ impl DiffDiagnostics {
             });
         }
 
-        Self { skipped_ops }
+        Self {
+            skipped_ops,
+            warnings: Vec::new(),
+        }
     }
 
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.skipped_ops.is_empty()
+        self.skipped_ops.is_empty() && self.warnings.is_empty()
     }
 }
 
+/// A planned change that is emitted but deserves operator attention,
+/// e.g. because it rewrites the table or can lose data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffWarning {
+    pub target: String,
+    pub message: String,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct SkippedOpDiagnostic {
     pub kind: SkippedOpKind,
@@ -80,9 +92,10 @@ pub enum SkippedOpKind {
     DropPartition,
     DropComment,
     Revoke,
+    DropEnumLabel,
 }
 
-pub const SUPPRESSED_OP_KINDS: [SkippedOpKind; 20] = [
+pub const SUPPRESSED_OP_KINDS: [SkippedOpKind; 21] = [
     SkippedOpKind::DropTable,
     SkippedOpKind::DropView,
     SkippedOpKind::DropMaterializedView,
@@ -103,6 +116,7 @@ pub const SUPPRESSED_OP_KINDS: [SkippedOpKind; 20] = [
     SkippedOpKind::DropPartition,
     SkippedOpKind::DropComment,
     SkippedOpKind::Revoke,
+    SkippedOpKind::DropEnumLabel,
 ];
 
 #[must_use]
@@ -136,6 +150,7 @@ impl SkippedOpKind {
             Self::DropPartition => "DROP PARTITION",
             Self::DropComment => "DROP COMMENT",
             Self::Revoke => "REVOKE",
+            Self::DropEnumLabel => "DROP ENUM/SET LABEL",
         }
     }
 
@@ -146,7 +161,7 @@ impl SkippedOpKind {
             Self::DropMaterializedView => matches!(op, DiffOp::DropMaterializedView(_)),
             Self::DropSequence => matches!(op, DiffOp::DropSequence(_)),
             Self::DropTrigger => matches!(op, DiffOp::DropTrigger { .. }),
-            Self::DropFunction => matches!(op, DiffOp::DropFunction(_)),
+            Self::DropFunction => matches!(op, DiffOp::DropFunction { .. }),
             Self::DropType => matches!(op, DiffOp::DropType(_)),
             Self::DropDomain => matches!(op, DiffOp::DropDomain(_)),
             Self::DropExtension => matches!(op, DiffOp::DropExtension(_)),
@@ -161,6 +176,16 @@ impl SkippedOpKind {
             Self::DropPartition => matches!(op, DiffOp::DropPartition { .. }),
             Self::DropComment => matches!(op, DiffOp::DropComment { .. }),
             Self::Revoke => matches!(op, DiffOp::Revoke(_)),
+            // Label changes that survive without enable_drop are emitted in both plans
+            // and cancel out, so only label removals are reported here.
+            Self::DropEnumLabel => matches!(
+                op,
+                DiffOp::AlterColumn { changes, .. }
+                    if changes.iter().any(|change| matches!(
+                        change,
+                        ColumnChange::SetType(DataType::Enum(_) | DataType::Set(_))
+                    ))
+            ),
         }
     }
 }
@@ -0,0 +1,47 @@
+use crate::DataType;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum LabelChange {
+    /// New labels only appended after the existing ones; existing rows keep their values.
+    Append,
+    /// Same label set in a different order; the stored ordinals change.
+    Reorder,
+    /// Existing labels disappear; rows holding them lose their value.
+    Remove(Vec<String>),
+}
+
+/// Classifies a change between two inline `ENUM` or two inline `SET` label lists.
+/// Returns `None` for identical lists or any other pair of types.
+pub(super) fn classify_label_change(
+    desired: &DataType,
+    current: &DataType,
+) -> Option<(&'static str, LabelChange)> {
+    let (kind, desired_labels, current_labels) = match (desired, current) {
+        (DataType::Enum(desired_labels), DataType::Enum(current_labels)) => {
+            ("ENUM", desired_labels, current_labels)
+        }
+        (DataType::Set(desired_labels), DataType::Set(current_labels)) => {
+            ("SET", desired_labels, current_labels)
+        }
+        _ => return None,
+    };
+
+    if desired_labels == current_labels {
+        return None;
+    }
+
+    let removed = current_labels
+        .iter()
+        .filter(|label| !desired_labels.contains(label))
+        .cloned()
+        .collect::<Vec<_>>();
+    let change = if !removed.is_empty() {
+        LabelChange::Remove(removed)
+    } else if desired_labels.starts_with(current_labels) {
+        LabelChange::Append
+    } else {
+        LabelChange::Reorder
+    };
+
+    Some((kind, change))
+}
@@ -1,7 +1,8 @@
-use std::collections::BTreeMap;
+use std::{cmp::Ordering, collections::BTreeMap};
 
 use crate::{
-    DiffConfig, DiffOp, Ident, Partition, PartitionElement, PartitionStrategy, QualifiedName,
+    DiffConfig, DiffOp, Expr, Ident, Literal, Partition, PartitionBound, PartitionChangeStrategy,
+    PartitionElement, PartitionStrategy, QualifiedName,
 };
 
 pub(super) fn diff_partition(
@@ -48,6 +49,11 @@ fn compare_partition_updates(
         return;
     }
 
+    if config.partition_change_strategy == PartitionChangeStrategy::Reorganize {
+        reorganize_partition_updates(table, desired, current, config, ops);
+        return;
+    }
+
     let desired_by_name = map_elements_by_name(&desired.partitions);
     let current_by_name = map_elements_by_name(&current.partitions);
 
@@ -88,6 +94,238 @@ fn compare_partition_updates(
     }
 }
 
+fn reorganize_partition_updates(
+    table: &QualifiedName,
+    desired: &Partition,
+    current: &Partition,
+    config: &DiffConfig,
+    ops: &mut Vec<DiffOp>,
+) {
+    match desired.strategy {
+        PartitionStrategy::Hash | PartitionStrategy::Key => {
+            if desired.partitions.len() != current.partitions.len() {
+                ops.push(DiffOp::ChangePartitionCount {
+                    table: table.clone(),
+                    from: current.partitions.len(),
+                    to: desired.partitions.len(),
+                });
+            }
+        }
+        PartitionStrategy::Range => {
+            reorganize_range_partitions(table, desired, current, config, ops)
+        }
+        PartitionStrategy::List => reorganize_list_partitions(table, desired, current, config, ops),
+    }
+}
+
+/// RANGE partitions can only be reorganized in adjacent runs covering the same range, so
+/// the current and desired lists are cut at the upper bounds they share and each run that
+/// differs is reorganized as a whole. Partitions past the last shared bound form the tail.
+fn reorganize_range_partitions(
+    table: &QualifiedName,
+    desired: &Partition,
+    current: &Partition,
+    config: &DiffConfig,
+    ops: &mut Vec<DiffOp>,
+) {
+    let mut current_start = 0usize;
+    let mut desired_start = 0usize;
+    for (current_index, current_element) in current.partitions.iter().enumerate() {
+        let Some(desired_index) = desired.partitions[desired_start..]
+            .iter()
+            .position(|desired_element| {
+                current_element.bound.is_some() && desired_element.bound == current_element.bound
+            })
+            .map(|offset| desired_start + offset)
+        else {
+            continue;
+        };
+
+        let from = &current.partitions[current_start..=current_index];
+        let into = &desired.partitions[desired_start..=desired_index];
+        if !same_elements(from, into) {
+            ops.push(reorganize(table, from, into));
+        }
+        current_start = current_index + 1;
+        desired_start = desired_index + 1;
+    }
+
+    let from = &current.partitions[current_start..];
+    let into = &desired.partitions[desired_start..];
+    match (from.is_empty(), into.is_empty()) {
+        (true, true) => {}
+        (true, false) => {
+            for element in into {
+                ops.push(add_partition_for_element(table, desired, element.clone()));
+            }
+        }
+        (false, true) => {
+            if config.enable_drop {
+                emit_partition_drops(table, from, ops);
+            }
+        }
+        // Only the last partitions may grow their range in place; a shrinking tail loses the
+        // rows past the new end, so it is dropped and re-added behind enable_drop.
+        (false, false) => {
+            let current_end = from.last().and_then(|element| element.bound.as_ref());
+            let covering = into.iter().position(|element| {
+                bound_order(element.bound.as_ref(), current_end).is_some_and(Ordering::is_ge)
+            });
+            match covering {
+                Some(covering) => {
+                    ops.push(reorganize(table, from, &into[..=covering]));
+                    for element in &into[covering + 1..] {
+                        ops.push(add_partition_for_element(table, desired, element.clone()));
+                    }
+                }
+                None if into
+                    .iter()
+                    .all(|element| bound_order(element.bound.as_ref(), current_end).is_some()) =>
+                {
+                    if config.enable_drop {
+                        emit_partition_drops(table, from, ops);
+                        for element in into {
+                            ops.push(add_partition_for_element(table, desired, element.clone()));
+                        }
+                    }
+                }
+                // Bounds that cannot be compared are left for the database to check.
+                None => ops.push(reorganize(table, from, into)),
+            }
+        }
+    }
+}
+
+/// LIST partitions that share a value or a name are reorganized together, so a removed
+/// partition whose values moved elsewhere is merged rather than dropped with its rows.
+fn reorganize_list_partitions(
+    table: &QualifiedName,
+    desired: &Partition,
+    current: &Partition,
+    config: &DiffConfig,
+    ops: &mut Vec<DiffOp>,
+) {
+    let current_count = current.partitions.len();
+    let mut groups = (0..current_count + desired.partitions.len()).collect::<Vec<_>>();
+    for (current_index, current_element) in current.partitions.iter().enumerate() {
+        for (desired_index, desired_element) in desired.partitions.iter().enumerate() {
+            if list_elements_overlap(current_element, desired_element) {
+                join_groups(&mut groups, current_index, current_count + desired_index);
+            }
+        }
+    }
+
+    let mut dropped = Vec::new();
+    let mut seen = Vec::new();
+    for index in 0..groups.len() {
+        let group = find_group(&mut groups, index);
+        if seen.contains(&group) {
+            continue;
+        }
+        seen.push(group);
+
+        let mut from = Vec::new();
+        let mut into = Vec::new();
+        for member in 0..groups.len() {
+            if find_group(&mut groups, member) != group {
+                continue;
+            }
+            if member < current_count {
+                from.push(current.partitions[member].clone());
+            } else {
+                into.push(desired.partitions[member - current_count].clone());
+            }
+        }
+
+        if into.is_empty() {
+            dropped.extend(from);
+        } else if from.is_empty() {
+            for element in into {
+                ops.push(add_partition_for_element(table, desired, element));
+            }
+        } else if !same_elements(&from, &into) {
+            ops.push(reorganize(table, &from, &into));
+        }
+    }
+
+    // Values no desired partition lists have nowhere to go, so their rows are dropped.
+    if config.enable_drop {
+        emit_partition_drops(table, &dropped, ops);
+    }
+}
+
+fn list_elements_overlap(current: &PartitionElement, desired: &PartitionElement) -> bool {
+    if IdentKey::from(&current.name) == IdentKey::from(&desired.name) {
+        return true;
+    }
+    match (&current.bound, &desired.bound) {
+        (Some(PartitionBound::In(current_values)), Some(PartitionBound::In(desired_values))) => {
+            current_values
+                .iter()
+                .any(|value| desired_values.contains(value))
+        }
+        _ => false,
+    }
+}
+
+fn find_group(groups: &mut [usize], index: usize) -> usize {
+    let mut root = index;
+    while groups[root] != root {
+        root = groups[root];
+    }
+    groups[index] = root;
+    root
+}
+
+fn join_groups(groups: &mut [usize], left: usize, right: usize) {
+    let left = find_group(groups, left);
+    let right = find_group(groups, right);
+    groups[left.max(right)] = left.min(right);
+}
+
+fn same_elements(current: &[PartitionElement], desired: &[PartitionElement]) -> bool {
+    current.len() == desired.len()
+        && current.iter().zip(desired).all(|(current, desired)| {
+            IdentKey::from(&current.name) == IdentKey::from(&desired.name)
+                && !partition_element_changed(desired, current)
+        })
+}
+
+fn reorganize(
+    table: &QualifiedName,
+    from: &[PartitionElement],
+    into: &[PartitionElement],
+) -> DiffOp {
+    DiffOp::ReorganizePartition {
+        table: table.clone(),
+        from: from.iter().map(|element| element.name.clone()).collect(),
+        into: into.to_vec(),
+    }
+}
+
+fn bound_order(left: Option<&PartitionBound>, right: Option<&PartitionBound>) -> Option<Ordering> {
+    match (left?, right?) {
+        (PartitionBound::MaxValue, PartitionBound::MaxValue) => Some(Ordering::Equal),
+        (PartitionBound::MaxValue, _) => Some(Ordering::Greater),
+        (_, PartitionBound::MaxValue) => Some(Ordering::Less),
+        (PartitionBound::LessThan(left), PartitionBound::LessThan(right)) => {
+            Some(integer_values(left)?.cmp(&integer_values(right)?))
+        }
+        _ => None,
+    }
+}
+
+fn integer_values(values: &[Expr]) -> Option<Vec<i64>> {
+    values
+        .iter()
+        .map(|value| match value {
+            Expr::Literal(Literal::Integer(value)) => Some(*value),
+            Expr::Raw(raw) => raw.trim().parse().ok(),
+            _ => None,
+        })
+        .collect()
+}
+
 fn partition_key(partition: &Partition) -> PartitionKey {
     PartitionKey {
         strategy: partition.strategy.clone(),
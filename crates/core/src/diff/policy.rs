@@ -19,9 +19,32 @@ impl EquivalencePolicy for DefaultEquivalencePolicy {}
 
 pub static DEFAULT_EQUIVALENCE_POLICY: DefaultEquivalencePolicy = DefaultEquivalencePolicy;
 
+/// How the diff engine expresses changes to the partitions of an existing table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PartitionChangeStrategy {
+    /// Changed partitions are dropped and re-added; dropping is gated by `enable_drop`.
+    #[default]
+    DropAndAdd,
+    /// Changed bounds and range splits are reorganized in place, and HASH/KEY partition
+    /// counts are resized, so existing rows are kept.
+    Reorganize,
+}
+
 #[derive(Clone)]
 pub struct DiffConfig {
     pub enable_drop: bool,
+    /// Reposition existing columns to match the desired column order.
+    pub enable_column_reorder: bool,
+    pub partition_change_strategy: PartitionChangeStrategy,
+    /// Emit a `DiffOp::TableDefinition` ahead of the ops of every changed table, for
+    /// dialects that can only apply some table changes by recreating the table.
+    pub emit_table_definitions: bool,
+    /// Compare primary keys and foreign keys of tables that exist on both sides, for
+    /// dialects whose generator can apply `SetPrimaryKey`, `AddForeignKey` and their drops.
+    pub diff_table_constraints: bool,
+    /// `TableOptions::extra` keys the dialect wants compared. Every other key is a parser
+    /// hint that only feeds rendering.
+    pub diffed_table_options: &'static [&'static str],
     pub schema_search_path: Vec<String>,
     pub equivalence_policy: Arc<dyn EquivalencePolicy>,
 }
@@ -34,6 +57,11 @@ impl DiffConfig {
     ) -> Self {
         Self {
             enable_drop,
+            enable_column_reorder: false,
+            partition_change_strategy: PartitionChangeStrategy::default(),
+            emit_table_definitions: false,
+            diff_table_constraints: false,
+            diffed_table_options: &[],
             schema_search_path,
             equivalence_policy,
         }
@@ -44,6 +72,11 @@ impl Default for DiffConfig {
     fn default() -> Self {
         Self {
             enable_drop: false,
+            enable_column_reorder: false,
+            partition_change_strategy: PartitionChangeStrategy::default(),
+            emit_table_definitions: false,
+            diff_table_constraints: false,
+            diffed_table_options: &[],
             schema_search_path: Vec::new(),
             equivalence_policy: Arc::new(DefaultEquivalencePolicy),
         }
@@ -0,0 +1,188 @@
+use super::name_resolution::{IdentKey, QualifiedNameKey};
+use crate::{
+    CheckConstraint, DiffConfig, DiffOp, ForeignKey, Ident, PrimaryKey, QualifiedName,
+    exprs_equivalent,
+};
+
+pub(super) fn diff_primary_key(
+    table: &QualifiedName,
+    desired: Option<&PrimaryKey>,
+    current: Option<&PrimaryKey>,
+    config: &DiffConfig,
+    ops: &mut Vec<DiffOp>,
+) {
+    match (desired, current) {
+        (Some(desired_pk), Some(current_pk)) => {
+            if ident_keys(&desired_pk.columns) == ident_keys(&current_pk.columns) {
+                return;
+            }
+            // A table has a single primary key, so a changed key is replaced rather than
+            // dropped; `enable_drop` only gates removing it outright.
+            ops.push(DiffOp::DropPrimaryKey {
+                table: table.clone(),
+            });
+            ops.push(DiffOp::SetPrimaryKey {
+                table: table.clone(),
+                pk: desired_pk.clone(),
+            });
+        }
+        (Some(desired_pk), None) => {
+            ops.push(DiffOp::SetPrimaryKey {
+                table: table.clone(),
+                pk: desired_pk.clone(),
+            });
+        }
+        (None, Some(_)) => {
+            if config.enable_drop {
+                ops.push(DiffOp::DropPrimaryKey {
+                    table: table.clone(),
+                });
+            }
+        }
+        (None, None) => {}
+    }
+}
+
+/// Foreign keys are paired by name, or by their column mapping when the desired key is
+/// unnamed. Unnamed current keys cannot be addressed by `DropForeignKey`; dialects that
+/// need to remove them rely on `DiffOp::TableDefinition`.
+pub(super) fn diff_foreign_keys(
+    table: &QualifiedName,
+    desired: &[ForeignKey],
+    current: &[ForeignKey],
+    config: &DiffConfig,
+    ops: &mut Vec<DiffOp>,
+) {
+    let mut matched_current = vec![false; current.len()];
+
+    for desired_fk in desired {
+        let Some(position) = matching_foreign_key(desired_fk, current, &matched_current) else {
+            ops.push(DiffOp::AddForeignKey {
+                table: table.clone(),
+                fk: desired_fk.clone(),
+            });
+            continue;
+        };
+        matched_current[position] = true;
+
+        let current_fk = &current[position];
+        if foreign_keys_equivalent(desired_fk, current_fk) {
+            continue;
+        }
+        if let Some(name) = &current_fk.name {
+            ops.push(DiffOp::DropForeignKey {
+                table: table.clone(),
+                name: name.clone(),
+            });
+        }
+        ops.push(DiffOp::AddForeignKey {
+            table: table.clone(),
+            fk: desired_fk.clone(),
+        });
+    }
+
+    if config.enable_drop {
+        for (current_fk, matched) in current.iter().zip(matched_current) {
+            if let (false, Some(name)) = (matched, &current_fk.name) {
+                ops.push(DiffOp::DropForeignKey {
+                    table: table.clone(),
+                    name: name.clone(),
+                });
+            }
+        }
+    }
+}
+
+pub(super) fn matching_foreign_key(
+    desired: &ForeignKey,
+    current: &[ForeignKey],
+    matched_current: &[bool],
+) -> Option<usize> {
+    current
+        .iter()
+        .enumerate()
+        .filter(|(position, _)| !matched_current[*position])
+        .find(|(_, current_fk)| match (&desired.name, &current_fk.name) {
+            (Some(desired_name), Some(current_name)) => {
+                IdentKey::from(desired_name) == IdentKey::from(current_name)
+            }
+            (Some(_), None) => false,
+            (None, _) => foreign_key_signatures_match(desired, current_fk),
+        })
+        .map(|(position, _)| position)
+}
+
+/// Whether the checks without a name differ; no op can address them individually.
+pub(super) fn unnamed_checks_changed(
+    desired: &[CheckConstraint],
+    current: &[CheckConstraint],
+    config: &DiffConfig,
+) -> bool {
+    let desired_unnamed = desired.iter().filter(|check| check.name.is_none());
+    let current_unnamed = current
+        .iter()
+        .filter(|check| check.name.is_none())
+        .collect::<Vec<_>>();
+
+    let mut matched_current = vec![false; current_unnamed.len()];
+    for desired_check in desired_unnamed {
+        let position = current_unnamed
+            .iter()
+            .enumerate()
+            .position(|(position, current_check)| {
+                !matched_current[position]
+                    && exprs_equivalent(
+                        config.equivalence_policy.as_ref(),
+                        &desired_check.expr,
+                        &current_check.expr,
+                    )
+            });
+        match position {
+            Some(position) => matched_current[position] = true,
+            None => return true,
+        }
+    }
+
+    config.enable_drop && matched_current.contains(&false)
+}
+
+/// Whether an unnamed current foreign key disappears from the desired table.
+pub(super) fn unnamed_foreign_keys_dropped(
+    desired: &[ForeignKey],
+    current: &[ForeignKey],
+    config: &DiffConfig,
+) -> bool {
+    if !config.enable_drop {
+        return false;
+    }
+
+    let mut matched_current = vec![false; current.len()];
+    for desired_fk in desired {
+        if let Some(position) = matching_foreign_key(desired_fk, current, &matched_current) {
+            matched_current[position] = true;
+        }
+    }
+
+    current
+        .iter()
+        .zip(matched_current)
+        .any(|(current_fk, matched)| !matched && current_fk.name.is_none())
+}
+
+fn foreign_key_signatures_match(left: &ForeignKey, right: &ForeignKey) -> bool {
+    ident_keys(&left.columns) == ident_keys(&right.columns)
+        && QualifiedNameKey::from(&left.referenced_table)
+            == QualifiedNameKey::from(&right.referenced_table)
+        && ident_keys(&left.referenced_columns) == ident_keys(&right.referenced_columns)
+}
+
+fn foreign_keys_equivalent(desired: &ForeignKey, current: &ForeignKey) -> bool {
+    foreign_key_signatures_match(desired, current)
+        && desired.on_delete == current.on_delete
+        && desired.on_update == current.on_update
+        && desired.deferrable == current.deferrable
+}
+
+fn ident_keys(idents: &[Ident]) -> Vec<IdentKey> {
+    idents.iter().map(IdentKey::from).collect()
+}
@@ -0,0 +1,340 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{
+    name_resolution::{IdentKey, IndexLookupKey, IndexOwnerKey, QualifiedNameKey},
+    table_constraints::{
+        matching_foreign_key, unnamed_checks_changed, unnamed_foreign_keys_dropped,
+    },
+    view_rebuild::dependent_view_order,
+};
+use crate::{
+    Column, DiffConfig, DiffOp, IndexDef, SchemaObject, Table, Value, exprs_equivalent, extra_keys,
+};
+
+/// The indexes a table declares itself, on both sides of the diff.
+pub(super) struct DeclaredIndexes<'a> {
+    pub(super) desired: Vec<&'a IndexDef>,
+    pub(super) current: Vec<&'a IndexDef>,
+}
+
+/// Whether `index` is declared by its table; see `extra_keys::TABLE_DECLARED_INDEXES`.
+pub(super) fn declared_by_table(index: &IndexDef) -> bool {
+    extra_keys::TABLE_DECLARED_INDEXES
+        .iter()
+        .any(|key| matches!(index.extra.get(*key), Some(Value::Bool(true))))
+}
+
+/// Inserts a `DiffOp::TableDefinition` at `table_ops_start` when the table picked up
+/// ops, or when something no op can address changed (unnamed constraints, declared
+/// indexes, generated expressions, collations, identities).
+pub(super) fn push_table_definition(
+    desired: &Table,
+    current: &Table,
+    declared: &DeclaredIndexes<'_>,
+    config: &DiffConfig,
+    table_ops_start: usize,
+    ops: &mut Vec<DiffOp>,
+) {
+    if !config.emit_table_definitions {
+        return;
+    }
+
+    let declared_indexes = target_declared_indexes(declared, config);
+    let changed = ops.len() > table_ops_start
+        || declared_indexes_changed(&declared_indexes, &declared.current)
+        || unnamed_checks_changed(&desired.checks, &current.checks, config)
+        || unnamed_foreign_keys_dropped(&desired.foreign_keys, &current.foreign_keys, config)
+        || column_attributes_changed(desired, current, config);
+    if !changed {
+        return;
+    }
+
+    ops.insert(
+        table_ops_start,
+        DiffOp::TableDefinition {
+            current: Box::new(current.clone()),
+            target: Box::new(target_definition(desired, current, config)),
+            dependent_views: Vec::new(),
+            dependent_indexes: Vec::new(),
+            declared_indexes,
+            dependent_triggers: Vec::new(),
+            referencing_foreign_keys: Vec::new(),
+        },
+    );
+}
+
+/// The desired declared indexes, plus the current ones `enable_drop` keeps.
+fn target_declared_indexes(declared: &DeclaredIndexes<'_>, config: &DiffConfig) -> Vec<IndexDef> {
+    let kept = declared.current.iter().filter(|current_index| {
+        !config.enable_drop
+            && !declared
+                .desired
+                .iter()
+                .any(|desired_index| same_index_name(desired_index, current_index))
+    });
+    declared
+        .desired
+        .iter()
+        .chain(kept)
+        .map(|index| (*index).clone())
+        .collect()
+}
+
+fn declared_indexes_changed(target: &[IndexDef], current: &[&IndexDef]) -> bool {
+    target.len() != current.len()
+        || target.iter().any(|target_index| {
+            !current.iter().any(|current_index| {
+                same_index_name(target_index, current_index)
+                    && target_index.columns == current_index.columns
+                    && target_index.where_clause == current_index.where_clause
+                    && target_index.extra == current_index.extra
+            })
+        })
+}
+
+fn same_index_name(left: &IndexDef, right: &IndexDef) -> bool {
+    left.name.as_ref().map(IdentKey::from) == right.name.as_ref().map(IdentKey::from)
+}
+
+/// Fills `dependent_views`, `dependent_indexes`, `dependent_triggers` and
+/// `referencing_foreign_keys` of every `DiffOp::TableDefinition` once all ops are known, so objects the diff already handles are not handed to the
+/// dialect a second time.
+pub(super) fn attach_dependents(current: &[SchemaObject], ops: &mut [DiffOp]) {
+    let dropped = ops
+        .iter()
+        .filter_map(|op| match op {
+            DiffOp::DropView(name) => Some(QualifiedNameKey::from(name)),
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>();
+    let views_by_key = current
+        .iter()
+        .filter_map(|object| match object {
+            SchemaObject::View(view) => Some((QualifiedNameKey::from(&view.name), view)),
+            _ => None,
+        })
+        .filter(|(view_key, _)| !dropped.contains(view_key))
+        .collect::<BTreeMap<_, _>>();
+    let touched_indexes = ops
+        .iter()
+        .filter_map(|op| match op {
+            DiffOp::DropIndex { owner, name }
+            | DiffOp::RenameIndex {
+                owner, from: name, ..
+            } => Some(IndexLookupKey {
+                owner: IndexOwnerKey::from(owner),
+                name: IdentKey::from(name),
+            }),
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>();
+    let dropped_triggers = ops
+        .iter()
+        .filter_map(|op| match op {
+            DiffOp::DropTrigger { name, .. } => Some(QualifiedNameKey::from(name)),
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>();
+    let dropped_tables = ops
+        .iter()
+        .filter_map(|op| match op {
+            DiffOp::DropTable(name) => Some(QualifiedNameKey::from(name)),
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>();
+    let dropped_foreign_keys = ops
+        .iter()
+        .filter_map(|op| match op {
+            DiffOp::DropForeignKey { table, name } => {
+                Some((QualifiedNameKey::from(table), IdentKey::from(name)))
+            }
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>();
+
+    for op in ops {
+        if let DiffOp::TableDefinition {
+            current: current_table,
+            dependent_views,
+            dependent_indexes,
+            dependent_triggers,
+            referencing_foreign_keys,
+            ..
+        } = op
+        {
+            if !views_by_key.is_empty() {
+                *dependent_views = dependent_view_order(&current_table.name, &views_by_key)
+                    .iter()
+                    .filter_map(|view_key| views_by_key.get(view_key))
+                    .map(|view| (*view).clone())
+                    .collect();
+            }
+
+            let owner = IndexOwnerKey::Table(QualifiedNameKey::from(&current_table.name));
+            *dependent_indexes = current
+                .iter()
+                .filter_map(|object| match object {
+                    SchemaObject::Index(index) => Some(index),
+                    _ => None,
+                })
+                .filter(|index| IndexOwnerKey::from(&index.owner) == owner)
+                .filter(|index| {
+                    index.name.as_ref().is_some_and(|name| {
+                        !touched_indexes.contains(&IndexLookupKey {
+                            owner: owner.clone(),
+                            name: IdentKey::from(name),
+                        })
+                    })
+                })
+                .cloned()
+                .collect();
+
+            let table_key = QualifiedNameKey::from(&current_table.name);
+            *dependent_triggers = current
+                .iter()
+                .filter_map(|object| match object {
+                    SchemaObject::Trigger(trigger) => Some(trigger),
+                    _ => None,
+                })
+                .filter(|trigger| QualifiedNameKey::from(&trigger.table) == table_key)
+                .filter(|trigger| {
+                    !dropped_triggers.contains(&QualifiedNameKey::from(&trigger.name))
+                })
+                .cloned()
+                .collect();
+
+            *referencing_foreign_keys = current
+                .iter()
+                .filter_map(|object| match object {
+                    SchemaObject::Table(table) => Some(table),
+                    _ => None,
+                })
+                .filter(|table| {
+                    let key = QualifiedNameKey::from(&table.name);
+                    key != table_key && !dropped_tables.contains(&key)
+                })
+                .flat_map(|table| {
+                    let key = QualifiedNameKey::from(&table.name);
+                    let dropped_foreign_keys = &dropped_foreign_keys;
+                    let table_key = &table_key;
+                    table
+                        .foreign_keys
+                        .iter()
+                        .filter(move |fk| {
+                            QualifiedNameKey::from(&fk.referenced_table) == *table_key
+                                && !fk.name.as_ref().is_some_and(|name| {
+                                    dropped_foreign_keys
+                                        .contains(&(key.clone(), IdentKey::from(name)))
+                                })
+                        })
+                        .map(|fk| (table.name.clone(), fk.clone()))
+                })
+                .collect();
+        }
+    }
+}
+
+fn column_attributes_changed(desired: &Table, current: &Table, config: &DiffConfig) -> bool {
+    desired.columns.iter().any(|desired_column| {
+        let Some(current_column) = current
+            .columns
+            .iter()
+            .find(|current_column| same_column(desired_column, current_column))
+        else {
+            return false;
+        };
+
+        let generated_matches = match (&desired_column.generated, &current_column.generated) {
+            (Some(desired_generated), Some(current_generated)) => {
+                desired_generated.stored == current_generated.stored
+                    && exprs_equivalent(
+                        config.equivalence_policy.as_ref(),
+                        &desired_generated.expr,
+                        &current_generated.expr,
+                    )
+            }
+            (None, None) => true,
+            _ => false,
+        };
+
+        !generated_matches
+            || desired_column.collation != current_column.collation
+            || desired_column.identity != current_column.identity
+    })
+}
+
+/// The desired table, plus whatever the current table keeps because `enable_drop` is off.
+fn target_definition(desired: &Table, current: &Table, config: &DiffConfig) -> Table {
+    let mut target = desired.clone();
+    if config.enable_drop {
+        return target;
+    }
+
+    let kept_columns = current
+        .columns
+        .iter()
+        .filter(|current_column| {
+            !desired
+                .columns
+                .iter()
+                .any(|desired_column| same_column(desired_column, current_column))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    target.columns.extend(kept_columns);
+
+    if target.primary_key.is_none() {
+        target.primary_key = current.primary_key.clone();
+    }
+
+    let mut matched_current = vec![false; current.foreign_keys.len()];
+    for desired_fk in &desired.foreign_keys {
+        if let Some(position) =
+            matching_foreign_key(desired_fk, &current.foreign_keys, &matched_current)
+        {
+            matched_current[position] = true;
+        }
+    }
+    target.foreign_keys.extend(
+        current
+            .foreign_keys
+            .iter()
+            .zip(matched_current)
+            .filter(|(_, matched)| !matched)
+            .map(|(current_fk, _)| current_fk.clone()),
+    );
+
+    let kept_checks = current
+        .checks
+        .iter()
+        .filter(|current_check| match &current_check.name {
+            Some(current_name) => !desired.checks.iter().any(|desired_check| {
+                desired_check
+                    .name
+                    .as_ref()
+                    .is_some_and(|name| IdentKey::from(name) == IdentKey::from(current_name))
+            }),
+            None => !desired.checks.iter().any(|desired_check| {
+                desired_check.name.is_none()
+                    && exprs_equivalent(
+                        config.equivalence_policy.as_ref(),
+                        &desired_check.expr,
+                        &current_check.expr,
+                    )
+            }),
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    target.checks.extend(kept_checks);
+
+    target
+}
+
+fn same_column(desired: &Column, current: &Column) -> bool {
+    let current_key = IdentKey::from(&current.name);
+    IdentKey::from(&desired.name) == current_key
+        || desired
+            .renamed_from
+            .as_ref()
+            .is_some_and(|from| IdentKey::from(from) == current_key)
+}
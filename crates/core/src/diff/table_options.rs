@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+
+use crate::{DiffConfig, DiffOp, QualifiedName, TableOptions, Value, extra_keys, value_total_eq};
+
+pub(super) fn diff_table_options(
+    table: &QualifiedName,
+    desired: &TableOptions,
+    current: &TableOptions,
+    config: &DiffConfig,
+    ops: &mut Vec<DiffOp>,
+) {
+    let mut changed = BTreeMap::new();
+
+    for &key in config.diffed_table_options {
+        match (desired.extra.get(key), current.extra.get(key)) {
+            (Some(desired_value), Some(current_value)) => {
+                if !value_total_eq(desired_value, current_value) {
+                    changed.insert(key.to_string(), desired_value.clone());
+                }
+            }
+            (Some(desired_value), None) => {
+                changed.insert(key.to_string(), desired_value.clone());
+            }
+            // A key missing from the desired side resets the option to the server default,
+            // which the dialect generator spells out from `Value::Null`.
+            (None, Some(_)) => {
+                changed.insert(key.to_string(), Value::Null);
+            }
+            (None, None) => {}
+        }
+    }
+
+    if changed.is_empty() {
+        return;
+    }
+
+    ops.push(DiffOp::AlterTableOptions {
+        table: table.clone(),
+        options: TableOptions { extra: changed },
+    });
+}
+
+/// Whether an option from `extra_keys::RECREATED_TABLE_OPTIONS` differs, so the table
+/// cannot be altered in place.
+pub(super) fn requires_recreate(desired: &TableOptions, current: &TableOptions) -> bool {
+    extra_keys::RECREATED_TABLE_OPTIONS.iter().any(|key| {
+        match (desired.extra.get(*key), current.extra.get(*key)) {
+            (Some(desired_value), Some(current_value)) => {
+                !value_total_eq(desired_value, current_value)
+            }
+            (None, None) => false,
+            _ => true,
+        }
+    })
+}
@@ -1,8 +1,9 @@
 use crate::{
     CheckConstraint, Column, ColumnPosition, Comment, CommentTarget, DataType, Domain,
-    EnumValuePosition, ExclusionConstraint, Expr, Extension, ForeignKey, Function, GeneratedColumn,
-    Ident, Identity, IndexDef, IndexOwner, MaterializedView, Partition, Policy, PrimaryKey,
-    Privilege, QualifiedName, SchemaDef, Sequence, Table, TableOptions, Trigger, TypeDef, View,
+    EnumValuePosition, Event, ExclusionConstraint, Expr, Extension, ForeignKey, Function,
+    GeneratedColumn, Ident, Identity, IndexDef, IndexOwner, MaterializedView, Partition,
+    PartitionElement, Policy, Pragma, PrimaryKey, Privilege, QualifiedName, SchemaDef, Sequence,
+    Synonym, Table, TableOptions, Trigger, TypeDef, View,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -15,6 +16,28 @@ pub enum DiffOp {
         to: QualifiedName,
     },
 
+    /// The definition a changed table ends up with once the diff is applied, alongside
+    /// its current definition. Only emitted when `DiffConfig::emit_table_definitions`
+    /// is set; the table's own ops still describe what changed.
+    TableDefinition {
+        current: Box<Table>,
+        target: Box<Table>,
+        /// Current views reading from the table, directly or through other views, in
+        /// creation order. Views the diff drops on its own are left out.
+        dependent_views: Vec<View>,
+        /// Current indexes on the table that the diff neither drops nor renames, which
+        /// includes every index the table declares itself.
+        dependent_indexes: Vec<IndexDef>,
+        /// Indexes the target definition declares itself, such as SQLite `UNIQUE`
+        /// constraints; see `extra_keys::TABLE_DECLARED_INDEXES`.
+        declared_indexes: Vec<IndexDef>,
+        /// Current triggers on the table that the diff does not drop.
+        dependent_triggers: Vec<Trigger>,
+        /// Current foreign keys of other tables that reference the table, each with the
+        /// table declaring it. Foreign keys the diff drops on its own are left out.
+        referencing_foreign_keys: Vec<(QualifiedName, ForeignKey)>,
+    },
+
     // --- Column (scoped to a table) ---
     AddColumn {
         table: QualifiedName,
@@ -96,6 +119,18 @@ pub enum DiffOp {
         table: QualifiedName,
         name: Ident,
     },
+    /// Redistributes the rows of the `from` partitions into `into` without losing data.
+    ReorganizePartition {
+        table: QualifiedName,
+        from: Vec<Ident>,
+        into: Vec<PartitionElement>,
+    },
+    /// Grows or shrinks the partition count of a HASH/KEY partitioned table.
+    ChangePartitionCount {
+        table: QualifiedName,
+        from: usize,
+        to: usize,
+    },
 
     // --- View ---
     CreateView(View),
@@ -113,6 +148,10 @@ pub enum DiffOp {
         changes: Vec<SequenceChange>,
     },
 
+    // --- Synonym (SQL Server) ---
+    CreateSynonym(Synonym),
+    DropSynonym(QualifiedName),
+
     // --- Trigger ---
     CreateTrigger(Trigger),
     DropTrigger {
@@ -122,7 +161,16 @@ pub enum DiffOp {
 
     // --- Function ---
     CreateFunction(Function),
-    DropFunction(QualifiedName),
+    DropFunction {
+        name: QualifiedName,
+        /// Dialects that drop procedures and functions with different statements
+        /// need to know which one the current object was.
+        procedure: bool,
+    },
+
+    // --- Event (MySQL) ---
+    CreateEvent(Event),
+    DropEvent(QualifiedName),
 
     // --- Type (ENUM, composite) ---
     CreateType(TypeDef),
@@ -165,6 +213,9 @@ pub enum DiffOp {
         table: QualifiedName,
     },
 
+    // --- Pragma (SQLite) ---
+    SetPragma(Pragma),
+
     // --- Table Options ---
     AlterTableOptions {
         table: QualifiedName,
@@ -180,6 +231,7 @@ pub enum ColumnChange {
     SetIdentity(Option<Identity>),
     SetGenerated(Option<GeneratedColumn>),
     SetCollation(Option<String>),
+    SetPosition(ColumnPosition),
 }
 
 pub fn is_mysql_change_column_full_redefinition(changes: &[ColumnChange]) -> bool {
@@ -198,6 +250,7 @@ pub fn is_mysql_change_column_full_redefinition(changes: &[ColumnChange]) -> boo
             ColumnChange::SetIdentity(_) => has_set_identity = true,
             ColumnChange::SetGenerated(_) => has_set_generated = true,
             ColumnChange::SetCollation(_) => has_set_collation = true,
+            ColumnChange::SetPosition(_) => {}
         }
     }
 
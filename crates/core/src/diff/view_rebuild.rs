@@ -40,6 +40,29 @@ pub(super) fn build_view_rebuild_plan(
     }
 }
 
+/// Views reading from `table`, directly or through other views, in creation order.
+pub(super) fn dependent_view_order(
+    table: &QualifiedName,
+    views_by_key: &BTreeMap<QualifiedNameKey, &View>,
+) -> Vec<QualifiedNameKey> {
+    let roots = views_by_key
+        .iter()
+        .filter(|(_, view)| {
+            extract_relation_references(&view.query)
+                .iter()
+                .any(|reference| references_table(&view.name, reference, table))
+        })
+        .map(|(view_key, _)| view_key.clone())
+        .collect::<BTreeSet<_>>();
+    if roots.is_empty() {
+        return Vec::new();
+    }
+
+    let dependency_graph = build_dependency_graph(views_by_key);
+    let dependents = expand_rebuild_closure(&roots, &dependency_graph);
+    topological_order(&dependents, &dependency_graph)
+}
+
 fn changed_view_roots(
     desired_by_key: &BTreeMap<QualifiedNameKey, &View>,
     current_by_key: &BTreeMap<QualifiedNameKey, &View>,
@@ -312,6 +335,24 @@ fn resolve_view_reference(
     None
 }
 
+fn references_table(
+    source_view: &QualifiedName,
+    reference: &ViewReference,
+    table: &QualifiedName,
+) -> bool {
+    let table_key = QualifiedNameKey::from(table);
+    let schema = reference
+        .schema
+        .as_ref()
+        .or(source_view.schema.as_ref())
+        .cloned();
+    qualified_name_key(schema.clone(), reference.name.clone()) == table_key
+        || (schema.is_none()
+            && table.schema.is_some()
+            && qualified_name_key(None, reference.name.clone())
+                == qualified_name_key(None, table.name.clone()))
+}
+
 fn qualified_name_key(schema: Option<Ident>, name: Ident) -> QualifiedNameKey {
     QualifiedNameKey::from(&QualifiedName { schema, name })
 }
@@ -86,15 +86,17 @@ impl<'a> Executor<'a> {
                     context,
                 } => {
                     if let Some(transaction) = tx.as_mut() {
-                        transaction.execute(sql).map_err(|source| {
-                            Self::build_statement_failed(
-                                cursor,
-                                sql,
-                                context.as_ref(),
-                                *executed_statements,
-                                source,
-                            )
-                        })?;
+                        transaction
+                            .execute_with_context(sql, context.as_ref())
+                            .map_err(|source| {
+                                Self::build_statement_failed(
+                                    cursor,
+                                    sql,
+                                    context.as_ref(),
+                                    *executed_statements,
+                                    source,
+                                )
+                            })?;
                     }
                     *executed_statements += 1;
                     last_statement_index = cursor;
@@ -131,9 +133,11 @@ impl<'a> Executor<'a> {
         context: Option<&StatementContext>,
         executed_statements: &mut usize,
     ) -> Result<usize> {
-        self.adapter.execute(sql).map_err(|source| {
-            Self::build_statement_failed(start, sql, context, *executed_statements, source)
-        })?;
+        self.adapter
+            .execute_with_context(sql, context)
+            .map_err(|source| {
+                Self::build_statement_failed(start, sql, context, *executed_statements, source)
+            })?;
         *executed_statements += 1;
         Ok(start + 1)
     }
@@ -6,10 +6,50 @@ mod types;
 pub mod extra_keys {
     pub mod mysql {
         pub const AUTO_INCREMENT: &str = "mysql.auto_increment";
+        pub const TABLE_ENGINE: &str = "mysql.engine";
+        pub const TABLE_CHARSET: &str = "mysql.charset";
+        pub const TABLE_COLLATE: &str = "mysql.collate";
+        pub const TABLE_ROW_FORMAT: &str = "mysql.row_format";
+        pub const TABLE_COMMENT: &str = "mysql.comment";
     }
 
+    pub mod sqlite {
+        pub const TABLE_WITHOUT_ROWID: &str = "sqlite.without_rowid";
+        pub const TABLE_STRICT: &str = "sqlite.strict";
+        /// `Value::Bool(true)` on the index behind a `UNIQUE` constraint of `CREATE TABLE`,
+        /// column-level ones included. SQLite has no DDL to add or drop such a constraint.
+        pub const INDEX_UNIQUE_CONSTRAINT: &str = "sqlite.unique_constraint";
+        /// Conflict resolution (`ROLLBACK`, `ABORT`, `FAIL`, `IGNORE`, `REPLACE`) of the
+        /// primary key.
+        pub const TABLE_PRIMARY_KEY_CONFLICT: &str = "sqlite.primary_key_conflict";
+        /// Module of a `CREATE VIRTUAL TABLE ... USING`, e.g. `fts5`; only set on virtual
+        /// tables, which have no columns of their own.
+        pub const TABLE_VIRTUAL_MODULE: &str = "sqlite.virtual_module";
+        /// Module arguments of a virtual table as written, joined by `, `.
+        pub const TABLE_VIRTUAL_ARGUMENTS: &str = "sqlite.virtual_arguments";
+    }
+
+    /// Table option keys that can only be given when the table is created. A change to
+    /// any of them drops and recreates the table, which `enable_drop` gates like any
+    /// other drop since the rows go with it.
+    pub const RECREATED_TABLE_OPTIONS: [&str; 2] = [
+        sqlite::TABLE_VIRTUAL_MODULE,
+        sqlite::TABLE_VIRTUAL_ARGUMENTS,
+    ];
+
+    /// Index keys that mark an index its table declares. The diff engine never adds or
+    /// drops such an index on its own: it only changes through `DiffOp::TableDefinition`.
+    pub const TABLE_DECLARED_INDEXES: [&str; 1] = [sqlite::INDEX_UNIQUE_CONSTRAINT];
+
     pub mod mssql {
         pub const DEFAULT_CONSTRAINT_NAME: &str = "mssql.default_constraint_name";
+        /// Start and end columns of `PERIOD FOR SYSTEM_TIME`.
+        pub const TABLE_PERIOD_START: &str = "mssql.period_start";
+        pub const TABLE_PERIOD_END: &str = "mssql.period_end";
+        /// `Value::Bool(true)` while `SYSTEM_VERSIONING = ON`.
+        pub const TABLE_SYSTEM_VERSIONING: &str = "mssql.system_versioning";
+        /// `schema.name` of the history table, when one is named.
+        pub const TABLE_HISTORY_TABLE: &str = "mssql.history_table";
     }
 }
 
@@ -20,12 +60,12 @@ pub use expr::{
 pub use ident::{Ident, QualifiedName};
 pub use schema_object::{
     CheckConstraint, CheckOption, Column, ColumnPosition, Comment, CommentTarget, Deferrable,
-    Domain, EnumValuePosition, ExclusionConstraint, ExclusionElement, Extension, ForeignKey,
+    Domain, EnumValuePosition, Event, ExclusionConstraint, ExclusionElement, Extension, ForeignKey,
     ForeignKeyAction, Function, FunctionParam, FunctionParamMode, FunctionSecurity,
     GeneratedColumn, Identity, IndexColumn, IndexDef, IndexOwner, MaterializedView, NullsOrder,
-    Partition, PartitionBound, PartitionElement, PartitionStrategy, Policy, PolicyCommand,
+    Partition, PartitionBound, PartitionElement, PartitionStrategy, Policy, PolicyCommand, Pragma,
     PrimaryKey, Privilege, PrivilegeObject, PrivilegeOp, SchemaDef, SchemaObject, Sequence,
-    SortOrder, Table, TableOptions, Trigger, TriggerEvent, TriggerForEach, TriggerTiming, TypeDef,
-    TypeKind, View, ViewSecurity, Volatility,
+    SortOrder, Synonym, Table, TableOptions, Trigger, TriggerEvent, TriggerForEach, TriggerTiming,
+    TypeDef, TypeKind, View, ViewSecurity, Volatility,
 };
 pub use types::{DataType, Value, float_total_cmp, value_total_eq};
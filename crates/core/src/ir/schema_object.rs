@@ -9,8 +9,10 @@ pub enum SchemaObject {
     MaterializedView(MaterializedView),
     Index(IndexDef),
     Sequence(Sequence),
+    Synonym(Synonym),
     Trigger(Trigger),
     Function(Function),
+    Event(Event),
     Type(TypeDef),
     Domain(Domain),
     Extension(Extension),
@@ -18,6 +20,7 @@ pub enum SchemaObject {
     Comment(Comment),
     Privilege(Privilege),
     Policy(Policy),
+    Pragma(Pragma),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -269,6 +272,14 @@ pub struct Sequence {
     pub owned_by: Option<(QualifiedName, Ident)>,
 }
 
+/// A SQL Server `CREATE SYNONYM name FOR target`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Synonym {
+    pub name: QualifiedName,
+    /// `[server.][database.][schema.]object`, one entry per part as written.
+    pub target: Vec<Ident>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ColumnPosition {
     First,
@@ -281,6 +292,8 @@ pub struct Trigger {
     pub table: QualifiedName,
     pub timing: TriggerTiming,
     pub events: Vec<TriggerEvent>,
+    /// Columns named by `UPDATE OF`; empty when the trigger fires on any update.
+    pub update_columns: Vec<Ident>,
     pub for_each: TriggerForEach,
     pub when_clause: Option<Expr>,
     pub body: String,
@@ -318,6 +331,19 @@ pub struct Function {
     pub security: Option<FunctionSecurity>,
 }
 
+/// A MySQL scheduled event: `CREATE EVENT name ON SCHEDULE schedule DO body`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub name: QualifiedName,
+    /// The `ON SCHEDULE` clause without its keywords, as written.
+    pub schedule: String,
+    /// `ON COMPLETION PRESERVE` keeps a one-time event after it has run.
+    pub preserve: bool,
+    pub enabled: bool,
+    pub comment: Option<String>,
+    pub body: String,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FunctionParam {
     pub name: Option<Ident>,
@@ -355,9 +381,21 @@ pub struct TypeDef {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TypeKind {
-    Enum { labels: Vec<String> },
-    Composite { fields: Vec<(Ident, DataType)> },
-    Range { subtype: DataType },
+    Enum {
+        labels: Vec<String>,
+    },
+    Composite {
+        fields: Vec<(Ident, DataType)>,
+    },
+    Range {
+        subtype: DataType,
+    },
+    /// A SQL Server table type, used for table-valued parameters.
+    Table {
+        columns: Vec<Column>,
+        primary_key: Option<PrimaryKey>,
+        checks: Vec<CheckConstraint>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -382,6 +420,14 @@ pub struct Extension {
     pub version: Option<String>,
 }
 
+/// A persistent SQLite `PRAGMA name = value` setting. `name.schema` is the database it
+/// applies to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pragma {
+    pub name: QualifiedName,
+    pub value: Value,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SchemaDef {
     pub name: Ident,
@@ -31,6 +31,10 @@ pub enum DataType {
     Jsonb,
     Uuid,
     Array(Box<DataType>),
+    /// Inline label list of a MySQL `ENUM(...)` column, in declaration order.
+    Enum(Vec<String>),
+    /// Inline label list of a MySQL `SET(...)` column, in declaration order.
+    Set(Vec<String>),
     Custom(String),
 }
 
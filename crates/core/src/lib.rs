@@ -16,17 +16,17 @@ mod statement;
 
 pub use adapter::{DatabaseAdapter, Transaction};
 pub use annotation::{
-    AnnotationAttachment, AnnotationExtractor, AnnotationTarget, RenameAnnotation,
-    attach_annotations,
+    AnnotationAttachment, AnnotationExtractor, AnnotationTarget, BackfillAnnotation,
+    RenameAnnotation, attach_annotations, attach_backfills,
 };
 pub use config::{ConnectionConfig, Version};
 pub use dialect::Dialect;
 pub use diff::{
     ColumnChange, DEFAULT_EQUIVALENCE_POLICY, DefaultEquivalencePolicy, DiffConfig,
-    DiffDiagnostics, DiffEngine, DiffOp, DiffOutcome, DomainChange, EquivalencePolicy,
-    EquivalencePolicyContractError, SequenceChange, SkippedOpDiagnostic, SkippedOpKind, TypeChange,
-    custom_types_equivalent, exprs_equivalent, is_mysql_change_column_full_redefinition,
-    verify_equivalence_policy_contract,
+    DiffDiagnostics, DiffEngine, DiffOp, DiffOutcome, DiffWarning, DomainChange, EquivalencePolicy,
+    EquivalencePolicyContractError, PartitionChangeStrategy, SequenceChange, SkippedOpDiagnostic,
+    SkippedOpKind, TypeChange, custom_types_equivalent, exprs_equivalent,
+    is_mysql_change_column_full_redefinition, verify_equivalence_policy_contract,
 };
 pub use error::{
     DiffError, Error, ExecutionError, GenerateError, ParseError, Result, SourceLocation,
@@ -34,15 +34,15 @@ pub use error::{
 pub use executor::Executor;
 pub use ir::{
     BinaryOperator, CheckConstraint, CheckOption, Column, ColumnPosition, Comment, CommentTarget,
-    ComparisonOp, DataType, Deferrable, Domain, EnumValuePosition, ExclusionConstraint,
+    ComparisonOp, DataType, Deferrable, Domain, EnumValuePosition, Event, ExclusionConstraint,
     ExclusionElement, Expr, Extension, ForeignKey, ForeignKeyAction, Function, FunctionParam,
     FunctionParamMode, FunctionSecurity, GeneratedColumn, Ident, Identity, IndexColumn, IndexDef,
     IndexOwner, IsTest, Literal, MaterializedView, NullsOrder, Partition, PartitionBound,
-    PartitionElement, PartitionStrategy, Policy, PolicyCommand, PrimaryKey, Privilege,
+    PartitionElement, PartitionStrategy, Policy, PolicyCommand, Pragma, PrimaryKey, Privilege,
     PrivilegeObject, PrivilegeOp, QualifiedName, SchemaDef, SchemaObject, Sequence, SetQuantifier,
-    SortOrder, SubQuery, Table, TableOptions, Trigger, TriggerEvent, TriggerForEach, TriggerTiming,
-    TypeDef, TypeKind, UnaryOperator, Value, View, ViewSecurity, Volatility, WindowSpec,
-    extra_keys, float_total_cmp, value_total_eq,
+    SortOrder, SubQuery, Synonym, Table, TableOptions, Trigger, TriggerEvent, TriggerForEach,
+    TriggerTiming, TypeDef, TypeKind, UnaryOperator, Value, View, ViewSecurity, Volatility,
+    WindowSpec, extra_keys, float_total_cmp, value_total_eq,
 };
 pub use orchestrator::{Mode, Orchestrator, OrchestratorOptions, OrchestratorOutput};
 pub use plan::{DdlPlan, DdlPlanner, build_ddl_plan, sort_diff_ops};
@@ -17,6 +17,7 @@ pub enum Mode {
 pub struct OrchestratorOptions {
     pub mode: Mode,
     pub enable_drop: bool,
+    pub enable_column_reorder: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -53,15 +54,16 @@ impl<'a> Orchestrator<'a> {
             Mode::Apply | Mode::DryRun => {
                 let current = self.parse_and_normalize(&current_sql)?;
                 let desired = self.parse_and_normalize(desired_sql)?;
-                let diff_config = self.diff_config(adapter.as_ref(), options.enable_drop);
+                let diff_config = self.diff_config(adapter.as_ref(), options);
                 let diff_outcome =
                     self.diff_engine
                         .diff_with_diagnostics(&desired, &current, &diff_config)?;
                 let statements = self.dialect.generate_ddl(&diff_outcome.ops)?;
 
                 if options.mode == Mode::Apply {
-                    let mut executor = Executor::new(adapter.as_mut());
-                    executor.execute_plan(&statements)?;
+                    adapter.prepare_apply(&statements)?;
+                    let outcome = Executor::new(adapter.as_mut()).execute_plan(&statements);
+                    adapter.finish_apply(outcome)?;
                     Ok(OrchestratorOutput::Applied)
                 } else {
                     let rendered = self.render_dry_run(&statements, &diff_outcome.diagnostics);
@@ -89,14 +91,24 @@ impl<'a> Orchestrator<'a> {
         self.render_export(&objects)
     }
 
-    fn diff_config(&self, adapter: &dyn DatabaseAdapter, enable_drop: bool) -> DiffConfig {
-        DiffConfig::new(
-            enable_drop,
+    fn diff_config(
+        &self,
+        adapter: &dyn DatabaseAdapter,
+        options: OrchestratorOptions,
+    ) -> DiffConfig {
+        let mut config = DiffConfig::new(
+            options.enable_drop,
             adapter.schema_search_path(),
             Arc::new(DelegatingEquivalencePolicy {
                 inner: self.dialect.equivalence_policy(),
             }),
-        )
+        );
+        config.enable_column_reorder = options.enable_column_reorder;
+        config.partition_change_strategy = self.dialect.partition_change_strategy();
+        config.emit_table_definitions = self.dialect.requires_table_definitions();
+        config.diff_table_constraints = self.dialect.diffs_table_constraints();
+        config.diffed_table_options = self.dialect.diffed_table_options();
+        config
     }
 
     fn render_dry_run(&self, statements: &[Statement], diagnostics: &DiffDiagnostics) -> String {
@@ -104,16 +116,27 @@ impl<'a> Orchestrator<'a> {
         let mut rendered = String::new();
 
         renderer.render_skipped_diagnostics(&mut rendered, &skipped_messages(diagnostics));
+        renderer.render_warning_diagnostics(&mut rendered, &warning_messages(diagnostics));
         rendered.push_str(&renderer.render(statements));
 
         rendered
     }
 
+    /// Each object ends its own batch, so modules that must start one (as in MSSQL)
+    /// read back.
     fn render_export(&self, objects: &[SchemaObject]) -> Result<String> {
+        let renderer = Renderer::new(self.dialect);
         let mut rendered = String::new();
         for object in objects {
-            rendered.push_str(&self.dialect.to_sql(object)?);
+            // Objects another statement declares, such as constraints kept as
+            // indexes, render to nothing.
+            let sql = self.dialect.to_sql(object)?;
+            if sql.is_empty() {
+                continue;
+            }
+            rendered.push_str(&sql);
             rendered.push('\n');
+            renderer.push_batch_separator(&mut rendered);
         }
         Ok(rendered)
     }
@@ -133,6 +156,14 @@ impl EquivalencePolicy for DelegatingEquivalencePolicy {
     }
 }
 
+fn warning_messages(diagnostics: &DiffDiagnostics) -> Vec<String> {
+    diagnostics
+        .warnings
+        .iter()
+        .map(|warning| format!("{}: {}", warning.target, warning.message))
+        .collect()
+}
+
 fn skipped_messages(diagnostics: &DiffDiagnostics) -> Vec<String> {
     diagnostics
         .skipped_ops
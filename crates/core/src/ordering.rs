@@ -13,33 +13,36 @@ enum PriorityGroup {
     DropPolicy = 1,
     DropTrigger = 2,
     DropView = 3,
-    DropForeignKey = 4,
-    DropIndex = 5,
-    DropTable = 6,
-    DropSequence = 7,
-    DropDomain = 8,
-    DropType = 9,
-    DropFunction = 10,
-    DropSchema = 11,
-    DropExtension = 12,
-    CreateExtension = 13,
-    CreateSchema = 14,
-    CreateType = 15,
-    AlterType = 16,
-    CreateDomain = 17,
-    AlterDomain = 18,
-    CreateSequence = 19,
-    AlterSequence = 20,
-    CreateTable = 21,
-    TableScoped = 22,
-    AddForeignKey = 23,
-    CreateView = 24,
-    CreateMaterializedView = 25,
-    AddIndex = 26,
-    CreateTriggerOrFunction = 27,
-    CreatePolicy = 28,
-    Comment = 29,
-    Privilege = 30,
+    DropSynonym = 4,
+    DropForeignKey = 5,
+    DropIndex = 6,
+    DropTable = 7,
+    DropSequence = 8,
+    DropDomain = 9,
+    DropType = 10,
+    DropFunction = 11,
+    DropSchema = 12,
+    DropExtension = 13,
+    CreateExtension = 14,
+    CreateSchema = 15,
+    CreateType = 16,
+    AlterType = 17,
+    CreateDomain = 18,
+    AlterDomain = 19,
+    CreateSequence = 20,
+    AlterSequence = 21,
+    CreateTable = 22,
+    TableScoped = 23,
+    AddForeignKey = 24,
+    CreateSynonym = 25,
+    CreateView = 26,
+    CreateMaterializedView = 27,
+    AddIndex = 28,
+    CreateTriggerOrFunction = 29,
+    CreatePolicy = 30,
+    Comment = 31,
+    Privilege = 32,
+    SetPragma = 33,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -53,6 +56,7 @@ enum TableSubPriority {
     Constraints = 6,
     Partition = 7,
     TableOptions = 8,
+    Definition = 9,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -118,15 +122,17 @@ pub fn sort_diff_ops(ops: Vec<DiffOp>) -> Vec<DiffOp> {
 fn priority_group(op: &DiffOp) -> PriorityGroup {
     match op {
         DiffOp::DropPolicy { .. } => PriorityGroup::DropPolicy,
-        DiffOp::DropTrigger { .. } => PriorityGroup::DropTrigger,
+        // Events run statements against tables, so they go with triggers.
+        DiffOp::DropTrigger { .. } | DiffOp::DropEvent(_) => PriorityGroup::DropTrigger,
         DiffOp::DropView(_) | DiffOp::DropMaterializedView(_) => PriorityGroup::DropView,
+        DiffOp::DropSynonym(_) => PriorityGroup::DropSynonym,
         DiffOp::DropForeignKey { .. } => PriorityGroup::DropForeignKey,
         DiffOp::DropIndex { .. } => PriorityGroup::DropIndex,
         DiffOp::DropTable(_) => PriorityGroup::DropTable,
         DiffOp::DropSequence(_) => PriorityGroup::DropSequence,
         DiffOp::DropDomain(_) => PriorityGroup::DropDomain,
         DiffOp::DropType(_) => PriorityGroup::DropType,
-        DiffOp::DropFunction(_) => PriorityGroup::DropFunction,
+        DiffOp::DropFunction { .. } => PriorityGroup::DropFunction,
         DiffOp::DropSchema(_) => PriorityGroup::DropSchema,
         DiffOp::DropExtension(_) => PriorityGroup::DropExtension,
         DiffOp::CreateExtension(_) => PriorityGroup::CreateExtension,
@@ -151,17 +157,22 @@ fn priority_group(op: &DiffOp) -> PriorityGroup {
         | DiffOp::DropExclusion { .. }
         | DiffOp::AddPartition { .. }
         | DiffOp::DropPartition { .. }
-        | DiffOp::AlterTableOptions { .. } => PriorityGroup::TableScoped,
+        | DiffOp::ReorganizePartition { .. }
+        | DiffOp::ChangePartitionCount { .. }
+        | DiffOp::AlterTableOptions { .. }
+        | DiffOp::TableDefinition { .. } => PriorityGroup::TableScoped,
         DiffOp::AddForeignKey { .. } => PriorityGroup::AddForeignKey,
+        DiffOp::CreateSynonym(_) => PriorityGroup::CreateSynonym,
         DiffOp::CreateView(_) => PriorityGroup::CreateView,
         DiffOp::CreateMaterializedView(_) => PriorityGroup::CreateMaterializedView,
         DiffOp::AddIndex(_) | DiffOp::RenameIndex { .. } => PriorityGroup::AddIndex,
-        DiffOp::CreateTrigger(_) | DiffOp::CreateFunction(_) => {
+        DiffOp::CreateTrigger(_) | DiffOp::CreateFunction(_) | DiffOp::CreateEvent(_) => {
             PriorityGroup::CreateTriggerOrFunction
         }
         DiffOp::CreatePolicy(_) => PriorityGroup::CreatePolicy,
         DiffOp::SetComment(_) | DiffOp::DropComment { .. } => PriorityGroup::Comment,
         DiffOp::Grant(_) | DiffOp::Revoke(_) => PriorityGroup::Privilege,
+        DiffOp::SetPragma(_) => PriorityGroup::SetPragma,
     }
 }
 
@@ -206,7 +217,10 @@ fn table_key_for_table_scoped_op(op: &DiffOp) -> Option<QualifiedNameKey> {
         | DiffOp::DropExclusion { table, .. }
         | DiffOp::AddPartition { table, .. }
         | DiffOp::DropPartition { table, .. }
+        | DiffOp::ReorganizePartition { table, .. }
+        | DiffOp::ChangePartitionCount { table, .. }
         | DiffOp::AlterTableOptions { table, .. } => Some(QualifiedNameKey::from(table)),
+        DiffOp::TableDefinition { target, .. } => Some(QualifiedNameKey::from(&target.name)),
         _ => None,
     }
 }
@@ -225,8 +239,12 @@ fn table_sub_priority(op: &DiffOp) -> TableSubPriority {
         | DiffOp::DropCheck { .. }
         | DiffOp::AddExclusion { .. }
         | DiffOp::DropExclusion { .. } => TableSubPriority::Constraints,
-        DiffOp::AddPartition { .. } | DiffOp::DropPartition { .. } => TableSubPriority::Partition,
+        DiffOp::AddPartition { .. }
+        | DiffOp::DropPartition { .. }
+        | DiffOp::ReorganizePartition { .. }
+        | DiffOp::ChangePartitionCount { .. } => TableSubPriority::Partition,
         DiffOp::AlterTableOptions { .. } => TableSubPriority::TableOptions,
+        DiffOp::TableDefinition { .. } => TableSubPriority::Definition,
         _ => TableSubPriority::TableOptions,
     }
 }
@@ -1,6 +1,7 @@
 use crate::{Dialect, Statement};
 
 const SKIPPED_DIAGNOSTICS_HEADER: &str = "-- Skipped operations (enable_drop=false):";
+const WARNING_DIAGNOSTICS_HEADER: &str = "-- Warnings:";
 
 pub struct Renderer<'a> {
     dialect: &'a dyn Dialect,
@@ -30,7 +31,7 @@ impl<'a> Renderer<'a> {
         rendered
     }
 
-    fn push_batch_separator(&self, rendered: &mut String) {
+    pub(crate) fn push_batch_separator(&self, rendered: &mut String) {
         let separator = self.dialect.batch_separator();
         if separator.is_empty() {
             return;
@@ -56,6 +57,20 @@ impl<'a> Renderer<'a> {
         rendered.push('\n');
     }
 
+    pub(crate) fn render_warning_diagnostics(&self, rendered: &mut String, diagnostics: &[String]) {
+        if diagnostics.is_empty() {
+            return;
+        }
+
+        self.render_diagnostics_header(rendered, WARNING_DIAGNOSTICS_HEADER);
+        for message in diagnostics {
+            rendered.push_str("-- Warning: ");
+            rendered.push_str(message);
+            rendered.push('\n');
+        }
+        rendered.push('\n');
+    }
+
     fn render_diagnostics_header(&self, rendered: &mut String, header: &str) {
         rendered.push_str(header);
         rendered.push('\n');
@@ -20,10 +20,15 @@ pub enum StatementContext {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SqliteRebuildStep {
+    DisableForeignKeys,
     CreateShadowTable,
     CopyData,
+    DropDependentViews,
     DropOldTable,
     RenameShadowTable,
+    RecreateDependentViews,
     RecreateIndexes,
     RecreateTriggers,
+    CheckForeignKeys,
+    RestoreForeignKeys,
 }
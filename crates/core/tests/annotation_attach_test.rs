@@ -1,8 +1,9 @@
 use std::collections::BTreeMap;
 
 use stateql_core::{
-    AnnotationAttachment, AnnotationTarget, Column, DataType, DiffError, Error, Expr, Ident,
-    Literal, QualifiedName, RenameAnnotation, SchemaObject, Table, attach_annotations,
+    AnnotationAttachment, AnnotationTarget, BackfillAnnotation, Column, DataType, DiffError, Error,
+    Expr, Ident, Literal, ParseError, QualifiedName, RenameAnnotation, SchemaObject, Table, Value,
+    attach_annotations, attach_backfills,
 };
 
 fn qualified(name: &str) -> QualifiedName {
@@ -98,3 +99,54 @@ fn orphan_annotations_fail_fast_without_mutating_targets() {
     };
     assert_eq!(table.renamed_from, None);
 }
+
+#[test]
+fn backfills_attach_to_the_column_on_their_line_or_fail_fast() {
+    let mut objects = vec![SchemaObject::Table(make_table("users", &["id", "slug"]))];
+    let attachments = vec![
+        AnnotationAttachment {
+            line: 1,
+            target: AnnotationTarget::Table(qualified("users")),
+        },
+        AnnotationAttachment {
+            line: 3,
+            target: AnnotationTarget::TableColumn {
+                table: qualified("users"),
+                column: Ident::unquoted("slug"),
+            },
+        },
+    ];
+    let backfill = |line| BackfillAnnotation {
+        line,
+        expr: "'user-' || id".to_string(),
+    };
+
+    let error = attach_backfills(
+        &mut objects,
+        &[backfill(3), backfill(1)],
+        &attachments,
+        "backfill",
+    )
+    .expect_err("a backfill on a table line has no column");
+    assert!(matches!(
+        error,
+        Error::Parse(ParseError::StatementConversion {
+            source_location: Some(ref location),
+            ..
+        }) if location.line == 1
+    ));
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    assert!(table.columns[1].extra.is_empty());
+
+    attach_backfills(&mut objects, &[backfill(3)], &attachments, "backfill")
+        .expect("attach backfill");
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    assert_eq!(
+        table.columns[1].extra.get("backfill"),
+        Some(&Value::String("'user-' || id".to_string()))
+    );
+}
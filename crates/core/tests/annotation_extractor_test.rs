@@ -1,4 +1,6 @@
-use stateql_core::{AnnotationExtractor, Ident, RenameAnnotation};
+use stateql_core::{
+    AnnotationExtractor, BackfillAnnotation, Error, Ident, ParseError, RenameAnnotation,
+};
 
 #[test]
 fn extracts_renamed_annotations_from_comments_only_and_preserves_lines() {
@@ -40,3 +42,42 @@ fn accepts_deprecated_rename_alias_for_future_warning_handling() {
     );
     assert!(!clean_sql.contains("@rename from"));
 }
+
+#[test]
+fn extracts_backfill_annotations_as_the_rest_of_their_comment() {
+    let sql = concat!(
+        "CREATE TABLE users (\n",
+        "  note text DEFAULT '-- @backfill literal',\n",
+        "  slug text NOT NULL -- @backfill 'user-' || id\n",
+        ");\n"
+    );
+
+    let (clean_sql, annotations) =
+        AnnotationExtractor::extract_backfills(sql).expect("extract backfill annotations");
+
+    assert_eq!(
+        annotations,
+        vec![BackfillAnnotation {
+            line: 3,
+            expr: "'user-' || id".to_string(),
+        }]
+    );
+    assert_eq!(sql.lines().count(), clean_sql.lines().count());
+    assert!(clean_sql.contains("'-- @backfill literal'"));
+    assert!(!clean_sql.contains("'user-' || id"));
+}
+
+#[test]
+fn backfill_annotation_without_expression_is_a_parse_error() {
+    let sql = "CREATE TABLE users (\n  slug text NOT NULL -- @backfill\n);\n";
+
+    let error = AnnotationExtractor::extract_backfills(sql)
+        .expect_err("an empty backfill must not be ignored");
+
+    match error {
+        Error::Parse(ParseError::StatementConversion {
+            source_location, ..
+        }) => assert_eq!(source_location.map(|location| location.line), Some(2)),
+        other => panic!("expected a parse error, got {other:?}"),
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+
+use stateql_core::{
+    Column, ColumnChange, ColumnPosition, DataType, DiffConfig, DiffEngine, DiffOp, Ident,
+    QualifiedName, SchemaObject, Table,
+};
+
+fn ident(value: &str) -> Ident {
+    Ident::unquoted(value)
+}
+
+fn users() -> QualifiedName {
+    QualifiedName {
+        schema: None,
+        name: ident("users"),
+    }
+}
+
+fn column(name: &str) -> Column {
+    Column {
+        name: ident(name),
+        data_type: DataType::Integer,
+        not_null: false,
+        default: None,
+        identity: None,
+        generated: None,
+        comment: None,
+        collation: None,
+        renamed_from: None,
+        extra: BTreeMap::new(),
+    }
+}
+
+fn users_table(columns: &[&str]) -> SchemaObject {
+    let mut table = Table::named("users");
+    table.columns = columns.iter().map(|name| column(name)).collect();
+    SchemaObject::Table(table)
+}
+
+fn reorder_config() -> DiffConfig {
+    DiffConfig {
+        enable_column_reorder: true,
+        ..DiffConfig::default()
+    }
+}
+
+fn moved_to(name: &str, position: ColumnPosition) -> DiffOp {
+    DiffOp::AlterColumn {
+        table: users(),
+        column: ident(name),
+        changes: vec![
+            ColumnChange::SetType(DataType::Integer),
+            ColumnChange::SetNotNull(false),
+            ColumnChange::SetDefault(None),
+            ColumnChange::SetIdentity(None),
+            ColumnChange::SetGenerated(None),
+            ColumnChange::SetCollation(None),
+            ColumnChange::SetPosition(position),
+        ],
+    }
+}
+
+#[test]
+fn column_order_is_ignored_by_default() {
+    let desired = vec![users_table(&["b", "a", "c"])];
+    let current = vec![users_table(&["a", "b", "c"])];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &DiffConfig::default())
+        .expect("diff should succeed");
+
+    assert!(ops.is_empty(), "reordering must be opt-in: {ops:?}");
+}
+
+#[test]
+fn only_columns_outside_the_ordered_run_are_moved() {
+    let desired = vec![users_table(&["b", "c", "d", "a"])];
+    let current = vec![users_table(&["a", "b", "c", "d"])];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorder_config())
+        .expect("diff should succeed");
+
+    assert_eq!(ops, vec![moved_to("a", ColumnPosition::After(ident("d")))]);
+}
+
+#[test]
+fn moves_are_emitted_in_desired_order_with_first_for_the_leading_column() {
+    let desired = vec![users_table(&["d", "c", "a", "b"])];
+    let current = vec![users_table(&["a", "b", "c", "d"])];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorder_config())
+        .expect("diff should succeed");
+
+    assert_eq!(
+        ops,
+        vec![
+            moved_to("d", ColumnPosition::First),
+            moved_to("c", ColumnPosition::After(ident("d"))),
+        ]
+    );
+}
+
+#[test]
+fn added_columns_are_positioned_after_their_desired_predecessor() {
+    let desired = vec![users_table(&["id", "first", "second", "email"])];
+    let current = vec![users_table(&["id", "email"])];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorder_config())
+        .expect("diff should succeed");
+
+    assert_eq!(
+        ops,
+        vec![
+            DiffOp::AddColumn {
+                table: users(),
+                column: Box::new(column("first")),
+                position: Some(ColumnPosition::After(ident("id"))),
+            },
+            DiffOp::AddColumn {
+                table: users(),
+                column: Box::new(column("second")),
+                position: Some(ColumnPosition::After(ident("first"))),
+            },
+        ]
+    );
+}
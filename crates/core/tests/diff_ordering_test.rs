@@ -86,26 +86,27 @@ fn by_tag() -> BTreeMap<&'static str, DiffOp> {
 fn design_priority(op: &DiffOp) -> u8 {
     match op {
         DiffOp::DropPolicy { .. } => 1,
-        DiffOp::DropTrigger { .. } => 2,
+        DiffOp::DropTrigger { .. } | DiffOp::DropEvent(_) => 2,
         DiffOp::DropView(_) | DiffOp::DropMaterializedView(_) => 3,
-        DiffOp::DropForeignKey { .. } => 4,
-        DiffOp::DropIndex { .. } => 5,
-        DiffOp::DropTable(_) => 6,
-        DiffOp::DropSequence(_) => 7,
-        DiffOp::DropDomain(_) => 8,
-        DiffOp::DropType(_) => 9,
-        DiffOp::DropFunction(_) => 10,
-        DiffOp::DropSchema(_) => 11,
-        DiffOp::DropExtension(_) => 12,
-        DiffOp::CreateExtension(_) => 13,
-        DiffOp::CreateSchema(_) => 14,
-        DiffOp::CreateType(_) => 15,
-        DiffOp::AlterType { .. } => 16,
-        DiffOp::CreateDomain(_) => 17,
-        DiffOp::AlterDomain { .. } => 18,
-        DiffOp::CreateSequence(_) => 19,
-        DiffOp::AlterSequence { .. } => 20,
-        DiffOp::CreateTable(_) => 21,
+        DiffOp::DropSynonym(_) => 4,
+        DiffOp::DropForeignKey { .. } => 5,
+        DiffOp::DropIndex { .. } => 6,
+        DiffOp::DropTable(_) => 7,
+        DiffOp::DropSequence(_) => 8,
+        DiffOp::DropDomain(_) => 9,
+        DiffOp::DropType(_) => 10,
+        DiffOp::DropFunction { .. } => 11,
+        DiffOp::DropSchema(_) => 12,
+        DiffOp::DropExtension(_) => 13,
+        DiffOp::CreateExtension(_) => 14,
+        DiffOp::CreateSchema(_) => 15,
+        DiffOp::CreateType(_) => 16,
+        DiffOp::AlterType { .. } => 17,
+        DiffOp::CreateDomain(_) => 18,
+        DiffOp::AlterDomain { .. } => 19,
+        DiffOp::CreateSequence(_) => 20,
+        DiffOp::AlterSequence { .. } => 21,
+        DiffOp::CreateTable(_) => 22,
         DiffOp::RenameTable { .. }
         | DiffOp::RenameColumn { .. }
         | DiffOp::AlterColumn { .. }
@@ -119,53 +120,61 @@ fn design_priority(op: &DiffOp) -> u8 {
         | DiffOp::DropExclusion { .. }
         | DiffOp::AddPartition { .. }
         | DiffOp::DropPartition { .. }
-        | DiffOp::AlterTableOptions { .. } => 22,
-        DiffOp::AddForeignKey { .. } => 23,
-        DiffOp::CreateView(_) => 24,
-        DiffOp::CreateMaterializedView(_) => 25,
-        DiffOp::AddIndex(_) | DiffOp::RenameIndex { .. } => 26,
-        DiffOp::CreateTrigger(_) | DiffOp::CreateFunction(_) => 27,
-        DiffOp::CreatePolicy(_) => 28,
-        DiffOp::SetComment(_) | DiffOp::DropComment { .. } => 29,
-        DiffOp::Grant(_) | DiffOp::Revoke(_) => 30,
+        | DiffOp::ReorganizePartition { .. }
+        | DiffOp::ChangePartitionCount { .. }
+        | DiffOp::AlterTableOptions { .. }
+        | DiffOp::TableDefinition { .. } => 23,
+        DiffOp::AddForeignKey { .. } => 24,
+        DiffOp::CreateSynonym(_) => 25,
+        DiffOp::CreateView(_) => 26,
+        DiffOp::CreateMaterializedView(_) => 27,
+        DiffOp::AddIndex(_) | DiffOp::RenameIndex { .. } => 28,
+        DiffOp::CreateTrigger(_) | DiffOp::CreateFunction(_) | DiffOp::CreateEvent(_) => 29,
+        DiffOp::CreatePolicy(_) => 30,
+        DiffOp::SetComment(_) | DiffOp::DropComment { .. } => 31,
+        DiffOp::Grant(_) | DiffOp::Revoke(_) => 32,
+        DiffOp::SetPragma(_) => 33,
     }
 }
 
 #[test]
-fn sorts_using_design_priority_groups_1_through_30() {
+fn sorts_using_design_priority_groups_1_through_33() {
     let ops_by_tag = by_tag();
 
     let design_fixture: Vec<(u8, &'static [&'static str])> = vec![
         (1, &["DropPolicy"]),
-        (2, &["DropTrigger"]),
+        (2, &["DropTrigger", "DropEvent"]),
         (3, &["DropView", "DropMaterializedView"]),
-        (4, &["DropForeignKey"]),
-        (5, &["DropIndex"]),
-        (6, &["DropTable"]),
-        (7, &["DropSequence"]),
-        (8, &["DropDomain"]),
-        (9, &["DropType"]),
-        (10, &["DropFunction"]),
-        (11, &["DropSchema"]),
-        (12, &["DropExtension"]),
-        (13, &["CreateExtension"]),
-        (14, &["CreateSchema"]),
-        (15, &["CreateType"]),
-        (16, &["AlterType"]),
-        (17, &["CreateDomain"]),
-        (18, &["AlterDomain"]),
-        (19, &["CreateSequence"]),
-        (20, &["AlterSequence"]),
-        (21, &["CreateTable"]),
-        (22, &["RenameTable"]),
-        (23, &["AddForeignKey"]),
-        (24, &["CreateView"]),
-        (25, &["CreateMaterializedView"]),
-        (26, &["AddIndex", "RenameIndex"]),
-        (27, &["CreateTrigger", "CreateFunction"]),
-        (28, &["CreatePolicy"]),
-        (29, &["SetComment", "DropComment"]),
-        (30, &["Grant", "Revoke"]),
+        (4, &["DropSynonym"]),
+        (5, &["DropForeignKey"]),
+        (6, &["DropIndex"]),
+        (7, &["DropTable"]),
+        (8, &["DropSequence"]),
+        (9, &["DropDomain"]),
+        (10, &["DropType"]),
+        (11, &["DropFunction"]),
+        (12, &["DropSchema"]),
+        (13, &["DropExtension"]),
+        (14, &["CreateExtension"]),
+        (15, &["CreateSchema"]),
+        (16, &["CreateType"]),
+        (17, &["AlterType"]),
+        (18, &["CreateDomain"]),
+        (19, &["AlterDomain"]),
+        (20, &["CreateSequence"]),
+        (21, &["AlterSequence"]),
+        (22, &["CreateTable"]),
+        (23, &["RenameTable"]),
+        (24, &["AddForeignKey"]),
+        (25, &["CreateSynonym"]),
+        (26, &["CreateView"]),
+        (27, &["CreateMaterializedView"]),
+        (28, &["AddIndex", "RenameIndex"]),
+        (29, &["CreateTrigger", "CreateFunction", "CreateEvent"]),
+        (30, &["CreatePolicy"]),
+        (31, &["SetComment", "DropComment"]),
+        (32, &["Grant", "Revoke"]),
+        (33, &["SetPragma"]),
     ];
 
     let mut unsorted = Vec::new();
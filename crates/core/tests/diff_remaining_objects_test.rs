@@ -2,10 +2,11 @@ use std::collections::BTreeMap;
 
 use stateql_core::{
     CheckConstraint, Comment, CommentTarget, DataType, DiffConfig, DiffEngine, DiffError, DiffOp,
-    Domain, Error, Expr, Extension, Function, FunctionParam, FunctionParamMode, Ident, Identity,
-    Literal, MaterializedView, Policy, PolicyCommand, Privilege, PrivilegeObject, PrivilegeOp,
-    QualifiedName, SchemaDef, SchemaObject, Sequence, SequenceChange, Table, Trigger, TriggerEvent,
-    TriggerForEach, TriggerTiming, TypeChange, TypeDef, TypeKind, View,
+    Domain, Error, Event, Expr, Extension, Function, FunctionParam, FunctionParamMode, Ident,
+    Identity, Literal, MaterializedView, Policy, PolicyCommand, Pragma, Privilege, PrivilegeObject,
+    PrivilegeOp, QualifiedName, SchemaDef, SchemaObject, Sequence, SequenceChange, Synonym, Table,
+    Trigger, TriggerEvent, TriggerForEach, TriggerTiming, TypeChange, TypeDef, TypeKind, Value,
+    View,
 };
 
 fn ident(value: &str) -> Ident {
@@ -140,6 +141,7 @@ fn trigger(name: &str, table_name: &str, body: &str) -> Trigger {
         table: qualified(table_name),
         timing: TriggerTiming::Before,
         events: vec![TriggerEvent::Insert],
+        update_columns: Vec::new(),
         for_each: TriggerForEach::Row,
         when_clause: None,
         body: body.to_string(),
@@ -205,6 +207,31 @@ fn comment(target_table: &str, text: Option<&str>) -> Comment {
     }
 }
 
+fn pragma(name: &str, value: Value) -> Pragma {
+    Pragma {
+        name: unqualified(name),
+        value,
+    }
+}
+
+fn synonym(name: &str, target: &[&str]) -> Synonym {
+    Synonym {
+        name: qualified(name),
+        target: target.iter().copied().map(ident).collect(),
+    }
+}
+
+fn event(name: &str, schedule: &str) -> Event {
+    Event {
+        name: qualified(name),
+        schedule: schedule.to_string(),
+        preserve: false,
+        enabled: true,
+        comment: None,
+        body: "DELETE FROM sessions".to_string(),
+    }
+}
+
 fn policy(name: &str, table_name: &str, using_expr: &str) -> Policy {
     Policy {
         name: ident(name),
@@ -348,7 +375,10 @@ fn diffs_sequence_trigger_function_type_and_domain_variants() {
         table: Some(qualified("users")),
     }));
 
-    assert!(ops.contains(&DiffOp::DropFunction(qualified("set_updated_at"))));
+    assert!(ops.contains(&DiffOp::DropFunction {
+        name: qualified("set_updated_at"),
+        procedure: false,
+    }));
     assert!(ops.contains(&DiffOp::CreateFunction(function(
         "set_updated_at",
         "BEGIN RETURN 2; END",
@@ -357,7 +387,10 @@ fn diffs_sequence_trigger_function_type_and_domain_variants() {
         "function_create",
         "BEGIN RETURN 1; END",
     ))));
-    assert!(ops.contains(&DiffOp::DropFunction(qualified("function_drop"))));
+    assert!(ops.contains(&DiffOp::DropFunction {
+        name: qualified("function_drop"),
+        procedure: false,
+    }));
 
     assert!(ops.contains(&DiffOp::AlterType {
         name: qualified("status"),
@@ -484,6 +517,111 @@ fn compares_privilege_variants_without_fail_fast_error() {
     );
 }
 
+#[test]
+fn sets_declared_pragmas_that_differ_and_leaves_undeclared_ones_alone() {
+    let engine = DiffEngine::new();
+    let desired = vec![
+        SchemaObject::Pragma(pragma("user_version", Value::Integer(4))),
+        SchemaObject::Pragma(pragma("journal_mode", Value::String("wal".to_string()))),
+    ];
+    let current = vec![
+        SchemaObject::Pragma(pragma("application_id", Value::Integer(7))),
+        SchemaObject::Pragma(pragma("user_version", Value::Integer(3))),
+        SchemaObject::Pragma(pragma("journal_mode", Value::String("wal".to_string()))),
+    ];
+
+    let ops = engine
+        .diff(&desired, &current, &with_enable_drop(true))
+        .expect("pragmas should be compared");
+
+    assert_eq!(
+        ops,
+        vec![DiffOp::SetPragma(pragma("user_version", Value::Integer(4)))]
+    );
+}
+
+#[test]
+fn recreates_synonyms_whose_target_changed() {
+    let engine = DiffEngine::new();
+    let desired = vec![
+        SchemaObject::Synonym(synonym("customers", &["crm", "dbo", "customers"])),
+        SchemaObject::Synonym(synonym("orders", &["sales", "orders"])),
+        SchemaObject::Synonym(synonym("invoices", &["billing", "invoices"])),
+    ];
+    let current = vec![
+        SchemaObject::Synonym(synonym("customers", &["crm_old", "dbo", "customers"])),
+        SchemaObject::Synonym(synonym("orders", &["sales", "orders"])),
+        SchemaObject::Synonym(synonym("legacy", &["archive", "legacy"])),
+    ];
+
+    let ops = engine
+        .diff(&desired, &current, &with_enable_drop(true))
+        .expect("synonyms should be compared");
+
+    assert_eq!(
+        ops,
+        vec![
+            DiffOp::DropSynonym(qualified("customers")),
+            DiffOp::DropSynonym(qualified("legacy")),
+            DiffOp::CreateSynonym(synonym("customers", &["crm", "dbo", "customers"])),
+            DiffOp::CreateSynonym(synonym("invoices", &["billing", "invoices"])),
+        ]
+    );
+
+    let ops = engine
+        .diff(&desired, &current, &with_enable_drop(false))
+        .expect("synonyms should be compared");
+
+    assert_eq!(
+        ops,
+        vec![
+            DiffOp::CreateSynonym(synonym("customers", &["crm", "dbo", "customers"])),
+            DiffOp::CreateSynonym(synonym("invoices", &["billing", "invoices"])),
+        ]
+    );
+}
+
+#[test]
+fn recreates_events_whose_definition_changed() {
+    let engine = DiffEngine::new();
+    let desired = vec![
+        SchemaObject::Event(event("purge", "EVERY 1 HOUR")),
+        SchemaObject::Event(event("rollup", "EVERY 1 DAY")),
+        SchemaObject::Event(event("archive", "EVERY 1 WEEK")),
+    ];
+    let current = vec![
+        SchemaObject::Event(event("purge", "EVERY 1 DAY")),
+        SchemaObject::Event(event("rollup", "EVERY 1 DAY")),
+        SchemaObject::Event(event("legacy", "EVERY 1 MONTH")),
+    ];
+
+    let ops = engine
+        .diff(&desired, &current, &with_enable_drop(true))
+        .expect("events should be compared");
+
+    assert_eq!(
+        ops,
+        vec![
+            DiffOp::DropEvent(qualified("purge")),
+            DiffOp::DropEvent(qualified("legacy")),
+            DiffOp::CreateEvent(event("purge", "EVERY 1 HOUR")),
+            DiffOp::CreateEvent(event("archive", "EVERY 1 WEEK")),
+        ]
+    );
+
+    let ops = engine
+        .diff(&desired, &current, &with_enable_drop(false))
+        .expect("events should be compared");
+
+    assert_eq!(
+        ops,
+        vec![
+            DiffOp::CreateEvent(event("purge", "EVERY 1 HOUR")),
+            DiffOp::CreateEvent(event("archive", "EVERY 1 WEEK")),
+        ]
+    );
+}
+
 #[test]
 fn fails_when_desired_has_explicit_sequence_and_identity_overlap() {
     let engine = DiffEngine::new();
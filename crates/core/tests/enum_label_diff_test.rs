@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+
+use stateql_core::{
+    Column, ColumnChange, DataType, DiffConfig, DiffEngine, DiffOp, DiffWarning, Ident,
+    SchemaObject, SkippedOpKind, Table,
+};
+
+fn labels(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+fn orders_with_status(data_type: DataType) -> SchemaObject {
+    let mut table = Table::named("orders");
+    table.columns = vec![Column {
+        name: Ident::unquoted("status"),
+        data_type,
+        not_null: true,
+        default: None,
+        identity: None,
+        generated: None,
+        comment: None,
+        collation: None,
+        renamed_from: None,
+        extra: BTreeMap::new(),
+    }];
+    SchemaObject::Table(table)
+}
+
+fn with_enable_drop(enable_drop: bool) -> DiffConfig {
+    DiffConfig {
+        enable_drop,
+        ..DiffConfig::default()
+    }
+}
+
+fn set_type(op: &DiffOp) -> Option<&DataType> {
+    match op {
+        DiffOp::AlterColumn { changes, .. } => changes.iter().find_map(|change| match change {
+            ColumnChange::SetType(data_type) => Some(data_type),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+#[test]
+fn appended_enum_labels_alter_the_column_without_warnings() {
+    let desired = vec![orders_with_status(DataType::Enum(labels(&[
+        "new", "paid", "shipped",
+    ])))];
+    let current = vec![orders_with_status(DataType::Enum(labels(&["new", "paid"])))];
+
+    let outcome = DiffEngine::new()
+        .diff_with_diagnostics(&desired, &current, &with_enable_drop(false))
+        .expect("diff should succeed");
+
+    assert_eq!(
+        outcome.ops.len(),
+        1,
+        "expected one alter: {:?}",
+        outcome.ops
+    );
+    assert_eq!(
+        set_type(&outcome.ops[0]),
+        Some(&DataType::Enum(labels(&["new", "paid", "shipped"])))
+    );
+    assert!(
+        outcome.diagnostics.is_empty(),
+        "append-only label changes are safe: {:?}",
+        outcome.diagnostics
+    );
+}
+
+#[test]
+fn reordered_set_labels_carry_a_rewrite_warning() {
+    let desired = vec![orders_with_status(DataType::Set(labels(&["b", "a"])))];
+    let current = vec![orders_with_status(DataType::Set(labels(&["a", "b"])))];
+
+    let outcome = DiffEngine::new()
+        .diff_with_diagnostics(&desired, &current, &with_enable_drop(false))
+        .expect("diff should succeed");
+
+    assert_eq!(
+        outcome.ops.len(),
+        1,
+        "expected one alter: {:?}",
+        outcome.ops
+    );
+    assert_eq!(
+        outcome.diagnostics.warnings,
+        vec![DiffWarning {
+            target: "orders.status".to_string(),
+            message: "SET labels are reordered; the table will be rewritten".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn removed_enum_labels_require_enable_drop() {
+    let desired = vec![orders_with_status(DataType::Enum(labels(&["new", "paid"])))];
+    let current = vec![orders_with_status(DataType::Enum(labels(&[
+        "new", "paid", "legacy",
+    ])))];
+
+    let outcome = DiffEngine::new()
+        .diff_with_diagnostics(&desired, &current, &with_enable_drop(false))
+        .expect("diff should succeed");
+
+    assert!(
+        outcome.ops.is_empty(),
+        "label removal must be suppressed without enable_drop: {:?}",
+        outcome.ops
+    );
+    let kinds = outcome
+        .diagnostics
+        .skipped_ops
+        .iter()
+        .map(|diagnostic| diagnostic.kind)
+        .collect::<Vec<_>>();
+    assert_eq!(kinds, vec![SkippedOpKind::DropEnumLabel]);
+}
+
+#[test]
+fn removed_enum_labels_warn_when_enable_drop_is_set() {
+    let desired = vec![orders_with_status(DataType::Enum(labels(&["new", "paid"])))];
+    let current = vec![orders_with_status(DataType::Enum(labels(&[
+        "new", "paid", "legacy",
+    ])))];
+
+    let outcome = DiffEngine::new()
+        .diff_with_diagnostics(&desired, &current, &with_enable_drop(true))
+        .expect("diff should succeed");
+
+    assert_eq!(
+        outcome.ops.len(),
+        1,
+        "expected one alter: {:?}",
+        outcome.ops
+    );
+    assert_eq!(outcome.diagnostics.warnings.len(), 1);
+    assert!(
+        outcome.diagnostics.warnings[0]
+            .message
+            .contains("ENUM labels 'legacy' are removed"),
+        "unexpected warning: {:?}",
+        outcome.diagnostics.warnings
+    );
+}
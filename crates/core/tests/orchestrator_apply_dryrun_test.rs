@@ -32,6 +32,7 @@ fn dry_run_returns_sql_and_does_not_execute() {
             OrchestratorOptions {
                 mode: Mode::DryRun,
                 enable_drop: true,
+                enable_column_reorder: false,
             },
         )
         .expect("dry-run should succeed");
@@ -50,6 +51,7 @@ fn dry_run_returns_sql_and_does_not_execute() {
         dialect.executed_sql().is_empty(),
         "dry-run must not execute statements",
     );
+    assert!(dialect.apply_hooks().is_empty());
 }
 
 #[test]
@@ -65,6 +67,7 @@ fn apply_executes_generated_statements() {
             OrchestratorOptions {
                 mode: Mode::Apply,
                 enable_drop: true,
+                enable_column_reorder: false,
             },
         )
         .expect("apply should succeed");
@@ -78,4 +81,11 @@ fn apply_executes_generated_statements() {
             "COMMIT".to_string(),
         ],
     );
+    assert_eq!(
+        dialect.apply_hooks(),
+        vec![
+            "prepare_apply(1)".to_string(),
+            "finish_apply(ok=true)".to_string(),
+        ],
+    );
 }
@@ -83,6 +83,7 @@ fn wires_enable_drop_and_renders_skipped_diagnostics() {
             OrchestratorOptions {
                 mode: Mode::DryRun,
                 enable_drop: false,
+                enable_column_reorder: false,
             },
         )
         .expect("dry-run should succeed when drop is disabled");
@@ -101,6 +102,7 @@ fn wires_enable_drop_and_renders_skipped_diagnostics() {
             OrchestratorOptions {
                 mode: Mode::DryRun,
                 enable_drop: true,
+                enable_column_reorder: false,
             },
         )
         .expect("dry-run should succeed when drop is enabled");
@@ -141,6 +143,7 @@ fn wires_schema_search_path_into_diff_config() {
             OrchestratorOptions {
                 mode: Mode::DryRun,
                 enable_drop: true,
+                enable_column_reorder: false,
             },
         )
         .expect("dry-run should succeed");
@@ -173,6 +176,7 @@ fn wires_schema_search_path_into_diff_config() {
             OrchestratorOptions {
                 mode: Mode::DryRun,
                 enable_drop: true,
+                enable_column_reorder: false,
             },
         )
         .expect("dry-run should succeed");
@@ -225,6 +229,7 @@ fn wires_equivalence_policy_into_diff_config() {
             OrchestratorOptions {
                 mode: Mode::DryRun,
                 enable_drop: true,
+                enable_column_reorder: false,
             },
         )
         .expect("strict-policy dry-run should succeed");
@@ -264,6 +269,7 @@ fn wires_equivalence_policy_into_diff_config() {
             OrchestratorOptions {
                 mode: Mode::DryRun,
                 enable_drop: true,
+                enable_column_reorder: false,
             },
         )
         .expect("relaxed-policy dry-run should succeed");
@@ -33,6 +33,7 @@ fn export_is_idempotent_across_two_passes() {
                 OrchestratorOptions {
                     mode: Mode::Export,
                     enable_drop: false,
+                    enable_column_reorder: false,
                 },
             )
             .expect("first export should succeed"),
@@ -50,6 +51,7 @@ fn export_is_idempotent_across_two_passes() {
                 OrchestratorOptions {
                     mode: Mode::Export,
                     enable_drop: false,
+                    enable_column_reorder: false,
                 },
             )
             .expect("second export should succeed"),
@@ -78,6 +80,7 @@ fn export_roundtrip_helper_detects_mismatch() {
                 OrchestratorOptions {
                     mode: Mode::Export,
                     enable_drop: false,
+                    enable_column_reorder: false,
                 },
             )
             .expect("first export should succeed"),
@@ -22,6 +22,7 @@ fn export_routes_current_schema_through_to_sql() {
             OrchestratorOptions {
                 mode: Mode::Export,
                 enable_drop: true,
+                enable_column_reorder: false,
             },
         )
         .expect("export should succeed");
@@ -0,0 +1,303 @@
+use std::collections::BTreeMap;
+
+use stateql_core::{
+    DiffConfig, DiffEngine, DiffOp, Expr, Ident, Literal, Partition, PartitionBound,
+    PartitionChangeStrategy, PartitionElement, PartitionStrategy, QualifiedName, SchemaObject,
+    Table,
+};
+
+fn ident(value: &str) -> Ident {
+    Ident::unquoted(value)
+}
+
+fn orders() -> QualifiedName {
+    QualifiedName {
+        schema: None,
+        name: ident("orders"),
+    }
+}
+
+fn element(name: &str, bound: PartitionBound) -> PartitionElement {
+    PartitionElement {
+        name: ident(name),
+        bound: Some(bound),
+        extra: BTreeMap::new(),
+    }
+}
+
+fn less_than(value: i64) -> PartitionBound {
+    PartitionBound::LessThan(vec![Expr::Literal(Literal::Integer(value))])
+}
+
+fn list(values: &[i64]) -> PartitionBound {
+    PartitionBound::In(
+        values
+            .iter()
+            .map(|value| Expr::Literal(Literal::Integer(*value)))
+            .collect(),
+    )
+}
+
+fn partitioned_orders(
+    strategy: PartitionStrategy,
+    elements: Vec<PartitionElement>,
+) -> SchemaObject {
+    let mut table = Table::named("orders");
+    table.partition = Some(Partition {
+        strategy,
+        columns: vec![ident("created_year")],
+        partitions: elements,
+    });
+    SchemaObject::Table(table)
+}
+
+fn hash_partitions(count: usize) -> Vec<PartitionElement> {
+    (0..count)
+        .map(|index| PartitionElement {
+            name: ident(&format!("p{index}")),
+            bound: None,
+            extra: BTreeMap::new(),
+        })
+        .collect()
+}
+
+fn reorganize_config() -> DiffConfig {
+    DiffConfig {
+        partition_change_strategy: PartitionChangeStrategy::Reorganize,
+        ..DiffConfig::default()
+    }
+}
+
+#[test]
+fn maxvalue_split_is_reorganized_without_enable_drop() {
+    let desired = vec![partitioned_orders(
+        PartitionStrategy::Range,
+        vec![
+            element("p2025", less_than(2026)),
+            element("p2026", less_than(2027)),
+            element("pmax", PartitionBound::MaxValue),
+        ],
+    )];
+    let current = vec![partitioned_orders(
+        PartitionStrategy::Range,
+        vec![
+            element("p2025", less_than(2026)),
+            element("pmax", PartitionBound::MaxValue),
+        ],
+    )];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorganize_config())
+        .expect("diff should succeed");
+
+    assert_eq!(
+        ops,
+        vec![DiffOp::ReorganizePartition {
+            table: orders(),
+            from: vec![ident("pmax")],
+            into: vec![
+                element("p2026", less_than(2027)),
+                element("pmax", PartitionBound::MaxValue),
+            ],
+        }]
+    );
+}
+
+#[test]
+fn bound_change_reorganizes_partition_and_trailing_additions_are_added() {
+    let desired = vec![partitioned_orders(
+        PartitionStrategy::Range,
+        vec![
+            element("p2025", less_than(2027)),
+            element("p2027", less_than(2028)),
+        ],
+    )];
+    let current = vec![partitioned_orders(
+        PartitionStrategy::Range,
+        vec![element("p2025", less_than(2026))],
+    )];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorganize_config())
+        .expect("diff should succeed");
+
+    assert_eq!(
+        ops,
+        vec![
+            DiffOp::ReorganizePartition {
+                table: orders(),
+                from: vec![ident("p2025")],
+                into: vec![element("p2025", less_than(2027))],
+            },
+            DiffOp::AddPartition {
+                table: orders(),
+                partition: Partition {
+                    strategy: PartitionStrategy::Range,
+                    columns: vec![ident("created_year")],
+                    partitions: vec![element("p2027", less_than(2028))],
+                },
+            },
+        ]
+    );
+}
+
+#[test]
+fn hash_partition_count_change_resizes_instead_of_dropping() {
+    let desired = vec![partitioned_orders(
+        PartitionStrategy::Hash,
+        hash_partitions(2),
+    )];
+    let current = vec![partitioned_orders(
+        PartitionStrategy::Hash,
+        hash_partitions(4),
+    )];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorganize_config())
+        .expect("diff should succeed");
+
+    assert_eq!(
+        ops,
+        vec![DiffOp::ChangePartitionCount {
+            table: orders(),
+            from: 4,
+            to: 2,
+        }]
+    );
+}
+
+#[test]
+fn non_last_bound_change_reorganizes_the_adjacent_partitions_together() {
+    let desired = vec![partitioned_orders(
+        PartitionStrategy::Range,
+        vec![
+            element("p0", less_than(150)),
+            element("p1", less_than(200)),
+            element("pmax", PartitionBound::MaxValue),
+        ],
+    )];
+    let current = vec![partitioned_orders(
+        PartitionStrategy::Range,
+        vec![
+            element("p0", less_than(100)),
+            element("p1", less_than(200)),
+            element("pmax", PartitionBound::MaxValue),
+        ],
+    )];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorganize_config())
+        .expect("diff should succeed");
+
+    assert_eq!(
+        ops,
+        vec![DiffOp::ReorganizePartition {
+            table: orders(),
+            from: vec![ident("p0"), ident("p1")],
+            into: vec![element("p0", less_than(150)), element("p1", less_than(200))],
+        }]
+    );
+}
+
+#[test]
+fn removed_range_partition_is_merged_into_its_neighbour() {
+    let desired = vec![partitioned_orders(
+        PartitionStrategy::Range,
+        vec![element("p0", less_than(100)), element("p2", less_than(300))],
+    )];
+    let current = vec![partitioned_orders(
+        PartitionStrategy::Range,
+        vec![
+            element("p0", less_than(100)),
+            element("p1", less_than(200)),
+            element("p2", less_than(300)),
+        ],
+    )];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorganize_config())
+        .expect("diff should succeed");
+
+    assert_eq!(
+        ops,
+        vec![DiffOp::ReorganizePartition {
+            table: orders(),
+            from: vec![ident("p1"), ident("p2")],
+            into: vec![element("p2", less_than(300))],
+        }]
+    );
+}
+
+#[test]
+fn shrunk_range_tail_is_only_dropped_with_enable_drop() {
+    let desired = vec![partitioned_orders(
+        PartitionStrategy::Range,
+        vec![element("p0", less_than(100)), element("p1", less_than(150))],
+    )];
+    let current = vec![partitioned_orders(
+        PartitionStrategy::Range,
+        vec![element("p0", less_than(100)), element("p1", less_than(200))],
+    )];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorganize_config())
+        .expect("diff should succeed");
+    assert!(ops.is_empty(), "shrinking loses rows: {ops:?}");
+
+    let config = DiffConfig {
+        enable_drop: true,
+        ..reorganize_config()
+    };
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &config)
+        .expect("diff should succeed");
+    assert_eq!(
+        ops,
+        vec![
+            DiffOp::DropPartition {
+                table: orders(),
+                name: ident("p1"),
+            },
+            DiffOp::AddPartition {
+                table: orders(),
+                partition: Partition {
+                    strategy: PartitionStrategy::Range,
+                    columns: vec![ident("created_year")],
+                    partitions: vec![element("p1", less_than(150))],
+                },
+            },
+        ]
+    );
+}
+
+#[test]
+fn removed_list_partition_is_merged_into_the_partition_taking_its_values() {
+    let desired = vec![partitioned_orders(
+        PartitionStrategy::List,
+        vec![
+            element("p_east", list(&[1, 2, 3, 4])),
+            element("p_west", list(&[5, 6])),
+        ],
+    )];
+    let current = vec![partitioned_orders(
+        PartitionStrategy::List,
+        vec![
+            element("p_east", list(&[1, 2])),
+            element("p_north", list(&[3, 4])),
+            element("p_west", list(&[5, 6])),
+        ],
+    )];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorganize_config())
+        .expect("diff should succeed");
+
+    assert_eq!(
+        ops,
+        vec![DiffOp::ReorganizePartition {
+            table: orders(),
+            from: vec![ident("p_east"), ident("p_north")],
+            into: vec![element("p_east", list(&[1, 2, 3, 4]))],
+        }]
+    );
+}
@@ -94,6 +94,7 @@ fn s1_unknown_ddl_fails_fast_without_diff_or_execution() {
             OrchestratorOptions {
                 mode: Mode::DryRun,
                 enable_drop: true,
+                enable_column_reorder: false,
             },
         )
         .expect_err("unknown DDL must fail fast");
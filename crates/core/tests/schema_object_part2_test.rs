@@ -23,6 +23,7 @@ fn schema_object_remaining_top_level_variants_are_constructible() {
         table: qualified(Some("public"), "users"),
         timing: TriggerTiming::Before,
         events: vec![TriggerEvent::Insert, TriggerEvent::Update],
+        update_columns: Vec::new(),
         for_each: TriggerForEach::Row,
         when_clause: Some(Expr::Is {
             expr: Box::new(Expr::Ident(Ident::unquoted("active"))),
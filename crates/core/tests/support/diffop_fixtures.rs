@@ -2,18 +2,18 @@ use std::collections::BTreeMap;
 
 use stateql_core::{
     CheckConstraint, Column, ColumnChange, ColumnPosition, Comment, CommentTarget, DataType,
-    Deferrable, DiffOp, Domain, DomainChange, EnumValuePosition, ExclusionConstraint,
+    Deferrable, DiffOp, Domain, DomainChange, EnumValuePosition, Event, ExclusionConstraint,
     ExclusionElement, Expr, Extension, ForeignKey, ForeignKeyAction, Function, FunctionParam,
     FunctionParamMode, FunctionSecurity, GeneratedColumn, Ident, Identity, IndexColumn, IndexDef,
     IndexOwner, Literal, MaterializedView, NullsOrder, Partition, PartitionBound, PartitionElement,
-    PartitionStrategy, Policy, PolicyCommand, PrimaryKey, Privilege, PrivilegeObject, PrivilegeOp,
-    QualifiedName, SchemaDef, Sequence, SequenceChange, SetQuantifier, SortOrder, Table,
-    TableOptions, Trigger, TriggerEvent, TriggerForEach, TriggerTiming, TypeChange, TypeDef,
-    TypeKind, Value, View, Volatility, extra_keys,
+    PartitionStrategy, Policy, PolicyCommand, Pragma, PrimaryKey, Privilege, PrivilegeObject,
+    PrivilegeOp, QualifiedName, SchemaDef, Sequence, SequenceChange, SetQuantifier, SortOrder,
+    Synonym, Table, TableOptions, Trigger, TriggerEvent, TriggerForEach, TriggerTiming, TypeChange,
+    TypeDef, TypeKind, Value, View, Volatility, extra_keys,
 };
 
-pub const EXPECTED_DIFFOP_VARIANT_COUNT: usize = 48;
-pub const EXPECTED_COLUMN_CHANGE_VARIANT_COUNT: usize = 6;
+pub const EXPECTED_DIFFOP_VARIANT_COUNT: usize = 56;
+pub const EXPECTED_COLUMN_CHANGE_VARIANT_COUNT: usize = 7;
 pub const EXPECTED_SEQUENCE_CHANGE_VARIANT_COUNT: usize = 7;
 pub const EXPECTED_TYPE_CHANGE_VARIANT_COUNT: usize = 2;
 pub const EXPECTED_DOMAIN_CHANGE_VARIANT_COUNT: usize = 4;
@@ -185,12 +185,31 @@ fn sample_sequence() -> Sequence {
     }
 }
 
+fn sample_synonym() -> Synonym {
+    Synonym {
+        name: qualified(Some("dbo"), "customers"),
+        target: vec![ident("crm"), ident("dbo"), ident("customers")],
+    }
+}
+
+fn sample_event() -> Event {
+    Event {
+        name: qualified(None, "purge_sessions"),
+        schedule: "EVERY 1 DAY".to_string(),
+        preserve: false,
+        enabled: true,
+        comment: Some("nightly cleanup".to_string()),
+        body: "DELETE FROM sessions WHERE expires_at < NOW()".to_string(),
+    }
+}
+
 fn sample_trigger() -> Trigger {
     Trigger {
         name: qualified(Some("public"), "users_set_updated_at"),
         table: qualified(Some("public"), "users"),
         timing: TriggerTiming::Before,
         events: vec![TriggerEvent::Insert, TriggerEvent::Update],
+        update_columns: Vec::new(),
         for_each: TriggerForEach::Row,
         when_clause: Some(Expr::Is {
             expr: Box::new(Expr::Ident(ident("updated_at"))),
@@ -308,6 +327,7 @@ pub fn all_column_change_variants() -> Vec<ColumnChange> {
             stored: true,
         })),
         ColumnChange::SetCollation(Some("und-x-icu".to_string())),
+        ColumnChange::SetPosition(ColumnPosition::After(ident("id"))),
     ]
 }
 
@@ -358,6 +378,15 @@ pub fn all_diffop_variants() -> Vec<DiffOp> {
             from: table_name.clone(),
             to: qualified(Some("public"), "app_users"),
         },
+        DiffOp::TableDefinition {
+            current: Box::new(sample_table("users")),
+            target: Box::new(sample_table("users")),
+            dependent_views: Vec::new(),
+            dependent_indexes: Vec::new(),
+            declared_indexes: Vec::new(),
+            dependent_triggers: Vec::new(),
+            referencing_foreign_keys: Vec::new(),
+        },
         DiffOp::AddColumn {
             table: table_name.clone(),
             column: Box::new(sample_column("nickname")),
@@ -464,6 +493,23 @@ pub fn all_diffop_variants() -> Vec<DiffOp> {
             table: table_name.clone(),
             name: ident("users_tenant_a"),
         },
+        DiffOp::ReorganizePartition {
+            table: table_name.clone(),
+            from: vec![ident("users_tenant_a")],
+            into: vec![PartitionElement {
+                name: ident("users_tenant_a"),
+                bound: Some(PartitionBound::In(vec![
+                    Expr::Literal(Literal::String("tenant_a".to_string())),
+                    Expr::Literal(Literal::String("tenant_b".to_string())),
+                ])),
+                extra: BTreeMap::new(),
+            }],
+        },
+        DiffOp::ChangePartitionCount {
+            table: table_name.clone(),
+            from: 4,
+            to: 8,
+        },
         DiffOp::CreateView(sample_view()),
         DiffOp::DropView(qualified(Some("public"), "active_users")),
         DiffOp::CreateMaterializedView(sample_materialized_view()),
@@ -474,13 +520,20 @@ pub fn all_diffop_variants() -> Vec<DiffOp> {
             name: qualified(Some("public"), "users_id_seq"),
             changes: all_sequence_change_variants(),
         },
+        DiffOp::CreateSynonym(sample_synonym()),
+        DiffOp::DropSynonym(qualified(Some("dbo"), "customers")),
         DiffOp::CreateTrigger(sample_trigger()),
         DiffOp::DropTrigger {
             name: qualified(Some("public"), "users_set_updated_at"),
             table: Some(table_name.clone()),
         },
         DiffOp::CreateFunction(sample_function()),
-        DiffOp::DropFunction(qualified(Some("public"), "set_updated_at")),
+        DiffOp::DropFunction {
+            name: qualified(Some("public"), "set_updated_at"),
+            procedure: false,
+        },
+        DiffOp::CreateEvent(sample_event()),
+        DiffOp::DropEvent(qualified(None, "purge_sessions")),
         DiffOp::CreateType(sample_type()),
         DiffOp::DropType(qualified(Some("public"), "status")),
         DiffOp::AlterType {
@@ -517,6 +570,13 @@ pub fn all_diffop_variants() -> Vec<DiffOp> {
             name: ident("users_isolation"),
             table: table_name.clone(),
         },
+        DiffOp::SetPragma(Pragma {
+            name: QualifiedName {
+                schema: None,
+                name: ident("user_version"),
+            },
+            value: Value::Integer(3),
+        }),
         DiffOp::AlterTableOptions {
             table: table_name,
             options: TableOptions {
@@ -531,6 +591,7 @@ pub fn diffop_variant_tag(op: &DiffOp) -> &'static str {
         DiffOp::CreateTable(_) => "CreateTable",
         DiffOp::DropTable(_) => "DropTable",
         DiffOp::RenameTable { .. } => "RenameTable",
+        DiffOp::TableDefinition { .. } => "TableDefinition",
         DiffOp::AddColumn { .. } => "AddColumn",
         DiffOp::DropColumn { .. } => "DropColumn",
         DiffOp::AlterColumn { .. } => "AlterColumn",
@@ -548,6 +609,8 @@ pub fn diffop_variant_tag(op: &DiffOp) -> &'static str {
         DiffOp::DropPrimaryKey { .. } => "DropPrimaryKey",
         DiffOp::AddPartition { .. } => "AddPartition",
         DiffOp::DropPartition { .. } => "DropPartition",
+        DiffOp::ReorganizePartition { .. } => "ReorganizePartition",
+        DiffOp::ChangePartitionCount { .. } => "ChangePartitionCount",
         DiffOp::CreateView(_) => "CreateView",
         DiffOp::DropView(_) => "DropView",
         DiffOp::CreateMaterializedView(_) => "CreateMaterializedView",
@@ -555,10 +618,14 @@ pub fn diffop_variant_tag(op: &DiffOp) -> &'static str {
         DiffOp::CreateSequence(_) => "CreateSequence",
         DiffOp::DropSequence(_) => "DropSequence",
         DiffOp::AlterSequence { .. } => "AlterSequence",
+        DiffOp::CreateSynonym(_) => "CreateSynonym",
+        DiffOp::DropSynonym(_) => "DropSynonym",
         DiffOp::CreateTrigger(_) => "CreateTrigger",
         DiffOp::DropTrigger { .. } => "DropTrigger",
         DiffOp::CreateFunction(_) => "CreateFunction",
-        DiffOp::DropFunction(_) => "DropFunction",
+        DiffOp::DropFunction { .. } => "DropFunction",
+        DiffOp::CreateEvent(_) => "CreateEvent",
+        DiffOp::DropEvent(_) => "DropEvent",
         DiffOp::CreateType(_) => "CreateType",
         DiffOp::DropType(_) => "DropType",
         DiffOp::AlterType { .. } => "AlterType",
@@ -575,6 +642,7 @@ pub fn diffop_variant_tag(op: &DiffOp) -> &'static str {
         DiffOp::Revoke(_) => "Revoke",
         DiffOp::CreatePolicy(_) => "CreatePolicy",
         DiffOp::DropPolicy { .. } => "DropPolicy",
+        DiffOp::SetPragma(_) => "SetPragma",
         DiffOp::AlterTableOptions { .. } => "AlterTableOptions",
     }
 }
@@ -586,6 +654,7 @@ pub fn column_change_variant_tag(change: &ColumnChange) -> &'static str {
         ColumnChange::SetDefault(_) => "SetDefault",
         ColumnChange::SetIdentity(_) => "SetIdentity",
         ColumnChange::SetGenerated(_) => "SetGenerated",
+        ColumnChange::SetPosition(_) => "SetPosition",
         ColumnChange::SetCollation(_) => "SetCollation",
     }
 }
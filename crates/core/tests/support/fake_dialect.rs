@@ -20,6 +20,7 @@ struct FakeAdapterState {
     export_schema_sql: String,
     schema_search_path: Vec<String>,
     executed_sql: Vec<String>,
+    apply_hooks: Vec<String>,
 }
 
 impl Default for FakeAdapterState {
@@ -28,6 +29,7 @@ impl Default for FakeAdapterState {
             export_schema_sql: String::new(),
             schema_search_path: vec!["public".to_string()],
             executed_sql: Vec::new(),
+            apply_hooks: Vec::new(),
         }
     }
 }
@@ -77,6 +79,24 @@ impl DatabaseAdapter for FakeAdapter {
             patch: 0,
         })
     }
+
+    fn prepare_apply(&mut self, statements: &[Statement]) -> Result<()> {
+        self.state
+            .lock()
+            .expect("fake adapter mutex should lock")
+            .apply_hooks
+            .push(format!("prepare_apply({})", statements.len()));
+        Ok(())
+    }
+
+    fn finish_apply(&mut self, outcome: Result<()>) -> Result<()> {
+        self.state
+            .lock()
+            .expect("fake adapter mutex should lock")
+            .apply_hooks
+            .push(format!("finish_apply(ok={})", outcome.is_ok()));
+        outcome
+    }
 }
 
 pub struct FakeDialect {
@@ -173,6 +193,16 @@ impl FakeDialect {
             .executed_sql
             .clone()
     }
+
+    pub fn apply_hooks(&self) -> Vec<String> {
+        let state = self.state.lock().expect("fake dialect mutex should lock");
+        state
+            .adapter_state
+            .lock()
+            .expect("fake adapter mutex should lock")
+            .apply_hooks
+            .clone()
+    }
 }
 
 impl Dialect for FakeDialect {
@@ -0,0 +1,397 @@
+use std::collections::BTreeMap;
+
+use stateql_core::{
+    CheckConstraint, Column, DataType, DiffConfig, DiffEngine, DiffOp, Expr, ForeignKey, Ident,
+    IndexColumn, IndexDef, IndexOwner, PrimaryKey, QualifiedName, SchemaObject, Table, Trigger,
+    TriggerEvent, TriggerForEach, TriggerTiming, View,
+};
+
+fn ident(value: &str) -> Ident {
+    Ident::unquoted(value)
+}
+
+fn users() -> QualifiedName {
+    QualifiedName {
+        schema: None,
+        name: ident("users"),
+    }
+}
+
+fn column(name: &str) -> Column {
+    Column {
+        name: ident(name),
+        data_type: DataType::Integer,
+        not_null: false,
+        default: None,
+        identity: None,
+        generated: None,
+        comment: None,
+        collation: None,
+        renamed_from: None,
+        extra: BTreeMap::new(),
+    }
+}
+
+fn users_table(columns: &[&str]) -> Table {
+    let mut table = Table::named("users");
+    table.columns = columns.iter().map(|name| column(name)).collect();
+    table
+}
+
+fn unnamed_check(expr: &str) -> CheckConstraint {
+    CheckConstraint {
+        name: None,
+        expr: Expr::Raw(expr.to_string()),
+        no_inherit: false,
+    }
+}
+
+fn definition_config(enable_drop: bool) -> DiffConfig {
+    DiffConfig {
+        enable_drop,
+        emit_table_definitions: true,
+        ..DiffConfig::default()
+    }
+}
+
+fn constraint_config() -> DiffConfig {
+    DiffConfig {
+        diff_table_constraints: true,
+        ..DiffConfig::default()
+    }
+}
+
+fn diff(desired: Table, current: Table, config: &DiffConfig) -> Vec<DiffOp> {
+    DiffEngine::new()
+        .diff(
+            &[SchemaObject::Table(desired)],
+            &[SchemaObject::Table(current)],
+            config,
+        )
+        .expect("diff should succeed")
+}
+
+#[test]
+fn changed_primary_key_is_replaced() {
+    let mut desired = users_table(&["id", "tenant_id"]);
+    desired.primary_key = Some(PrimaryKey {
+        name: None,
+        columns: vec![ident("tenant_id"), ident("id")],
+    });
+    let mut current = users_table(&["id", "tenant_id"]);
+    current.primary_key = Some(PrimaryKey {
+        name: None,
+        columns: vec![ident("id")],
+    });
+
+    let ops = diff(desired.clone(), current, &constraint_config());
+
+    assert_eq!(
+        ops,
+        vec![
+            DiffOp::DropPrimaryKey { table: users() },
+            DiffOp::SetPrimaryKey {
+                table: users(),
+                pk: desired.primary_key.expect("desired primary key"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn keys_are_only_compared_when_the_dialect_diffs_table_constraints() {
+    let mut desired = users_table(&["id", "team_id"]);
+    desired.primary_key = Some(PrimaryKey {
+        name: None,
+        columns: vec![ident("id")],
+    });
+    let fk = ForeignKey {
+        name: Some(ident("users_team_fk")),
+        columns: vec![ident("team_id")],
+        referenced_table: QualifiedName {
+            schema: None,
+            name: ident("teams"),
+        },
+        referenced_columns: vec![ident("id")],
+        on_delete: None,
+        on_update: None,
+        deferrable: None,
+        extra: BTreeMap::new(),
+    };
+    desired.foreign_keys = vec![fk.clone()];
+    let current = users_table(&["id", "team_id"]);
+
+    assert!(diff(desired.clone(), current.clone(), &DiffConfig::default()).is_empty());
+    assert_eq!(
+        diff(desired.clone(), current, &constraint_config()),
+        vec![
+            DiffOp::SetPrimaryKey {
+                table: users(),
+                pk: desired.primary_key.expect("desired primary key"),
+            },
+            DiffOp::AddForeignKey { table: users(), fk },
+        ]
+    );
+}
+
+#[test]
+fn table_definition_is_only_emitted_when_requested() {
+    let desired = users_table(&["id", "email"]);
+    let current = users_table(&["id"]);
+
+    let without_definitions = diff(desired.clone(), current.clone(), &DiffConfig::default());
+    assert!(
+        !without_definitions
+            .iter()
+            .any(|op| matches!(op, DiffOp::TableDefinition { .. }))
+    );
+
+    let with_definitions = diff(desired.clone(), current.clone(), &definition_config(true));
+    assert!(matches!(
+        with_definitions.first(),
+        Some(DiffOp::AddColumn { .. })
+    ));
+    // The definition follows the table's own ops.
+    assert_eq!(
+        with_definitions.last(),
+        Some(&DiffOp::TableDefinition {
+            current: Box::new(current),
+            target: Box::new(desired),
+            dependent_views: Vec::new(),
+            dependent_indexes: Vec::new(),
+            declared_indexes: Vec::new(),
+            dependent_triggers: Vec::new(),
+            referencing_foreign_keys: Vec::new(),
+        })
+    );
+}
+
+#[test]
+fn unnamed_check_change_emits_table_definition_alone() {
+    let mut desired = users_table(&["id"]);
+    desired.checks = vec![unnamed_check("id > 1")];
+    let mut current = users_table(&["id"]);
+    current.checks = vec![unnamed_check("id > 0")];
+
+    let ops = diff(desired.clone(), current.clone(), &definition_config(true));
+
+    assert_eq!(
+        ops,
+        vec![DiffOp::TableDefinition {
+            current: Box::new(current),
+            target: Box::new(desired),
+            dependent_views: Vec::new(),
+            dependent_indexes: Vec::new(),
+            declared_indexes: Vec::new(),
+            dependent_triggers: Vec::new(),
+            referencing_foreign_keys: Vec::new(),
+        }]
+    );
+}
+
+#[test]
+fn target_definition_keeps_current_columns_without_enable_drop() {
+    let desired = users_table(&["id", "email"]);
+    let current = users_table(&["id", "legacy"]);
+
+    let ops = diff(desired, current, &definition_config(false));
+
+    let Some(DiffOp::TableDefinition { target, .. }) = ops.last() else {
+        panic!("expected a table definition, got {ops:?}");
+    };
+    let column_names = target
+        .columns
+        .iter()
+        .map(|column| column.name.value.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(column_names, vec!["id", "email", "legacy"]);
+}
+
+#[test]
+fn table_definition_lists_dependent_views_in_creation_order() {
+    let view = |name: &str, query: &str| {
+        View::new(
+            QualifiedName {
+                schema: None,
+                name: ident(name),
+            },
+            query,
+        )
+    };
+    let active = view("active_users", "SELECT id FROM users WHERE id > 0");
+    let counted = view("active_count", "SELECT count(*) FROM active_users");
+    let unrelated = view("numbers", "SELECT 1");
+    let views = [counted.clone(), unrelated, active.clone()];
+    let schema = |table: Table| {
+        let mut objects = vec![SchemaObject::Table(table)];
+        objects.extend(views.iter().cloned().map(SchemaObject::View));
+        objects
+    };
+
+    let ops = DiffEngine::new()
+        .diff(
+            &schema(users_table(&["id", "email"])),
+            &schema(users_table(&["id"])),
+            &definition_config(true),
+        )
+        .expect("diff should succeed");
+
+    let Some(DiffOp::TableDefinition {
+        dependent_views, ..
+    }) = ops.last()
+    else {
+        panic!("expected a table definition, got {ops:?}");
+    };
+    assert_eq!(dependent_views, &vec![active, counted]);
+}
+
+#[test]
+fn table_definition_lists_the_indexes_the_diff_keeps() {
+    let index = |name: &str, column: &str| IndexDef {
+        name: Some(ident(name)),
+        owner: IndexOwner::Table(users()),
+        columns: vec![IndexColumn {
+            expr: Expr::Ident(ident(column)),
+        }],
+        unique: false,
+        method: None,
+        where_clause: None,
+        concurrent: false,
+        extra: BTreeMap::new(),
+    };
+    let kept = index("users_email", "email");
+    let dropped = index("users_id", "id");
+
+    let ops = DiffEngine::new()
+        .diff(
+            &[
+                SchemaObject::Table(users_table(&["id", "email", "name"])),
+                SchemaObject::Index(kept.clone()),
+            ],
+            &[
+                SchemaObject::Table(users_table(&["id", "email"])),
+                SchemaObject::Index(kept.clone()),
+                SchemaObject::Index(dropped),
+            ],
+            &definition_config(true),
+        )
+        .expect("diff should succeed");
+
+    let Some(DiffOp::TableDefinition {
+        dependent_indexes, ..
+    }) = ops
+        .iter()
+        .find(|op| matches!(op, DiffOp::TableDefinition { .. }))
+    else {
+        panic!("expected a table definition, got {ops:?}");
+    };
+    assert_eq!(dependent_indexes, &vec![kept]);
+    assert!(ops.iter().any(|op| matches!(op, DiffOp::DropIndex { .. })));
+}
+
+#[test]
+fn table_definition_lists_the_triggers_the_diff_keeps() {
+    let trigger = |name: &str| Trigger {
+        name: QualifiedName {
+            schema: None,
+            name: ident(name),
+        },
+        table: users(),
+        timing: TriggerTiming::After,
+        events: vec![TriggerEvent::Insert],
+        update_columns: Vec::new(),
+        for_each: TriggerForEach::Row,
+        when_clause: None,
+        body: "BEGIN SELECT 1; END".to_string(),
+    };
+    let kept = trigger("users_audit");
+    let dropped = trigger("users_touch");
+
+    let ops = DiffEngine::new()
+        .diff(
+            &[
+                SchemaObject::Table(users_table(&["id", "email", "name"])),
+                SchemaObject::Trigger(kept.clone()),
+            ],
+            &[
+                SchemaObject::Table(users_table(&["id", "email"])),
+                SchemaObject::Trigger(kept.clone()),
+                SchemaObject::Trigger(dropped),
+            ],
+            &definition_config(true),
+        )
+        .expect("diff should succeed");
+
+    let Some(DiffOp::TableDefinition {
+        dependent_triggers, ..
+    }) = ops
+        .iter()
+        .find(|op| matches!(op, DiffOp::TableDefinition { .. }))
+    else {
+        panic!("expected a table definition, got {ops:?}");
+    };
+    assert_eq!(dependent_triggers, &vec![kept]);
+    assert!(
+        ops.iter()
+            .any(|op| matches!(op, DiffOp::DropTrigger { .. }))
+    );
+}
+
+#[test]
+fn table_definition_lists_the_foreign_keys_referencing_it_that_the_diff_keeps() {
+    let sessions = |foreign_keys: Vec<ForeignKey>| {
+        let mut table = Table::named("sessions");
+        table.columns = vec![column("user_id"), column("owner_id")];
+        table.foreign_keys = foreign_keys;
+        table
+    };
+    let foreign_key = |name: &str, column: &str| ForeignKey {
+        name: Some(ident(name)),
+        columns: vec![ident(column)],
+        referenced_table: users(),
+        referenced_columns: vec![ident("id")],
+        on_delete: None,
+        on_update: None,
+        deferrable: None,
+        extra: BTreeMap::new(),
+    };
+    let kept = foreign_key("sessions_user_fk", "user_id");
+    let dropped = foreign_key("sessions_owner_fk", "owner_id");
+    let config = DiffConfig {
+        diff_table_constraints: true,
+        ..definition_config(true)
+    };
+
+    let ops = DiffEngine::new()
+        .diff(
+            &[
+                SchemaObject::Table(users_table(&["id", "email", "name"])),
+                SchemaObject::Table(sessions(vec![kept.clone()])),
+            ],
+            &[
+                SchemaObject::Table(users_table(&["id", "email"])),
+                SchemaObject::Table(sessions(vec![kept.clone(), dropped])),
+            ],
+            &config,
+        )
+        .expect("diff should succeed");
+
+    let Some(DiffOp::TableDefinition {
+        referencing_foreign_keys,
+        ..
+    }) = ops
+        .iter()
+        .find(|op| matches!(op, DiffOp::TableDefinition { target, .. } if target.name == users()))
+    else {
+        panic!("expected a users table definition, got {ops:?}");
+    };
+    let sessions_name = QualifiedName {
+        schema: None,
+        name: ident("sessions"),
+    };
+    assert_eq!(referencing_foreign_keys, &vec![(sessions_name, kept)]);
+    assert!(
+        ops.iter()
+            .any(|op| matches!(op, DiffOp::DropForeignKey { .. }))
+    );
+}
@@ -0,0 +1,153 @@
+use std::collections::BTreeMap;
+
+use stateql_core::{
+    DiffConfig, DiffEngine, DiffOp, Ident, QualifiedName, SchemaObject, Table, TableOptions, Value,
+    extra_keys::{mysql as mysql_keys, sqlite as sqlite_keys},
+};
+
+fn table_with_options(options: &[(&str, Value)]) -> SchemaObject {
+    let mut table = Table::named("users");
+    table.options.extra = options
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect();
+    SchemaObject::Table(table)
+}
+
+fn diffed_options_config() -> DiffConfig {
+    DiffConfig {
+        diffed_table_options: &[mysql_keys::TABLE_ENGINE, mysql_keys::TABLE_COMMENT],
+        ..DiffConfig::default()
+    }
+}
+
+fn users() -> QualifiedName {
+    QualifiedName {
+        schema: None,
+        name: Ident::unquoted("users"),
+    }
+}
+
+#[test]
+fn changed_and_removed_table_options_are_emitted_together() {
+    let desired = vec![table_with_options(&[(
+        mysql_keys::TABLE_ENGINE,
+        Value::String("myisam".to_string()),
+    )])];
+    let current = vec![table_with_options(&[
+        (
+            mysql_keys::TABLE_ENGINE,
+            Value::String("memory".to_string()),
+        ),
+        (
+            mysql_keys::TABLE_COMMENT,
+            Value::String("legacy".to_string()),
+        ),
+    ])];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &diffed_options_config())
+        .expect("diff should succeed");
+
+    assert_eq!(
+        ops,
+        vec![DiffOp::AlterTableOptions {
+            table: users(),
+            options: TableOptions {
+                extra: BTreeMap::from([
+                    (mysql_keys::TABLE_COMMENT.to_string(), Value::Null),
+                    (
+                        mysql_keys::TABLE_ENGINE.to_string(),
+                        Value::String("myisam".to_string())
+                    ),
+                ]),
+            },
+        }]
+    );
+}
+
+#[test]
+fn hint_keys_outside_diffed_table_options_are_ignored() {
+    let desired = vec![table_with_options(&[(
+        "mysql.source_sql",
+        Value::String("CREATE TABLE users (id int)".to_string()),
+    )])];
+    let current = vec![table_with_options(&[(
+        "mysql.source_sql",
+        Value::String("CREATE TABLE `users` (`id` int)".to_string()),
+    )])];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &diffed_options_config())
+        .expect("diff should succeed");
+
+    assert!(ops.is_empty(), "hint keys must not be diffed: {ops:?}");
+}
+
+#[test]
+fn only_table_options_the_dialect_lists_are_diffed() {
+    let desired = vec![table_with_options(&[(
+        mysql_keys::TABLE_ENGINE,
+        Value::String("myisam".to_string()),
+    )])];
+    let current = vec![table_with_options(&[(
+        mysql_keys::TABLE_ENGINE,
+        Value::String("memory".to_string()),
+    )])];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &DiffConfig::default())
+        .expect("diff should succeed");
+
+    assert!(
+        ops.is_empty(),
+        "unlisted options must not be diffed: {ops:?}"
+    );
+}
+
+#[test]
+fn creation_only_table_options_recreate_the_table_when_drops_are_enabled() {
+    let virtual_table = |arguments: &str| {
+        table_with_options(&[
+            (
+                sqlite_keys::TABLE_VIRTUAL_MODULE,
+                Value::String("fts5".to_string()),
+            ),
+            (
+                sqlite_keys::TABLE_VIRTUAL_ARGUMENTS,
+                Value::String(arguments.to_string()),
+            ),
+        ])
+    };
+    let desired = vec![virtual_table("title, body")];
+    let current = vec![virtual_table("title")];
+
+    let ops = DiffEngine::new()
+        .diff(
+            &desired,
+            &current,
+            &DiffConfig {
+                enable_drop: true,
+                ..DiffConfig::default()
+            },
+        )
+        .expect("diff should succeed");
+    let SchemaObject::Table(desired_table) = &desired[0] else {
+        unreachable!("virtual_table builds a table");
+    };
+    assert_eq!(
+        ops,
+        vec![
+            DiffOp::DropTable(users()),
+            DiffOp::CreateTable(desired_table.clone()),
+        ]
+    );
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &DiffConfig::default())
+        .expect("diff should succeed");
+    assert!(
+        ops.is_empty(),
+        "recreate must wait for enable_drop: {ops:?}"
+    );
+}
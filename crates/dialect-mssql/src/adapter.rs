@@ -8,14 +8,14 @@ use futures_util::TryStreamExt;
 use stateql_core::{
     ConnectionConfig, DatabaseAdapter, ExecutionError, Result, Transaction, Version,
 };
-use tiberius::{AuthMethod, Client, Config, QueryItem};
+use tiberius::{AuthMethod, Client, Config, EncryptionLevel, QueryItem};
 use tokio::{
     net::TcpStream,
     runtime::{Builder, Runtime},
 };
 use tokio_util::compat::{Compat, TokioAsyncWriteCompatExt};
 
-use crate::export_queries;
+use crate::{export_queries, to_sql};
 
 type TdsClient = Client<Compat<TcpStream>>;
 
@@ -24,6 +24,8 @@ const CONNECT_SQL: &str = "CONNECT mssql";
 const DEFAULT_MSSQL_HOST: &str = "127.0.0.1";
 const DEFAULT_MSSQL_PORT: u16 = 1433;
 const DEFAULT_MSSQL_SCHEMA: &str = "dbo";
+/// Prefix of the history tables SQL Server names itself.
+const AUTOMATIC_HISTORY_TABLE_PREFIX: &str = "MSSQL_TemporalHistoryFor_";
 const DEFAULT_MSSQL_VERSION: &str = "15.0.2000.5";
 const MINIMUM_MSSQL_PRODUCT_MAJOR_VERSION: u16 = 15;
 const MINIMUM_MSSQL_YEAR_VERSION: u16 = 2019;
@@ -31,6 +33,9 @@ const YEAR_VERSION_THRESHOLD: u16 = 1000;
 const SERVER_VERSION_OVERRIDE_KEY: &str = "mssql.server_version";
 const SCHEMA_SEARCH_PATH_OVERRIDE_KEY: &str = "mssql.schema_search_path";
 const EXPORT_SCHEMA_SQL_OVERRIDE_KEY: &str = "mssql.export_schema_sql";
+const ENCRYPT_KEY: &str = "mssql.encrypt";
+const CA_CERT_KEY: &str = "mssql.ca_cert";
+const TRUST_SERVER_CERTIFICATE_KEY: &str = "mssql.trust_server_certificate";
 const POISONED_CONNECTION_MESSAGE: &str = "mssql connection state was poisoned";
 
 pub(crate) struct MssqlAdapter {
@@ -49,6 +54,22 @@ struct LiveState {
     client: TdsClient,
 }
 
+/// Encryption is required and the server certificate is validated against the system
+/// trust store unless the connection says otherwise.
+#[derive(Debug)]
+struct TlsOptions {
+    encryption: EncryptionLevel,
+    trust: CertificateTrust,
+}
+
+#[derive(Debug)]
+enum CertificateTrust {
+    SystemStore,
+    CaCertificate(String),
+    /// Accepts any certificate; only with an explicit opt-in.
+    TrustAll,
+}
+
 #[derive(Debug)]
 struct ExportColumn {
     name: String,
@@ -58,6 +79,25 @@ struct ExportColumn {
     scale: i32,
     not_null: bool,
     identity: Option<IdentitySpec>,
+    collation: Option<String>,
+    default: Option<DefaultSpec>,
+    computed: Option<ComputedSpec>,
+    /// `ROW START` or `ROW END` for the period columns of a temporal table.
+    generated_always: Option<&'static str>,
+    hidden: bool,
+}
+
+#[derive(Debug)]
+struct DefaultSpec {
+    /// `None` inside a table type, where SQL Server names constraints itself.
+    name: Option<String>,
+    definition: String,
+}
+
+#[derive(Debug)]
+struct ComputedSpec {
+    definition: String,
+    persisted: bool,
 }
 
 #[derive(Debug)]
@@ -68,13 +108,39 @@ struct IdentitySpec {
 }
 
 #[derive(Debug)]
-struct PrimaryKeySpec {
+struct IndexSpec {
+    name: String,
+    clustered_kind: &'static str,
+    columnstore: bool,
+    unique: bool,
+    unique_constraint: bool,
+    filter: Option<String>,
+    columns: Vec<(String, bool)>,
+    included: Vec<String>,
+}
+
+#[derive(Debug)]
+struct ForeignKeySpec {
     name: String,
+    referenced_table: String,
+    columns: Vec<String>,
+    referenced_columns: Vec<String>,
+    on_delete: Option<String>,
+    on_update: Option<String>,
+    not_for_replication: bool,
+}
+
+#[derive(Debug)]
+struct PrimaryKeySpec {
+    /// `None` inside a table type, where SQL Server names constraints itself.
+    name: Option<String>,
     clustered_kind: Option<&'static str>,
     columns: Vec<(String, bool)>,
 }
 
 pub(crate) fn connect(config: &ConnectionConfig) -> Result<Box<dyn DatabaseAdapter>> {
+    let tls = parse_tls_options(config)?;
+
     if let Some(export_schema_sql) = config.extra.get(EXPORT_SCHEMA_SQL_OVERRIDE_KEY) {
         let raw_version = config
             .extra
@@ -102,7 +168,7 @@ pub(crate) fn connect(config: &ConnectionConfig) -> Result<Box<dyn DatabaseAdapt
         ensure_minimum_version(&parsed_version, raw_version.as_str())?;
     }
 
-    let mut live_state = connect_live_state(config)?;
+    let mut live_state = connect_live_state(config, &tls)?;
 
     let raw_version = if let Some(raw_version) = config.extra.get(SERVER_VERSION_OVERRIDE_KEY) {
         raw_version.clone()
@@ -164,12 +230,12 @@ impl DatabaseAdapter for MssqlAdapter {
     }
 }
 
-fn connect_live_state(config: &ConnectionConfig) -> Result<LiveState> {
+fn connect_live_state(config: &ConnectionConfig, tls: &TlsOptions) -> Result<LiveState> {
     let runtime = Builder::new_current_thread()
         .enable_all()
         .build()
         .map_err(|source| execution_error(CONNECT_SQL, source))?;
-    let tds_config = build_tiberius_config(config)?;
+    let tds_config = build_tiberius_config(config, tls)?;
 
     let client = runtime.block_on(async {
         let tcp = TcpStream::connect(tds_config.get_addr())
@@ -186,7 +252,7 @@ fn connect_live_state(config: &ConnectionConfig) -> Result<LiveState> {
     Ok(LiveState { runtime, client })
 }
 
-fn build_tiberius_config(config: &ConnectionConfig) -> Result<Config> {
+fn build_tiberius_config(config: &ConnectionConfig, tls: &TlsOptions) -> Result<Config> {
     let host = config
         .host
         .as_deref()
@@ -204,11 +270,66 @@ fn build_tiberius_config(config: &ConnectionConfig) -> Result<Config> {
     tds_config.port(port);
     tds_config.database(config.database.clone());
     tds_config.authentication(AuthMethod::sql_server(user, password));
-    tds_config.trust_cert();
+    tds_config.encryption(tls.encryption);
+    match &tls.trust {
+        CertificateTrust::SystemStore => {}
+        CertificateTrust::CaCertificate(path) => tds_config.trust_cert_ca(path),
+        CertificateTrust::TrustAll => tds_config.trust_cert(),
+    }
 
     Ok(tds_config)
 }
 
+fn parse_tls_options(config: &ConnectionConfig) -> Result<TlsOptions> {
+    let encryption = match config.extra.get(ENCRYPT_KEY).map(|value| value.trim()) {
+        None => EncryptionLevel::Required,
+        Some(value) if value.eq_ignore_ascii_case("required") => EncryptionLevel::Required,
+        Some(value) if value.eq_ignore_ascii_case("on") => EncryptionLevel::On,
+        Some(value) if value.eq_ignore_ascii_case("off") => EncryptionLevel::Off,
+        Some(value) => {
+            return Err(tls_option_error(format!(
+                "`{ENCRYPT_KEY}` must be off, on or required, got `{value}`"
+            )));
+        }
+    };
+    let trust_all = match config
+        .extra
+        .get(TRUST_SERVER_CERTIFICATE_KEY)
+        .map(|value| value.trim())
+    {
+        None => false,
+        Some(value) if value.eq_ignore_ascii_case("true") => true,
+        Some(value) if value.eq_ignore_ascii_case("false") => false,
+        Some(value) => {
+            return Err(tls_option_error(format!(
+                "`{TRUST_SERVER_CERTIFICATE_KEY}` must be true or false, got `{value}`"
+            )));
+        }
+    };
+    let ca_certificate = config
+        .extra
+        .get(CA_CERT_KEY)
+        .map(|path| path.trim())
+        .filter(|path| !path.is_empty());
+
+    let trust = match (ca_certificate, trust_all) {
+        (Some(_), true) => {
+            return Err(tls_option_error(format!(
+                "`{CA_CERT_KEY}` and `{TRUST_SERVER_CERTIFICATE_KEY}` cannot be combined"
+            )));
+        }
+        (Some(path), false) => CertificateTrust::CaCertificate(path.to_string()),
+        (None, true) => CertificateTrust::TrustAll,
+        (None, false) => CertificateTrust::SystemStore,
+    };
+
+    Ok(TlsOptions { encryption, trust })
+}
+
+fn tls_option_error(message: String) -> stateql_core::Error {
+    execution_error(CONNECT_SQL, io::Error::other(message))
+}
+
 fn lock_live_state<'a>(
     state: &'a Mutex<LiveState>,
     sql: &str,
@@ -220,7 +341,9 @@ fn lock_live_state<'a>(
 
 fn export_schema_live(state: &mut LiveState) -> Result<String> {
     let table_rows = query_rows(state, export_queries::TABLE_NAMES_QUERY)?;
-    let mut statements = Vec::with_capacity(table_rows.len());
+    // Sequences and table types first: defaults and modules refer to them.
+    let mut statements = export_sequences(state)?;
+    statements.extend(export_table_types(state)?);
 
     for row in &table_rows {
         let schema_name = row
@@ -231,22 +354,39 @@ fn export_schema_live(state: &mut LiveState) -> Result<String> {
         if table_name.trim().is_empty() {
             continue;
         }
-        statements.push(render_table_ddl(
+        statements.extend(render_table_ddl(
             state,
             schema_name.trim(),
             table_name.trim(),
         )?);
     }
 
-    Ok(statements.join("\n\n"))
+    statements.extend(export_synonyms(state)?);
+
+    // Each module is a batch of its own.
+    let mut batches = Vec::new();
+    if !statements.is_empty() {
+        batches.push(statements.join("\n\n"));
+    }
+    batches.extend(export_module_definitions(state)?);
+    // Descriptions last: they may belong to any table, view or procedure above.
+    let descriptions = export_descriptions(state)?;
+    if !descriptions.is_empty() {
+        batches.push(descriptions.join("\n"));
+    }
+    Ok(batches.join("\nGO\n"))
 }
 
-fn render_table_ddl(state: &mut LiveState, schema_name: &str, table_name: &str) -> Result<String> {
+/// The table, followed by its indexes.
+fn render_table_ddl(
+    state: &mut LiveState,
+    schema_name: &str,
+    table_name: &str,
+) -> Result<Vec<String>> {
     let object_id_literal = format!("{}.{}", quote_ident(schema_name), quote_ident(table_name));
-    let columns_query = export_queries::COLUMN_DEFINITIONS_QUERY_TEMPLATE
-        .replace("{object_id_literal}", object_id_literal.as_str());
-    let primary_key_query = export_queries::PRIMARY_KEY_QUERY_TEMPLATE
-        .replace("{object_id_literal}", object_id_literal.as_str());
+    let object_id = format!("OBJECT_ID(N'{object_id_literal}')");
+    let table_query = |template: &str| template.replace("{object_id}", &object_id);
+    let columns_query = table_query(export_queries::COLUMN_DEFINITIONS_QUERY_TEMPLATE);
 
     let column_rows = query_rows(state, columns_query.as_str())?;
     if column_rows.is_empty() {
@@ -260,19 +400,250 @@ fn render_table_ddl(state: &mut LiveState, schema_name: &str, table_name: &str)
         .iter()
         .map(|row| parse_export_column(row.as_slice()))
         .collect::<Result<Vec<_>>>()?;
-    let primary_key = parse_primary_key_spec(&query_rows(state, primary_key_query.as_str())?);
+    let primary_key = parse_primary_key_spec(&query_rows(
+        state,
+        &table_query(export_queries::PRIMARY_KEY_QUERY_TEMPLATE),
+    )?);
+    let indexes = parse_index_specs(&query_rows(
+        state,
+        &table_query(export_queries::INDEXES_QUERY_TEMPLATE),
+    )?);
+    let foreign_keys = parse_foreign_key_specs(&query_rows(
+        state,
+        &table_query(export_queries::FOREIGN_KEYS_QUERY_TEMPLATE),
+    )?);
+    let checks = query_rows(
+        state,
+        &table_query(export_queries::CHECK_CONSTRAINTS_QUERY_TEMPLATE),
+    )?;
+    let temporal = query_rows(state, &table_query(export_queries::TEMPORAL_QUERY_TEMPLATE))?;
+    let temporal = temporal.first().map(Vec::as_slice).unwrap_or_default();
+    let temporal_field = |index: usize| {
+        temporal
+            .get(index)
+            .map(|value| value.trim())
+            .unwrap_or_default()
+    };
 
     let mut definitions = columns.iter().map(render_export_column).collect::<Vec<_>>();
+    if !temporal_field(0).is_empty() && !temporal_field(1).is_empty() {
+        definitions.push(format!(
+            "PERIOD FOR SYSTEM_TIME ({}, {})",
+            quote_ident(temporal_field(0)),
+            quote_ident(temporal_field(1))
+        ));
+    }
 
     if let Some(primary_key) = primary_key {
         definitions.push(render_primary_key(primary_key));
     }
+    definitions.extend(
+        indexes
+            .iter()
+            .filter(|index| index.unique_constraint)
+            .map(render_unique_constraint),
+    );
+    definitions.extend(foreign_keys.iter().map(render_foreign_key));
+    definitions.extend(checks.iter().filter_map(|row| {
+        let name = row.first()?.trim();
+        let definition = row.get(1)?.trim();
+        (!name.is_empty()).then(|| format!("CONSTRAINT {} CHECK {definition}", quote_ident(name)))
+    }));
+
+    let mut table_options = String::new();
+    if temporal_field(2) == "1" {
+        table_options.push_str(" WITH (SYSTEM_VERSIONING = ON");
+        // A history table SQL Server named itself is left for it to name again.
+        if !temporal_field(3).is_empty()
+            && !temporal_field(4).starts_with(AUTOMATIC_HISTORY_TABLE_PREFIX)
+        {
+            table_options.push_str(&format!(" (HISTORY_TABLE = {})", temporal_field(3)));
+        }
+        table_options.push(')');
+    }
 
-    Ok(format!(
-        "CREATE TABLE {} (\n    {}\n);",
+    let mut statements = vec![format!(
+        "CREATE TABLE {} (\n    {}\n){table_options};",
         object_id_literal,
         definitions.join(",\n    ")
-    ))
+    )];
+    statements.extend(
+        indexes
+            .iter()
+            .filter(|index| !index.unique_constraint)
+            .map(|index| render_export_index(&object_id_literal, index)),
+    );
+    Ok(statements)
+}
+
+fn export_sequences(state: &mut LiveState) -> Result<Vec<String>> {
+    let rows = query_rows(state, export_queries::SEQUENCES_QUERY)?;
+    Ok(rows
+        .iter()
+        .filter(|row| row.get(1).is_some_and(|name| !name.trim().is_empty()))
+        .map(|row| render_export_sequence(row))
+        .collect())
+}
+
+/// Bounds that do not fit the IR, those of a wide `decimal`, are left out; they are the
+/// defaults of their type in that case.
+fn render_export_sequence(row: &[String]) -> String {
+    let field = |index: usize| row.get(index).map(|value| value.trim()).unwrap_or_default();
+    let data_type = field(2).to_ascii_uppercase();
+    let data_type = match data_type.as_str() {
+        "DECIMAL" | "NUMERIC" => format!("{data_type}({}, 0)", field(3)),
+        _ => data_type,
+    };
+    let mut sql = format!(
+        "CREATE SEQUENCE {}.{} AS {data_type}",
+        quote_ident(field(0)),
+        quote_ident(field(1))
+    );
+    let options = [
+        ("START WITH", 4),
+        ("INCREMENT BY", 5),
+        ("MINVALUE", 6),
+        ("MAXVALUE", 7),
+    ];
+    for (keyword, index) in options {
+        if let Some(value) = parse_i64_field(row.get(index)) {
+            sql.push_str(&format!(" {keyword} {value}"));
+        }
+    }
+    sql.push_str(if field(8) == "1" {
+        " CYCLE"
+    } else {
+        " NO CYCLE"
+    });
+    if field(9) == "0" {
+        sql.push_str(" NO CACHE");
+    } else if let Some(cache) = parse_i64_field(row.get(10)) {
+        sql.push_str(&format!(" CACHE {cache}"));
+    }
+    sql.push(';');
+    sql
+}
+
+fn export_synonyms(state: &mut LiveState) -> Result<Vec<String>> {
+    let rows = query_rows(state, export_queries::SYNONYMS_QUERY)?;
+    Ok(rows
+        .iter()
+        .filter_map(|row| {
+            let schema = row.first()?.trim();
+            let name = row.get(1)?.trim();
+            let target = row.get(2)?.trim();
+            (!name.is_empty() && !target.is_empty()).then(|| {
+                format!(
+                    "CREATE SYNONYM {}.{} FOR {target};",
+                    quote_ident(schema),
+                    quote_ident(name)
+                )
+            })
+        })
+        .collect())
+}
+
+/// Table types are read through the hidden table behind each type. SQL Server names their
+/// constraints itself, so names are left out and the parser accepts the result.
+fn export_table_types(state: &mut LiveState) -> Result<Vec<String>> {
+    let type_rows = query_rows(state, export_queries::TABLE_TYPES_QUERY)?;
+    let mut statements = Vec::with_capacity(type_rows.len());
+    for row in &type_rows {
+        let (Some(schema), Some(name), Some(object_id)) = (row.first(), row.get(1), row.get(2))
+        else {
+            continue;
+        };
+        let type_query = |template: &str| template.replace("{object_id}", object_id.trim());
+        let columns_query = type_query(export_queries::COLUMN_DEFINITIONS_QUERY_TEMPLATE);
+        let mut columns = query_rows(state, columns_query.as_str())?
+            .iter()
+            .map(|row| parse_export_column(row.as_slice()))
+            .collect::<Result<Vec<_>>>()?;
+        if columns.is_empty() {
+            return Err(execution_error(
+                columns_query.as_str(),
+                io::Error::other("table type export produced no columns"),
+            ));
+        }
+        for column in &mut columns {
+            if let Some(default) = &mut column.default {
+                default.name = None;
+            }
+        }
+        let primary_key = parse_primary_key_spec(&query_rows(
+            state,
+            &type_query(export_queries::PRIMARY_KEY_QUERY_TEMPLATE),
+        )?);
+        let checks = query_rows(
+            state,
+            &type_query(export_queries::CHECK_CONSTRAINTS_QUERY_TEMPLATE),
+        )?;
+
+        let mut definitions = columns.iter().map(render_export_column).collect::<Vec<_>>();
+        if let Some(mut primary_key) = primary_key {
+            primary_key.name = None;
+            definitions.push(render_primary_key(primary_key));
+        }
+        definitions.extend(checks.iter().filter_map(|row| {
+            let definition = row.get(1)?.trim();
+            (!definition.is_empty()).then(|| format!("CHECK {definition}"))
+        }));
+        statements.push(format!(
+            "CREATE TYPE {}.{} AS TABLE (\n    {}\n);",
+            quote_ident(schema.trim()),
+            quote_ident(name.trim()),
+            definitions.join(",\n    ")
+        ));
+    }
+    Ok(statements)
+}
+
+fn export_descriptions(state: &mut LiveState) -> Result<Vec<String>> {
+    let rows = query_rows(state, export_queries::DESCRIPTIONS_QUERY)?;
+    Ok(rows
+        .iter()
+        .filter_map(|row| {
+            let field = |index: usize| row.get(index).map(|value| value.trim());
+            let (schema, name, kind, column) = (field(0)?, field(1)?, field(2)?, field(3)?);
+            // Descriptions are kept as they were written, surrounding spaces included.
+            let description = row.get(4)?;
+            let mut levels = vec![("SCHEMA", schema), (kind, name)];
+            if !column.is_empty() {
+                levels.push(("COLUMN", column));
+            }
+            (!name.is_empty()).then(|| {
+                to_sql::render_extended_property_call(
+                    "sp_addextendedproperty",
+                    &levels,
+                    Some(description),
+                )
+            })
+        })
+        .collect())
+}
+
+/// Definitions are stored as they were written, so they are exported verbatim.
+fn export_module_definitions(state: &mut LiveState) -> Result<Vec<String>> {
+    query_rows(state, export_queries::MODULE_DEFINITIONS_QUERY)?
+        .into_iter()
+        .map(|row| {
+            let definition = row.get(2).map(|value| value.trim()).unwrap_or_default();
+            if definition.is_empty() {
+                let name = format!(
+                    "{}.{}",
+                    row.first().map(String::as_str).unwrap_or_default(),
+                    row.get(1).map(String::as_str).unwrap_or_default()
+                );
+                return Err(execution_error(
+                    export_queries::MODULE_DEFINITIONS_QUERY,
+                    io::Error::other(format!(
+                        "definition of module {name} is not readable (WITH ENCRYPTION?)"
+                    )),
+                ));
+            }
+            Ok(definition.to_string())
+        })
+        .collect()
 }
 
 fn parse_export_column(row: &[String]) -> Result<ExportColumn> {
@@ -301,6 +672,25 @@ fn parse_export_column(row: &[String]) -> Result<ExportColumn> {
         None
     };
 
+    let generated_always = match row.get(15).map(|value| value.trim()) {
+        Some("1") => Some("ROW START"),
+        Some("2") => Some("ROW END"),
+        _ => None,
+    };
+    let hidden = row.get(16).is_some_and(|value| value.trim() == "1");
+
+    let collation = non_empty_field(row.get(10));
+    let default = non_empty_field(row.get(11))
+        .zip(non_empty_field(row.get(12)))
+        .map(|(name, definition)| DefaultSpec {
+            name: Some(name),
+            definition,
+        });
+    let computed = non_empty_field(row.get(13)).map(|definition| ComputedSpec {
+        definition,
+        persisted: row.get(14).is_some_and(|value| value.trim() == "1"),
+    });
+
     Ok(ExportColumn {
         name,
         data_type,
@@ -309,9 +699,106 @@ fn parse_export_column(row: &[String]) -> Result<ExportColumn> {
         scale,
         not_null,
         identity,
+        collation,
+        default,
+        computed,
+        generated_always,
+        hidden,
     })
 }
 
+fn parse_index_specs(rows: &[Vec<String>]) -> Vec<IndexSpec> {
+    let mut indexes: Vec<IndexSpec> = Vec::new();
+    for row in rows {
+        let Some(name) = non_empty_field(row.first()) else {
+            continue;
+        };
+        let Some(column) = non_empty_field(row.get(5)) else {
+            continue;
+        };
+        if indexes.last().is_none_or(|index| index.name != name) {
+            let type_desc = row.get(1).map(|value| value.to_ascii_uppercase());
+            indexes.push(IndexSpec {
+                name,
+                clustered_kind: match type_desc.as_deref() {
+                    Some(value) if value.contains("NONCLUSTERED") => "NONCLUSTERED",
+                    _ => "CLUSTERED",
+                },
+                columnstore: type_desc
+                    .as_deref()
+                    .is_some_and(|value| value.contains("COLUMNSTORE")),
+                unique: row.get(2).is_some_and(|value| value.trim() == "1"),
+                unique_constraint: row.get(3).is_some_and(|value| value.trim() == "1"),
+                filter: non_empty_field(row.get(4)),
+                columns: Vec::new(),
+                included: Vec::new(),
+            });
+        }
+        let index = indexes.last_mut().expect("an index was just pushed");
+        // Every column of a columnstore index is reported as included; a clustered one
+        // covers the whole table and is created without a column list.
+        if index.columnstore {
+            if index.clustered_kind == "NONCLUSTERED" {
+                index.columns.push((column, false));
+            }
+        } else if row.get(7).is_some_and(|value| value.trim() == "1") {
+            index.included.push(column);
+        } else {
+            let is_descending = row.get(6).is_some_and(|value| value.trim() == "1");
+            index.columns.push((column, is_descending));
+        }
+    }
+    indexes
+}
+
+fn parse_foreign_key_specs(rows: &[Vec<String>]) -> Vec<ForeignKeySpec> {
+    let mut foreign_keys: Vec<ForeignKeySpec> = Vec::new();
+    for row in rows {
+        let (Some(name), Some(column), Some(referenced_column)) = (
+            non_empty_field(row.first()),
+            non_empty_field(row.get(3)),
+            non_empty_field(row.get(4)),
+        ) else {
+            continue;
+        };
+        if foreign_keys
+            .last()
+            .is_none_or(|foreign_key| foreign_key.name != name)
+        {
+            foreign_keys.push(ForeignKeySpec {
+                name,
+                referenced_table: format!(
+                    "{}.{}",
+                    quote_ident(
+                        row.get(1)
+                            .map(String::as_str)
+                            .unwrap_or(DEFAULT_MSSQL_SCHEMA)
+                    ),
+                    quote_ident(row.get(2).map(String::as_str).unwrap_or_default())
+                ),
+                columns: Vec::new(),
+                referenced_columns: Vec::new(),
+                on_delete: referential_action(row.get(5)),
+                on_update: referential_action(row.get(6)),
+                not_for_replication: row.get(7).is_some_and(|value| value.trim() == "1"),
+            });
+        }
+        let foreign_key = foreign_keys
+            .last_mut()
+            .expect("a foreign key was just pushed");
+        foreign_key.columns.push(column);
+        foreign_key.referenced_columns.push(referenced_column);
+    }
+    foreign_keys
+}
+
+/// `NO_ACTION` is the default and is left out.
+fn referential_action(raw: Option<&String>) -> Option<String> {
+    non_empty_field(raw)
+        .filter(|action| !action.eq_ignore_ascii_case("NO_ACTION"))
+        .map(|action| action.replace('_', " "))
+}
+
 fn parse_primary_key_spec(rows: &[Vec<String>]) -> Option<PrimaryKeySpec> {
     let first = rows.first()?;
     let name = first.first()?.trim().to_string();
@@ -343,19 +830,47 @@ fn parse_primary_key_spec(rows: &[Vec<String>]) -> Option<PrimaryKeySpec> {
     }
 
     Some(PrimaryKeySpec {
-        name,
+        name: Some(name),
         clustered_kind,
         columns,
     })
 }
 
 fn render_export_column(column: &ExportColumn) -> String {
+    if let Some(computed) = &column.computed {
+        let mut sql = format!(
+            "{} AS {}",
+            quote_ident(column.name.as_str()),
+            computed.definition.trim()
+        );
+        if computed.persisted {
+            sql.push_str(" PERSISTED");
+            if column.not_null {
+                sql.push_str(" NOT NULL");
+            }
+        }
+        return sql;
+    }
+
     let mut sql = format!(
         "{} {}",
         quote_ident(column.name.as_str()),
         render_export_data_type(column)
     );
 
+    if let Some(collation) = &column.collation {
+        sql.push_str(" COLLATE ");
+        sql.push_str(collation);
+    }
+
+    if let Some(generated_always) = column.generated_always {
+        sql.push_str(" GENERATED ALWAYS AS ");
+        sql.push_str(generated_always);
+        if column.hidden {
+            sql.push_str(" HIDDEN");
+        }
+    }
+
     if column.not_null {
         sql.push_str(" NOT NULL");
     }
@@ -371,14 +886,26 @@ fn render_export_column(column: &ExportColumn) -> String {
         }
     }
 
+    if let Some(default) = &column.default {
+        if let Some(name) = &default.name {
+            sql.push_str(" CONSTRAINT ");
+            sql.push_str(quote_ident(name.as_str()).as_str());
+        }
+        sql.push_str(" DEFAULT ");
+        sql.push_str(default.definition.trim());
+    }
+
     sql
 }
 
 fn render_primary_key(primary_key: PrimaryKeySpec) -> String {
-    let mut sql = format!(
-        "CONSTRAINT {} PRIMARY KEY",
-        quote_ident(primary_key.name.as_str())
-    );
+    let mut sql = String::new();
+    if let Some(name) = &primary_key.name {
+        sql.push_str("CONSTRAINT ");
+        sql.push_str(quote_ident(name.as_str()).as_str());
+        sql.push(' ');
+    }
+    sql.push_str("PRIMARY KEY");
     if let Some(clustered_kind) = primary_key.clustered_kind {
         sql.push(' ');
         sql.push_str(clustered_kind);
@@ -403,6 +930,94 @@ fn render_primary_key(primary_key: PrimaryKeySpec) -> String {
     sql
 }
 
+fn render_unique_constraint(index: &IndexSpec) -> String {
+    format!(
+        "CONSTRAINT {} UNIQUE {} ({})",
+        quote_ident(index.name.as_str()),
+        index.clustered_kind,
+        render_key_columns(&index.columns)
+    )
+}
+
+fn render_foreign_key(foreign_key: &ForeignKeySpec) -> String {
+    let quote_all = |columns: &[String]| {
+        columns
+            .iter()
+            .map(|column| quote_ident(column))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let mut sql = format!(
+        "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({})",
+        quote_ident(foreign_key.name.as_str()),
+        quote_all(&foreign_key.columns),
+        foreign_key.referenced_table,
+        quote_all(&foreign_key.referenced_columns)
+    );
+    if let Some(action) = &foreign_key.on_delete {
+        sql.push_str(" ON DELETE ");
+        sql.push_str(action);
+    }
+    if let Some(action) = &foreign_key.on_update {
+        sql.push_str(" ON UPDATE ");
+        sql.push_str(action);
+    }
+    if foreign_key.not_for_replication {
+        sql.push_str(" NOT FOR REPLICATION");
+    }
+    sql
+}
+
+fn render_export_index(table: &str, index: &IndexSpec) -> String {
+    let mut sql = format!(
+        "CREATE {}{}{} INDEX {} ON {table}",
+        if index.unique { "UNIQUE " } else { "" },
+        index.clustered_kind,
+        if index.columnstore {
+            " COLUMNSTORE"
+        } else {
+            ""
+        },
+        quote_ident(index.name.as_str()),
+    );
+    if !index.columns.is_empty() {
+        sql.push_str(" (");
+        sql.push_str(render_key_columns(&index.columns).as_str());
+        sql.push(')');
+    }
+    if !index.included.is_empty() {
+        let included = index
+            .included
+            .iter()
+            .map(|column| quote_ident(column))
+            .collect::<Vec<_>>()
+            .join(", ");
+        sql.push_str(" INCLUDE (");
+        sql.push_str(included.as_str());
+        sql.push(')');
+    }
+    if let Some(filter) = &index.filter {
+        sql.push_str(" WHERE ");
+        sql.push_str(filter.trim());
+    }
+    sql.push(';');
+    sql
+}
+
+fn render_key_columns(columns: &[(String, bool)]) -> String {
+    columns
+        .iter()
+        .map(|(name, is_descending)| {
+            if *is_descending {
+                format!("{} DESC", quote_ident(name))
+            } else {
+                quote_ident(name)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 fn render_export_data_type(column: &ExportColumn) -> String {
     let data_type = column.data_type.trim().to_ascii_uppercase();
     match data_type.as_str() {
@@ -534,6 +1149,12 @@ fn parse_i64_field(raw: Option<&String>) -> Option<i64> {
     raw.and_then(|value| value.trim().parse::<i64>().ok())
 }
 
+fn non_empty_field(raw: Option<&String>) -> Option<String> {
+    raw.map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .map(ToString::to_string)
+}
+
 fn ensure_minimum_version(version: &Version, raw_version: &str) -> Result<()> {
     let supported = if version.major >= YEAR_VERSION_THRESHOLD {
         version.major >= MINIMUM_MSSQL_YEAR_VERSION
@@ -10,6 +10,7 @@ SELECT
     SCHEMA_NAME(t.schema_id) AS table_schema,
     t.name AS table_name
 FROM sys.tables AS t
+WHERE t.temporal_type <> 1
 ORDER BY table_schema ASC, table_name ASC;
 "#;
 
@@ -24,11 +25,25 @@ SELECT
     CASE WHEN c.is_identity = 1 THEN '1' ELSE '0' END AS is_identity,
     COALESCE(CAST(ic.seed_value AS nvarchar(64)), '') AS seed_value,
     COALESCE(CAST(ic.increment_value AS nvarchar(64)), '') AS increment_value,
-    CASE WHEN COALESCE(ic.is_not_for_replication, 0) = 1 THEN '1' ELSE '0' END AS identity_not_for_replication
+    CASE WHEN COALESCE(ic.is_not_for_replication, 0) = 1 THEN '1' ELSE '0' END AS identity_not_for_replication,
+    CASE
+        WHEN c.collation_name <> CAST(DATABASEPROPERTYEX(DB_NAME(), 'Collation') AS sysname)
+        THEN c.collation_name
+        ELSE ''
+    END AS collation_name,
+    COALESCE(dc.name, '') AS default_name,
+    COALESCE(dc.definition, '') AS default_definition,
+    COALESCE(cc.definition, '') AS computed_definition,
+    CASE WHEN COALESCE(cc.is_persisted, 0) = 1 THEN '1' ELSE '0' END AS is_persisted,
+    CAST(c.generated_always_type AS nvarchar(8)) AS generated_always_type,
+    CASE WHEN c.is_hidden = 1 THEN '1' ELSE '0' END AS is_hidden
 FROM sys.columns AS c
 JOIN sys.types AS ty ON c.user_type_id = ty.user_type_id
 LEFT JOIN sys.identity_columns AS ic ON c.object_id = ic.object_id AND c.column_id = ic.column_id
-WHERE c.object_id = OBJECT_ID(N'{object_id_literal}')
+LEFT JOIN sys.default_constraints AS dc
+    ON c.object_id = dc.parent_object_id AND c.column_id = dc.parent_column_id
+LEFT JOIN sys.computed_columns AS cc ON c.object_id = cc.object_id AND c.column_id = cc.column_id
+WHERE c.object_id = {object_id}
 ORDER BY c.column_id ASC;
 "#;
 
@@ -41,7 +56,150 @@ SELECT
 FROM sys.indexes AS i
 JOIN sys.index_columns AS ic ON i.object_id = ic.object_id AND i.index_id = ic.index_id
 JOIN sys.columns AS c ON ic.object_id = c.object_id AND ic.column_id = c.column_id
-WHERE i.object_id = OBJECT_ID(N'{object_id_literal}')
+WHERE i.object_id = {object_id}
   AND i.is_primary_key = 1
 ORDER BY ic.key_ordinal ASC;
 "#;
+
+/// Unique constraints and rowstore indexes other than the primary key, one row per key or
+/// included column.
+pub(crate) const INDEXES_QUERY_TEMPLATE: &str = r#"
+SELECT
+    i.name AS index_name,
+    i.type_desc AS index_type_desc,
+    CASE WHEN i.is_unique = 1 THEN '1' ELSE '0' END AS is_unique,
+    CASE WHEN i.is_unique_constraint = 1 THEN '1' ELSE '0' END AS is_unique_constraint,
+    COALESCE(i.filter_definition, '') AS filter_definition,
+    c.name AS column_name,
+    CASE WHEN ic.is_descending_key = 1 THEN '1' ELSE '0' END AS is_descending,
+    CASE WHEN ic.is_included_column = 1 THEN '1' ELSE '0' END AS is_included
+FROM sys.indexes AS i
+JOIN sys.index_columns AS ic ON i.object_id = ic.object_id AND i.index_id = ic.index_id
+JOIN sys.columns AS c ON ic.object_id = c.object_id AND ic.column_id = c.column_id
+WHERE i.object_id = {object_id}
+  AND i.is_primary_key = 0
+  AND i.is_hypothetical = 0
+  AND i.type IN (1, 2, 5, 6)
+ORDER BY i.name ASC, ic.is_included_column ASC, ic.key_ordinal ASC, ic.index_column_id ASC;
+"#;
+
+pub(crate) const FOREIGN_KEYS_QUERY_TEMPLATE: &str = r#"
+SELECT
+    fk.name AS constraint_name,
+    SCHEMA_NAME(rt.schema_id) AS referenced_schema,
+    rt.name AS referenced_table,
+    pc.name AS column_name,
+    rc.name AS referenced_column_name,
+    fk.delete_referential_action_desc AS on_delete,
+    fk.update_referential_action_desc AS on_update,
+    CASE WHEN fk.is_not_for_replication = 1 THEN '1' ELSE '0' END AS is_not_for_replication
+FROM sys.foreign_keys AS fk
+JOIN sys.foreign_key_columns AS fkc ON fk.object_id = fkc.constraint_object_id
+JOIN sys.tables AS rt ON fk.referenced_object_id = rt.object_id
+JOIN sys.columns AS pc
+    ON fkc.parent_object_id = pc.object_id AND fkc.parent_column_id = pc.column_id
+JOIN sys.columns AS rc
+    ON fkc.referenced_object_id = rc.object_id AND fkc.referenced_column_id = rc.column_id
+WHERE fk.parent_object_id = {object_id}
+ORDER BY fk.name ASC, fkc.constraint_column_id ASC;
+"#;
+
+pub(crate) const CHECK_CONSTRAINTS_QUERY_TEMPLATE: &str = r#"
+SELECT
+    cc.name AS constraint_name,
+    cc.definition AS definition
+FROM sys.check_constraints AS cc
+WHERE cc.parent_object_id = {object_id}
+ORDER BY cc.name ASC;
+"#;
+
+/// History tables are listed with the table they belong to, never on their own.
+pub(crate) const TEMPORAL_QUERY_TEMPLATE: &str = r#"
+SELECT
+    COALESCE(COL_NAME(p.object_id, p.start_column_id), '') AS period_start,
+    COALESCE(COL_NAME(p.object_id, p.end_column_id), '') AS period_end,
+    CASE WHEN t.temporal_type = 2 THEN '1' ELSE '0' END AS is_system_versioned,
+    COALESCE(QUOTENAME(SCHEMA_NAME(h.schema_id)) + '.' + QUOTENAME(h.name), '') AS history_table,
+    COALESCE(h.name, '') AS history_name
+FROM sys.tables AS t
+LEFT JOIN sys.periods AS p ON t.object_id = p.object_id
+LEFT JOIN sys.tables AS h ON t.history_table_id = h.object_id
+WHERE t.object_id = {object_id};
+"#;
+
+pub(crate) const SEQUENCES_QUERY: &str = r#"
+SELECT
+    SCHEMA_NAME(s.schema_id) AS sequence_schema,
+    s.name AS sequence_name,
+    TYPE_NAME(s.user_type_id) AS data_type,
+    CAST(s.precision AS nvarchar(32)) AS precision,
+    CAST(s.start_value AS nvarchar(64)) AS start_value,
+    CAST(s.increment AS nvarchar(64)) AS increment,
+    CAST(s.minimum_value AS nvarchar(64)) AS minimum_value,
+    CAST(s.maximum_value AS nvarchar(64)) AS maximum_value,
+    CASE WHEN s.is_cycling = 1 THEN '1' ELSE '0' END AS is_cycling,
+    CASE WHEN s.is_cached = 1 THEN '1' ELSE '0' END AS is_cached,
+    COALESCE(CAST(s.cache_size AS nvarchar(32)), '') AS cache_size
+FROM sys.sequences AS s
+ORDER BY sequence_schema ASC, sequence_name ASC;
+"#;
+
+/// `base_object_name` is stored bracketed, as `[database].[schema].[object]`.
+pub(crate) const SYNONYMS_QUERY: &str = r#"
+SELECT
+    SCHEMA_NAME(sn.schema_id) AS synonym_schema,
+    sn.name AS synonym_name,
+    sn.base_object_name AS base_object_name
+FROM sys.synonyms AS sn
+ORDER BY synonym_schema ASC, synonym_name ASC;
+"#;
+
+/// The columns and constraints of a table type hang off its hidden table object.
+pub(crate) const TABLE_TYPES_QUERY: &str = r#"
+SELECT
+    SCHEMA_NAME(tt.schema_id) AS type_schema,
+    tt.name AS type_name,
+    CAST(tt.type_table_object_id AS nvarchar(32)) AS type_table_object_id
+FROM sys.table_types AS tt
+WHERE tt.is_user_defined = 1
+ORDER BY type_schema ASC, type_name ASC;
+"#;
+
+/// `MS_Description` of tables, their columns, views and procedures; history tables have
+/// none of their own.
+pub(crate) const DESCRIPTIONS_QUERY: &str = r#"
+SELECT
+    SCHEMA_NAME(o.schema_id) AS object_schema,
+    o.name AS object_name,
+    CASE o.type WHEN 'U' THEN 'TABLE' WHEN 'V' THEN 'VIEW' ELSE 'PROCEDURE' END AS object_type,
+    COALESCE(c.name, '') AS column_name,
+    CAST(ep.value AS nvarchar(max)) AS description
+FROM sys.extended_properties AS ep
+JOIN sys.objects AS o ON ep.major_id = o.object_id
+LEFT JOIN sys.columns AS c ON ep.major_id = c.object_id AND ep.minor_id = c.column_id
+LEFT JOIN sys.tables AS t ON o.object_id = t.object_id
+WHERE ep.class = 1
+  AND ep.name = N'MS_Description'
+  AND o.is_ms_shipped = 0
+  AND o.type IN ('U', 'V', 'P')
+  AND COALESCE(t.temporal_type, 0) <> 1
+  AND (ep.minor_id = 0 OR (o.type = 'U' AND c.name IS NOT NULL))
+ORDER BY object_schema ASC, object_name ASC, ep.minor_id ASC;
+"#;
+
+/// Views, procedures, functions and DML triggers, functions first so the views and
+/// procedures using them come after.
+pub(crate) const MODULE_DEFINITIONS_QUERY: &str = r#"
+SELECT
+    SCHEMA_NAME(o.schema_id) AS module_schema,
+    o.name AS module_name,
+    COALESCE(m.definition, '') AS definition
+FROM sys.sql_modules AS m
+JOIN sys.objects AS o ON m.object_id = o.object_id
+WHERE o.is_ms_shipped = 0
+  AND o.type IN ('FN', 'IF', 'TF', 'V', 'P', 'TR')
+ORDER BY
+    CASE o.type WHEN 'V' THEN 1 WHEN 'P' THEN 2 WHEN 'TR' THEN 3 ELSE 0 END ASC,
+    module_schema ASC,
+    module_name ASC;
+"#;
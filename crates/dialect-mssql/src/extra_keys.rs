@@ -1,5 +1,24 @@
-pub(crate) const TABLE_SOURCE_SQL: &str = "mssql.source_sql";
-pub(crate) const TABLE_HAS_IDENTITY: &str = "mssql.has_identity";
-pub(crate) const TABLE_HAS_NOT_FOR_REPLICATION: &str = "mssql.has_not_for_replication";
+use stateql_core::extra_keys::mssql;
+
 pub(crate) const TABLE_PRIMARY_KEY_CLUSTERED: &str = "mssql.primary_key_clustered";
 pub(crate) const COLUMN_IDENTITY_NOT_FOR_REPLICATION: &str = "mssql.identity_not_for_replication";
+pub(crate) const FOREIGN_KEY_NOT_FOR_REPLICATION: &str = "mssql.not_for_replication";
+/// Set on the unique index backing a `UNIQUE` constraint.
+pub(crate) const INDEX_UNIQUE_CONSTRAINT: &str = "mssql.unique_constraint";
+/// Columns of an index's `INCLUDE` list, comma separated.
+pub(crate) const INDEX_INCLUDE_COLUMNS: &str = "mssql.include_columns";
+/// Set on a `COLUMNSTORE` index; `method` still carries `CLUSTERED`/`NONCLUSTERED`.
+pub(crate) const INDEX_COLUMNSTORE: &str = "mssql.columnstore";
+/// `ROW START` or `ROW END` on a `GENERATED ALWAYS AS ...` period column.
+pub(crate) const COLUMN_GENERATED_ALWAYS: &str = "mssql.generated_always";
+/// Set on a period column declared `HIDDEN`.
+pub(crate) const COLUMN_HIDDEN: &str = "mssql.hidden";
+
+/// Table options compared by the diff engine; the temporal ones are the only table
+/// options SQL Server tables carry.
+pub(crate) const DIFFED_TABLE_OPTIONS: [&str; 4] = [
+    mssql::TABLE_PERIOD_START,
+    mssql::TABLE_PERIOD_END,
+    mssql::TABLE_SYSTEM_VERSIONING,
+    mssql::TABLE_HISTORY_TABLE,
+];
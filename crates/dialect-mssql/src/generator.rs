@@ -1,27 +1,352 @@
 use std::fmt::Write as _;
 
 use stateql_core::{
-    BinaryOperator, CheckConstraint, Column, ComparisonOp, DataType, DiffOp, Expr, ForeignKey,
-    ForeignKeyAction, GenerateError, Ident, IndexOwner, IsTest, Literal, PrimaryKey, QualifiedName,
-    Result, SchemaObject, SetQuantifier, Statement, UnaryOperator, Value,
+    BinaryOperator, CheckConstraint, Column, ColumnChange, Comment, CommentTarget, ComparisonOp,
+    DataType, DiffOp, Expr, ForeignKey, ForeignKeyAction, GenerateError, Ident, IndexDef,
+    IndexOwner, IsTest, Literal, PrimaryKey, QualifiedName, Result, SchemaObject, SequenceChange,
+    SetQuantifier, Statement, Table, TableOptions, UnaryOperator, Value,
 };
 
-use crate::{extra_keys, to_sql};
+use stateql_core::extra_keys::mssql as mssql_keys;
+
+use crate::{extra_keys, table_clauses::unquote, to_sql};
 
 const GENERATOR_TARGET: &str = "mssql ddl generator";
 
+const DECLARE_CONSTRAINT: &str = "DECLARE @constraint sysname;\n";
+const RESET_CONSTRAINT: &str = "SET @constraint = NULL;\n";
+
 pub(crate) fn generate_ddl(dialect_name: &str, ops: &[DiffOp]) -> Result<Vec<Statement>> {
     let mut statements = Vec::new();
+    let definitions = table_definitions(ops);
 
-    for op in ops {
-        emit_op(dialect_name, op, &mut statements)?;
+    let mut index = 0;
+    while index < ops.len() {
+        // Column ops of one table are adjacent once ordered.
+        if let Some(definition) = versioned_column_change(&ops[index], &definitions) {
+            let count = ops[index..]
+                .iter()
+                .take_while(|op| {
+                    versioned_column_change(op, &definitions)
+                        .is_some_and(|other| other.target.name == definition.target.name)
+                })
+                .count();
+            emit_versioned_column_batch(
+                dialect_name,
+                definition,
+                &ops[index..index + count],
+                &definitions,
+                &mut statements,
+            )?;
+            index += count;
+            continue;
+        }
+        emit_op(
+            dialect_name,
+            &ops[index],
+            &definitions,
+            false,
+            &mut statements,
+        )?;
+        index += 1;
     }
 
     Ok(statements)
 }
 
-fn emit_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) -> Result<()> {
+/// A changed table as `DiffOp::TableDefinition` describes it.
+struct TableDefinition<'a> {
+    current: &'a Table,
+    target: &'a Table,
+    dependent_indexes: &'a [IndexDef],
+    referencing_foreign_keys: &'a [(QualifiedName, ForeignKey)],
+}
+
+fn table_definitions(ops: &[DiffOp]) -> Vec<TableDefinition<'_>> {
+    ops.iter()
+        .filter_map(|op| match op {
+            DiffOp::TableDefinition {
+                current,
+                target,
+                dependent_indexes,
+                referencing_foreign_keys,
+                ..
+            } => Some(TableDefinition {
+                current,
+                target,
+                dependent_indexes,
+                referencing_foreign_keys,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The definition of the table a column op changes, when that table is system-versioned.
+fn versioned_column_change<'a>(
+    op: &DiffOp,
+    definitions: &'a [TableDefinition<'a>],
+) -> Option<&'a TableDefinition<'a>> {
+    let table = match op {
+        DiffOp::AddColumn { table, .. }
+        | DiffOp::DropColumn { table, .. }
+        | DiffOp::AlterColumn { table, .. }
+        | DiffOp::RenameColumn { table, .. } => table,
+        _ => return None,
+    };
+    definitions.iter().find(|definition| {
+        definition.target.name == *table
+            && matches!(
+                definition
+                    .current
+                    .options
+                    .extra
+                    .get(mssql_keys::TABLE_SYSTEM_VERSIONING),
+                Some(Value::Bool(true))
+            )
+    })
+}
+
+/// SQL Server refuses most column changes while `SYSTEM_VERSIONING` is on, so versioning is
+/// switched off, each change is made to the table and mirrored on its history table, and
+/// versioning is switched back on against the same history table. The steps are one batch
+/// so they succeed or fail together.
+fn emit_versioned_column_batch(
+    dialect_name: &str,
+    definition: &TableDefinition<'_>,
+    ops: &[DiffOp],
+    definitions: &[TableDefinition<'_>],
+    out: &mut Vec<Statement>,
+) -> Result<()> {
+    let table_sql = render_qualified_name(&definition.target.name);
+    let Some(Value::String(history_table)) = definition
+        .current
+        .options
+        .extra
+        .get(mssql_keys::TABLE_HISTORY_TABLE)
+    else {
+        return Err(unsupported_diff_op(
+            dialect_name,
+            &ops[0],
+            "mssql column changes to a system-versioned table need a named HISTORY_TABLE",
+        ));
+    };
+    let history = history_table_name(history_table);
+
+    // Dropping discovered constraints uses a variable, which a batch may declare only once.
+    let mut steps = vec![
+        DECLARE_CONSTRAINT.trim_end().to_string(),
+        format!("ALTER TABLE {table_sql} SET (SYSTEM_VERSIONING = OFF);"),
+    ];
+    let mut history_steps = Vec::new();
+    for op in ops {
+        let mut rendered = Vec::new();
+        emit_op(dialect_name, op, definitions, true, &mut rendered)?;
+        steps.extend(
+            rendered
+                .into_iter()
+                .filter_map(|statement| match statement {
+                    Statement::Sql { sql, .. } => Some(sql),
+                    Statement::BatchBoundary => None,
+                }),
+        );
+        history_steps.extend(render_history_column_change(
+            dialect_name,
+            op,
+            definition,
+            &history,
+        )?);
+    }
+    steps.extend(history_steps);
+    steps.push(format!(
+        "ALTER TABLE {table_sql} SET ({});",
+        to_sql::render_system_versioning_on(Some(&Value::String(history_table.clone())))
+    ));
+
+    append_sql(out, steps.join("\n"));
+    Ok(())
+}
+
+/// The same column change on the history table, which has no constraints, identity or
+/// computed columns of its own.
+fn render_history_column_change(
+    dialect_name: &str,
+    op: &DiffOp,
+    definition: &TableDefinition<'_>,
+    history: &QualifiedName,
+) -> Result<Vec<String>> {
+    let history_sql = render_qualified_name(history);
+    let sql = match op {
+        DiffOp::AddColumn { column, .. } => {
+            if column.generated.is_some() {
+                return Err(unsupported_diff_op(
+                    dialect_name,
+                    op,
+                    "mssql cannot add a computed column to a system-versioned table",
+                ));
+            }
+            let mut sql = format!(
+                "ALTER TABLE {history_sql} ADD {}",
+                render_history_column(column)
+            );
+            match &column.default {
+                Some(default) => write!(sql, " DEFAULT {}", render_expr(default))
+                    .expect("writing to String should not fail"),
+                // Existing history rows need a value for the identity column.
+                None if column.identity.is_some() => sql.push_str(" DEFAULT 0"),
+                None => {}
+            }
+            sql.push(';');
+            sql
+        }
+        DiffOp::DropColumn { column, .. } => format!(
+            "ALTER TABLE {history_sql} DROP COLUMN {};",
+            render_ident(column)
+        ),
+        DiffOp::AlterColumn {
+            column, changes, ..
+        } => {
+            let redefines = changes.iter().any(|change| {
+                matches!(
+                    change,
+                    ColumnChange::SetType(_)
+                        | ColumnChange::SetNotNull(_)
+                        | ColumnChange::SetCollation(_)
+                )
+            });
+            let Some(column) = find_column(definition.target, column).filter(|_| redefines) else {
+                return Ok(Vec::new());
+            };
+            format!(
+                "ALTER TABLE {history_sql} ALTER COLUMN {};",
+                render_history_column(column)
+            )
+        }
+        DiffOp::RenameColumn { from, to, .. } => render_column_rename(history, from, to),
+        _ => return Ok(Vec::new()),
+    };
+    Ok(vec![sql])
+}
+
+/// `name type [COLLATE c] NULL|NOT NULL`.
+fn render_history_column(column: &Column) -> String {
+    let mut sql = format!(
+        "{} {}",
+        render_ident(&column.name),
+        render_data_type(&column.data_type)
+    );
+    if let Some(collation) = &column.collation {
+        write!(sql, " COLLATE {}", collation.trim()).expect("writing to String should not fail");
+    }
+    sql.push_str(if column.not_null {
+        " NOT NULL"
+    } else {
+        " NULL"
+    });
+    sql
+}
+
+/// A `schema.name` history table option as a qualified name.
+fn history_table_name(history_table: &str) -> QualifiedName {
+    match history_table.rsplit_once('.') {
+        Some((schema, name)) => QualifiedName {
+            schema: Some(Ident::unquoted(unquote(schema.trim()))),
+            name: Ident::unquoted(unquote(name.trim())),
+        },
+        None => QualifiedName {
+            schema: None,
+            name: Ident::unquoted(unquote(history_table.trim())),
+        },
+    }
+}
+
+/// Versioning is switched off around any change to the period or the history table and
+/// back on afterwards; the period is dropped and added rather than altered.
+fn render_alter_table_options(
+    dialect_name: &str,
+    op: &DiffOp,
+    table: &QualifiedName,
+    options: &TableOptions,
+    definitions: &[TableDefinition<'_>],
+) -> Result<String> {
+    if options.extra.is_empty()
+        || options
+            .extra
+            .keys()
+            .any(|key| !extra_keys::DIFFED_TABLE_OPTIONS.contains(&key.as_str()))
+    {
+        return Err(unsupported_diff_op(dialect_name, op, GENERATOR_TARGET));
+    }
+    let definition = definitions
+        .iter()
+        .find(|definition| definition.target.name == *table);
+    let target = |key: &str| match options.extra.get(key) {
+        Some(Value::Null) => None,
+        Some(value) => Some(value.clone()),
+        None => definition.and_then(|definition| definition.target.options.extra.get(key).cloned()),
+    };
+    // Without the table definition a changed key is taken to have been the opposite.
+    let current_is_set = |key: &str| match definition {
+        Some(definition) => definition.current.options.extra.contains_key(key),
+        None => match options.extra.get(key) {
+            Some(Value::Null) => true,
+            Some(_) => false,
+            None => target(key).is_some(),
+        },
+    };
+
+    let table_sql = render_qualified_name(table);
+    let target_versioned = matches!(
+        target(mssql_keys::TABLE_SYSTEM_VERSIONING),
+        Some(Value::Bool(true))
+    );
+    let period_changed = options.extra.contains_key(mssql_keys::TABLE_PERIOD_START)
+        || options.extra.contains_key(mssql_keys::TABLE_PERIOD_END);
+
+    let mut steps = Vec::new();
+    if current_is_set(mssql_keys::TABLE_SYSTEM_VERSIONING) {
+        steps.push(format!(
+            "ALTER TABLE {table_sql} SET (SYSTEM_VERSIONING = OFF);"
+        ));
+    }
+    if period_changed {
+        if current_is_set(mssql_keys::TABLE_PERIOD_START) {
+            steps.push(format!(
+                "ALTER TABLE {table_sql} DROP PERIOD FOR SYSTEM_TIME;"
+            ));
+        }
+        if let (Some(Value::String(start)), Some(Value::String(end))) = (
+            target(mssql_keys::TABLE_PERIOD_START),
+            target(mssql_keys::TABLE_PERIOD_END),
+        ) {
+            steps.push(format!(
+                "ALTER TABLE {table_sql} ADD PERIOD FOR SYSTEM_TIME ({}, {});",
+                render_ident(&Ident::unquoted(start)),
+                render_ident(&Ident::unquoted(end))
+            ));
+        }
+    }
+    if target_versioned {
+        steps.push(format!(
+            "ALTER TABLE {table_sql} SET ({});",
+            to_sql::render_system_versioning_on(target(mssql_keys::TABLE_HISTORY_TABLE).as_ref())
+        ));
+    }
+    Ok(steps.join("\n"))
+}
+
+/// `constraint_declared` is set when the op is rendered into a batch that already declares
+/// the variable discovered constraints are dropped through.
+fn emit_op(
+    dialect_name: &str,
+    op: &DiffOp,
+    definitions: &[TableDefinition<'_>],
+    constraint_declared: bool,
+    out: &mut Vec<Statement>,
+) -> Result<()> {
     match op {
+        // Read up front by `table_definitions`; ALTER COLUMN needs the whole column.
+        DiffOp::TableDefinition { .. } => {}
         DiffOp::CreateTable(table) => {
             let sql = render_object_sql(dialect_name, op, &SchemaObject::Table(table.clone()))?;
             append_sql(out, sql);
@@ -70,8 +395,31 @@ fn emit_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) -> Result<
                 ),
             );
         }
-        DiffOp::AlterColumn { .. } => {
-            return Err(unsupported_diff_op(dialect_name, op, GENERATOR_TARGET));
+        DiffOp::AlterColumn {
+            table,
+            column,
+            changes,
+        } => {
+            let Some(definition) = definitions
+                .iter()
+                .find(|definition| definition.target.name == *table)
+            else {
+                return Err(unsupported_diff_op(
+                    dialect_name,
+                    op,
+                    "mssql alter column requires the table definition",
+                ));
+            };
+            for sql in render_alter_column(
+                dialect_name,
+                op,
+                definition,
+                column,
+                changes,
+                constraint_declared,
+            )? {
+                append_sql(out, sql);
+            }
         }
         DiffOp::RenameColumn { table, from, to } => {
             append_sql(out, render_column_rename(table, from, to));
@@ -98,14 +446,7 @@ fn emit_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) -> Result<
                     ));
                 }
             };
-            append_sql(
-                out,
-                format!(
-                    "DROP INDEX {} ON {};",
-                    render_ident(name),
-                    render_qualified_name(owner_table)
-                ),
-            );
+            append_sql(out, render_drop_index(owner_table, name));
         }
         DiffOp::RenameIndex { owner, from, to } => {
             let owner_table = match owner {
@@ -184,12 +525,16 @@ fn emit_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) -> Result<
                 ),
             );
         }
-        DiffOp::DropPrimaryKey { .. } => {
-            return Err(unsupported_diff_op(
-                dialect_name,
-                op,
-                "mssql drop primary key requires a named constraint",
-            ));
+        DiffOp::DropPrimaryKey { table } => {
+            // The op carries no name, and SQL Server names unnamed keys itself.
+            append_sql(
+                out,
+                render_drop_discovered_constraint(
+                    table,
+                    &primary_key_constraint_lookup(table),
+                    constraint_declared,
+                ),
+            );
         }
         DiffOp::CreateView(view) => {
             let sql = render_object_sql(dialect_name, op, &SchemaObject::View(view.clone()))?;
@@ -198,6 +543,38 @@ fn emit_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) -> Result<
         DiffOp::DropView(name) => {
             append_sql(out, format!("DROP VIEW {};", render_qualified_name(name)));
         }
+        DiffOp::CreateSequence(sequence) => {
+            let sql =
+                render_object_sql(dialect_name, op, &SchemaObject::Sequence(sequence.clone()))?;
+            append_sql(out, sql);
+        }
+        DiffOp::DropSequence(name) => {
+            append_sql(
+                out,
+                format!("DROP SEQUENCE {};", render_qualified_name(name)),
+            );
+        }
+        DiffOp::AlterSequence { name, changes } => {
+            let sql = render_alter_sequence(dialect_name, op, name, changes)?;
+            append_sql(out, sql);
+        }
+        DiffOp::CreateSynonym(synonym) => {
+            let sql = render_object_sql(dialect_name, op, &SchemaObject::Synonym(synonym.clone()))?;
+            append_sql(out, sql);
+        }
+        DiffOp::DropSynonym(name) => {
+            append_sql(
+                out,
+                format!("DROP SYNONYM {};", render_qualified_name(name)),
+            );
+        }
+        DiffOp::CreateType(type_def) => {
+            let sql = render_object_sql(dialect_name, op, &SchemaObject::Type(type_def.clone()))?;
+            append_sql(out, sql);
+        }
+        DiffOp::DropType(name) => {
+            append_sql(out, format!("DROP TYPE {};", render_qualified_name(name)));
+        }
         DiffOp::CreateTrigger(trigger) => {
             let sql = render_object_sql(dialect_name, op, &SchemaObject::Trigger(trigger.clone()))?;
             append_sql(out, sql);
@@ -213,11 +590,8 @@ fn emit_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) -> Result<
                 render_object_sql(dialect_name, op, &SchemaObject::Function(function.clone()))?;
             append_sql(out, sql);
         }
-        DiffOp::DropFunction(name) => {
-            append_sql(
-                out,
-                format!("DROP FUNCTION {};", render_qualified_name(name)),
-            );
+        DiffOp::DropFunction { name, .. } => {
+            append_sql(out, render_drop_function(name));
         }
         DiffOp::CreateSchema(schema) => {
             let sql = render_object_sql(dialect_name, op, &SchemaObject::Schema(schema.clone()))?;
@@ -226,12 +600,483 @@ fn emit_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) -> Result<
         DiffOp::DropSchema(name) => {
             append_sql(out, format!("DROP SCHEMA {};", render_qualified_name(name)));
         }
+        DiffOp::AlterTableOptions { table, options } => {
+            let sql = render_alter_table_options(dialect_name, op, table, options, definitions)?;
+            append_sql(out, sql);
+        }
+        DiffOp::SetComment(comment) => {
+            let sql = render_set_comment(dialect_name, op, comment)?;
+            append_sql(out, sql);
+        }
+        DiffOp::DropComment { target } => {
+            let sql = render_drop_comment(dialect_name, op, target)?;
+            append_sql(out, sql);
+        }
         _ => return Err(unsupported_diff_op(dialect_name, op, GENERATOR_TARGET)),
     }
 
     Ok(())
 }
 
+/// `ALTER COLUMN` restates the type and nullability, and SQL Server refuses it while an
+/// index, key or check depends on the column, so those are dropped first and recreated
+/// afterwards, along with the foreign keys of other tables that reference the column. Defaults are separate constraints: the current one is dropped under
+/// whatever name SQL Server gave it and the target default is added back `FOR` the column.
+fn render_alter_column(
+    dialect_name: &str,
+    op: &DiffOp,
+    definition: &TableDefinition<'_>,
+    column_name: &Ident,
+    changes: &[ColumnChange],
+    constraint_declared: bool,
+) -> Result<Vec<String>> {
+    let table = &definition.target.name;
+    let Some(column) = find_column(definition.target, column_name) else {
+        return Err(unsupported_diff_op(
+            dialect_name,
+            op,
+            format!(
+                "mssql alter column {} is missing from the table definition",
+                column_name.value
+            ),
+        ));
+    };
+    if column.generated.is_some() {
+        return Err(unsupported_diff_op(
+            dialect_name,
+            op,
+            "mssql cannot alter computed columns",
+        ));
+    }
+    let current_column = find_column(definition.current, column_name).or_else(|| {
+        column
+            .renamed_from
+            .as_ref()
+            .and_then(|from| find_column(definition.current, from))
+    });
+    let had_default = current_column.is_some_and(|current| current.default.is_some());
+
+    let redefines = changes.iter().any(|change| {
+        matches!(
+            change,
+            ColumnChange::SetType(_) | ColumnChange::SetNotNull(_) | ColumnChange::SetCollation(_)
+        )
+    });
+    let type_changes = changes
+        .iter()
+        .any(|change| matches!(change, ColumnChange::SetType(_)));
+    let default_changes = changes
+        .iter()
+        .any(|change| matches!(change, ColumnChange::SetDefault(_)));
+
+    let mut drops = Vec::new();
+    let mut alter = Vec::new();
+    let mut recreates = Vec::new();
+
+    if redefines {
+        let table_sql = render_qualified_name(table);
+        let current_table = definition.current;
+
+        for fk in current_table.foreign_keys.iter().filter(|fk| {
+            fk.columns.iter().any(|name| same_ident(name, column_name))
+                || (same_table(&fk.referenced_table, table)
+                    && references_column(fk, current_table, column_name))
+        }) {
+            let Some(name) = &fk.name else {
+                return Err(unsupported_diff_op(
+                    dialect_name,
+                    op,
+                    "mssql alter column cannot recreate an unnamed foreign key",
+                ));
+            };
+            drops.push(format!(
+                "ALTER TABLE {table_sql} DROP CONSTRAINT {};",
+                render_ident(name)
+            ));
+            recreates.push(format!(
+                "ALTER TABLE {table_sql} ADD {};",
+                render_foreign_key(fk)
+            ));
+        }
+
+        for (referencing_table, fk) in definition
+            .referencing_foreign_keys
+            .iter()
+            .filter(|(_, fk)| references_column(fk, current_table, column_name))
+        {
+            let Some(name) = &fk.name else {
+                return Err(unsupported_diff_op(
+                    dialect_name,
+                    op,
+                    "mssql alter column cannot recreate an unnamed foreign key referencing it",
+                ));
+            };
+            let referencing_sql = render_qualified_name(referencing_table);
+            drops.push(format!(
+                "ALTER TABLE {referencing_sql} DROP CONSTRAINT {};",
+                render_ident(name)
+            ));
+            recreates.push(format!(
+                "ALTER TABLE {referencing_sql} ADD {};",
+                render_foreign_key(fk)
+            ));
+        }
+
+        for check in current_table
+            .checks
+            .iter()
+            .filter(|check| expr_mentions_column(&check.expr, column_name))
+        {
+            let Some(name) = &check.name else {
+                return Err(unsupported_diff_op(
+                    dialect_name,
+                    op,
+                    "mssql alter column cannot recreate an unnamed check",
+                ));
+            };
+            drops.push(format!(
+                "ALTER TABLE {table_sql} DROP CONSTRAINT {};",
+                render_ident(name)
+            ));
+            recreates.push(format!(
+                "ALTER TABLE {table_sql} ADD {};",
+                render_check(check)
+            ));
+        }
+
+        let mut index_recreates = Vec::new();
+        for index in definition
+            .dependent_indexes
+            .iter()
+            .filter(|index| index_uses_column(index, column_name))
+        {
+            let Some(name) = &index.name else {
+                return Err(unsupported_diff_op(
+                    dialect_name,
+                    op,
+                    "mssql alter column cannot recreate an unnamed index",
+                ));
+            };
+            drops.push(if to_sql::is_unique_constraint(index) {
+                format!(
+                    "ALTER TABLE {table_sql} DROP CONSTRAINT {};",
+                    render_ident(name)
+                )
+            } else {
+                format!("DROP INDEX {} ON {table_sql};", render_ident(name))
+            });
+            index_recreates.push(render_object_sql(
+                dialect_name,
+                op,
+                &SchemaObject::Index(index.clone()),
+            )?);
+        }
+
+        if let Some(pk) = current_table
+            .primary_key
+            .as_ref()
+            .filter(|pk| pk.columns.iter().any(|name| same_ident(name, column_name)))
+        {
+            drops.push(match &pk.name {
+                Some(name) => format!(
+                    "ALTER TABLE {table_sql} DROP CONSTRAINT {};",
+                    render_ident(name)
+                ),
+                None => render_drop_discovered_constraint(
+                    table,
+                    &primary_key_constraint_lookup(table),
+                    constraint_declared,
+                ),
+            });
+            index_recreates.insert(
+                0,
+                format!("ALTER TABLE {table_sql} ADD {};", render_primary_key(pk)),
+            );
+        }
+        recreates.splice(0..0, index_recreates);
+
+        if type_changes && had_default {
+            drops.push(render_drop_discovered_constraint(
+                table,
+                &default_constraint_lookup(table, column_name),
+                constraint_declared,
+            ));
+        }
+
+        let mut sql = format!(
+            "ALTER TABLE {table_sql} ALTER COLUMN {} {}",
+            render_ident(&column.name),
+            render_data_type(&column.data_type)
+        );
+        if let Some(collation) = &column.collation {
+            write!(sql, " COLLATE {}", collation.trim())
+                .expect("writing to String should not fail");
+        }
+        sql.push_str(if column.not_null {
+            " NOT NULL;"
+        } else {
+            " NULL;"
+        });
+        alter.push(sql);
+    }
+
+    let default_dropped = redefines && type_changes && had_default;
+    if default_changes || default_dropped {
+        if !default_dropped && had_default {
+            alter.push(render_drop_discovered_constraint(
+                table,
+                &default_constraint_lookup(table, column_name),
+                constraint_declared,
+            ));
+        }
+        if let Some(default) = &column.default {
+            alter.push(render_add_default(table, column, default));
+        }
+    }
+
+    drops.extend(alter);
+    drops.extend(recreates);
+    Ok(drops)
+}
+
+fn render_add_default(table: &QualifiedName, column: &Column, default: &Expr) -> String {
+    let constraint = match column
+        .extra
+        .get(stateql_core::extra_keys::mssql::DEFAULT_CONSTRAINT_NAME)
+    {
+        Some(Value::String(name)) => {
+            format!("CONSTRAINT {} ", render_ident(&Ident::unquoted(name)))
+        }
+        _ => String::new(),
+    };
+    format!(
+        "ALTER TABLE {} ADD {constraint}DEFAULT {} FOR {};",
+        render_qualified_name(table),
+        render_expr(default),
+        render_ident(&column.name)
+    )
+}
+
+/// SQL Server names unnamed constraints itself (`DF__users__status__3B75D760`), so such
+/// a constraint is dropped by looking its name up when the batch runs. `lookup` selects
+/// the name; nothing is dropped when it finds none. A batch declares the variable once, so
+/// with `declared` set the variable is only reset.
+fn render_drop_discovered_constraint(
+    table: &QualifiedName,
+    lookup: &str,
+    declared: bool,
+) -> String {
+    let table_sql = escape_sql_literal(&render_qualified_name(table));
+    let variable = if declared {
+        RESET_CONSTRAINT
+    } else {
+        DECLARE_CONSTRAINT
+    };
+    format!(
+        "{variable}\
+         SELECT @constraint = {lookup};\n\
+         IF @constraint IS NOT NULL EXEC(N'ALTER TABLE {table_sql} DROP CONSTRAINT ' + QUOTENAME(@constraint));"
+    )
+}
+
+/// The drop op does not say whether the index backs a `UNIQUE` constraint, which SQL
+/// Server only lets `ALTER TABLE ... DROP CONSTRAINT` remove, so the batch checks.
+fn render_drop_index(table: &QualifiedName, name: &Ident) -> String {
+    let table_sql = render_qualified_name(table);
+    format!(
+        "IF EXISTS (SELECT 1 FROM sys.key_constraints WHERE type = 'UQ' AND name = N'{}' AND parent_object_id = OBJECT_ID(N'{}'))\n\
+         ALTER TABLE {table_sql} DROP CONSTRAINT {};\n\
+         ELSE\n\
+         DROP INDEX {} ON {table_sql};",
+        escape_sql_literal(&name.value),
+        escape_sql_literal(&table_sql),
+        render_ident(name),
+        render_ident(name)
+    )
+}
+
+/// SQL Server has no `START WITH` on `ALTER SEQUENCE`; a new start restarts the sequence
+/// there. A type change cannot be altered in place.
+fn render_alter_sequence(
+    dialect_name: &str,
+    op: &DiffOp,
+    name: &QualifiedName,
+    changes: &[SequenceChange],
+) -> Result<String> {
+    let mut sql = format!("ALTER SEQUENCE {}", render_qualified_name(name));
+    for change in changes {
+        match change {
+            SequenceChange::SetType(_) => {
+                return Err(unsupported_diff_op(dialect_name, op, GENERATOR_TARGET));
+            }
+            SequenceChange::SetStart(start) => {
+                write!(sql, " RESTART WITH {start}").expect("writing to String should not fail");
+            }
+            SequenceChange::SetIncrement(increment) => {
+                write!(sql, " INCREMENT BY {increment}")
+                    .expect("writing to String should not fail");
+            }
+            SequenceChange::SetMinValue(Some(min_value)) => {
+                write!(sql, " MINVALUE {min_value}").expect("writing to String should not fail");
+            }
+            SequenceChange::SetMinValue(None) => sql.push_str(" NO MINVALUE"),
+            SequenceChange::SetMaxValue(Some(max_value)) => {
+                write!(sql, " MAXVALUE {max_value}").expect("writing to String should not fail");
+            }
+            SequenceChange::SetMaxValue(None) => sql.push_str(" NO MAXVALUE"),
+            SequenceChange::SetCycle(true) => sql.push_str(" CYCLE"),
+            SequenceChange::SetCycle(false) => sql.push_str(" NO CYCLE"),
+            SequenceChange::SetCache(0) => sql.push_str(" NO CACHE"),
+            SequenceChange::SetCache(cache) => {
+                write!(sql, " CACHE {cache}").expect("writing to String should not fail");
+            }
+        }
+    }
+    sql.push(';');
+    Ok(sql)
+}
+
+/// Procedures share the function IR, so the drop checks which one the name refers to.
+fn render_drop_function(name: &QualifiedName) -> String {
+    let name_sql = render_qualified_name(name);
+    format!(
+        "IF OBJECT_ID(N'{}', N'P') IS NOT NULL\n\
+         DROP PROCEDURE {name_sql};\n\
+         ELSE\n\
+         DROP FUNCTION {name_sql};",
+        escape_sql_literal(&name_sql)
+    )
+}
+
+/// The op does not say whether a description exists yet, so the server is asked.
+fn render_set_comment(dialect_name: &str, op: &DiffOp, comment: &Comment) -> Result<String> {
+    let (Some(levels), Some(lookup), Some(text)) = (
+        to_sql::extended_property_levels(&comment.target),
+        description_lookup(&comment.target),
+        comment.text.as_deref(),
+    ) else {
+        return Err(unsupported_diff_op(dialect_name, op, GENERATOR_TARGET));
+    };
+    Ok(format!(
+        "IF EXISTS ({})\n\
+         {}\n\
+         ELSE\n\
+         {}",
+        lookup,
+        to_sql::render_extended_property_call("sp_updateextendedproperty", &levels, Some(text)),
+        to_sql::render_extended_property_call("sp_addextendedproperty", &levels, Some(text))
+    ))
+}
+
+/// Comments are changed after every drop, and dropping a table, column, view or procedure
+/// takes its description with it, so a description already gone is skipped.
+fn render_drop_comment(dialect_name: &str, op: &DiffOp, target: &CommentTarget) -> Result<String> {
+    let (Some(levels), Some(lookup)) = (
+        to_sql::extended_property_levels(target),
+        description_lookup(target),
+    ) else {
+        return Err(unsupported_diff_op(dialect_name, op, GENERATOR_TARGET));
+    };
+    Ok(format!(
+        "IF EXISTS ({})\n\
+         {}",
+        lookup,
+        to_sql::render_extended_property_call("sp_dropextendedproperty", &levels, None)
+    ))
+}
+
+fn description_lookup(target: &CommentTarget) -> Option<String> {
+    let (object, minor_id) = match target {
+        CommentTarget::Column { table, column } => {
+            let object = escape_sql_literal(&render_qualified_name(table));
+            let minor_id = format!(
+                "COLUMNPROPERTY(OBJECT_ID(N'{object}'), N'{}', 'ColumnId')",
+                escape_sql_literal(&column.value)
+            );
+            (object, minor_id)
+        }
+        CommentTarget::Table(name) | CommentTarget::View(name) | CommentTarget::Function(name) => (
+            escape_sql_literal(&render_qualified_name(name)),
+            "0".to_string(),
+        ),
+        _ => return None,
+    };
+    Some(format!(
+        "SELECT 1 FROM sys.extended_properties WHERE class = 1 \
+         AND major_id = OBJECT_ID(N'{object}') AND minor_id = {minor_id} \
+         AND name = N'{}'",
+        to_sql::DESCRIPTION_PROPERTY
+    ))
+}
+
+fn default_constraint_lookup(table: &QualifiedName, column: &Ident) -> String {
+    format!(
+        "dc.name FROM sys.default_constraints AS dc \
+         JOIN sys.columns AS c ON c.object_id = dc.parent_object_id AND c.column_id = dc.parent_column_id \
+         WHERE dc.parent_object_id = OBJECT_ID(N'{}') AND c.name = N'{}'",
+        escape_sql_literal(&render_qualified_name(table)),
+        escape_sql_literal(&column.value)
+    )
+}
+
+fn primary_key_constraint_lookup(table: &QualifiedName) -> String {
+    format!(
+        "name FROM sys.key_constraints WHERE type = 'PK' AND parent_object_id = OBJECT_ID(N'{}')",
+        escape_sql_literal(&render_qualified_name(table))
+    )
+}
+
+fn find_column<'a>(table: &'a Table, name: &Ident) -> Option<&'a Column> {
+    table
+        .columns
+        .iter()
+        .find(|column| same_ident(&column.name, name))
+}
+
+// Identifiers compare case-insensitively under SQL Server's default collations.
+fn same_ident(left: &Ident, right: &Ident) -> bool {
+    left.value.eq_ignore_ascii_case(&right.value)
+}
+
+fn same_table(left: &QualifiedName, right: &QualifiedName) -> bool {
+    same_ident(&left.name, &right.name)
+        && match (&left.schema, &right.schema) {
+            (Some(left), Some(right)) => same_ident(left, right),
+            (left, right) => left.is_none() && right.is_none(),
+        }
+}
+
+/// Whether `fk` references `column` of `table`; a foreign key naming no columns
+/// references the primary key.
+fn references_column(fk: &ForeignKey, table: &Table, column: &Ident) -> bool {
+    if fk.referenced_columns.is_empty() {
+        return table
+            .primary_key
+            .as_ref()
+            .is_some_and(|pk| pk.columns.iter().any(|name| same_ident(name, column)));
+    }
+    fk.referenced_columns
+        .iter()
+        .any(|name| same_ident(name, column))
+}
+
+fn index_uses_column(index: &IndexDef, column: &Ident) -> bool {
+    index
+        .columns
+        .iter()
+        .any(|index_column| expr_mentions_column(&index_column.expr, column))
+}
+
+fn expr_mentions_column(expr: &Expr, column: &Ident) -> bool {
+    match expr {
+        Expr::Ident(ident) | Expr::QualifiedIdent { name: ident, .. } => same_ident(ident, column),
+        Expr::Raw(raw) => raw
+            .split(|ch: char| !(ch.is_alphanumeric() || ch == '_' || ch == '@' || ch == '#'))
+            .any(|word| word.eq_ignore_ascii_case(&column.value)),
+        other => expr_mentions_column(&Expr::Raw(render_expr(other)), column),
+    }
+}
+
 fn render_table_rename(from: &QualifiedName, to: &QualifiedName) -> String {
     render_sp_rename(&sp_rename_table_target(from), &to.name.value, None)
 }
@@ -270,12 +1115,26 @@ fn escape_sql_literal(value: &str) -> String {
 }
 
 fn render_column_definition(dialect_name: &str, op: &DiffOp, column: &Column) -> Result<String> {
-    if column.generated.is_some() {
-        return Err(unsupported_diff_op(
-            dialect_name,
-            op,
-            "mssql add-column does not support generated columns",
-        ));
+    if let Some(generated) = &column.generated {
+        if column.default.is_some() || column.identity.is_some() {
+            return Err(unsupported_diff_op(
+                dialect_name,
+                op,
+                "mssql computed columns cannot have a default or identity",
+            ));
+        }
+        let mut sql = format!(
+            "{} AS {}",
+            render_ident(&column.name),
+            render_expr(&generated.expr)
+        );
+        if generated.stored {
+            sql.push_str(" PERSISTED");
+            if column.not_null {
+                sql.push_str(" NOT NULL");
+            }
+        }
+        return Ok(sql);
     }
 
     let mut sql = format!(
@@ -316,6 +1175,7 @@ fn render_column_definition(dialect_name: &str, op: &DiffOp, column: &Column) ->
         }
     }
 
+    sql.push_str(&to_sql::render_generated_always(column));
     if column.not_null {
         sql.push_str(" NOT NULL");
     }
@@ -364,6 +1224,15 @@ fn render_foreign_key(foreign_key: &ForeignKey) -> String {
             .expect("writing to String should not fail");
     }
 
+    if matches!(
+        foreign_key
+            .extra
+            .get(extra_keys::FOREIGN_KEY_NOT_FOR_REPLICATION),
+        Some(Value::Bool(true))
+    ) {
+        sql.push_str(" NOT FOR REPLICATION");
+    }
+
     sql
 }
 
@@ -454,6 +1323,7 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::CreateTable(_) => "CreateTable",
         DiffOp::DropTable(_) => "DropTable",
         DiffOp::RenameTable { .. } => "RenameTable",
+        DiffOp::TableDefinition { .. } => "TableDefinition",
         DiffOp::AddColumn { .. } => "AddColumn",
         DiffOp::DropColumn { .. } => "DropColumn",
         DiffOp::AlterColumn { .. } => "AlterColumn",
@@ -471,6 +1341,8 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::DropPrimaryKey { .. } => "DropPrimaryKey",
         DiffOp::AddPartition { .. } => "AddPartition",
         DiffOp::DropPartition { .. } => "DropPartition",
+        DiffOp::ReorganizePartition { .. } => "ReorganizePartition",
+        DiffOp::ChangePartitionCount { .. } => "ChangePartitionCount",
         DiffOp::CreateView(_) => "CreateView",
         DiffOp::DropView(_) => "DropView",
         DiffOp::CreateMaterializedView(_) => "CreateMaterializedView",
@@ -478,10 +1350,14 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::CreateSequence(_) => "CreateSequence",
         DiffOp::DropSequence(_) => "DropSequence",
         DiffOp::AlterSequence { .. } => "AlterSequence",
+        DiffOp::CreateSynonym(_) => "CreateSynonym",
+        DiffOp::DropSynonym(_) => "DropSynonym",
         DiffOp::CreateTrigger(_) => "CreateTrigger",
         DiffOp::DropTrigger { .. } => "DropTrigger",
         DiffOp::CreateFunction(_) => "CreateFunction",
-        DiffOp::DropFunction(_) => "DropFunction",
+        DiffOp::DropFunction { .. } => "DropFunction",
+        DiffOp::CreateEvent(_) => "CreateEvent",
+        DiffOp::DropEvent(_) => "DropEvent",
         DiffOp::CreateType(_) => "CreateType",
         DiffOp::DropType(_) => "DropType",
         DiffOp::AlterType { .. } => "AlterType",
@@ -498,6 +1374,7 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::Revoke(_) => "Revoke",
         DiffOp::CreatePolicy(_) => "CreatePolicy",
         DiffOp::DropPolicy { .. } => "DropPolicy",
+        DiffOp::SetPragma(_) => "SetPragma",
         DiffOp::AlterTableOptions { .. } => "AlterTableOptions",
     }
 }
@@ -542,7 +1419,7 @@ fn render_data_type(data_type: &DataType) -> String {
         DataType::Timestamp { .. } => "DATETIME2".to_string(),
         DataType::Json | DataType::Jsonb => "NVARCHAR(MAX)".to_string(),
         DataType::Uuid => "UNIQUEIDENTIFIER".to_string(),
-        DataType::Array(_) => "NVARCHAR(MAX)".to_string(),
+        DataType::Array(_) | DataType::Enum(_) | DataType::Set(_) => "NVARCHAR(MAX)".to_string(),
         DataType::Custom(custom) => custom.trim().to_ascii_uppercase(),
     }
 }
@@ -0,0 +1,222 @@
+//! A small cursor over the tokens of a statement sqlparser cannot read, shared by the
+//! module, sequence and synonym readers.
+
+use std::io;
+
+use stateql_core::{DataType, Expr, FunctionParam, FunctionParamMode, Ident, QualifiedName};
+
+use crate::{
+    parser::parse_bracketed_ident,
+    table_clauses::{Token, TokenKind, scan_tokens},
+};
+
+type ConversionResult<T> = std::result::Result<T, io::Error>;
+
+/// A cursor over the header tokens of a statement read by hand.
+pub(crate) struct Header<'a> {
+    pub(crate) sql: &'a str,
+    tokens: Vec<Token>,
+    cursor: usize,
+    /// Parenthesis depth of the token under the cursor while scanning a list.
+    pub(crate) depth: usize,
+}
+
+impl<'a> Header<'a> {
+    pub(crate) fn new(sql: &'a str) -> Self {
+        Self {
+            sql,
+            tokens: scan_tokens(sql),
+            cursor: 0,
+            depth: 0,
+        }
+    }
+
+    pub(crate) fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.cursor)
+    }
+
+    pub(crate) fn peek_word(&self, keyword: &str) -> bool {
+        self.peek()
+            .is_some_and(|token| token.is_word(self.sql, keyword))
+    }
+
+    pub(crate) fn eat_word(&mut self, keyword: &str) -> bool {
+        let matched = self.peek_word(keyword);
+        if matched {
+            self.cursor += 1;
+        }
+        matched
+    }
+
+    pub(crate) fn eat_punct(&mut self, punct: u8) -> bool {
+        let matched = self
+            .peek()
+            .is_some_and(|token| token.kind == TokenKind::Punct(punct));
+        if matched {
+            self.cursor += 1;
+        }
+        matched
+    }
+
+    pub(crate) fn expect_word(&mut self, keyword: &str) -> ConversionResult<()> {
+        if self.eat_word(keyword) {
+            return Ok(());
+        }
+        Err(self.unexpected(keyword))
+    }
+
+    pub(crate) fn expect_punct(&mut self, punct: u8) -> ConversionResult<()> {
+        if self.eat_punct(punct) {
+            return Ok(());
+        }
+        Err(self.unexpected(&char::from(punct).to_string()))
+    }
+
+    pub(crate) fn next_token(&mut self, expected: &str) -> ConversionResult<Token> {
+        let token = self
+            .peek()
+            .copied()
+            .filter(|token| !matches!(token.kind, TokenKind::Punct(_)))
+            .ok_or_else(|| self.unexpected(expected))?;
+        self.cursor += 1;
+        Ok(token)
+    }
+
+    pub(crate) fn unexpected(&self, expected: &str) -> io::Error {
+        let found = self
+            .peek()
+            .map_or("end of batch", |token| token.text(self.sql));
+        conversion_error(format!(
+            "expected {expected} in mssql statement header, found `{found}`"
+        ))
+    }
+
+    /// `name` or `schema.name`.
+    pub(crate) fn object_name(&mut self) -> ConversionResult<QualifiedName> {
+        let first = self.next_token("object name")?;
+        if !self.eat_punct(b'.') {
+            return Ok(QualifiedName {
+                schema: None,
+                name: parse_bracketed_ident(first.text(self.sql)),
+            });
+        }
+        let second = self.next_token("object name")?;
+        if self
+            .peek()
+            .is_some_and(|token| token.kind == TokenKind::Punct(b'.'))
+        {
+            return Err(conversion_error(
+                "unsupported qualified name in mssql statement header",
+            ));
+        }
+        Ok(QualifiedName {
+            schema: Some(parse_bracketed_ident(first.text(self.sql))),
+            name: parse_bracketed_ident(second.text(self.sql)),
+        })
+    }
+
+    /// Module options have no place in the IR, so they are refused rather than dropped.
+    pub(crate) fn reject_options(&self, module: &str) -> ConversionResult<()> {
+        if self.peek_word("WITH") {
+            return Err(conversion_error(format!(
+                "unsupported mssql {module} option: WITH clauses are not supported"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Source text from the cursor up to the first token `stop` accepts; parentheses are
+    /// tracked in `depth`.
+    pub(crate) fn text_until(
+        &mut self,
+        expected: &str,
+        stop: impl Fn(&Self, &Token) -> bool,
+    ) -> ConversionResult<&'a str> {
+        let start = self.cursor;
+        self.depth = 0;
+        while let Some(token) = self.peek().copied() {
+            if stop(self, &token) {
+                break;
+            }
+            match token.kind {
+                TokenKind::Punct(b'(') => self.depth += 1,
+                TokenKind::Punct(b')') => self.depth = self.depth.saturating_sub(1),
+                _ => {}
+            }
+            self.cursor += 1;
+        }
+        if self.cursor == start {
+            return Err(self.unexpected(expected));
+        }
+        Ok(&self.sql[self.tokens[start].start..self.tokens[self.cursor - 1].end])
+    }
+
+    /// `@name [AS] type [= default] [OUT | OUTPUT | READONLY]`, comma separated, up to the
+    /// first token `stop` accepts.
+    pub(crate) fn params(
+        &mut self,
+        stop: impl Fn(&Self, &Token) -> bool,
+    ) -> ConversionResult<Vec<FunctionParam>> {
+        let mut params = Vec::new();
+        self.depth = 0;
+        while self
+            .peek()
+            .is_some_and(|token| !stop(self, token) && token.text(self.sql).starts_with('@'))
+        {
+            let name = self.next_token("parameter name")?.text(self.sql);
+            self.eat_word("AS");
+            let data_type = self.text_until("parameter type", |header, token| {
+                header.depth == 0 && (param_boundary(header, token) || stop(header, token))
+            })?;
+            let mut param = FunctionParam {
+                name: Some(Ident::unquoted(name.trim_start_matches('@'))),
+                data_type: DataType::Custom(data_type.to_string()),
+                mode: None,
+                default: None,
+            };
+            if self.eat_punct(b'=') {
+                let default = self.text_until("parameter default", |header, token| {
+                    header.depth == 0 && (param_boundary(header, token) || stop(header, token))
+                })?;
+                param.default = Some(Expr::Raw(default.to_string()));
+            }
+            if self.eat_word("OUT") || self.eat_word("OUTPUT") {
+                param.mode = Some(FunctionParamMode::Out);
+            } else if self.eat_word("READONLY") {
+                // Table-valued parameters must be READONLY; it stays with the type.
+                param.data_type = DataType::Custom(format!("{data_type} READONLY"));
+            }
+            params.push(param);
+            self.depth = 0;
+            if !self.eat_punct(b',') {
+                break;
+            }
+        }
+        Ok(params)
+    }
+
+    /// Everything after the header, without a trailing `;`.
+    pub(crate) fn body(&self, module: &str) -> ConversionResult<String> {
+        let body = self
+            .peek()
+            .map(|token| self.sql[token.start..].trim().trim_end_matches(';').trim())
+            .unwrap_or_default();
+        if body.is_empty() {
+            return Err(conversion_error(format!(
+                "mssql {module} has an empty body"
+            )));
+        }
+        Ok(body.to_string())
+    }
+}
+
+fn param_boundary(header: &Header<'_>, token: &Token) -> bool {
+    matches!(token.kind, TokenKind::Punct(b',') | TokenKind::Punct(b'='))
+        || ["OUT", "OUTPUT", "READONLY"]
+            .iter()
+            .any(|keyword| token.is_word(header.sql, keyword))
+}
+
+pub(crate) fn conversion_error(message: impl Into<String>) -> io::Error {
+    io::Error::other(message.into())
+}
@@ -3,8 +3,12 @@ mod equivalence;
 mod export_queries;
 mod extra_keys;
 mod generator;
+mod header;
+mod modules;
 mod normalize;
+mod objects;
 mod parser;
+mod table_clauses;
 mod to_sql;
 
 use stateql_core::{
@@ -54,6 +58,18 @@ impl Dialect for MssqlDialect {
         &equivalence::MSSQL_EQUIVALENCE_POLICY
     }
 
+    fn requires_table_definitions(&self) -> bool {
+        true
+    }
+
+    fn diffs_table_constraints(&self) -> bool {
+        true
+    }
+
+    fn diffed_table_options(&self) -> &'static [&'static str] {
+        &extra_keys::DIFFED_TABLE_OPTIONS
+    }
+
     fn quote_ident(&self, ident: &Ident) -> String {
         format!("[{}]", ident.value)
     }
@@ -0,0 +1,211 @@
+//! Views, procedures, functions and triggers are T-SQL modules: each one is a batch of its
+//! own, and SQL Server keeps its definition verbatim. Only the header is read here; the body
+//! (everything after `AS`) is kept as written, so an exported definition compares equal to
+//! the file it was created from.
+
+use std::io;
+
+use stateql_core::{
+    DataType, Function, QualifiedName, SchemaObject, Trigger, TriggerEvent, TriggerForEach,
+    TriggerTiming, View,
+};
+
+use crate::{
+    header::{Header, conversion_error},
+    parser::parse_bracketed_ident,
+    table_clauses::TokenKind,
+};
+
+type ConversionResult<T> = std::result::Result<T, io::Error>;
+
+const MODULE_LANGUAGE: &str = "tsql";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ModuleKind {
+    View,
+    Procedure,
+    Function,
+    Trigger,
+}
+
+/// The module a batch creates, if it starts with `CREATE [OR ALTER] VIEW`, `PROC[EDURE]`,
+/// `FUNCTION` or `TRIGGER`.
+pub(crate) fn module_kind(batch: &str) -> Option<ModuleKind> {
+    module_header(&mut Header::new(batch))
+}
+
+fn module_header(header: &mut Header<'_>) -> Option<ModuleKind> {
+    if !header.eat_word("CREATE") {
+        return None;
+    }
+    if header.eat_word("OR") && !header.eat_word("ALTER") {
+        return None;
+    }
+    let kind = if header.eat_word("VIEW") {
+        ModuleKind::View
+    } else if header.eat_word("PROC") || header.eat_word("PROCEDURE") {
+        ModuleKind::Procedure
+    } else if header.eat_word("FUNCTION") {
+        ModuleKind::Function
+    } else if header.eat_word("TRIGGER") {
+        ModuleKind::Trigger
+    } else {
+        return None;
+    };
+    Some(kind)
+}
+
+pub(crate) fn convert_module(batch: &str) -> ConversionResult<SchemaObject> {
+    let mut header = Header::new(batch);
+    let kind = module_header(&mut header)
+        .ok_or_else(|| conversion_error("mssql batch does not create a module"))?;
+    let name = header.object_name()?;
+
+    match kind {
+        ModuleKind::View => convert_view(header, name),
+        ModuleKind::Procedure => convert_procedure(header, name),
+        ModuleKind::Function => convert_function(header, name),
+        ModuleKind::Trigger => convert_trigger(header, name),
+    }
+}
+
+fn convert_view(mut header: Header<'_>, name: QualifiedName) -> ConversionResult<SchemaObject> {
+    let mut columns = Vec::new();
+    if header.eat_punct(b'(') {
+        loop {
+            let column = header.next_token("view column")?;
+            columns.push(parse_bracketed_ident(column.text(header.sql)));
+            if header.eat_punct(b')') {
+                break;
+            }
+            header.expect_punct(b',')?;
+        }
+    }
+    header.reject_options("view")?;
+    header.expect_word("AS")?;
+
+    let mut view = View::new(name, header.body("view")?);
+    view.columns = columns;
+    Ok(SchemaObject::View(view))
+}
+
+/// Procedures have no IR of their own: they are functions without a return type.
+fn convert_procedure(
+    mut header: Header<'_>,
+    name: QualifiedName,
+) -> ConversionResult<SchemaObject> {
+    let params = if header.eat_punct(b'(') {
+        let params = header
+            .params(|header, token| token.kind == TokenKind::Punct(b')') && header.depth == 0)?;
+        header.expect_punct(b')')?;
+        params
+    } else {
+        header.params(|header, token| {
+            header.depth == 0
+                && (token.is_word(header.sql, "AS")
+                    || token.is_word(header.sql, "WITH")
+                    || token.is_word(header.sql, "FOR"))
+        })?
+    };
+    header.reject_options("procedure")?;
+    if header.peek_word("FOR") {
+        return Err(conversion_error(
+            "unsupported mssql procedure option: FOR REPLICATION",
+        ));
+    }
+    header.expect_word("AS")?;
+
+    Ok(SchemaObject::Function(Function {
+        name,
+        params,
+        return_type: None,
+        language: MODULE_LANGUAGE.to_string(),
+        body: header.body("procedure")?,
+        volatility: None,
+        security: None,
+    }))
+}
+
+fn convert_function(mut header: Header<'_>, name: QualifiedName) -> ConversionResult<SchemaObject> {
+    header.expect_punct(b'(')?;
+    let params =
+        header.params(|header, token| token.kind == TokenKind::Punct(b')') && header.depth == 0)?;
+    header.expect_punct(b')')?;
+    header.expect_word("RETURNS")?;
+
+    // `int`, `TABLE` or `@result TABLE (...)`, up to `WITH`, `AS` or the body itself.
+    let return_type = header.text_until("return type", |header, token| {
+        header.depth == 0
+            && ["WITH", "AS", "BEGIN", "RETURN"]
+                .iter()
+                .any(|keyword| token.is_word(header.sql, keyword))
+    })?;
+    header.reject_options("function")?;
+    header.eat_word("AS");
+
+    Ok(SchemaObject::Function(Function {
+        name,
+        params,
+        return_type: Some(DataType::Custom(return_type.to_string())),
+        language: MODULE_LANGUAGE.to_string(),
+        body: header.body("function")?,
+        volatility: None,
+        security: None,
+    }))
+}
+
+fn convert_trigger(mut header: Header<'_>, name: QualifiedName) -> ConversionResult<SchemaObject> {
+    header.expect_word("ON")?;
+    if header.peek_word("DATABASE") || header.peek_word("ALL") {
+        return Err(conversion_error(
+            "unsupported mssql DDL trigger: only triggers on a table or view are accepted",
+        ));
+    }
+    let table = header.object_name()?;
+    header.reject_options("trigger")?;
+
+    let timing = if header.eat_word("FOR") || header.eat_word("AFTER") {
+        TriggerTiming::After
+    } else if header.eat_word("INSTEAD") {
+        header.expect_word("OF")?;
+        TriggerTiming::InsteadOf
+    } else {
+        return Err(conversion_error(
+            "mssql trigger needs FOR, AFTER or INSTEAD OF",
+        ));
+    };
+
+    let mut events = Vec::new();
+    loop {
+        let event = header.next_token("trigger event")?;
+        events.push(match event.text(header.sql).to_ascii_uppercase().as_str() {
+            "INSERT" => TriggerEvent::Insert,
+            "UPDATE" => TriggerEvent::Update,
+            "DELETE" => TriggerEvent::Delete,
+            other => {
+                return Err(conversion_error(format!(
+                    "unsupported mssql trigger event: {other}"
+                )));
+            }
+        });
+        if !header.eat_punct(b',') {
+            break;
+        }
+    }
+    if !header.eat_word("AS") {
+        return Err(conversion_error(
+            "unsupported mssql trigger option: only AS may follow the trigger events",
+        ));
+    }
+
+    Ok(SchemaObject::Trigger(Trigger {
+        name,
+        table,
+        timing,
+        events,
+        update_columns: Vec::new(),
+        for_each: TriggerForEach::Statement,
+        when_clause: None,
+        body: header.body("trigger")?,
+    }))
+}
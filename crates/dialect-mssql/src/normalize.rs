@@ -1,9 +1,12 @@
 use stateql_core::{
-    CommentTarget, Expr, Ident, IndexDef, IndexOwner, PrivilegeObject, QualifiedName, SchemaObject,
-    Table, TypeDef, TypeKind, Value,
+    CommentTarget, DataType, Expr, Ident, IndexDef, IndexOwner, PrivilegeObject, QualifiedName,
+    SchemaObject, Sequence, Table, TypeDef, TypeKind, Value,
 };
 
-use crate::extra_keys;
+use crate::{
+    extra_keys,
+    table_clauses::{TokenKind, scan_tokens, unquote},
+};
 
 pub(crate) fn normalize_object(object: &mut SchemaObject) {
     normalize_object_types(object);
@@ -20,7 +23,8 @@ fn normalize_object_types(object: &mut SchemaObject) {
                     *collation = collation.trim().to_ascii_lowercase();
                 }
             }
-            normalize_source_sql_hint(table);
+            normalize_primary_key_clustering(table);
+            normalize_temporal_options(table);
         }
         SchemaObject::View(_) => {}
         SchemaObject::MaterializedView(materialized_view) => {
@@ -28,12 +32,13 @@ fn normalize_object_types(object: &mut SchemaObject) {
                 types::normalize_data_type(&mut column.data_type);
             }
         }
-        SchemaObject::Index(_) => {}
-        SchemaObject::Sequence(sequence) => {
-            if let Some(data_type) = &mut sequence.data_type {
-                types::normalize_data_type(data_type);
-            }
+        SchemaObject::Index(index) => {
+            normalize_index_method(index);
+            normalize_include_columns(index);
         }
+        SchemaObject::Sequence(sequence) => normalize_sequence(sequence),
+        SchemaObject::Synonym(_) => {}
+        SchemaObject::Event(_) => {}
         SchemaObject::Trigger(_) => {}
         SchemaObject::Function(function) => {
             if let Some(return_type) = &mut function.return_type {
@@ -50,14 +55,94 @@ fn normalize_object_types(object: &mut SchemaObject) {
         SchemaObject::Comment(_) => {}
         SchemaObject::Privilege(_) => {}
         SchemaObject::Policy(_) => {}
+        SchemaObject::Pragma(_) => {}
+    }
+}
+
+/// A primary key is clustered unless it says otherwise.
+fn normalize_primary_key_clustering(table: &mut Table) {
+    if table.primary_key.is_some() {
+        table
+            .options
+            .extra
+            .entry(extra_keys::TABLE_PRIMARY_KEY_CLUSTERED.to_string())
+            .or_insert(Value::Bool(true));
     }
 }
 
-fn normalize_source_sql_hint(table: &mut Table) {
-    if let Some(Value::String(source_sql)) =
-        table.options.extra.get_mut(extra_keys::TABLE_SOURCE_SQL)
+/// Period columns are always `NOT NULL`; period column and history table names compare like
+/// any other identifier.
+fn normalize_temporal_options(table: &mut Table) {
+    for column in &mut table.columns {
+        if column
+            .extra
+            .contains_key(extra_keys::COLUMN_GENERATED_ALWAYS)
+        {
+            column.not_null = true;
+        }
+    }
+    for key in [
+        stateql_core::extra_keys::mssql::TABLE_PERIOD_START,
+        stateql_core::extra_keys::mssql::TABLE_PERIOD_END,
+    ] {
+        if let Some(Value::String(column)) = table.options.extra.get_mut(key) {
+            *column = column.to_ascii_lowercase();
+        }
+    }
+    if let Some(Value::String(history_table)) = table
+        .options
+        .extra
+        .get_mut(stateql_core::extra_keys::mssql::TABLE_HISTORY_TABLE)
     {
-        *source_sql = source_sql.trim().to_string();
+        *history_table = history_table
+            .split('.')
+            .map(|part| unquote(part.trim()).to_ascii_lowercase())
+            .collect::<Vec<_>>()
+            .join(".");
+    }
+}
+
+/// An index, and the index behind a `UNIQUE` constraint, is nonclustered unless it says
+/// otherwise.
+fn normalize_index_method(index: &mut IndexDef) {
+    let method = index
+        .method
+        .as_deref()
+        .map_or("NONCLUSTERED".to_string(), |method| {
+            method.trim().to_ascii_uppercase()
+        });
+    index.method = Some(method);
+}
+
+fn normalize_include_columns(index: &mut IndexDef) {
+    if let Some(Value::String(columns)) = index.extra.get_mut(extra_keys::INDEX_INCLUDE_COLUMNS) {
+        *columns = columns
+            .split(',')
+            .map(|column| unquote(column.trim()).to_ascii_lowercase())
+            .collect::<Vec<_>>()
+            .join(", ");
+    }
+}
+
+/// SQL Server fills in every sequence option, so an omitted one is compared as its default:
+/// a `bigint` counting up by one from the smallest value of its type.
+fn normalize_sequence(sequence: &mut Sequence) {
+    let data_type = sequence.data_type.get_or_insert(DataType::BigInt);
+    types::normalize_data_type(data_type);
+    let (type_min, type_max) = types::integer_bounds(data_type);
+    let increment = *sequence.increment.get_or_insert(1);
+    if sequence.min_value.is_none() {
+        sequence.min_value = type_min;
+    }
+    if sequence.max_value.is_none() {
+        sequence.max_value = type_max;
+    }
+    if sequence.start.is_none() {
+        sequence.start = if increment > 0 {
+            sequence.min_value
+        } else {
+            sequence.max_value
+        };
     }
 }
 
@@ -75,6 +160,33 @@ fn normalize_type_def(type_def: &mut TypeDef) {
             }
         }
         TypeKind::Range { subtype } => types::normalize_data_type(subtype),
+        // Constraints of a table type are always system-named, so names are not compared.
+        TypeKind::Table {
+            columns,
+            primary_key,
+            checks,
+        } => {
+            for column in columns {
+                normalize_ident(&mut column.name);
+                types::normalize_data_type(&mut column.data_type);
+                if let Some(collation) = &mut column.collation {
+                    *collation = collation.trim().to_ascii_lowercase();
+                }
+                if let Some(default) = &mut column.default {
+                    normalize_expr(default);
+                }
+            }
+            if let Some(primary_key) = primary_key {
+                primary_key.name = None;
+                for column in &mut primary_key.columns {
+                    normalize_ident(column);
+                }
+            }
+            for check in checks {
+                check.name = None;
+                normalize_expr(&mut check.expr);
+            }
+        }
     }
 }
 
@@ -164,6 +276,13 @@ fn normalize_identifier_case(object: &mut SchemaObject) {
                 normalize_ident(column);
             }
         }
+        SchemaObject::Synonym(synonym) => {
+            normalize_qualified_name(&mut synonym.name);
+            for part in &mut synonym.target {
+                normalize_ident(part);
+            }
+        }
+        SchemaObject::Event(event) => normalize_qualified_name(&mut event.name),
         SchemaObject::Trigger(trigger) => {
             normalize_qualified_name(&mut trigger.name);
             normalize_qualified_name(&mut trigger.table);
@@ -190,6 +309,7 @@ fn normalize_identifier_case(object: &mut SchemaObject) {
             normalize_privilege_object(&mut privilege.on);
             normalize_ident(&mut privilege.grantee);
         }
+        SchemaObject::Pragma(_) => {}
         SchemaObject::Policy(policy) => {
             normalize_ident(&mut policy.name);
             normalize_qualified_name(&mut policy.table);
@@ -293,6 +413,8 @@ fn normalize_object_exprs(object: &mut SchemaObject) {
         }
         SchemaObject::Index(index) => normalize_index_exprs(index),
         SchemaObject::Sequence(_) => {}
+        SchemaObject::Synonym(_) => {}
+        SchemaObject::Event(_) => {}
         SchemaObject::Trigger(trigger) => {
             if let Some(when_clause) = &mut trigger.when_clause {
                 normalize_expr(when_clause);
@@ -320,6 +442,7 @@ fn normalize_object_exprs(object: &mut SchemaObject) {
         SchemaObject::Schema(_) => {}
         SchemaObject::Comment(_) => {}
         SchemaObject::Privilege(_) => {}
+        SchemaObject::Pragma(_) => {}
         SchemaObject::Policy(policy) => {
             if let Some(using_expr) = &mut policy.using_expr {
                 normalize_expr(using_expr);
@@ -331,16 +454,122 @@ fn normalize_object_exprs(object: &mut SchemaObject) {
     }
 }
 
+/// SQL Server stores a filter such as `status = 1` as `([status]=(1))`, so raw index
+/// expressions are compared in a canonical spelling.
 fn normalize_index_exprs(index: &mut IndexDef) {
     for column in &mut index.columns {
-        normalize_expr(&mut column.expr);
+        normalize_index_expr(&mut column.expr);
     }
 
     if let Some(where_clause) = &mut index.where_clause {
-        normalize_expr(where_clause);
+        normalize_index_expr(where_clause);
+    }
+}
+
+fn normalize_index_expr(expr: &mut Expr) {
+    match expr {
+        Expr::Raw(raw) => *raw = canonical_index_expr(raw),
+        other => normalize_expr(other),
+    }
+}
+
+/// Lowercases words and simple bracketed identifiers, unwraps parenthesized numbers and
+/// outer parentheses, and keeps a space only between words.
+fn canonical_index_expr(raw: &str) -> String {
+    let mut tokens = scan_tokens(raw)
+        .into_iter()
+        .map(|token| {
+            let text = token.text(raw);
+            let text = match token.kind {
+                TokenKind::Quoted if !text.starts_with('\'') && is_simple_ident(unquote(text)) => {
+                    unquote(text).to_ascii_lowercase()
+                }
+                TokenKind::Quoted => text.to_string(),
+                TokenKind::Word => text.to_ascii_lowercase(),
+                TokenKind::Punct(byte) => char::from(byte).to_string(),
+            };
+            CanonicalToken {
+                text,
+                word: !matches!(token.kind, TokenKind::Punct(_)),
+                start: token.start,
+                end: token.end,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut index = 0;
+    while index + 2 < tokens.len() {
+        let wrapped_number = tokens[index].is_punct("(")
+            && tokens[index + 1]
+                .text
+                .bytes()
+                .all(|byte| byte.is_ascii_digit())
+            && tokens[index + 2].is_punct(")")
+            && (index == 0 || !tokens[index - 1].word);
+        if wrapped_number {
+            tokens.remove(index + 2);
+            tokens.remove(index);
+        } else {
+            index += 1;
+        }
+    }
+    while tokens.first().is_some_and(|token| token.is_punct("("))
+        && matching_paren(&tokens) == Some(tokens.len() - 1)
+    {
+        tokens.pop();
+        tokens.remove(0);
+    }
+
+    let mut canonical = String::new();
+    for (index, token) in tokens.iter().enumerate() {
+        let previous = index.checked_sub(1).map(|previous| &tokens[previous]);
+        if previous
+            .is_some_and(|previous| previous.word && token.word && previous.end < token.start)
+        {
+            canonical.push(' ');
+        }
+        canonical.push_str(&token.text);
+    }
+    canonical
+}
+
+struct CanonicalToken {
+    text: String,
+    word: bool,
+    start: usize,
+    end: usize,
+}
+
+impl CanonicalToken {
+    fn is_punct(&self, punct: &str) -> bool {
+        !self.word && self.text == punct
     }
 }
 
+fn matching_paren(tokens: &[CanonicalToken]) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, token) in tokens.iter().enumerate() {
+        if token.is_punct("(") {
+            depth += 1;
+        } else if token.is_punct(")") {
+            depth = depth.checked_sub(1)?;
+            if depth == 0 {
+                return Some(index);
+            }
+        }
+    }
+    None
+}
+
+fn is_simple_ident(text: &str) -> bool {
+    text.bytes()
+        .next()
+        .is_some_and(|byte| byte.is_ascii_alphabetic() || byte == b'_')
+        && text
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_')
+}
+
 fn normalize_expr(expr: &mut Expr) {
     match expr {
         Expr::Literal(_) | Expr::Null => {}
@@ -442,6 +671,24 @@ fn normalize_expr(expr: &mut Expr) {
 mod types {
     use stateql_core::DataType;
 
+    /// The range of an integer sequence type, or `None` where it does not fit in an `i64`.
+    pub(super) fn integer_bounds(data_type: &DataType) -> (Option<i64>, Option<i64>) {
+        match data_type {
+            DataType::SmallInt => (Some(i16::MIN.into()), Some(i16::MAX.into())),
+            DataType::Integer => (Some(i32::MIN.into()), Some(i32::MAX.into())),
+            DataType::BigInt => (Some(i64::MIN), Some(i64::MAX)),
+            DataType::Custom(custom) if custom == "tinyint" => (Some(0), Some(255)),
+            DataType::Numeric {
+                precision: Some(precision @ 1..=18),
+                scale: None | Some(0),
+            } => {
+                let max = 10_i64.pow(*precision) - 1;
+                (Some(-max), Some(max))
+            }
+            _ => (None, None),
+        }
+    }
+
     pub(super) fn normalize_data_type(data_type: &mut DataType) {
         match data_type {
             DataType::Array(inner) => normalize_data_type(inner.as_mut()),
@@ -452,91 +699,87 @@ mod types {
         }
     }
 
+    /// Only spellings SQL Server treats as the same type are folded together; `varchar`,
+    /// `char`, `text`, `ntext` and the `datetime` family keep their own names so a change
+    /// between them is a type change.
     fn normalize_custom_type(raw: &str) -> DataType {
         let canonical = normalize_custom(raw);
         if canonical.is_empty() {
             return DataType::Custom(String::new());
         }
 
-        if canonical == "bit" || canonical == "bool" || canonical == "boolean" {
-            return DataType::Boolean;
-        }
-        if canonical == "smallint" {
-            return DataType::SmallInt;
-        }
-        if canonical == "int" || canonical == "integer" {
-            return DataType::Integer;
-        }
-        if canonical == "bigint" {
-            return DataType::BigInt;
-        }
-        if canonical == "real" {
-            return DataType::Real;
-        }
-        if canonical == "float" {
-            return DataType::DoublePrecision;
-        }
-        if canonical == "text" || canonical == "ntext" {
-            return DataType::Text;
-        }
-        if canonical == "date" {
-            return DataType::Date;
-        }
-        if canonical == "time" {
-            return DataType::Time {
-                with_timezone: false,
-            };
-        }
-        if canonical == "datetime"
-            || canonical == "datetime2"
-            || canonical == "smalldatetime"
-            || canonical == "datetimeoffset"
-        {
-            return DataType::Timestamp {
-                with_timezone: false,
-            };
-        }
-        if canonical == "uniqueidentifier" {
-            return DataType::Uuid;
+        match canonical.as_str() {
+            "bit" | "bool" | "boolean" => return DataType::Boolean,
+            "smallint" => return DataType::SmallInt,
+            "int" | "integer" => return DataType::Integer,
+            "bigint" => return DataType::BigInt,
+            "real" | "float(24)" => return DataType::Real,
+            "float" | "float(53)" => return DataType::DoublePrecision,
+            "date" => return DataType::Date,
+            "time" | "time(7)" => {
+                return DataType::Time {
+                    with_timezone: false,
+                };
+            }
+            "datetime2" | "datetime2(7)" => {
+                return DataType::Timestamp {
+                    with_timezone: false,
+                };
+            }
+            "uniqueidentifier" => return DataType::Uuid,
+            "nvarchar(max)" => return DataType::Text,
+            "varbinary(max)" => return DataType::Blob,
+            "nvarchar" => return DataType::Varchar { length: Some(1) },
+            "nchar" => return DataType::Char { length: Some(1) },
+            _ => {}
         }
-        if canonical.starts_with("decimal") || canonical.starts_with("numeric") {
-            return DataType::Numeric {
-                precision: None,
-                scale: None,
-            };
+
+        for prefix in ["decimal", "numeric"] {
+            if let Some(arguments) = parenthesized_arguments(&canonical, prefix) {
+                let (precision, scale) = match arguments.as_slice() {
+                    [] => (Some(18), Some(0)),
+                    [precision] => (Some(*precision), Some(0)),
+                    [precision, scale] => (Some(*precision), Some(*scale)),
+                    _ => return DataType::Custom(canonical),
+                };
+                return DataType::Numeric { precision, scale };
+            }
         }
         if let Some(length) = parse_parenthesized_length(&canonical, "nvarchar") {
             return DataType::Varchar {
                 length: Some(length),
             };
         }
-        if canonical == "nvarchar(max)" || canonical == "varchar(max)" {
-            return DataType::Text;
-        }
-        if let Some(length) = parse_parenthesized_length(&canonical, "varchar") {
-            return DataType::Varchar {
-                length: Some(length),
-            };
-        }
         if let Some(length) = parse_parenthesized_length(&canonical, "nchar") {
             return DataType::Char {
                 length: Some(length),
             };
         }
-        if let Some(length) = parse_parenthesized_length(&canonical, "char") {
-            return DataType::Char {
-                length: Some(length),
-            };
-        }
-        if canonical == "varbinary(max)" || canonical == "binary(max)" || canonical == "image" {
-            return DataType::Blob;
-        }
 
         DataType::Custom(canonical)
     }
 
     fn normalize_custom(raw: &str) -> String {
-        collapse_spaces(raw).to_ascii_lowercase()
+        collapse_spaces(raw)
+            .replace("( ", "(")
+            .replace(" (", "(")
+            .replace(" )", ")")
+            .replace(", ", ",")
+            .replace(" ,", ",")
+            .to_ascii_lowercase()
+    }
+
+    /// `Some(vec![])` for a bare `prefix`, the numbers of `prefix(a, b)` otherwise.
+    fn parenthesized_arguments(canonical: &str, prefix: &str) -> Option<Vec<u32>> {
+        let body = canonical.strip_prefix(prefix)?;
+        if body.is_empty() {
+            return Some(Vec::new());
+        }
+        let inner = body.strip_prefix('(')?.strip_suffix(')')?;
+        inner
+            .split(',')
+            .map(|argument| argument.trim().parse::<u32>().ok())
+            .collect()
     }
 
     fn parse_parenthesized_length(canonical: &str, prefix: &str) -> Option<u32> {
@@ -0,0 +1,298 @@
+//! Sequences, synonyms and descriptions are read by hand: SQL Server takes sequence options
+//! in any order, which sqlparser does not, sqlparser has no `CREATE SYNONYM` at all, and
+//! descriptions are `MS_Description` extended properties set through a stored procedure.
+
+use std::io;
+
+use stateql_core::{
+    Comment, CommentTarget, DataType, Ident, QualifiedName, SchemaObject, Sequence, Synonym,
+};
+
+use crate::{
+    header::{Header, conversion_error},
+    parser::parse_bracketed_ident,
+    table_clauses::{Token, TokenKind},
+    to_sql::DESCRIPTION_PROPERTY,
+};
+
+type ConversionResult<T> = std::result::Result<T, io::Error>;
+
+const SEQUENCE_OPTIONS: [&str; 7] = [
+    "START",
+    "INCREMENT",
+    "MINVALUE",
+    "MAXVALUE",
+    "NO",
+    "CYCLE",
+    "CACHE",
+];
+
+/// Parameters of `sp_addextendedproperty`, in positional order.
+const PROPERTY_PARAMS: [&str; 8] = [
+    "@name",
+    "@value",
+    "@level0type",
+    "@level0name",
+    "@level1type",
+    "@level1name",
+    "@level2type",
+    "@level2name",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ObjectKind {
+    Sequence,
+    Synonym,
+    Comment,
+}
+
+/// The object a statement creates, if it starts with `CREATE SEQUENCE` or `CREATE SYNONYM`
+/// or executes `sp_addextendedproperty` or `sp_updateextendedproperty`.
+pub(crate) fn object_kind(statement: &str) -> Option<ObjectKind> {
+    object_header(&mut Header::new(statement))
+}
+
+fn object_header(header: &mut Header<'_>) -> Option<ObjectKind> {
+    if header.eat_word("EXEC") || header.eat_word("EXECUTE") {
+        if header.eat_word("sys") && !header.eat_punct(b'.') {
+            return None;
+        }
+        return (header.eat_word("sp_addextendedproperty")
+            || header.eat_word("sp_updateextendedproperty"))
+        .then_some(ObjectKind::Comment);
+    }
+    if !header.eat_word("CREATE") {
+        return None;
+    }
+    if header.eat_word("SEQUENCE") {
+        Some(ObjectKind::Sequence)
+    } else if header.eat_word("SYNONYM") {
+        Some(ObjectKind::Synonym)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn convert_object(statement: &str) -> ConversionResult<SchemaObject> {
+    let mut header = Header::new(statement);
+    let kind = object_header(&mut header).ok_or_else(|| {
+        conversion_error("mssql statement does not create a sequence, synonym or description")
+    })?;
+    let object = match kind {
+        ObjectKind::Sequence => {
+            let name = header.object_name()?;
+            SchemaObject::Sequence(convert_sequence(&mut header, name)?)
+        }
+        ObjectKind::Synonym => {
+            let name = header.object_name()?;
+            SchemaObject::Synonym(convert_synonym(&mut header, name)?)
+        }
+        ObjectKind::Comment => SchemaObject::Comment(convert_description(&mut header)?),
+    };
+    header.eat_punct(b';');
+    if header.peek().is_some() {
+        return Err(header.unexpected("end of statement"));
+    }
+    Ok(object)
+}
+
+/// `CREATE SEQUENCE name [AS type]` followed by `START WITH`, `INCREMENT BY`,
+/// `[NO] MINVALUE`, `[NO] MAXVALUE`, `[NO] CYCLE` and `CACHE [n] | NO CACHE` in any order.
+fn convert_sequence(header: &mut Header<'_>, name: QualifiedName) -> ConversionResult<Sequence> {
+    let mut sequence = Sequence {
+        name,
+        data_type: None,
+        increment: None,
+        min_value: None,
+        max_value: None,
+        start: None,
+        cache: None,
+        cycle: false,
+        owned_by: None,
+    };
+    if header.eat_word("AS") {
+        let data_type = header.text_until("sequence type", |header, token| {
+            header.depth == 0
+                && (token.kind == TokenKind::Punct(b';')
+                    || SEQUENCE_OPTIONS
+                        .iter()
+                        .any(|keyword| token.is_word(header.sql, keyword)))
+        })?;
+        sequence.data_type = Some(DataType::Custom(data_type.to_string()));
+    }
+
+    loop {
+        if header.eat_word("START") {
+            header.expect_word("WITH")?;
+            sequence.start = Some(signed_number(header)?);
+        } else if header.eat_word("INCREMENT") {
+            header.expect_word("BY")?;
+            sequence.increment = Some(signed_number(header)?);
+        } else if header.eat_word("MINVALUE") {
+            sequence.min_value = Some(signed_number(header)?);
+        } else if header.eat_word("MAXVALUE") {
+            sequence.max_value = Some(signed_number(header)?);
+        } else if header.eat_word("CYCLE") {
+            sequence.cycle = true;
+        } else if header.eat_word("CACHE") {
+            // Without a size SQL Server picks one, which is the same as saying nothing.
+            let sized = header
+                .peek()
+                .is_some_and(|token| token.kind == TokenKind::Word && !is_option(header, token));
+            sequence.cache = if sized {
+                Some(signed_number(header)?)
+            } else {
+                None
+            };
+        } else if header.eat_word("NO") {
+            if header.eat_word("MINVALUE") {
+                sequence.min_value = None;
+            } else if header.eat_word("MAXVALUE") {
+                sequence.max_value = None;
+            } else if header.eat_word("CYCLE") {
+                sequence.cycle = false;
+            } else if header.eat_word("CACHE") {
+                sequence.cache = Some(0);
+            } else {
+                return Err(header.unexpected("MINVALUE, MAXVALUE, CYCLE or CACHE"));
+            }
+        } else {
+            break;
+        }
+    }
+    Ok(sequence)
+}
+
+fn is_option(header: &Header<'_>, token: &Token) -> bool {
+    SEQUENCE_OPTIONS
+        .iter()
+        .any(|keyword| token.is_word(header.sql, keyword))
+}
+
+fn signed_number(header: &mut Header<'_>) -> ConversionResult<i64> {
+    let negative = header.eat_punct(b'-');
+    if !negative {
+        header.eat_punct(b'+');
+    }
+    let token = header.next_token("number")?;
+    let text = token.text(header.sql);
+    let invalid = || conversion_error(format!("invalid mssql sequence value `{text}`"));
+    // Parsed wide so the smallest `bigint` survives its sign.
+    let value = text.parse::<i128>().map_err(|_| invalid())?;
+    i64::try_from(if negative { -value } else { value }).map_err(|_| invalid())
+}
+
+/// `CREATE SYNONYM name FOR [server.][database.][schema.]object`.
+fn convert_synonym(header: &mut Header<'_>, name: QualifiedName) -> ConversionResult<Synonym> {
+    header.expect_word("FOR")?;
+    let mut target = vec![parse_bracketed_ident(
+        header.next_token("synonym target")?.text(header.sql),
+    )];
+    while header.eat_punct(b'.') {
+        target.push(parse_bracketed_ident(
+            header.next_token("synonym target")?.text(header.sql),
+        ));
+    }
+    if target.len() > 4 {
+        return Err(conversion_error(
+            "mssql synonym target has more than four name parts",
+        ));
+    }
+    Ok(Synonym { name, target })
+}
+
+/// The arguments of `sp_addextendedproperty`, by name or by position, setting the
+/// `MS_Description` of a table, a table column, a view or a procedure.
+fn convert_description(header: &mut Header<'_>) -> ConversionResult<Comment> {
+    let mut args: [Option<String>; 8] = Default::default();
+    let mut position = 0;
+    while header
+        .peek()
+        .is_some_and(|token| token.kind != TokenKind::Punct(b';'))
+    {
+        let index = if header
+            .peek()
+            .is_some_and(|token| token.text(header.sql).starts_with('@'))
+        {
+            let param = header.next_token("parameter name")?.text(header.sql);
+            header.expect_punct(b'=')?;
+            PROPERTY_PARAMS
+                .iter()
+                .position(|known| known.eq_ignore_ascii_case(param))
+                .ok_or_else(|| {
+                    conversion_error(format!(
+                        "unknown mssql extended property parameter `{param}`"
+                    ))
+                })?
+        } else {
+            position
+        };
+        if index >= args.len() {
+            return Err(conversion_error(
+                "too many arguments to mssql extended property procedure",
+            ));
+        }
+        args[index] = string_literal(header)?;
+        position = index + 1;
+        if !header.eat_punct(b',') {
+            break;
+        }
+    }
+
+    let arg = |index: usize| args[index].as_deref();
+    if !arg(0).is_some_and(|name| name.eq_ignore_ascii_case(DESCRIPTION_PROPERTY)) {
+        return Err(conversion_error(format!(
+            "unsupported mssql extended property: only {DESCRIPTION_PROPERTY} is supported"
+        )));
+    }
+    let (Some(schema), Some(object)) = (arg(3), arg(5)) else {
+        return Err(conversion_error(
+            "mssql extended property must name a schema and an object",
+        ));
+    };
+    if !arg(2).is_some_and(|kind| kind.eq_ignore_ascii_case("SCHEMA")) {
+        return Err(conversion_error(
+            "mssql extended property level0type must be SCHEMA",
+        ));
+    }
+    let name = QualifiedName {
+        schema: Some(Ident::unquoted(schema)),
+        name: Ident::unquoted(object),
+    };
+    let level1 = arg(4).unwrap_or_default().to_ascii_uppercase();
+    let target = match (level1.as_str(), arg(6), arg(7)) {
+        ("TABLE", None, None) => CommentTarget::Table(name),
+        ("TABLE", Some(kind), Some(column)) if kind.eq_ignore_ascii_case("COLUMN") => {
+            CommentTarget::Column {
+                table: name,
+                column: Ident::unquoted(column),
+            }
+        }
+        ("VIEW", None, None) => CommentTarget::View(name),
+        ("PROCEDURE", None, None) => CommentTarget::Function(name),
+        _ => {
+            return Err(conversion_error(
+                "unsupported mssql extended property target: only tables, table columns, views \
+                 and procedures are supported",
+            ));
+        }
+    };
+    Ok(Comment {
+        target,
+        text: arg(1).map(str::to_string),
+    })
+}
+
+/// `N'text'`, `'text'` or `NULL`.
+fn string_literal(header: &mut Header<'_>) -> ConversionResult<Option<String>> {
+    if header.eat_word("NULL") {
+        return Ok(None);
+    }
+    header.eat_word("N");
+    let token = header.next_token("string literal")?;
+    let text = token.text(header.sql);
+    text.strip_prefix('\'')
+        .and_then(|rest| rest.strip_suffix('\''))
+        .map(|inner| Some(inner.replace("''", "'")))
+        .ok_or_else(|| conversion_error(format!("expected string literal, found `{text}`")))
+}
@@ -1,39 +1,152 @@
-use std::io;
-
-use sqlparser::{dialect::MsSqlDialect, parser::Parser};
+use std::{collections::BTreeMap, io};
+
+use sqlparser::{
+    ast::{
+        AlterTableOperation, ColumnDef, ColumnOption, CreateIndex, CreateTable, Expr as SqlExpr,
+        ForeignKeyConstraint, Ident as SqlIdent, IdentityPropertyFormatKind, IdentityPropertyKind,
+        IndexColumn as SqlIndexColumn, ObjectName, ReferentialAction, Statement as SqlStatement,
+        TableConstraint,
+    },
+    dialect::MsSqlDialect,
+    parser::{Parser, ParserError},
+};
 use stateql_core::{
-    AnnotationAttachment, AnnotationExtractor, AnnotationTarget, Ident, ParseError, QualifiedName,
-    Result, SchemaObject, SourceLocation, Table, Value, attach_annotations,
+    AnnotationAttachment, AnnotationExtractor, AnnotationTarget, CheckConstraint, Column, DataType,
+    Expr, ForeignKey, ForeignKeyAction, GeneratedColumn, Ident, Identity, IndexColumn, IndexDef,
+    IndexOwner, ParseError, PrimaryKey, QualifiedName, Result, SchemaObject, SourceLocation, Table,
+    TypeDef, TypeKind, Value, attach_annotations,
 };
 
-use crate::extra_keys;
+use crate::{
+    extra_keys,
+    header::Header,
+    modules, objects,
+    table_clauses::{
+        ColumnstoreIndex, ComputedColumn, Element, ElementKind, TableClauses, TokenKind, blank_out,
+        columnstore_index, is_columnstore_index, lift_table_clauses, scan_tokens,
+    },
+};
 
 type ConversionResult<T> = std::result::Result<T, io::Error>;
 
+const BATCH_SEPARATOR: &str = "GO";
+const SESSION_SETTINGS: [&str; 9] = [
+    "ANSI_NULLS",
+    "ANSI_PADDING",
+    "ANSI_WARNINGS",
+    "ARITHABORT",
+    "CONCAT_NULL_YIELDS_NULL",
+    "NUMERIC_ROUNDABORT",
+    "QUOTED_IDENTIFIER",
+    "NOCOUNT",
+    "XACT_ABORT",
+];
+
 pub(crate) fn parse_schema(sql: &str) -> Result<Vec<SchemaObject>> {
     let (clean_sql, annotations) = AnnotationExtractor::extract(sql)?;
-    let ast = Parser::parse_sql(&MsSqlDialect {}, &clean_sql).map_err(|source| {
-        ParseError::StatementConversion {
-            statement_index: 0,
-            source_sql: clean_sql.clone(),
-            source_location: Some(SourceLocation {
-                line: 1,
-                column: None,
-            }),
-            source: Box::new(source),
+    let mut parser_sql = String::with_capacity(clean_sql.len());
+    let mut statement_spans = Vec::new();
+    // Where each entry of `statement_spans` sits in `parser_sql`.
+    let mut parser_ranges = Vec::new();
+    let mut hand_spans = Vec::new();
+    for segment in split_batches(&clean_sql) {
+        match segment {
+            Segment::Separator(start, end) => {
+                parser_sql.push_str(&blank_out(&clean_sql[start..end]))
+            }
+            Segment::Batch(start, end) => {
+                let batch = &clean_sql[start..end];
+                if modules::module_kind(batch).is_some() {
+                    // Blanked rather than dropped so sqlparser still reports file line numbers.
+                    parser_sql.push_str(&blank_out(batch));
+                    hand_spans.push((HandParsed::Module, start, end));
+                    continue;
+                }
+                for (span_start, span_end) in split_statement_spans(batch) {
+                    let fragment = &batch[span_start..span_end];
+                    // Comments, stray `;` and session settings leave nothing to convert.
+                    if is_blank_fragment(fragment) || is_session_setting(fragment) {
+                        parser_sql.push_str(&blank_out(fragment));
+                        continue;
+                    }
+                    if modules::module_kind(fragment).is_some() {
+                        let metadata =
+                            span_metadata(&clean_sql, start + span_start, start + span_end);
+                        return Err(statement_conversion_error(
+                            statement_spans.len() + hand_spans.len(),
+                            metadata.source_sql,
+                            metadata.source_location,
+                            conversion_error(
+                                "mssql CREATE VIEW, PROCEDURE, FUNCTION and TRIGGER must start a \
+                                 batch; put GO on the line before it",
+                            ),
+                        ));
+                    }
+                    let hand_parsed = if is_columnstore_index(fragment) {
+                        Some(HandParsed::ColumnstoreIndex)
+                    } else {
+                        objects::object_kind(fragment).map(|_| HandParsed::Object)
+                    };
+                    if let Some(kind) = hand_parsed {
+                        parser_sql.push_str(&blank_out(fragment));
+                        hand_spans.push((kind, start + span_start, start + span_end));
+                        continue;
+                    }
+                    let lifted = lift_table_clauses(fragment);
+                    parser_ranges.push((parser_sql.len(), parser_sql.len() + lifted.sql.len()));
+                    parser_sql.push_str(&lifted.sql);
+                    statement_spans.push(((start + span_start, start + span_end), lifted.clauses));
+                }
+            }
         }
-    })?;
+    }
 
-    let metadata = statement_metadata(&clean_sql);
-    let mut objects = Vec::with_capacity(ast.len());
-    let mut attachments = Vec::with_capacity(ast.len());
+    let ast = Parser::parse_sql(&MsSqlDialect {}, &parser_sql).map_err(|source| {
+        parse_failure(
+            &clean_sql,
+            &parser_sql,
+            &statement_spans,
+            &parser_ranges,
+            &hand_spans,
+            source,
+        )
+    })?;
 
-    for (statement_index, statement) in ast.iter().enumerate() {
-        let metadata = metadata
-            .get(statement_index)
-            .cloned()
-            .unwrap_or_else(|| fallback_metadata(statement));
-        let converted = convert_statement(statement, metadata.line).map_err(|source| {
+    let metadata = statement_metadata(&clean_sql, statement_spans);
+    let mut units = ast
+        .iter()
+        .enumerate()
+        .map(|(statement_index, statement)| {
+            let metadata = metadata
+                .get(statement_index)
+                .cloned()
+                .unwrap_or_else(|| fallback_metadata(statement));
+            (metadata.offset, Unit::Statement(statement, metadata))
+        })
+        .collect::<Vec<_>>();
+    units.extend(hand_spans.into_iter().map(|(kind, start, end)| {
+        let metadata = span_metadata(&clean_sql, start, end);
+        (metadata.offset, Unit::HandParsed(kind, metadata))
+    }));
+    units.sort_by_key(|(offset, _)| *offset);
+
+    let mut objects = Vec::with_capacity(units.len());
+    let mut attachments = Vec::with_capacity(units.len());
+
+    for (statement_index, (_, unit)) in units.into_iter().enumerate() {
+        let (converted, metadata) = match unit {
+            Unit::Statement(statement, metadata) => {
+                (convert_statement(statement, &metadata), metadata)
+            }
+            Unit::HandParsed(HandParsed::Module, metadata) => (convert_module(&metadata), metadata),
+            Unit::HandParsed(HandParsed::ColumnstoreIndex, metadata) => {
+                (convert_columnstore_statement(&metadata), metadata)
+            }
+            Unit::HandParsed(HandParsed::Object, metadata) => {
+                (convert_object_statement(&metadata), metadata)
+            }
+        };
+        let converted = converted.map_err(|source| {
             statement_conversion_error(
                 statement_index,
                 metadata.source_sql,
@@ -42,24 +155,301 @@ pub(crate) fn parse_schema(sql: &str) -> Result<Vec<SchemaObject>> {
             )
         })?;
 
-        objects.push(converted.object);
-        attachments.push(converted.attachment);
+        objects.extend(converted.objects);
+        attachments.extend(converted.attachments);
     }
 
     attach_annotations(&mut objects, &annotations, &attachments)?;
     Ok(objects)
 }
 
+/// A statement sqlparser read, or one read by hand, in source order.
+enum Unit<'a> {
+    Statement(&'a SqlStatement, StatementMetadata),
+    HandParsed(HandParsed, StatementMetadata),
+}
+
+/// Statements sqlparser cannot read, blanked out of its input and converted by hand.
+#[derive(Debug, Clone, Copy)]
+enum HandParsed {
+    Module,
+    ColumnstoreIndex,
+    /// A sequence, a synonym or a description.
+    Object,
+}
+
+enum Segment {
+    Batch(usize, usize),
+    /// A `GO` line.
+    Separator(usize, usize),
+}
+
+/// Lexical state carried from one line to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineState {
+    Code,
+    String,
+    DoubleQuoted,
+    Bracketed,
+    /// T-SQL block comments nest.
+    BlockComment(usize),
+}
+
+/// `GO` on a line of its own, optionally followed by a `--` comment, ends a batch. A `GO`
+/// line inside a string, a quoted name or a block comment is part of it.
+fn split_batches(sql: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut batch_start = 0usize;
+    let mut line_start = 0usize;
+    let mut state = LineState::Code;
+    for line in sql.split_inclusive('\n') {
+        let line_end = line_start + line.len();
+        if state == LineState::Code && is_separator_line(line) {
+            segments.push(Segment::Batch(batch_start, line_start));
+            segments.push(Segment::Separator(line_start, line_end));
+            batch_start = line_end;
+        } else {
+            state = scan_line(line, state);
+        }
+        line_start = line_end;
+    }
+    segments.push(Segment::Batch(batch_start, sql.len()));
+    segments
+}
+
+fn is_separator_line(line: &str) -> bool {
+    let code = line.split_once("--").map_or(line, |(code, _)| code);
+    code.trim().eq_ignore_ascii_case(BATCH_SEPARATOR)
+}
+
+/// The state at the end of `line`, given the state at its start.
+fn scan_line(line: &str, mut state: LineState) -> LineState {
+    let bytes = line.as_bytes();
+    let mut index = 0usize;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        let next = bytes.get(index + 1).copied();
+        match state {
+            LineState::Code => match (byte, next) {
+                (b'-', Some(b'-')) => return state,
+                (b'/', Some(b'*')) => {
+                    state = LineState::BlockComment(1);
+                    index += 1;
+                }
+                (b'\'', _) => state = LineState::String,
+                (b'"', _) => state = LineState::DoubleQuoted,
+                (b'[', _) => state = LineState::Bracketed,
+                _ => {}
+            },
+            LineState::BlockComment(depth) => match (byte, next) {
+                (b'*', Some(b'/')) => {
+                    state = match depth {
+                        1 => LineState::Code,
+                        _ => LineState::BlockComment(depth - 1),
+                    };
+                    index += 1;
+                }
+                (b'/', Some(b'*')) => {
+                    state = LineState::BlockComment(depth + 1);
+                    index += 1;
+                }
+                _ => {}
+            },
+            LineState::String | LineState::DoubleQuoted | LineState::Bracketed => {
+                let closing = match state {
+                    LineState::String => b'\'',
+                    LineState::DoubleQuoted => b'"',
+                    _ => b']',
+                };
+                if byte == closing {
+                    if next == Some(closing) {
+                        index += 1;
+                    } else {
+                        state = LineState::Code;
+                    }
+                }
+            }
+        }
+        index += 1;
+    }
+    state
+}
+
+fn is_blank_fragment(fragment: &str) -> bool {
+    scan_tokens(fragment)
+        .iter()
+        .all(|token| token.kind == TokenKind::Punct(b';'))
+}
+
+/// `SET ANSI_NULLS ON` and the like, which scripting tools emit around every object. They
+/// change how a batch is read, not the schema.
+fn is_session_setting(fragment: &str) -> bool {
+    let mut header = Header::new(fragment);
+    let matched = header.eat_word("SET")
+        && SESSION_SETTINGS
+            .iter()
+            .any(|setting| header.eat_word(setting))
+        && (header.eat_word("ON") || header.eat_word("OFF"));
+    header.eat_punct(b';');
+    matched && header.peek().is_none()
+}
+
+/// sqlparser reads every statement at once and reports its position within the whole
+/// input, so the failure is narrowed down to the first statement that fails on its own.
+fn parse_failure(
+    clean_sql: &str,
+    parser_sql: &str,
+    statement_spans: &[((usize, usize), TableClauses)],
+    parser_ranges: &[(usize, usize)],
+    hand_spans: &[(HandParsed, usize, usize)],
+    source: ParserError,
+) -> stateql_core::Error {
+    let failing = statement_spans
+        .iter()
+        .zip(parser_ranges)
+        .enumerate()
+        .find_map(
+            |(position, (((start, end), _), (parser_start, parser_end)))| {
+                Parser::parse_sql(&MsSqlDialect {}, &parser_sql[*parser_start..*parser_end])
+                    .err()
+                    .map(|source| (position, *start, *end, source))
+            },
+        );
+    let Some((position, start, end, source)) = failing else {
+        return ParseError::StatementConversion {
+            statement_index: 0,
+            source_sql: clean_sql.to_string(),
+            source_location: Some(SourceLocation {
+                line: 1,
+                column: None,
+            }),
+            source: Box::new(source),
+        }
+        .into();
+    };
+    let metadata = span_metadata(clean_sql, start, end);
+    let statement_index = position
+        + hand_spans
+            .iter()
+            .filter(|(_, hand_start, _)| *hand_start < start)
+            .count();
+    ParseError::StatementConversion {
+        statement_index,
+        source_sql: metadata.source_sql,
+        source_location: metadata.source_location,
+        source: Box::new(source),
+    }
+    .into()
+}
+
+fn span_metadata(sql: &str, start: usize, end: usize) -> StatementMetadata {
+    let fragment = &sql[start..end];
+    let offset = start + leading_trivia_len(fragment);
+    let line = offset_to_line(sql, offset);
+    StatementMetadata {
+        source_sql: fragment.trim().to_string(),
+        source_location: Some(SourceLocation { line, column: None }),
+        offset,
+        line,
+        table_clauses: TableClauses::default(),
+        computed_lines: Vec::new(),
+    }
+}
+
+fn convert_module(metadata: &StatementMetadata) -> ConversionResult<ConvertedStatement> {
+    let object = modules::convert_module(&metadata.source_sql)?;
+    let attachments = match &object {
+        SchemaObject::View(view) => vec![AnnotationAttachment {
+            line: metadata.line,
+            target: AnnotationTarget::View(view.name.clone()),
+        }],
+        _ => Vec::new(),
+    };
+    Ok(ConvertedStatement {
+        objects: vec![object],
+        attachments,
+    })
+}
+
+/// A table type keeps columns, a clustered primary key and checks; SQL Server names its
+/// constraints itself.
+fn convert_table_type(table: Table, unique_keys: Vec<IndexDef>) -> ConversionResult<TypeDef> {
+    if !unique_keys.is_empty() || !table.foreign_keys.is_empty() {
+        return Err(conversion_error(
+            "unsupported mssql table type: only PRIMARY KEY and CHECK constraints are supported",
+        ));
+    }
+    let nonclustered = matches!(
+        table
+            .options
+            .extra
+            .get(extra_keys::TABLE_PRIMARY_KEY_CLUSTERED),
+        Some(Value::Bool(false))
+    );
+    let named = table
+        .primary_key
+        .as_ref()
+        .is_some_and(|primary_key| primary_key.name.is_some())
+        || table.checks.iter().any(|check| check.name.is_some())
+        || table.columns.iter().any(|column| {
+            column
+                .extra
+                .contains_key(stateql_core::extra_keys::mssql::DEFAULT_CONSTRAINT_NAME)
+        });
+    if nonclustered || named {
+        return Err(conversion_error(
+            "unsupported mssql table type: constraints must be unnamed and the primary key clustered",
+        ));
+    }
+
+    Ok(TypeDef {
+        name: table.name,
+        kind: TypeKind::Table {
+            columns: table.columns,
+            primary_key: table.primary_key,
+            checks: table.checks,
+        },
+    })
+}
+
+fn convert_object_statement(metadata: &StatementMetadata) -> ConversionResult<ConvertedStatement> {
+    Ok(ConvertedStatement {
+        objects: vec![objects::convert_object(&metadata.source_sql)?],
+        attachments: Vec::new(),
+    })
+}
+
+fn convert_columnstore_statement(
+    metadata: &StatementMetadata,
+) -> ConversionResult<ConvertedStatement> {
+    let index = columnstore_index(&metadata.source_sql).ok_or_else(|| {
+        conversion_error(
+            "unsupported mssql COLUMNSTORE INDEX: only a column list and WHERE are supported",
+        )
+    })?;
+    Ok(ConvertedStatement {
+        objects: vec![SchemaObject::Index(convert_columnstore_index(index)?)],
+        attachments: Vec::new(),
+    })
+}
+
 #[derive(Clone)]
 struct StatementMetadata {
     source_sql: String,
     source_location: Option<SourceLocation>,
+    /// Byte offset of the statement in the cleaned SQL.
+    offset: usize,
     line: usize,
+    table_clauses: TableClauses,
+    /// Line of each computed column's name, in definition order.
+    computed_lines: Vec<usize>,
 }
 
 struct ConvertedStatement {
-    object: SchemaObject,
-    attachment: AnnotationAttachment,
+    /// A table is followed by the indexes backing its `UNIQUE` constraints.
+    objects: Vec<SchemaObject>,
+    /// Tables, their columns and views; indexes cannot carry `@renamed` annotations.
+    attachments: Vec<AnnotationAttachment>,
 }
 
 fn statement_conversion_error(
@@ -77,21 +467,33 @@ fn statement_conversion_error(
     .into()
 }
 
-fn statement_metadata(sql: &str) -> Vec<StatementMetadata> {
-    split_statement_spans(sql)
+fn statement_metadata(
+    sql: &str,
+    spans: impl IntoIterator<Item = ((usize, usize), TableClauses)>,
+) -> Vec<StatementMetadata> {
+    spans
         .into_iter()
-        .filter_map(|(start, end)| {
+        .filter_map(|((start, end), table_clauses)| {
             let fragment = sql.get(start..end).unwrap_or(sql);
             let source_sql = fragment.trim();
             if source_sql.is_empty() {
                 return None;
             }
-            let line_offset = start.saturating_add(leading_whitespace_len(fragment));
+            let line_offset = start.saturating_add(leading_trivia_len(fragment));
             let line = offset_to_line(sql, line_offset);
+            let computed_lines = table_clauses
+                .elements
+                .iter()
+                .filter_map(|element| element.computed.as_ref())
+                .map(|computed| offset_to_line(sql, start + computed.name_offset))
+                .collect();
             Some(StatementMetadata {
                 source_sql: source_sql.to_string(),
                 source_location: Some(SourceLocation { line, column: None }),
+                offset: line_offset,
                 line,
+                table_clauses,
+                computed_lines,
             })
         })
         .collect()
@@ -235,15 +637,19 @@ fn fallback_metadata(statement: &sqlparser::ast::Statement) -> StatementMetadata
             line: 1,
             column: None,
         }),
+        offset: usize::MAX,
         line: 1,
+        table_clauses: TableClauses::default(),
+        computed_lines: Vec::new(),
     }
 }
 
-fn leading_whitespace_len(fragment: &str) -> usize {
-    fragment
-        .char_indices()
-        .find_map(|(index, ch)| (!ch.is_whitespace()).then_some(index))
-        .unwrap_or(fragment.len())
+/// Length of the whitespace and comments before the first token, so a statement is
+/// located on the line it starts rather than on a comment above it.
+fn leading_trivia_len(fragment: &str) -> usize {
+    scan_tokens(fragment)
+        .first()
+        .map_or(fragment.len(), |token| token.start)
 }
 
 fn offset_to_line(sql: &str, offset: usize) -> usize {
@@ -260,225 +666,704 @@ fn offset_to_line(sql: &str, offset: usize) -> usize {
 }
 
 fn convert_statement(
-    statement: &sqlparser::ast::Statement,
-    line: usize,
+    statement: &SqlStatement,
+    metadata: &StatementMetadata,
 ) -> ConversionResult<ConvertedStatement> {
-    let statement_sql = statement.to_string();
-    let table_name = parse_create_table_name(&statement_sql).ok_or_else(|| {
-        conversion_error(format!(
+    match statement {
+        SqlStatement::CreateTable(create_table) => convert_create_table(create_table, metadata),
+        SqlStatement::CreateIndex(create_index) => Ok(ConvertedStatement {
+            objects: vec![SchemaObject::Index(convert_create_index(
+                create_index,
+                metadata.table_clauses.index_clustered,
+            )?)],
+            attachments: Vec::new(),
+        }),
+        SqlStatement::AlterTable(alter_table) => {
+            // `to_sql` renders a unique constraint as its own `ALTER TABLE ... ADD CONSTRAINT`.
+            let [
+                AlterTableOperation::AddConstraint {
+                    constraint: TableConstraint::Unique(unique),
+                    ..
+                },
+            ] = alter_table.operations.as_slice()
+            else {
+                return Err(conversion_error(
+                    "unsupported mssql ALTER TABLE: only ADD CONSTRAINT ... UNIQUE is accepted",
+                ));
+            };
+            let table = parse_object_name(&alter_table.name)?;
+            let index = unique_key(
+                &table,
+                unique.name.as_ref(),
+                index_column_names(&unique.columns)?,
+                clustered_from_index_name(unique.index_name.as_ref()),
+            )?;
+            Ok(ConvertedStatement {
+                objects: vec![SchemaObject::Index(index)],
+                attachments: Vec::new(),
+            })
+        }
+        other => Err(conversion_error(format!(
             "unsupported mssql statement kind: {}",
-            statement_kind(&statement_sql)
-        ))
-    })?;
+            statement_kind(&other.to_string())
+        ))),
+    }
+}
 
-    let mut table = Table::named(table_name.name.value.as_str());
-    table.name = table_name.clone();
-    apply_preconversion_hints(&statement_sql, &mut table);
+fn convert_create_table(
+    create_table: &CreateTable,
+    metadata: &StatementMetadata,
+) -> ConversionResult<ConvertedStatement> {
+    if create_table.query.is_some() {
+        return Err(conversion_error(
+            "unsupported mssql CREATE TABLE ... AS SELECT",
+        ));
+    }
 
-    let attachment = AnnotationAttachment {
-        line,
-        target: AnnotationTarget::Table(table_name),
-    };
+    let name = parse_object_name(&create_table.name)?;
+    let mut table = Table::named(name.name.value.as_str());
+    table.name = name;
+
+    let elements = definition_elements(create_table, &metadata.table_clauses)?;
+    let mut column_defs = create_table.columns.iter();
+    let mut constraints = create_table.constraints.iter();
+    let mut computed_lines = metadata.computed_lines.iter();
+    let mut unique_keys = Vec::new();
+    let mut column_lines = Vec::new();
+
+    for element in &elements {
+        match (element.kind, &element.computed) {
+            (ElementKind::Computed, Some(computed)) => {
+                let line = computed_lines.next().copied().unwrap_or(metadata.line);
+                let column = convert_computed_column(computed);
+                column_lines.push((line, column.name.clone()));
+                table.columns.push(column);
+            }
+            (ElementKind::Constraint, _) => {
+                let constraint = constraints
+                    .next()
+                    .ok_or_else(|| conversion_error("mssql table constraint is missing"))?;
+                convert_table_constraint(constraint, element, &mut table, &mut unique_keys)?;
+            }
+            (ElementKind::Column, _) | (ElementKind::Computed, None) => {
+                let column_def = column_defs
+                    .next()
+                    .ok_or_else(|| conversion_error("mssql column definition is missing"))?;
+                let column = convert_column(column_def, element, &mut table, &mut unique_keys)?;
+                let line = usize::try_from(column_def.name.span.start.line).unwrap_or(0);
+                column_lines.push((line, column.name.clone()));
+                table.columns.push(column);
+            }
+        }
+    }
+
+    if metadata.table_clauses.table_type {
+        if metadata.table_clauses.period.is_some()
+            || metadata.table_clauses.system_versioning.is_some()
+        {
+            return Err(conversion_error(
+                "unsupported mssql table type: table types cannot be system-versioned",
+            ));
+        }
+        return Ok(ConvertedStatement {
+            objects: vec![SchemaObject::Type(convert_table_type(table, unique_keys)?)],
+            attachments: Vec::new(),
+        });
+    }
+
+    apply_temporal_clauses(&mut table, &metadata.table_clauses);
+
+    let mut attachments = vec![AnnotationAttachment {
+        line: metadata.line,
+        target: AnnotationTarget::Table(table.name.clone()),
+    }];
+    // A column sharing the `CREATE TABLE` line leaves that line to the table.
+    for (line, column) in column_lines {
+        if line != metadata.line {
+            attachments.push(AnnotationAttachment {
+                line,
+                target: AnnotationTarget::TableColumn {
+                    table: table.name.clone(),
+                    column,
+                },
+            });
+        }
+    }
 
+    let mut objects = vec![SchemaObject::Table(table)];
+    objects.extend(unique_keys.into_iter().map(SchemaObject::Index));
     Ok(ConvertedStatement {
-        object: SchemaObject::Table(table),
-        attachment,
+        objects,
+        attachments,
     })
 }
 
-fn apply_preconversion_hints(statement_sql: &str, table: &mut Table) {
-    table.options.extra.insert(
-        extra_keys::TABLE_SOURCE_SQL.to_string(),
-        Value::String(statement_sql.trim().to_string()),
-    );
+/// The lifted clauses of each definition-list element, checked against what sqlparser
+/// reported so a clause is never applied to the wrong column or constraint.
+fn definition_elements(
+    create_table: &CreateTable,
+    clauses: &TableClauses,
+) -> ConversionResult<Vec<Element>> {
+    if clauses.columns().count() != create_table.columns.len()
+        || clauses.constraints().count() != create_table.constraints.len()
+    {
+        return Err(conversion_error(format!(
+            "could not match the definition list of mssql table {}",
+            create_table.name
+        )));
+    }
+    Ok(clauses.elements.clone())
+}
+
+fn convert_column(
+    column_def: &ColumnDef,
+    element: &Element,
+    table: &mut Table,
+    unique_keys: &mut Vec<IndexDef>,
+) -> ConversionResult<Column> {
+    let mut column = Column {
+        name: parse_sqlparser_ident(&column_def.name),
+        data_type: DataType::Custom(column_def.data_type.to_string()),
+        not_null: false,
+        default: None,
+        identity: None,
+        generated: None,
+        comment: None,
+        collation: None,
+        renamed_from: None,
+        extra: BTreeMap::new(),
+    };
+    let mut foreign_key = None;
+    if let Some(default) = &element.sequence_default {
+        column.default = Some(Expr::Raw(default.expr.clone()));
+        if let Some(name) = &default.name {
+            column.extra.insert(
+                stateql_core::extra_keys::mssql::DEFAULT_CONSTRAINT_NAME.to_string(),
+                Value::String(parse_bracketed_ident(name).value),
+            );
+        }
+    }
 
-    let normalized = statement_sql.to_ascii_uppercase();
+    for option_def in &column_def.options {
+        let constraint_name = option_def.name.as_ref();
+        match &option_def.option {
+            ColumnOption::Null => column.not_null = false,
+            ColumnOption::NotNull => column.not_null = true,
+            ColumnOption::Default(expr) => {
+                column.default = Some(Expr::Raw(expr.to_string()));
+                if let Some(name) = constraint_name {
+                    column.extra.insert(
+                        stateql_core::extra_keys::mssql::DEFAULT_CONSTRAINT_NAME.to_string(),
+                        Value::String(name.value.clone()),
+                    );
+                }
+            }
+            ColumnOption::Collation(collation) => {
+                column.collation = collation
+                    .0
+                    .last()
+                    .and_then(|part| part.as_ident())
+                    .map(|ident| ident.value.clone());
+            }
+            ColumnOption::Identity(IdentityPropertyKind::Identity(property)) => {
+                column.identity = Some(convert_identity(property.parameters.as_ref())?);
+            }
+            ColumnOption::PrimaryKey(primary_key) => {
+                set_primary_key(
+                    table,
+                    PrimaryKey {
+                        name: constraint_name
+                            .or(primary_key.name.as_ref())
+                            .map(parse_sqlparser_ident),
+                        columns: vec![column.name.clone()],
+                    },
+                    element.clustered,
+                )?;
+            }
+            ColumnOption::Unique(unique) => {
+                unique_keys.push(unique_key(
+                    &table.name,
+                    constraint_name.or(unique.name.as_ref()),
+                    vec![column.name.clone()],
+                    element.clustered,
+                )?);
+            }
+            ColumnOption::ForeignKey(references) => {
+                foreign_key = Some(table.foreign_keys.len());
+                table.foreign_keys.push(convert_foreign_key(
+                    constraint_name.or(references.name.as_ref()),
+                    vec![column.name.clone()],
+                    references,
+                )?);
+            }
+            ColumnOption::Check(check) => {
+                table.checks.push(CheckConstraint {
+                    name: constraint_name
+                        .or(check.name.as_ref())
+                        .map(parse_sqlparser_ident),
+                    expr: Expr::Raw(check.expr.to_string()),
+                    no_inherit: false,
+                });
+            }
+            other => {
+                return Err(conversion_error(format!(
+                    "unsupported mssql column option `{other}` on column {}",
+                    column_def.name
+                )));
+            }
+        }
+    }
 
-    if normalized.contains("IDENTITY") {
-        table.options.extra.insert(
-            extra_keys::TABLE_HAS_IDENTITY.to_string(),
-            Value::Bool(true),
+    if element.not_for_replication {
+        if column.identity.is_some() {
+            column.extra.insert(
+                extra_keys::COLUMN_IDENTITY_NOT_FOR_REPLICATION.to_string(),
+                Value::Bool(true),
+            );
+        } else if let Some(foreign_key) = foreign_key {
+            mark_not_for_replication(&mut table.foreign_keys[foreign_key]);
+        } else {
+            return Err(not_for_replication_error(&column_def.name.value));
+        }
+    }
+
+    if let Some(generated_always) = element.generated_always {
+        column.extra.insert(
+            extra_keys::COLUMN_GENERATED_ALWAYS.to_string(),
+            Value::String(generated_always.to_string()),
         );
+        if element.hidden {
+            column
+                .extra
+                .insert(extra_keys::COLUMN_HIDDEN.to_string(), Value::Bool(true));
+        }
     }
 
-    if normalized.contains("NOT FOR REPLICATION") {
+    Ok(column)
+}
+
+/// `PERIOD FOR SYSTEM_TIME` and `SYSTEM_VERSIONING = ON` become table options; `OFF` is the
+/// same as leaving the clause out.
+fn apply_temporal_clauses(table: &mut Table, clauses: &TableClauses) {
+    if let Some((start, end)) = &clauses.period {
         table.options.extra.insert(
-            extra_keys::TABLE_HAS_NOT_FOR_REPLICATION.to_string(),
-            Value::Bool(true),
+            stateql_core::extra_keys::mssql::TABLE_PERIOD_START.to_string(),
+            Value::String(parse_bracketed_ident(start).value),
+        );
+        table.options.extra.insert(
+            stateql_core::extra_keys::mssql::TABLE_PERIOD_END.to_string(),
+            Value::String(parse_bracketed_ident(end).value),
         );
     }
-
-    if normalized.contains("PRIMARY KEY CLUSTERED") {
+    if let Some(versioning) = clauses
+        .system_versioning
+        .as_ref()
+        .filter(|versioning| versioning.on)
+    {
         table.options.extra.insert(
-            extra_keys::TABLE_PRIMARY_KEY_CLUSTERED.to_string(),
+            stateql_core::extra_keys::mssql::TABLE_SYSTEM_VERSIONING.to_string(),
             Value::Bool(true),
         );
+        if let Some(history_table) = &versioning.history_table {
+            table.options.extra.insert(
+                stateql_core::extra_keys::mssql::TABLE_HISTORY_TABLE.to_string(),
+                Value::String(history_table.clone()),
+            );
+        }
+    }
+}
+
+fn convert_computed_column(computed: &ComputedColumn) -> Column {
+    Column {
+        name: parse_bracketed_ident(&computed.name),
+        data_type: DataType::Custom(String::new()),
+        not_null: computed.not_null,
+        default: None,
+        identity: None,
+        generated: Some(GeneratedColumn {
+            expr: Expr::Raw(computed.expr.trim().to_string()),
+            stored: computed.persisted,
+        }),
+        comment: None,
+        collation: None,
+        renamed_from: None,
+        extra: BTreeMap::new(),
     }
+}
 
-    if normalized.contains("PRIMARY KEY NONCLUSTERED") {
+fn convert_table_constraint(
+    constraint: &TableConstraint,
+    element: &Element,
+    table: &mut Table,
+    unique_keys: &mut Vec<IndexDef>,
+) -> ConversionResult<()> {
+    match constraint {
+        TableConstraint::PrimaryKey(primary_key) => {
+            set_primary_key(
+                table,
+                PrimaryKey {
+                    name: primary_key.name.as_ref().map(parse_sqlparser_ident),
+                    columns: index_column_names(&primary_key.columns)?,
+                },
+                element.clustered,
+            )?;
+        }
+        TableConstraint::Unique(unique) => {
+            unique_keys.push(unique_key(
+                &table.name,
+                unique.name.as_ref(),
+                index_column_names(&unique.columns)?,
+                element.clustered,
+            )?);
+        }
+        TableConstraint::ForeignKey(foreign_key) => {
+            let mut converted = convert_foreign_key(
+                foreign_key.name.as_ref(),
+                foreign_key
+                    .columns
+                    .iter()
+                    .map(parse_sqlparser_ident)
+                    .collect(),
+                foreign_key,
+            )?;
+            if element.not_for_replication {
+                mark_not_for_replication(&mut converted);
+            }
+            table.foreign_keys.push(converted);
+            return Ok(());
+        }
+        TableConstraint::Check(check) => {
+            table.checks.push(CheckConstraint {
+                name: check.name.as_ref().map(parse_sqlparser_ident),
+                expr: Expr::Raw(check.expr.to_string()),
+                no_inherit: false,
+            });
+        }
+        other => {
+            return Err(conversion_error(format!(
+                "unsupported mssql table constraint: {other}"
+            )));
+        }
+    }
+
+    if element.not_for_replication {
+        return Err(not_for_replication_error(&table.name.name.value));
+    }
+    Ok(())
+}
+
+fn set_primary_key(
+    table: &mut Table,
+    primary_key: PrimaryKey,
+    clustered: Option<bool>,
+) -> ConversionResult<()> {
+    if table.primary_key.is_some() {
+        return Err(conversion_error(format!(
+            "table {} has more than one primary key",
+            table.name.name.value
+        )));
+    }
+    table.primary_key = Some(primary_key);
+    if let Some(clustered) = clustered {
         table.options.extra.insert(
             extra_keys::TABLE_PRIMARY_KEY_CLUSTERED.to_string(),
-            Value::Bool(false),
+            Value::Bool(clustered),
         );
     }
+    Ok(())
 }
 
-fn parse_create_table_name(statement_sql: &str) -> Option<QualifiedName> {
-    let tokens: Vec<&str> = statement_sql.split_whitespace().collect();
-    if !tokens
-        .first()
-        .is_some_and(|token| eq_keyword(token, "CREATE"))
-    {
-        return None;
+/// A `UNIQUE` constraint becomes the unique index SQL Server creates for it, flagged so
+/// the generator adds and drops it as a constraint.
+fn unique_key(
+    table: &QualifiedName,
+    name: Option<&SqlIdent>,
+    columns: Vec<Ident>,
+    clustered: Option<bool>,
+) -> ConversionResult<IndexDef> {
+    let Some(name) = name else {
+        return Err(conversion_error(format!(
+            "UNIQUE constraints on mssql table {} need a name: CONSTRAINT <name> UNIQUE (...)",
+            table.name.value
+        )));
+    };
+    Ok(IndexDef {
+        name: Some(parse_sqlparser_ident(name)),
+        owner: IndexOwner::Table(table.clone()),
+        columns: columns
+            .into_iter()
+            .map(|column| IndexColumn {
+                expr: Expr::Ident(column),
+            })
+            .collect(),
+        unique: true,
+        method: clustered.map(|clustered| clustered_keyword(clustered).to_string()),
+        where_clause: None,
+        concurrent: false,
+        extra: BTreeMap::from([(
+            extra_keys::INDEX_UNIQUE_CONSTRAINT.to_string(),
+            Value::Bool(true),
+        )]),
+    })
+}
+
+fn convert_create_index(
+    create_index: &CreateIndex,
+    clustered: Option<bool>,
+) -> ConversionResult<IndexDef> {
+    let Some(name) = &create_index.name else {
+        return Err(conversion_error(
+            "unsupported mssql CREATE INDEX without a name",
+        ));
+    };
+    if create_index.using.is_some() || !create_index.with.is_empty() {
+        return Err(conversion_error(format!(
+            "unsupported mssql CREATE INDEX options on {name}"
+        )));
     }
 
-    let mut cursor = 1usize;
-    if !tokens
-        .get(cursor)
-        .is_some_and(|token| eq_keyword(token, "TABLE"))
-    {
-        return None;
-    }
-    cursor += 1;
-
-    if tokens
-        .get(cursor)
-        .is_some_and(|token| eq_keyword(token, "IF"))
-        && tokens
-            .get(cursor + 1)
-            .is_some_and(|token| eq_keyword(token, "NOT"))
-        && tokens
-            .get(cursor + 2)
-            .is_some_and(|token| eq_keyword(token, "EXISTS"))
-    {
-        cursor += 3;
+    let columns = create_index
+        .columns
+        .iter()
+        .map(|column| {
+            let order = &column.column;
+            let expr = match &order.expr {
+                SqlExpr::Identifier(ident)
+                    if order.options.asc != Some(false) && order.options.nulls_first.is_none() =>
+                {
+                    Expr::Ident(parse_sqlparser_ident(ident))
+                }
+                _ => Expr::Raw(order.to_string()),
+            };
+            IndexColumn { expr }
+        })
+        .collect();
+
+    let mut extra = BTreeMap::new();
+    if !create_index.include.is_empty() {
+        extra.insert(
+            extra_keys::INDEX_INCLUDE_COLUMNS.to_string(),
+            Value::String(
+                create_index
+                    .include
+                    .iter()
+                    .map(|column| column.value.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+        );
     }
 
-    parse_qualified_name_token(tokens.get(cursor)?)
+    Ok(IndexDef {
+        name: Some(parse_object_name(name)?.name),
+        owner: IndexOwner::Table(parse_object_name(&create_index.table_name)?),
+        columns,
+        unique: create_index.unique,
+        method: clustered.map(|clustered| clustered_keyword(clustered).to_string()),
+        where_clause: create_index
+            .predicate
+            .as_ref()
+            .map(|predicate| Expr::Raw(predicate.to_string())),
+        concurrent: false,
+        extra,
+    })
 }
 
-fn parse_qualified_name_token(raw: &str) -> Option<QualifiedName> {
-    let token = trim_identifier_token(raw);
-    if token.is_empty() {
-        return None;
+fn convert_columnstore_index(index: ColumnstoreIndex) -> ConversionResult<IndexDef> {
+    let mut table = index.table.iter().map(|part| parse_bracketed_ident(part));
+    let owner = match (table.next(), table.next()) {
+        (Some(schema), Some(name)) => QualifiedName {
+            schema: Some(schema),
+            name,
+        },
+        (Some(name), None) => QualifiedName { schema: None, name },
+        _ => {
+            return Err(conversion_error(
+                "unsupported mssql COLUMNSTORE INDEX without a table",
+            ));
+        }
+    };
+    if index.clustered && !index.columns.is_empty() {
+        return Err(conversion_error(format!(
+            "unsupported column list on mssql CLUSTERED COLUMNSTORE INDEX {}",
+            index.name
+        )));
     }
 
-    let parts = split_qualified_name(token);
-    if parts.is_empty() || parts.len() > 2 {
-        return None;
-    }
+    Ok(IndexDef {
+        name: Some(parse_bracketed_ident(&index.name)),
+        owner: IndexOwner::Table(owner),
+        columns: index
+            .columns
+            .iter()
+            .map(|column| IndexColumn {
+                expr: Expr::Ident(parse_bracketed_ident(column)),
+            })
+            .collect(),
+        unique: false,
+        method: Some(clustered_keyword(index.clustered).to_string()),
+        where_clause: index.predicate.map(Expr::Raw),
+        concurrent: false,
+        extra: BTreeMap::from([(extra_keys::INDEX_COLUMNSTORE.to_string(), Value::Bool(true))]),
+    })
+}
 
-    if parts.len() == 1 {
-        let name = parse_ident_token(&parts[0])?;
-        return Some(QualifiedName { schema: None, name });
+/// Outside `CREATE TABLE` sqlparser reads `CLUSTERED`/`NONCLUSTERED` as an index name.
+fn clustered_from_index_name(index_name: Option<&SqlIdent>) -> Option<bool> {
+    let index_name = index_name.filter(|ident| ident.quote_style.is_none())?;
+    [true, false].into_iter().find(|clustered| {
+        index_name
+            .value
+            .eq_ignore_ascii_case(clustered_keyword(*clustered))
+    })
+}
+
+fn clustered_keyword(clustered: bool) -> &'static str {
+    if clustered {
+        "CLUSTERED"
+    } else {
+        "NONCLUSTERED"
     }
+}
 
-    let schema = parse_ident_token(&parts[0])?;
-    let name = parse_ident_token(&parts[1])?;
-    Some(QualifiedName {
-        schema: Some(schema),
-        name,
+fn convert_identity(parameters: Option<&IdentityPropertyFormatKind>) -> ConversionResult<Identity> {
+    let (start, increment) = match parameters {
+        Some(
+            IdentityPropertyFormatKind::FunctionCall(parameters)
+            | IdentityPropertyFormatKind::StartAndIncrement(parameters),
+        ) => (
+            identity_value(&parameters.seed)?,
+            identity_value(&parameters.increment)?,
+        ),
+        None => (1, 1),
+    };
+    Ok(Identity {
+        always: false,
+        start: Some(start),
+        increment: Some(increment),
+        min_value: None,
+        max_value: None,
+        cache: None,
+        cycle: false,
     })
 }
 
-fn split_qualified_name(token: &str) -> Vec<String> {
-    let mut parts = Vec::new();
-    let mut current = String::new();
-    let mut in_double_quote = false;
-    let mut in_backtick_quote = false;
-    let mut in_bracket_ident = false;
-
-    for ch in token.chars() {
-        if in_double_quote {
-            current.push(ch);
-            if ch == '"' {
-                in_double_quote = false;
-            }
-            continue;
-        }
+fn identity_value(expr: &SqlExpr) -> ConversionResult<i64> {
+    let text = expr.to_string().replace(' ', "");
+    text.parse::<i64>()
+        .map_err(|_| conversion_error(format!("unsupported mssql IDENTITY value `{expr}`")))
+}
 
-        if in_backtick_quote {
-            current.push(ch);
-            if ch == '`' {
-                in_backtick_quote = false;
-            }
-            continue;
-        }
+fn convert_foreign_key(
+    name: Option<&SqlIdent>,
+    columns: Vec<Ident>,
+    foreign_key: &ForeignKeyConstraint,
+) -> ConversionResult<ForeignKey> {
+    Ok(ForeignKey {
+        name: name.map(parse_sqlparser_ident),
+        columns,
+        referenced_table: parse_object_name(&foreign_key.foreign_table)?,
+        referenced_columns: foreign_key
+            .referred_columns
+            .iter()
+            .map(parse_sqlparser_ident)
+            .collect(),
+        on_delete: foreign_key.on_delete.map(convert_referential_action),
+        on_update: foreign_key.on_update.map(convert_referential_action),
+        deferrable: None,
+        extra: BTreeMap::new(),
+    })
+}
 
-        if in_bracket_ident {
-            current.push(ch);
-            if ch == ']' {
-                in_bracket_ident = false;
-            }
-            continue;
-        }
+fn mark_not_for_replication(foreign_key: &mut ForeignKey) {
+    foreign_key.extra.insert(
+        extra_keys::FOREIGN_KEY_NOT_FOR_REPLICATION.to_string(),
+        Value::Bool(true),
+    );
+}
 
-        match ch {
-            '.' => {
-                parts.push(current.trim().to_string());
-                current.clear();
-            }
-            '"' => {
-                in_double_quote = true;
-                current.push(ch);
-            }
-            '`' => {
-                in_backtick_quote = true;
-                current.push(ch);
-            }
-            '[' => {
-                in_bracket_ident = true;
-                current.push(ch);
-            }
-            _ => {
-                current.push(ch);
-            }
-        }
-    }
+fn not_for_replication_error(owner: &str) -> io::Error {
+    conversion_error(format!(
+        "NOT FOR REPLICATION on {owner} is only supported for IDENTITY and FOREIGN KEY"
+    ))
+}
 
-    if !current.is_empty() {
-        parts.push(current.trim().to_string());
+fn convert_referential_action(action: ReferentialAction) -> ForeignKeyAction {
+    match action {
+        ReferentialAction::Restrict => ForeignKeyAction::Restrict,
+        ReferentialAction::Cascade => ForeignKeyAction::Cascade,
+        ReferentialAction::SetNull => ForeignKeyAction::SetNull,
+        ReferentialAction::NoAction => ForeignKeyAction::NoAction,
+        ReferentialAction::SetDefault => ForeignKeyAction::SetDefault,
     }
+}
 
-    parts
+fn index_column_names(columns: &[SqlIndexColumn]) -> ConversionResult<Vec<Ident>> {
+    columns
+        .iter()
+        .map(|column| match &column.column.expr {
+            SqlExpr::Identifier(ident) => Ok(parse_sqlparser_ident(ident)),
+            other => Err(conversion_error(format!(
+                "unsupported mssql constraint column: {other}"
+            ))),
+        })
+        .collect()
 }
 
-fn parse_ident_token(raw: &str) -> Option<Ident> {
-    let token = trim_identifier_token(raw);
-    if token.is_empty() {
-        return None;
+fn parse_object_name(name: &ObjectName) -> ConversionResult<QualifiedName> {
+    if name.0.is_empty() || name.0.len() > 2 {
+        return Err(conversion_error(format!(
+            "unsupported qualified name in mssql statement: {name}"
+        )));
     }
 
-    if let Some(inner) = token
-        .strip_prefix('`')
-        .and_then(|rest| rest.strip_suffix('`'))
-    {
-        return Some(Ident::quoted(inner.replace("``", "`")));
+    let identifiers = name
+        .0
+        .iter()
+        .map(|part| {
+            part.as_ident().ok_or_else(|| {
+                conversion_error(format!(
+                    "unsupported object name part in mssql statement: {part}"
+                ))
+            })
+        })
+        .collect::<ConversionResult<Vec<_>>>()?;
+
+    if identifiers.len() == 1 {
+        return Ok(QualifiedName {
+            schema: None,
+            name: parse_sqlparser_ident(identifiers[0]),
+        });
     }
 
-    if let Some(inner) = token
-        .strip_prefix('"')
-        .and_then(|rest| rest.strip_suffix('"'))
-    {
-        return Some(Ident::quoted(inner.replace("\"\"", "\"")));
+    Ok(QualifiedName {
+        schema: Some(parse_sqlparser_ident(identifiers[0])),
+        name: parse_sqlparser_ident(identifiers[1]),
+    })
+}
+
+fn parse_sqlparser_ident(ident: &SqlIdent) -> Ident {
+    if ident.quote_style.is_some() {
+        Ident::quoted(ident.value.clone())
+    } else {
+        Ident::unquoted(ident.value.clone())
     }
+}
 
-    if let Some(inner) = token
+pub(crate) fn parse_bracketed_ident(raw: &str) -> Ident {
+    if let Some(inner) = raw
         .strip_prefix('[')
         .and_then(|rest| rest.strip_suffix(']'))
     {
-        return Some(Ident::quoted(inner.replace("]]", "]")));
+        return Ident::quoted(inner.replace("]]", "]"));
     }
-
-    Some(Ident::unquoted(token))
-}
-
-fn trim_identifier_token(raw: &str) -> &str {
-    raw.trim()
-        .trim_start_matches('(')
-        .trim_end_matches(';')
-        .trim_end_matches(',')
-        .trim_end_matches(')')
+    if let Some(inner) = raw
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+    {
+        return Ident::quoted(inner.replace("\"\"", "\""));
+    }
+    Ident::unquoted(raw)
 }
 
 fn statement_kind(statement_sql: &str) -> String {
@@ -491,10 +1376,6 @@ fn statement_kind(statement_sql: &str) -> String {
     }
 }
 
-fn eq_keyword(token: &str, keyword: &str) -> bool {
-    trim_identifier_token(token).eq_ignore_ascii_case(keyword)
-}
-
 fn conversion_error(message: impl Into<String>) -> io::Error {
     io::Error::other(message.into())
 }
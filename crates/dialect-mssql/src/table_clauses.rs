@@ -0,0 +1,805 @@
+//! SQL Server accepts a few `CREATE TABLE` clauses sqlparser rejects or misreads:
+//! `NOT FOR REPLICATION`, `CLUSTERED`/`NONCLUSTERED` after `PRIMARY KEY` and `UNIQUE`,
+//! computed columns (`name AS expr [PERSISTED [NOT NULL]]`), `ON [PRIMARY]` /
+//! `TEXTIMAGE_ON [PRIMARY]` and the temporal table clauses (`GENERATED ALWAYS AS ROW START`,
+//! `PERIOD FOR SYSTEM_TIME` and `WITH (SYSTEM_VERSIONING = ...)`) and sequence defaults
+//! (`DEFAULT (NEXT VALUE FOR name)`). They are lifted out of the statement text (blanked with spaces
+//! so offsets and line numbers stay put) before the statement reaches sqlparser. The same
+//! goes for `CLUSTERED`/`NONCLUSTERED` in `CREATE INDEX`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ElementKind {
+    Column,
+    Constraint,
+    /// Blanked entirely; sqlparser never sees it.
+    Computed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ComputedColumn {
+    pub(crate) name: String,
+    /// Offset of the column name within the statement.
+    pub(crate) name_offset: usize,
+    pub(crate) expr: String,
+    pub(crate) persisted: bool,
+    pub(crate) not_null: bool,
+}
+
+/// One element of the definition list, in source order.
+#[derive(Debug, Clone)]
+pub(crate) struct Element {
+    pub(crate) kind: ElementKind,
+    pub(crate) not_for_replication: bool,
+    /// `Some(true)` for `CLUSTERED`, `Some(false)` for `NONCLUSTERED`.
+    pub(crate) clustered: Option<bool>,
+    pub(crate) computed: Option<ComputedColumn>,
+    /// `ROW START` or `ROW END` of a `GENERATED ALWAYS AS ...` period column.
+    pub(crate) generated_always: Option<&'static str>,
+    pub(crate) hidden: bool,
+    pub(crate) sequence_default: Option<SequenceDefault>,
+}
+
+/// `[CONSTRAINT name] DEFAULT (NEXT VALUE FOR sequence)` on a column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SequenceDefault {
+    /// The constraint name as written.
+    pub(crate) name: Option<String>,
+    /// The default expression as written.
+    pub(crate) expr: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TableClauses {
+    pub(crate) elements: Vec<Element>,
+    /// `CLUSTERED`/`NONCLUSTERED` of a `CREATE INDEX`.
+    pub(crate) index_clustered: Option<bool>,
+    /// The statement was a `CREATE TYPE ... AS TABLE`, handed to sqlparser as a table.
+    pub(crate) table_type: bool,
+    /// Start and end columns of `PERIOD FOR SYSTEM_TIME`, as written.
+    pub(crate) period: Option<(String, String)>,
+    pub(crate) system_versioning: Option<SystemVersioning>,
+}
+
+/// The index of the last token of a default expression that draws from a sequence:
+/// `NEXT VALUE FOR name`, in any number of parentheses. sqlparser cannot read it.
+fn sequence_default_end(fragment: &str, tokens: &[Token]) -> Option<usize> {
+    let opening = tokens
+        .iter()
+        .take_while(|token| token.kind == TokenKind::Punct(b'('))
+        .count();
+    let [next, value, for_, rest @ ..] = &tokens[opening..] else {
+        return None;
+    };
+    if !(next.is_word(fragment, "NEXT")
+        && value.is_word(fragment, "VALUE")
+        && for_.is_word(fragment, "FOR"))
+    {
+        return None;
+    }
+
+    // `name` or `schema.name`, then the closing parentheses.
+    let mut cursor = opening + 3;
+    let is_name = |token: &Token| matches!(token.kind, TokenKind::Word | TokenKind::Quoted);
+    if !rest.first().is_some_and(is_name) {
+        return None;
+    }
+    cursor += 1;
+    while tokens
+        .get(cursor)
+        .is_some_and(|token| token.kind == TokenKind::Punct(b'.'))
+        && tokens.get(cursor + 1).is_some_and(is_name)
+    {
+        cursor += 2;
+    }
+    for _ in 0..opening {
+        if !tokens
+            .get(cursor)
+            .is_some_and(|token| token.kind == TokenKind::Punct(b')'))
+        {
+            return None;
+        }
+        cursor += 1;
+    }
+    Some(cursor - 1)
+}
+
+/// `WITH (SYSTEM_VERSIONING = ON [(HISTORY_TABLE = name)])` or `= OFF`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct SystemVersioning {
+    pub(crate) on: bool,
+    /// The history table name as written.
+    pub(crate) history_table: Option<String>,
+}
+
+impl TableClauses {
+    /// Elements sqlparser reports as columns, in order.
+    pub(crate) fn columns(&self) -> impl Iterator<Item = &Element> {
+        self.elements
+            .iter()
+            .filter(|element| element.kind == ElementKind::Column)
+    }
+
+    /// Elements sqlparser reports as table constraints, in order.
+    pub(crate) fn constraints(&self) -> impl Iterator<Item = &Element> {
+        self.elements
+            .iter()
+            .filter(|element| element.kind == ElementKind::Constraint)
+    }
+}
+
+pub(crate) struct LiftedStatement {
+    pub(crate) sql: String,
+    pub(crate) clauses: TableClauses,
+}
+
+const CONSTRAINT_KEYWORDS: [&str; 6] = [
+    "CONSTRAINT",
+    "PRIMARY",
+    "UNIQUE",
+    "FOREIGN",
+    "CHECK",
+    "INDEX",
+];
+const DEFAULT_FILEGROUP: &str = "PRIMARY";
+
+pub(crate) fn lift_table_clauses(fragment: &str) -> LiftedStatement {
+    let mut lifted = LiftedStatement {
+        sql: fragment.to_string(),
+        clauses: TableClauses::default(),
+    };
+
+    let tokens = scan_tokens(fragment);
+    if let Some(rewritten) = table_type_as_table(fragment, &tokens) {
+        let mut lifted = lift_table_clauses(&rewritten);
+        lifted.clauses.table_type = true;
+        return lifted;
+    }
+    if let Some((keyword, clustered)) = index_clustered_keyword(fragment, &tokens) {
+        lifted.sql.replace_range(
+            keyword.start..keyword.end,
+            &blank_out(keyword.text(fragment)),
+        );
+        lifted.clauses.index_clustered = Some(clustered);
+        return lifted;
+    }
+    let Some(open) = definition_list_start(fragment, &tokens) else {
+        return lifted;
+    };
+
+    // Token ranges of each element, separators excluded.
+    let mut ranges = Vec::new();
+    let mut depth = 0usize;
+    let mut element_start = open + 1;
+    let mut close = None;
+    for (cursor, token) in tokens.iter().enumerate().skip(open) {
+        match token.kind {
+            TokenKind::Punct(b'(') => depth += 1,
+            TokenKind::Punct(b')') => {
+                depth -= 1;
+                if depth == 0 {
+                    ranges.push((element_start, cursor));
+                    close = Some(cursor);
+                    break;
+                }
+            }
+            TokenKind::Punct(b',') if depth == 1 => {
+                ranges.push((element_start, cursor));
+                element_start = cursor + 1;
+            }
+            _ => {}
+        }
+    }
+    let Some(close) = close else {
+        return lifted;
+    };
+
+    let mut blanked = Vec::new();
+    for (position, &(start, end)) in ranges.iter().enumerate() {
+        let element_tokens = &tokens[start..end];
+        let Some(first) = element_tokens.first() else {
+            continue;
+        };
+        if let Some(period) = system_time_period(fragment, element_tokens) {
+            blanked.push(element_span(&tokens, start, end, position));
+            lifted.clauses.period = Some(period);
+            continue;
+        }
+        let kind = if CONSTRAINT_KEYWORDS
+            .iter()
+            .any(|keyword| first.is_word(fragment, keyword))
+        {
+            ElementKind::Constraint
+        } else if element_tokens
+            .get(1)
+            .is_some_and(|token| token.is_word(fragment, "AS"))
+        {
+            ElementKind::Computed
+        } else {
+            ElementKind::Column
+        };
+        let mut element = Element {
+            kind,
+            not_for_replication: false,
+            clustered: None,
+            computed: None,
+            generated_always: None,
+            hidden: false,
+            sequence_default: None,
+        };
+
+        if kind == ElementKind::Computed {
+            if let Some(computed) = computed_column(fragment, element_tokens) {
+                blanked.push(element_span(&tokens, start, end, position));
+                element.computed = Some(computed);
+            } else {
+                element.kind = ElementKind::Column;
+            }
+            lifted.clauses.elements.push(element);
+            continue;
+        }
+
+        let mut cursor = start;
+        let mut depth = 0usize;
+        while cursor < end {
+            let token = &tokens[cursor];
+            match token.kind {
+                TokenKind::Punct(b'(') => depth += 1,
+                TokenKind::Punct(b')') => depth = depth.saturating_sub(1),
+                TokenKind::Word if depth == 0 => {
+                    if token.is_word(fragment, "NOT")
+                        && tokens[cursor + 1..end]
+                            .first()
+                            .is_some_and(|next| next.is_word(fragment, "FOR"))
+                        && tokens[cursor + 1..end]
+                            .get(1)
+                            .is_some_and(|next| next.is_word(fragment, "REPLICATION"))
+                    {
+                        element.not_for_replication = true;
+                        blanked.push((token.start, tokens[cursor + 2].end));
+                        cursor += 3;
+                        continue;
+                    }
+                    if token.is_word(fragment, "DEFAULT")
+                        && let Some(last) = sequence_default_end(fragment, &tokens[cursor + 1..end])
+                    {
+                        let expr_end = tokens[cursor + 1 + last].end;
+                        let named = cursor >= start + 2
+                            && tokens[cursor - 2].is_word(fragment, "CONSTRAINT");
+                        let clause_start = if named {
+                            tokens[cursor - 2].start
+                        } else {
+                            token.start
+                        };
+                        element.sequence_default = Some(SequenceDefault {
+                            name: named.then(|| tokens[cursor - 1].text(fragment).to_string()),
+                            expr: fragment[tokens[cursor + 1].start..expr_end].to_string(),
+                        });
+                        blanked.push((clause_start, expr_end));
+                        cursor += last + 2;
+                        continue;
+                    }
+                    if let Some((generated_always, hidden, last)) =
+                        generated_always_row(fragment, &tokens[cursor..end])
+                    {
+                        element.generated_always = Some(generated_always);
+                        element.hidden = hidden;
+                        blanked.push((token.start, tokens[cursor + last].end));
+                        cursor += last + 1;
+                        continue;
+                    }
+                    let follows_key = cursor > start
+                        && (tokens[cursor - 1].is_word(fragment, "KEY")
+                            || tokens[cursor - 1].is_word(fragment, "UNIQUE"));
+                    if follows_key {
+                        if token.is_word(fragment, "CLUSTERED") {
+                            element.clustered = Some(true);
+                            blanked.push((token.start, token.end));
+                        } else if token.is_word(fragment, "NONCLUSTERED") {
+                            element.clustered = Some(false);
+                            blanked.push((token.start, token.end));
+                        }
+                    }
+                }
+                _ => {}
+            }
+            cursor += 1;
+        }
+        lifted.clauses.elements.push(element);
+    }
+
+    blanked.extend(trailing_table_clauses(
+        fragment,
+        &tokens[close + 1..],
+        &mut lifted.clauses,
+    ));
+
+    for (start, end) in blanked {
+        let blank = blank_out(&lifted.sql[start..end]);
+        lifted.sql.replace_range(start..end, &blank);
+    }
+    lifted
+}
+
+/// Spaces of the same byte length as `text`, keeping its newlines.
+/// `CREATE TYPE name AS TABLE (` spelled as `CREATE TABLE name (`, padded to the same length
+/// so offsets after the name and line numbers stay put.
+fn table_type_as_table(fragment: &str, tokens: &[Token]) -> Option<String> {
+    if !(tokens.first()?.is_word(fragment, "CREATE") && tokens.get(1)?.is_word(fragment, "TYPE")) {
+        return None;
+    }
+    let as_position = tokens
+        .iter()
+        .position(|token| token.is_word(fragment, "AS"))?;
+    let table = tokens.get(as_position + 1)?;
+    if !table.is_word(fragment, "TABLE") {
+        return None;
+    }
+
+    let as_start = tokens[as_position].start;
+    let mut padding = blank_out(&fragment[as_start..table.end]);
+    padding.remove(0);
+    let mut sql = String::with_capacity(fragment.len());
+    sql.push_str(&fragment[..tokens[1].start]);
+    sql.push_str("TABLE");
+    sql.push_str(&fragment[tokens[1].end..as_start]);
+    sql.push_str(&padding);
+    sql.push_str(&fragment[table.end..]);
+    Some(sql)
+}
+
+pub(crate) fn blank_out(text: &str) -> String {
+    text.chars()
+        .map(|ch| {
+            if ch == '\n' {
+                "\n".to_string()
+            } else {
+                " ".repeat(ch.len_utf8())
+            }
+        })
+        .collect()
+}
+
+fn computed_column(fragment: &str, tokens: &[Token]) -> Option<ComputedColumn> {
+    let [name, _as, expr_start, ..] = tokens else {
+        return None;
+    };
+    let persisted = tokens
+        .iter()
+        .rposition(|token| token.is_word(fragment, "PERSISTED"));
+    let (expr_end, not_null) = match persisted {
+        Some(persisted) => {
+            let rest = &tokens[persisted + 1..];
+            let not_null = match rest {
+                [] => false,
+                [not, null] if not.is_word(fragment, "NOT") && null.is_word(fragment, "NULL") => {
+                    true
+                }
+                _ => return None,
+            };
+            (persisted, not_null)
+        }
+        None => (tokens.len(), false),
+    };
+    if expr_end <= 2 {
+        return None;
+    }
+
+    Some(ComputedColumn {
+        name: name.text(fragment).to_string(),
+        name_offset: name.start,
+        expr: fragment[expr_start.start..tokens[expr_end - 1].end].to_string(),
+        persisted: persisted.is_some(),
+        not_null,
+    })
+}
+
+/// `ON [PRIMARY]` and `TEXTIMAGE_ON [PRIMARY]` after the definition list only restate
+/// the default filegroup; any other trailing clause is left for sqlparser to reject.
+/// `ON [PRIMARY]`, `TEXTIMAGE_ON [PRIMARY]` and `WITH (SYSTEM_VERSIONING = ...)` after the
+/// definition list. Anything else leaves the trailing clauses to sqlparser.
+fn trailing_table_clauses(
+    fragment: &str,
+    trailing: &[Token],
+    clauses: &mut TableClauses,
+) -> Vec<(usize, usize)> {
+    let mut blanked = Vec::new();
+    let mut system_versioning = None;
+    let mut cursor = 0usize;
+    while cursor < trailing.len() {
+        let token = &trailing[cursor];
+        let is_filegroup_clause =
+            token.is_word(fragment, "ON") || token.is_word(fragment, "TEXTIMAGE_ON");
+        if let Some((versioning, last)) = system_versioning_clause(fragment, &trailing[cursor..]) {
+            blanked.push((token.start, trailing[cursor + last].end));
+            system_versioning = Some(versioning);
+            cursor += last + 1;
+            continue;
+        }
+        match trailing.get(cursor + 1) {
+            Some(filegroup)
+                if is_filegroup_clause
+                    && unquote(filegroup.text(fragment))
+                        .eq_ignore_ascii_case(DEFAULT_FILEGROUP) =>
+            {
+                blanked.push((token.start, filegroup.end));
+                cursor += 2;
+            }
+            _ if token.kind == TokenKind::Punct(b';') => cursor += 1,
+            _ => return Vec::new(),
+        }
+    }
+    clauses.system_versioning = system_versioning;
+    blanked
+}
+
+/// The source range of a definition list element together with one separator, so the list
+/// stays well formed once it is blanked.
+fn element_span(tokens: &[Token], start: usize, end: usize, position: usize) -> (usize, usize) {
+    if position > 0 {
+        (tokens[start - 1].start, tokens[end - 1].end)
+    } else {
+        (tokens[start].start, tokens[end].start)
+    }
+}
+
+/// `PERIOD FOR SYSTEM_TIME (start, end)`.
+fn system_time_period(fragment: &str, element: &[Token]) -> Option<(String, String)> {
+    let [period, for_, system_time, open, start, comma, end, close] = element else {
+        return None;
+    };
+    let shape = period.is_word(fragment, "PERIOD")
+        && for_.is_word(fragment, "FOR")
+        && system_time.is_word(fragment, "SYSTEM_TIME")
+        && open.kind == TokenKind::Punct(b'(')
+        && comma.kind == TokenKind::Punct(b',')
+        && close.kind == TokenKind::Punct(b')');
+    shape.then(|| {
+        (
+            start.text(fragment).to_string(),
+            end.text(fragment).to_string(),
+        )
+    })
+}
+
+/// `GENERATED ALWAYS AS ROW START|END [HIDDEN]`: the row keyword, whether the column is
+/// hidden and the index of the clause's last token.
+fn generated_always_row(fragment: &str, tokens: &[Token]) -> Option<(&'static str, bool, usize)> {
+    let [generated, always, as_, row, boundary, rest @ ..] = tokens else {
+        return None;
+    };
+    if !(generated.is_word(fragment, "GENERATED")
+        && always.is_word(fragment, "ALWAYS")
+        && as_.is_word(fragment, "AS")
+        && row.is_word(fragment, "ROW"))
+    {
+        return None;
+    }
+    let generated_always = if boundary.is_word(fragment, "START") {
+        "ROW START"
+    } else if boundary.is_word(fragment, "END") {
+        "ROW END"
+    } else {
+        return None;
+    };
+    let hidden = rest
+        .first()
+        .is_some_and(|token| token.is_word(fragment, "HIDDEN"));
+    Some((generated_always, hidden, if hidden { 5 } else { 4 }))
+}
+
+/// `WITH (SYSTEM_VERSIONING = ON [(HISTORY_TABLE = name [, DATA_CONSISTENCY_CHECK = ON|OFF])])`
+/// or `WITH (SYSTEM_VERSIONING = OFF)`, and the index of its closing parenthesis. Other table
+/// options are not recognized.
+fn system_versioning_clause(fragment: &str, tokens: &[Token]) -> Option<(SystemVersioning, usize)> {
+    let punct = |index: usize, byte: u8| {
+        tokens
+            .get(index)
+            .is_some_and(|token| token.kind == TokenKind::Punct(byte))
+    };
+    let word = |index: usize, keyword: &str| {
+        tokens
+            .get(index)
+            .is_some_and(|token| token.is_word(fragment, keyword))
+    };
+    if !(word(0, "WITH") && punct(1, b'(') && word(2, "SYSTEM_VERSIONING") && punct(3, b'=')) {
+        return None;
+    }
+    let mut versioning = SystemVersioning::default();
+    let mut cursor = if word(4, "ON") {
+        versioning.on = true;
+        5
+    } else if word(4, "OFF") {
+        5
+    } else {
+        return None;
+    };
+
+    if versioning.on && punct(cursor, b'(') {
+        cursor += 1;
+        loop {
+            if word(cursor, "HISTORY_TABLE") && punct(cursor + 1, b'=') {
+                cursor += 2;
+                let first = tokens.get(cursor)?;
+                let mut last = first;
+                cursor += 1;
+                while punct(cursor, b'.') {
+                    last = tokens.get(cursor + 1)?;
+                    cursor += 2;
+                }
+                versioning.history_table = Some(fragment[first.start..last.end].to_string());
+            } else if word(cursor, "DATA_CONSISTENCY_CHECK")
+                && punct(cursor + 1, b'=')
+                && (word(cursor + 2, "ON") || word(cursor + 2, "OFF"))
+            {
+                cursor += 3;
+            } else {
+                return None;
+            }
+            if punct(cursor, b',') {
+                cursor += 1;
+                continue;
+            }
+            if !punct(cursor, b')') {
+                return None;
+            }
+            cursor += 1;
+            break;
+        }
+    }
+    punct(cursor, b')').then_some((versioning, cursor))
+}
+
+/// Index of the `(` opening the definition list of a `CREATE TABLE`, or `None` for any
+/// other statement.
+fn definition_list_start(fragment: &str, tokens: &[Token]) -> Option<usize> {
+    let [create, table, ..] = tokens else {
+        return None;
+    };
+    if !(create.is_word(fragment, "CREATE") && table.is_word(fragment, "TABLE")) {
+        return None;
+    }
+    tokens
+        .iter()
+        .position(|token| token.kind == TokenKind::Punct(b'('))
+}
+
+/// `CREATE [CLUSTERED | NONCLUSTERED] COLUMNSTORE INDEX`, which sqlparser cannot read: a
+/// clustered columnstore index has no column list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ColumnstoreIndex {
+    pub(crate) clustered: bool,
+    pub(crate) name: String,
+    /// `[schema.]table`, one entry per part.
+    pub(crate) table: Vec<String>,
+    pub(crate) columns: Vec<String>,
+    pub(crate) predicate: Option<String>,
+}
+
+pub(crate) fn is_columnstore_index(fragment: &str) -> bool {
+    let tokens = scan_tokens(fragment);
+    columnstore_header_len(fragment, &tokens).is_some()
+}
+
+/// The pieces of a columnstore index, or `None` when the statement has clauses beyond
+/// the column list and the filter.
+pub(crate) fn columnstore_index(fragment: &str) -> Option<ColumnstoreIndex> {
+    let tokens = scan_tokens(fragment);
+    let header_len = columnstore_header_len(fragment, &tokens)?;
+    let clustered = tokens[1].is_word(fragment, "CLUSTERED");
+    let mut rest = tokens[header_len..]
+        .iter()
+        .take_while(|token| token.kind != TokenKind::Punct(b';'));
+
+    let name = rest.next()?.text(fragment).to_string();
+    if !rest.next()?.is_word(fragment, "ON") {
+        return None;
+    }
+    let mut table = vec![rest.next()?.text(fragment).to_string()];
+    let mut columns = Vec::new();
+    let mut predicate = None;
+    while let Some(token) = rest.next() {
+        match token.kind {
+            TokenKind::Punct(b'.') if columns.is_empty() && table.len() == 1 => {
+                table.push(rest.next()?.text(fragment).to_string());
+            }
+            TokenKind::Punct(b'(') if columns.is_empty() => loop {
+                columns.push(rest.next()?.text(fragment).to_string());
+                match rest.next()?.kind {
+                    TokenKind::Punct(b',') => {}
+                    TokenKind::Punct(b')') => break,
+                    _ => return None,
+                }
+            },
+            _ if token.is_word(fragment, "WHERE") => {
+                let end = rest.by_ref().last()?.end;
+                predicate = Some(fragment[token.end..end].trim().to_string());
+            }
+            _ => return None,
+        }
+    }
+
+    Some(ColumnstoreIndex {
+        clustered,
+        name,
+        table,
+        columns,
+        predicate,
+    })
+}
+
+fn columnstore_header_len(fragment: &str, tokens: &[Token]) -> Option<usize> {
+    let [create, second, ..] = tokens else {
+        return None;
+    };
+    if !create.is_word(fragment, "CREATE") {
+        return None;
+    }
+    let columnstore =
+        if second.is_word(fragment, "CLUSTERED") || second.is_word(fragment, "NONCLUSTERED") {
+            2
+        } else {
+            1
+        };
+    let is_columnstore = tokens
+        .get(columnstore)
+        .is_some_and(|token| token.is_word(fragment, "COLUMNSTORE"))
+        && tokens
+            .get(columnstore + 1)
+            .is_some_and(|token| token.is_word(fragment, "INDEX"));
+    is_columnstore.then_some(columnstore + 2)
+}
+
+/// The `CLUSTERED`/`NONCLUSTERED` token of `CREATE [UNIQUE] [NON]CLUSTERED INDEX`.
+fn index_clustered_keyword(fragment: &str, tokens: &[Token]) -> Option<(Token, bool)> {
+    let [create, rest @ ..] = tokens else {
+        return None;
+    };
+    if !create.is_word(fragment, "CREATE") {
+        return None;
+    }
+    let rest = match rest {
+        [unique, rest @ ..] if unique.is_word(fragment, "UNIQUE") => rest,
+        _ => rest,
+    };
+    let [keyword, index, ..] = rest else {
+        return None;
+    };
+    if !index.is_word(fragment, "INDEX") {
+        return None;
+    }
+    [true, false]
+        .into_iter()
+        .find(|clustered| {
+            keyword.is_word(
+                fragment,
+                if *clustered {
+                    "CLUSTERED"
+                } else {
+                    "NONCLUSTERED"
+                },
+            )
+        })
+        .map(|clustered| (*keyword, clustered))
+}
+
+pub(crate) fn unquote(text: &str) -> &str {
+    text.strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .or_else(|| {
+            text.strip_prefix('"')
+                .and_then(|rest| rest.strip_suffix('"'))
+        })
+        .unwrap_or(text)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenKind {
+    Word,
+    Quoted,
+    Punct(u8),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Token {
+    pub(crate) kind: TokenKind,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+impl Token {
+    pub(crate) fn text<'a>(&self, fragment: &'a str) -> &'a str {
+        &fragment[self.start..self.end]
+    }
+
+    pub(crate) fn is_word(&self, fragment: &str, keyword: &str) -> bool {
+        self.kind == TokenKind::Word && self.text(fragment).eq_ignore_ascii_case(keyword)
+    }
+}
+
+pub(crate) fn scan_tokens(fragment: &str) -> Vec<Token> {
+    let bytes = fragment.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0usize;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+
+        if byte.is_ascii_whitespace() {
+            index += 1;
+            continue;
+        }
+
+        if byte == b'-' && bytes.get(index + 1) == Some(&b'-') {
+            while index < bytes.len() && bytes[index] != b'\n' {
+                index += 1;
+            }
+            continue;
+        }
+
+        if byte == b'/' && bytes.get(index + 1) == Some(&b'*') {
+            index += 2;
+            while index < bytes.len()
+                && !(bytes[index] == b'*' && bytes.get(index + 1) == Some(&b'/'))
+            {
+                index += 1;
+            }
+            index = (index + 2).min(bytes.len());
+            continue;
+        }
+
+        let closing = match byte {
+            b'\'' => Some(b'\''),
+            b'"' => Some(b'"'),
+            b'[' => Some(b']'),
+            _ => None,
+        };
+        if let Some(closing) = closing {
+            let start = index;
+            index += 1;
+            while index < bytes.len() {
+                if bytes[index] == closing {
+                    if bytes.get(index + 1) == Some(&closing) {
+                        index += 2;
+                        continue;
+                    }
+                    break;
+                }
+                index += 1;
+            }
+            index = (index + 1).min(bytes.len());
+            tokens.push(Token {
+                kind: TokenKind::Quoted,
+                start,
+                end: index,
+            });
+            continue;
+        }
+
+        if is_word_byte(byte) {
+            let start = index;
+            while index < bytes.len() && is_word_byte(bytes[index]) {
+                index += 1;
+            }
+            tokens.push(Token {
+                kind: TokenKind::Word,
+                start,
+                end: index,
+            });
+            continue;
+        }
+
+        tokens.push(Token {
+            kind: TokenKind::Punct(byte),
+            start: index,
+            end: index + 1,
+        });
+        index += 1;
+    }
+
+    tokens
+}
+
+/// Non-ASCII bytes count as word bytes so multi-byte characters are never split.
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'@' || byte == b'#' || !byte.is_ascii()
+}
@@ -1,18 +1,27 @@
 use std::fmt::Write as _;
 
 use stateql_core::{
-    BinaryOperator, CheckConstraint, ComparisonOp, DataType, Expr, ForeignKey, ForeignKeyAction,
-    Function, FunctionParamMode, GenerateError, Ident, IndexDef, IndexOwner, IsTest, Literal,
-    SchemaDef, SchemaObject, Trigger, TriggerEvent, TriggerTiming, UnaryOperator, Value,
+    BinaryOperator, CheckConstraint, Column, Comment, CommentTarget, ComparisonOp, DataType, Expr,
+    ForeignKey, ForeignKeyAction, Function, FunctionParamMode, GenerateError, GeneratedColumn,
+    Ident, IndexDef, IndexOwner, IsTest, Literal, PrimaryKey, QualifiedName, SchemaDef,
+    SchemaObject, Sequence, Synonym, Trigger, TriggerEvent, TriggerTiming, TypeDef, TypeKind,
+    UnaryOperator, Value,
 };
 
-use crate::extra_keys;
+use crate::{extra_keys, table_clauses::unquote};
 
 const TO_SQL_TARGET: &str = "dialect export renderer";
 const UNSUPPORTED_TABLE_VARIANT: &str = "CreateTableUnsupportedShape";
+const UNSUPPORTED_SEQUENCE_VARIANT: &str = "CreateSequenceUnsupportedShape";
 const UNSUPPORTED_INDEX_VARIANT: &str = "CreateIndexUnsupportedShape";
 const UNSUPPORTED_TRIGGER_VARIANT: &str = "CreateTriggerUnsupportedShape";
 const UNSUPPORTED_FUNCTION_VARIANT: &str = "CreateFunctionUnsupportedShape";
+const UNSUPPORTED_COMMENT_VARIANT: &str = "SetCommentUnsupportedShape";
+/// Schema of a comment target named without one.
+const DEFAULT_SCHEMA: &str = "dbo";
+
+/// The extended property SQL Server tools show as an object's description.
+pub(crate) const DESCRIPTION_PROPERTY: &str = "MS_Description";
 
 pub(crate) fn render_object(
     dialect_name: &str,
@@ -28,29 +37,21 @@ pub(crate) fn render_object(
         SchemaObject::MaterializedView(_) => {
             unsupported_variant_error(dialect_name, "MaterializedView")
         }
-        SchemaObject::Sequence(_) => unsupported_variant_error(dialect_name, "Sequence"),
-        SchemaObject::Type(_) => unsupported_variant_error(dialect_name, "Type"),
+        SchemaObject::Sequence(sequence) => render_sequence(dialect_name, sequence),
+        SchemaObject::Synonym(synonym) => render_synonym(synonym),
+        SchemaObject::Event(_) => unsupported_variant_error(dialect_name, "Event"),
+        SchemaObject::Type(type_def) => render_type(dialect_name, type_def),
         SchemaObject::Domain(_) => unsupported_variant_error(dialect_name, "Domain"),
         SchemaObject::Extension(_) => unsupported_variant_error(dialect_name, "Extension"),
-        SchemaObject::Comment(_) => unsupported_variant_error(dialect_name, "Comment"),
+        SchemaObject::Comment(comment) => render_comment(dialect_name, comment),
         SchemaObject::Privilege(_) => unsupported_variant_error(dialect_name, "Privilege"),
         SchemaObject::Policy(_) => unsupported_variant_error(dialect_name, "Policy"),
+        SchemaObject::Pragma(_) => unsupported_variant_error(dialect_name, "Pragma"),
     }
 }
 
 fn render_table(dialect_name: &str, table: &stateql_core::Table) -> stateql_core::Result<String> {
-    if table.columns.is_empty()
-        && table.primary_key.is_none()
-        && table.foreign_keys.is_empty()
-        && table.checks.is_empty()
-    {
-        if let Some(source_hint) = source_table_sql_hint(table) {
-            return Ok(ensure_sql_terminated(source_hint));
-        }
-        return unsupported_shape_error(dialect_name, UNSUPPORTED_TABLE_VARIANT);
-    }
-
-    if !table.exclusions.is_empty() || table.partition.is_some() {
+    if table.columns.is_empty() || !table.exclusions.is_empty() || table.partition.is_some() {
         return unsupported_shape_error(dialect_name, UNSUPPORTED_TABLE_VARIANT);
     }
 
@@ -64,33 +65,15 @@ fn render_table(dialect_name: &str, table: &stateql_core::Table) -> stateql_core
     }
 
     if let Some(primary_key) = &table.primary_key {
-        let mut pk_sql = String::new();
-        if let Some(name) = &primary_key.name {
-            write!(pk_sql, "CONSTRAINT {} ", render_ident(name))
-                .expect("writing to String should not fail");
-        }
-        pk_sql.push_str("PRIMARY KEY");
-
-        if let Some(Value::Bool(clustered)) = table
+        let clustered = match table
             .options
             .extra
             .get(extra_keys::TABLE_PRIMARY_KEY_CLUSTERED)
         {
-            if *clustered {
-                pk_sql.push_str(" CLUSTERED");
-            } else {
-                pk_sql.push_str(" NONCLUSTERED");
-            }
-        }
-
-        let columns = primary_key
-            .columns
-            .iter()
-            .map(render_ident)
-            .collect::<Vec<_>>()
-            .join(", ");
-        write!(pk_sql, " ({columns})").expect("writing to String should not fail");
-        definitions.push(pk_sql);
+            Some(Value::Bool(clustered)) => Some(*clustered),
+            _ => None,
+        };
+        definitions.push(render_primary_key(primary_key, clustered));
     }
 
     for foreign_key in &table.foreign_keys {
@@ -101,25 +84,101 @@ fn render_table(dialect_name: &str, table: &stateql_core::Table) -> stateql_core
         definitions.push(render_check(check));
     }
 
+    let extra = &table.options.extra;
+    if let (Some(Value::String(start)), Some(Value::String(end))) = (
+        extra.get(stateql_core::extra_keys::mssql::TABLE_PERIOD_START),
+        extra.get(stateql_core::extra_keys::mssql::TABLE_PERIOD_END),
+    ) {
+        definitions.push(format!(
+            "PERIOD FOR SYSTEM_TIME ({}, {})",
+            render_ident(&Ident::unquoted(start)),
+            render_ident(&Ident::unquoted(end))
+        ));
+    }
+
     sql.push_str(&definitions.join(", "));
     sql.push(')');
+    if let Some(Value::Bool(true)) =
+        extra.get(stateql_core::extra_keys::mssql::TABLE_SYSTEM_VERSIONING)
+    {
+        write!(
+            sql,
+            " WITH ({})",
+            render_system_versioning_on(
+                extra.get(stateql_core::extra_keys::mssql::TABLE_HISTORY_TABLE)
+            )
+        )
+        .expect("writing to String should not fail");
+    }
     sql.push(';');
     Ok(sql)
 }
 
-fn source_table_sql_hint(table: &stateql_core::Table) -> Option<&str> {
-    match table.options.extra.get(extra_keys::TABLE_SOURCE_SQL) {
-        Some(Value::String(value)) if !value.trim().is_empty() => Some(value.as_str()),
-        _ => None,
+/// `SYSTEM_VERSIONING = ON`, naming the history table when there is one.
+pub(crate) fn render_system_versioning_on(history_table: Option<&Value>) -> String {
+    match history_table {
+        Some(Value::String(history_table)) => format!(
+            "SYSTEM_VERSIONING = ON (HISTORY_TABLE = {})",
+            render_history_table(history_table)
+        ),
+        _ => "SYSTEM_VERSIONING = ON".to_string(),
+    }
+}
+
+/// A `schema.name` history table option, bracketed part by part.
+pub(crate) fn render_history_table(history_table: &str) -> String {
+    history_table
+        .split('.')
+        .map(|part| render_ident(&Ident::unquoted(unquote(part.trim()))))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// ` GENERATED ALWAYS AS ROW START|END [HIDDEN]` for a period column, otherwise empty.
+pub(crate) fn render_generated_always(column: &Column) -> String {
+    let Some(Value::String(boundary)) = column.extra.get(extra_keys::COLUMN_GENERATED_ALWAYS)
+    else {
+        return String::new();
+    };
+    let mut sql = format!(" GENERATED ALWAYS AS {boundary}");
+    if let Some(Value::Bool(true)) = column.extra.get(extra_keys::COLUMN_HIDDEN) {
+        sql.push_str(" HIDDEN");
+    }
+    sql
+}
+
+fn render_primary_key(primary_key: &PrimaryKey, clustered: Option<bool>) -> String {
+    let mut sql = String::new();
+    if let Some(name) = &primary_key.name {
+        write!(sql, "CONSTRAINT {} ", render_ident(name))
+            .expect("writing to String should not fail");
+    }
+    sql.push_str("PRIMARY KEY");
+    match clustered {
+        Some(true) => sql.push_str(" CLUSTERED"),
+        Some(false) => sql.push_str(" NONCLUSTERED"),
+        None => {}
     }
+
+    let columns = primary_key
+        .columns
+        .iter()
+        .map(render_ident)
+        .collect::<Vec<_>>()
+        .join(", ");
+    write!(sql, " ({columns})").expect("writing to String should not fail");
+    sql
 }
 
 fn render_column(
     dialect_name: &str,
     column: &stateql_core::Column,
 ) -> stateql_core::Result<String> {
-    if column.generated.is_some() {
-        return unsupported_shape_error(dialect_name, UNSUPPORTED_TABLE_VARIANT);
+    if let Some(generated) = &column.generated {
+        if column.default.is_some() || column.identity.is_some() {
+            return unsupported_shape_error(dialect_name, UNSUPPORTED_TABLE_VARIANT);
+        }
+        return Ok(render_computed_column(column, generated));
     }
 
     let mut sql = format!(
@@ -160,6 +219,7 @@ fn render_column(
         }
     }
 
+    sql.push_str(&render_generated_always(column));
     if column.not_null {
         sql.push_str(" NOT NULL");
     }
@@ -170,6 +230,22 @@ fn render_column(
     Ok(sql)
 }
 
+/// A computed column has no declared type; `NOT NULL` is only allowed when it is persisted.
+fn render_computed_column(column: &Column, generated: &GeneratedColumn) -> String {
+    let mut sql = format!(
+        "{} AS {}",
+        render_ident(&column.name),
+        render_expr(&generated.expr)
+    );
+    if generated.stored {
+        sql.push_str(" PERSISTED");
+        if column.not_null {
+            sql.push_str(" NOT NULL");
+        }
+    }
+    sql
+}
+
 fn render_foreign_key(foreign_key: &ForeignKey) -> String {
     let mut sql = String::new();
     if let Some(name) = &foreign_key.name {
@@ -203,10 +279,22 @@ fn render_foreign_key(foreign_key: &ForeignKey) -> String {
         write!(sql, " ON UPDATE {}", render_fk_action(on_update))
             .expect("writing to String should not fail");
     }
+    if is_not_for_replication(foreign_key) {
+        sql.push_str(" NOT FOR REPLICATION");
+    }
 
     sql
 }
 
+fn is_not_for_replication(foreign_key: &ForeignKey) -> bool {
+    matches!(
+        foreign_key
+            .extra
+            .get(extra_keys::FOREIGN_KEY_NOT_FOR_REPLICATION),
+        Some(Value::Bool(true))
+    )
+}
+
 fn render_check(check: &CheckConstraint) -> String {
     if let Some(name) = &check.name {
         format!(
@@ -245,7 +333,12 @@ fn render_index(dialect_name: &str, index: &IndexDef) -> stateql_core::Result<St
         return unsupported_shape_error(dialect_name, UNSUPPORTED_INDEX_VARIANT);
     };
 
-    if index.columns.is_empty() {
+    let columnstore = matches!(
+        index.extra.get(extra_keys::INDEX_COLUMNSTORE),
+        Some(Value::Bool(true))
+    );
+    // Only a clustered columnstore index, which covers the whole table, has no column list.
+    if index.columns.is_empty() && !columnstore {
         return unsupported_shape_error(dialect_name, UNSUPPORTED_INDEX_VARIANT);
     }
 
@@ -255,6 +348,10 @@ fn render_index(dialect_name: &str, index: &IndexDef) -> stateql_core::Result<St
         | IndexOwner::MaterializedView(owner) => owner,
     };
 
+    if is_unique_constraint(index) {
+        return render_unique_constraint(dialect_name, owner, name, index);
+    }
+
     let mut sql = String::from("CREATE ");
     if index.unique {
         sql.push_str("UNIQUE ");
@@ -267,19 +364,39 @@ fn render_index(dialect_name: &str, index: &IndexDef) -> stateql_core::Result<St
         }
     }
 
+    if columnstore {
+        sql.push_str("COLUMNSTORE ");
+    }
+
     write!(
         sql,
-        "INDEX {} ON {} ({})",
+        "INDEX {} ON {}",
         render_ident(name),
         render_qualified_name(owner),
-        index
-            .columns
-            .iter()
-            .map(|column| render_expr(&column.expr))
-            .collect::<Vec<_>>()
-            .join(", "),
     )
     .expect("writing to String should not fail");
+    if !index.columns.is_empty() {
+        write!(
+            sql,
+            " ({})",
+            index
+                .columns
+                .iter()
+                .map(|column| render_expr(&column.expr))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+        .expect("writing to String should not fail");
+    }
+
+    if let Some(Value::String(include)) = index.extra.get(extra_keys::INDEX_INCLUDE_COLUMNS) {
+        let include = include
+            .split(',')
+            .map(|column| render_ident(&Ident::quoted(column.trim())))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(sql, " INCLUDE ({include})").expect("writing to String should not fail");
+    }
 
     if let Some(where_clause) = &index.where_clause {
         write!(sql, " WHERE {}", render_expr(where_clause))
@@ -290,8 +407,53 @@ fn render_index(dialect_name: &str, index: &IndexDef) -> stateql_core::Result<St
     Ok(sql)
 }
 
+pub(crate) fn is_unique_constraint(index: &IndexDef) -> bool {
+    matches!(
+        index.extra.get(extra_keys::INDEX_UNIQUE_CONSTRAINT),
+        Some(Value::Bool(true))
+    )
+}
+
+fn render_unique_constraint(
+    dialect_name: &str,
+    owner: &QualifiedName,
+    name: &Ident,
+    index: &IndexDef,
+) -> stateql_core::Result<String> {
+    if !index.unique || index.where_clause.is_some() {
+        return unsupported_shape_error(dialect_name, UNSUPPORTED_INDEX_VARIANT);
+    }
+
+    let mut sql = format!(
+        "ALTER TABLE {} ADD CONSTRAINT {} UNIQUE",
+        render_qualified_name(owner),
+        render_ident(name)
+    );
+    if let Some(method) = &index.method {
+        let upper = method.trim().to_ascii_uppercase();
+        if upper == "CLUSTERED" || upper == "NONCLUSTERED" {
+            write!(sql, " {upper}").expect("writing to String should not fail");
+        }
+    }
+    write!(
+        sql,
+        " ({});",
+        index
+            .columns
+            .iter()
+            .map(|column| render_expr(&column.expr))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+    .expect("writing to String should not fail");
+    Ok(sql)
+}
+
 fn render_trigger(dialect_name: &str, trigger: &Trigger) -> stateql_core::Result<String> {
-    if trigger.events.is_empty() || trigger.body.trim().is_empty() {
+    if trigger.events.is_empty()
+        || trigger.body.trim().is_empty()
+        || !trigger.update_columns.is_empty()
+    {
         return unsupported_shape_error(dialect_name, UNSUPPORTED_TRIGGER_VARIANT);
     }
 
@@ -308,22 +470,14 @@ fn render_trigger(dialect_name: &str, trigger: &Trigger) -> stateql_core::Result
             .join(", "),
     );
 
-    let body = trigger.body.trim().trim_end_matches(';').trim();
-    if body.to_ascii_uppercase().starts_with("BEGIN") {
-        sql.push_str(body);
-    } else {
-        write!(sql, "BEGIN {body}; END").expect("writing to String should not fail");
-    }
+    // The body of a trigger runs to the end of its batch, so it is kept as written.
+    sql.push_str(trigger.body.trim().trim_end_matches(';').trim());
     sql.push(';');
 
     Ok(sql)
 }
 
 fn render_function(dialect_name: &str, function: &Function) -> stateql_core::Result<String> {
-    let Some(return_type) = &function.return_type else {
-        return unsupported_shape_error(dialect_name, UNSUPPORTED_FUNCTION_VARIANT);
-    };
-
     let body = function.body.trim().trim_end_matches(';').trim();
     if body.is_empty() {
         return unsupported_shape_error(dialect_name, UNSUPPORTED_FUNCTION_VARIANT);
@@ -336,13 +490,25 @@ fn render_function(dialect_name: &str, function: &Function) -> stateql_core::Res
         .collect::<Vec<_>>()
         .join(", ");
 
+    // A function without a return type is a procedure.
+    let Some(return_type) = &function.return_type else {
+        let mut sql = format!("CREATE PROCEDURE {}", render_qualified_name(&function.name));
+        if !params.is_empty() {
+            write!(sql, " {params}").expect("writing to String should not fail");
+        }
+        write!(sql, " AS {body};").expect("writing to String should not fail");
+        return Ok(sql);
+    };
+
+    let return_type = render_data_type(return_type);
     let mut sql = format!(
-        "CREATE FUNCTION {}({params}) RETURNS {} AS ",
+        "CREATE FUNCTION {}({params}) RETURNS {return_type} AS ",
         render_qualified_name(&function.name),
-        render_data_type(return_type)
     );
 
-    if body.to_ascii_uppercase().starts_with("BEGIN") {
+    // Table-valued functions return from their body as written; a scalar body needs a
+    // BEGIN ... END block.
+    if body.to_ascii_uppercase().starts_with("BEGIN") || return_type.ends_with("TABLE") {
         sql.push_str(body);
     } else {
         write!(sql, "BEGIN {body}; END").expect("writing to String should not fail");
@@ -378,6 +544,152 @@ fn render_function_param(param: &stateql_core::FunctionParam) -> String {
     sql
 }
 
+/// SQL Server has no `OWNED BY`; a sequence is a free-standing object there.
+fn render_sequence(dialect_name: &str, sequence: &Sequence) -> stateql_core::Result<String> {
+    if sequence.owned_by.is_some() {
+        return unsupported_shape_error(dialect_name, UNSUPPORTED_SEQUENCE_VARIANT);
+    }
+
+    let mut sql = format!("CREATE SEQUENCE {}", render_qualified_name(&sequence.name));
+    if let Some(data_type) = &sequence.data_type {
+        write!(sql, " AS {}", render_data_type(data_type))
+            .expect("writing to String should not fail");
+    }
+    if let Some(start) = sequence.start {
+        write!(sql, " START WITH {start}").expect("writing to String should not fail");
+    }
+    if let Some(increment) = sequence.increment {
+        write!(sql, " INCREMENT BY {increment}").expect("writing to String should not fail");
+    }
+    sql.push_str(&render_sequence_options(sequence));
+    sql.push(';');
+    Ok(sql)
+}
+
+/// `MINVALUE`, `MAXVALUE`, `CYCLE` and `CACHE`, each led by a space; `CACHE 0` is how the
+/// IR spells `NO CACHE`.
+pub(crate) fn render_sequence_options(sequence: &Sequence) -> String {
+    let mut sql = String::new();
+    if let Some(min_value) = sequence.min_value {
+        write!(sql, " MINVALUE {min_value}").expect("writing to String should not fail");
+    }
+    if let Some(max_value) = sequence.max_value {
+        write!(sql, " MAXVALUE {max_value}").expect("writing to String should not fail");
+    }
+    if sequence.cycle {
+        sql.push_str(" CYCLE");
+    }
+    match sequence.cache {
+        Some(0) => sql.push_str(" NO CACHE"),
+        Some(cache) => write!(sql, " CACHE {cache}").expect("writing to String should not fail"),
+        None => {}
+    }
+    sql
+}
+
+fn render_synonym(synonym: &Synonym) -> stateql_core::Result<String> {
+    let target = synonym
+        .target
+        .iter()
+        .map(render_ident)
+        .collect::<Vec<_>>()
+        .join(".");
+    Ok(format!(
+        "CREATE SYNONYM {} FOR {target};",
+        render_qualified_name(&synonym.name)
+    ))
+}
+
+fn render_comment(dialect_name: &str, comment: &Comment) -> stateql_core::Result<String> {
+    let (Some(levels), Some(text)) = (extended_property_levels(&comment.target), &comment.text)
+    else {
+        return unsupported_shape_error(dialect_name, UNSUPPORTED_COMMENT_VARIANT);
+    };
+    Ok(render_extended_property_call(
+        "sp_addextendedproperty",
+        &levels,
+        Some(text),
+    ))
+}
+
+/// The `(type, name)` levels addressing a comment target. Descriptions are kept on tables,
+/// their columns, views and procedures; SQL Server functions take no comments here.
+pub(crate) fn extended_property_levels(
+    target: &CommentTarget,
+) -> Option<Vec<(&'static str, &str)>> {
+    let (kind, name, column) = match target {
+        CommentTarget::Table(name) => ("TABLE", name, None),
+        CommentTarget::Column { table, column } => ("TABLE", table, Some(column)),
+        CommentTarget::View(name) => ("VIEW", name, None),
+        CommentTarget::Function(name) => ("PROCEDURE", name, None),
+        _ => return None,
+    };
+    let schema = name
+        .schema
+        .as_ref()
+        .map_or(DEFAULT_SCHEMA, |schema| schema.value.as_str());
+    let mut levels = vec![("SCHEMA", schema), (kind, name.name.value.as_str())];
+    if let Some(column) = column {
+        levels.push(("COLUMN", column.value.as_str()));
+    }
+    Some(levels)
+}
+
+/// `EXEC sys.<procedure>` on the description of the object `levels` address.
+pub(crate) fn render_extended_property_call(
+    procedure: &str,
+    levels: &[(&str, &str)],
+    value: Option<&str>,
+) -> String {
+    let mut sql = format!("EXEC sys.{procedure} @name = N'{DESCRIPTION_PROPERTY}'");
+    if let Some(value) = value {
+        write!(sql, ", @value = N'{}'", value.replace('\'', "''"))
+            .expect("writing to String should not fail");
+    }
+    for (level, (kind, name)) in levels.iter().enumerate() {
+        write!(
+            sql,
+            ", @level{level}type = N'{kind}', @level{level}name = N'{}'",
+            name.replace('\'', "''")
+        )
+        .expect("writing to String should not fail");
+    }
+    sql.push(';');
+    sql
+}
+
+/// Only table types exist in SQL Server; a table type has no named constraints.
+fn render_type(dialect_name: &str, type_def: &TypeDef) -> stateql_core::Result<String> {
+    let TypeKind::Table {
+        columns,
+        primary_key,
+        checks,
+    } = &type_def.kind
+    else {
+        return unsupported_variant_error(dialect_name, "Type");
+    };
+    if columns.is_empty() {
+        return unsupported_variant_error(dialect_name, "Type");
+    }
+
+    let mut definitions = Vec::new();
+    for column in columns {
+        definitions.push(render_column(dialect_name, column)?);
+    }
+    if let Some(primary_key) = primary_key {
+        definitions.push(render_primary_key(primary_key, None));
+    }
+    for check in checks {
+        definitions.push(render_check(check));
+    }
+
+    Ok(format!(
+        "CREATE TYPE {} AS TABLE ({});",
+        render_qualified_name(&type_def.name),
+        definitions.join(", ")
+    ))
+}
+
 fn render_schema(schema: &SchemaDef) -> stateql_core::Result<String> {
     Ok(format!("CREATE SCHEMA {};", render_ident(&schema.name)))
 }
@@ -410,7 +722,7 @@ fn render_data_type(data_type: &DataType) -> String {
         DataType::Timestamp { .. } => "DATETIME2".to_string(),
         DataType::Json | DataType::Jsonb => "NVARCHAR(MAX)".to_string(),
         DataType::Uuid => "UNIQUEIDENTIFIER".to_string(),
-        DataType::Array(_) => "NVARCHAR(MAX)".to_string(),
+        DataType::Array(_) | DataType::Enum(_) | DataType::Set(_) => "NVARCHAR(MAX)".to_string(),
         DataType::Custom(custom) => custom.trim().to_ascii_uppercase(),
     }
 }
@@ -707,15 +1019,6 @@ fn render_ident(ident: &Ident) -> String {
     format!("[{}]", ident.value.replace(']', "]]"))
 }
 
-fn ensure_sql_terminated(sql: &str) -> String {
-    let trimmed = sql.trim();
-    if trimmed.ends_with(';') {
-        trimmed.to_string()
-    } else {
-        format!("{trimmed};")
-    }
-}
-
 fn unsupported_shape_error(dialect_name: &str, diff_op: &str) -> stateql_core::Result<String> {
     Err(GenerateError::UnsupportedDiffOp {
         diff_op: diff_op.to_string(),
@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use stateql_core::{ConnectionConfig, Dialect};
+use stateql_dialect_mssql::MssqlDialect;
+
+#[test]
+fn tls_options_are_accepted_on_the_override_backed_path() {
+    let mut connection = override_connection();
+    connection
+        .extra
+        .insert("mssql.encrypt".to_string(), "on".to_string());
+    connection.extra.insert(
+        "mssql.ca_cert".to_string(),
+        "/etc/ssl/mssql-ca.pem".to_string(),
+    );
+
+    MssqlDialect
+        .connect(&connection)
+        .expect("valid tls options should connect");
+}
+
+#[test]
+fn unknown_encryption_mode_is_rejected() {
+    let mut connection = override_connection();
+    connection
+        .extra
+        .insert("mssql.encrypt".to_string(), "optional".to_string());
+
+    let error = MssqlDialect
+        .connect(&connection)
+        .err()
+        .expect("unknown encryption mode should be rejected");
+
+    assert!(
+        format!("{error:?}").contains("must be off, on or required"),
+        "{error:?}"
+    );
+}
+
+#[test]
+fn ca_certificate_and_trust_opt_in_cannot_be_combined() {
+    let mut connection = override_connection();
+    connection.extra.insert(
+        "mssql.ca_cert".to_string(),
+        "/etc/ssl/mssql-ca.pem".to_string(),
+    );
+    connection.extra.insert(
+        "mssql.trust_server_certificate".to_string(),
+        "true".to_string(),
+    );
+
+    let error = MssqlDialect
+        .connect(&connection)
+        .err()
+        .expect("conflicting trust options should be rejected");
+
+    assert!(
+        format!("{error:?}").contains("cannot be combined"),
+        "{error:?}"
+    );
+}
+
+fn override_connection() -> ConnectionConfig {
+    ConnectionConfig {
+        host: Some("127.0.0.1".to_string()),
+        port: Some(1433),
+        user: Some("sa".to_string()),
+        password: Some("Passw0rd!".to_string()),
+        database: "stateql".to_string(),
+        socket: None,
+        extra: BTreeMap::from([
+            (
+                "mssql.server_version".to_string(),
+                "15.0.2000.5".to_string(),
+            ),
+            (
+                "mssql.export_schema_sql".to_string(),
+                "CREATE TABLE [dbo].[users] ([id] BIGINT NOT NULL);".to_string(),
+            ),
+        ]),
+    }
+}
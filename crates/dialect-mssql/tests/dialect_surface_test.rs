@@ -1,10 +1,11 @@
 use std::collections::BTreeMap;
 
 use stateql_core::{
-    Column, Comment, CommentTarget, ConnectionConfig, DataType, Dialect, Domain, Error,
-    ExecutionError, Expr, Extension, Function, FunctionParam, GenerateError, Ident, IndexColumn,
-    IndexDef, IndexOwner, Policy, PolicyCommand, Privilege, PrivilegeObject, QualifiedName,
-    SchemaDef, SchemaObject, Table, Trigger, TriggerEvent, TriggerForEach, TriggerTiming, View,
+    Column, Comment, CommentTarget, ConnectionConfig, DataType, Dialect, DiffConfig, DiffEngine,
+    DiffOp, Domain, Error, ExecutionError, Expr, Extension, Function, FunctionParam, GenerateError,
+    Ident, IndexColumn, IndexDef, IndexOwner, Orchestrator, Policy, PolicyCommand, Pragma,
+    Privilege, PrivilegeObject, QualifiedName, SchemaDef, SchemaObject, Table, Trigger,
+    TriggerEvent, TriggerForEach, TriggerTiming, Value, View,
 };
 use stateql_dialect_mssql::MssqlDialect;
 
@@ -27,6 +28,38 @@ CREATE TABLE [dbo].[Users] (\
     assert!(upper.contains("CLUSTERED"));
 }
 
+#[test]
+fn export_with_views_and_procedures_reads_back() {
+    let dialect = MssqlDialect;
+    let schema_sql = "\
+CREATE TABLE [dbo].[Users] ([Id] INT NOT NULL, [Name] NVARCHAR(100) NULL);
+GO
+CREATE VIEW [dbo].[UserNames] AS SELECT [Name] FROM [dbo].[Users];
+GO
+CREATE PROCEDURE [dbo].[CountUsers] AS BEGIN SELECT COUNT(*) FROM [dbo].[Users]; END;
+GO
+";
+    let mut objects = dialect.parse(schema_sql).expect("parse should succeed");
+    for object in &mut objects {
+        dialect.normalize(object);
+    }
+    // The export the orchestrator renders: every object ends its own batch.
+    let exported = objects
+        .iter()
+        .map(|object| {
+            let sql = dialect.to_sql(object).expect("to_sql should succeed");
+            format!("{sql}\n{}", dialect.batch_separator())
+        })
+        .collect::<String>();
+
+    assert!(
+        Orchestrator::new(&dialect)
+            .export_roundtrip_matches(&exported)
+            .expect("the export should parse back"),
+        "{exported}"
+    );
+}
+
 #[test]
 fn to_sql_supports_mssql_variants_and_rejects_unsupported_variants() {
     let dialect = MssqlDialect;
@@ -77,6 +110,54 @@ fn connect_rejects_sql_server_versions_below_2019() {
     );
 }
 
+#[test]
+fn primary_and_foreign_keys_are_diffed() {
+    let dialect = MssqlDialect;
+    assert!(dialect.diffs_table_constraints());
+
+    let ops = diff_sql(
+        &dialect,
+        "CREATE TABLE dbo.teams (id INT NOT NULL PRIMARY KEY);\n\
+         CREATE TABLE dbo.users (id INT NOT NULL, team_id INT, CONSTRAINT users_pk PRIMARY KEY (id), CONSTRAINT users_team_fk FOREIGN KEY (team_id) REFERENCES dbo.teams (id));",
+        "CREATE TABLE dbo.teams (id INT NOT NULL PRIMARY KEY);\n\
+         CREATE TABLE dbo.users (id INT NOT NULL, team_id INT);",
+    );
+
+    assert!(
+        ops.iter()
+            .any(|op| matches!(op, DiffOp::SetPrimaryKey { .. })),
+        "{ops:?}"
+    );
+    assert!(
+        ops.iter()
+            .any(|op| matches!(op, DiffOp::AddForeignKey { .. })),
+        "{ops:?}"
+    );
+    dialect
+        .generate_ddl(&ops)
+        .expect("mssql key changes should generate SQL");
+}
+
+/// Diffs two schemas with the settings the orchestrator derives from the dialect.
+fn diff_sql(dialect: &MssqlDialect, desired: &str, current: &str) -> Vec<DiffOp> {
+    let parse = |sql: &str| {
+        let mut objects = dialect.parse(sql).expect("schema should parse");
+        for object in &mut objects {
+            dialect.normalize(object);
+        }
+        objects
+    };
+    let config = DiffConfig {
+        emit_table_definitions: dialect.requires_table_definitions(),
+        diff_table_constraints: dialect.diffs_table_constraints(),
+        diffed_table_options: dialect.diffed_table_options(),
+        ..DiffConfig::default()
+    };
+    DiffEngine::new()
+        .diff(&parse(desired), &parse(current), &config)
+        .expect("diff should succeed")
+}
+
 fn canonical_export_sql(dialect: &MssqlDialect, sql: &str) -> String {
     let mut objects = dialect.parse(sql).expect("parse should succeed");
     for object in &mut objects {
@@ -152,6 +233,7 @@ fn supported_objects() -> Vec<SchemaObject> {
         table: qualified(Some("dbo"), "users"),
         timing: TriggerTiming::After,
         events: vec![TriggerEvent::Insert],
+        update_columns: Vec::new(),
         for_each: TriggerForEach::Statement,
         when_clause: None,
         body: "INSERT INTO dbo.audit_log(user_id) SELECT id FROM inserted".to_string(),
@@ -183,6 +265,10 @@ fn supported_objects() -> Vec<SchemaObject> {
         SchemaObject::Trigger(trigger),
         SchemaObject::Function(function),
         SchemaObject::Schema(schema),
+        SchemaObject::Comment(Comment {
+            target: CommentTarget::Table(qualified(Some("dbo"), "users")),
+            text: Some("comment".to_string()),
+        }),
     ]
 }
 
@@ -201,7 +287,7 @@ fn unsupported_objects() -> Vec<SchemaObject> {
             version: None,
         }),
         SchemaObject::Comment(Comment {
-            target: CommentTarget::Table(qualified(Some("dbo"), "users")),
+            target: CommentTarget::Index(qualified(Some("dbo"), "ix_users_id")),
             text: Some("comment".to_string()),
         }),
         SchemaObject::Privilege(Privilege {
@@ -219,5 +305,9 @@ fn unsupported_objects() -> Vec<SchemaObject> {
             roles: vec![Ident::unquoted("app")],
             permissive: true,
         }),
+        SchemaObject::Pragma(Pragma {
+            name: qualified(None, "user_version"),
+            value: Value::Integer(7),
+        }),
     ]
 }
@@ -5,7 +5,7 @@ use std::collections::BTreeSet;
 mod diffop_fixtures;
 
 use diffop_fixtures::{EXPECTED_DIFFOP_VARIANT_COUNT, all_diffop_variants, diffop_variant_tag};
-use stateql_core::{Dialect, DiffOp, Error, GenerateError, IndexOwner};
+use stateql_core::{Dialect, DiffOp, Error, GenerateError, IndexOwner, SequenceChange, TypeKind};
 use stateql_dialect_mssql::MssqlDialect;
 
 #[test]
@@ -37,7 +37,15 @@ fn every_diffop_variant_matches_mssql_support_contract() {
     let dialect = MssqlDialect;
 
     for op in &all_diffop_variants() {
-        if is_supported_diffop(op) {
+        if matches!(op, DiffOp::TableDefinition { .. }) {
+            let statements = dialect
+                .generate_ddl(std::slice::from_ref(op))
+                .expect("table definition should be accepted");
+            assert!(
+                statements.is_empty(),
+                "table definition only informs the table's own ops"
+            );
+        } else if is_supported_diffop(op) {
             let statements = dialect
                 .generate_ddl(std::slice::from_ref(op))
                 .unwrap_or_else(|error| panic!("expected supported op to generate SQL: {error:?}"));
@@ -59,8 +67,10 @@ fn is_supported_diffop(op: &DiffOp) -> bool {
         DiffOp::CreateTable(table) => table.exclusions.is_empty() && table.partition.is_none(),
         DiffOp::DropTable(_) => true,
         DiffOp::RenameTable { from, to } => from.schema == to.schema,
+        DiffOp::TableDefinition { .. } => true,
         DiffOp::AddColumn { position, .. } => position.is_none(),
         DiffOp::DropColumn { .. } => true,
+        // Generated from the table's `TableDefinition`, which a lone op lacks.
         DiffOp::AlterColumn { .. } => false,
         DiffOp::RenameColumn { .. } => true,
         DiffOp::AddIndex(index) => {
@@ -77,16 +87,22 @@ fn is_supported_diffop(op: &DiffOp) -> bool {
         DiffOp::AddExclusion { .. } => false,
         DiffOp::DropExclusion { .. } => false,
         DiffOp::SetPrimaryKey { .. } => true,
-        DiffOp::DropPrimaryKey { .. } => false,
+        DiffOp::DropPrimaryKey { .. } => true,
         DiffOp::AddPartition { .. } => false,
         DiffOp::DropPartition { .. } => false,
+        DiffOp::ReorganizePartition { .. } => false,
+        DiffOp::ChangePartitionCount { .. } => false,
         DiffOp::CreateView(_) => true,
         DiffOp::DropView(_) => true,
         DiffOp::CreateMaterializedView(_) => false,
         DiffOp::DropMaterializedView(_) => false,
-        DiffOp::CreateSequence(_) => false,
-        DiffOp::DropSequence(_) => false,
-        DiffOp::AlterSequence { .. } => false,
+        DiffOp::CreateSequence(sequence) => sequence.owned_by.is_none(),
+        DiffOp::DropSequence(_) => true,
+        DiffOp::AlterSequence { changes, .. } => !changes
+            .iter()
+            .any(|change| matches!(change, SequenceChange::SetType(_))),
+        DiffOp::CreateSynonym(_) => true,
+        DiffOp::DropSynonym(_) => true,
         DiffOp::CreateTrigger(trigger) => {
             !trigger.events.is_empty() && !trigger.body.trim().is_empty()
         }
@@ -94,9 +110,11 @@ fn is_supported_diffop(op: &DiffOp) -> bool {
         DiffOp::CreateFunction(function) => {
             function.return_type.is_some() && !function.body.trim().is_empty()
         }
-        DiffOp::DropFunction(_) => true,
-        DiffOp::CreateType(_) => false,
-        DiffOp::DropType(_) => false,
+        DiffOp::DropFunction { .. } => true,
+        DiffOp::CreateEvent(_) => false,
+        DiffOp::DropEvent(_) => false,
+        DiffOp::CreateType(type_def) => matches!(type_def.kind, TypeKind::Table { .. }),
+        DiffOp::DropType(_) => true,
         DiffOp::AlterType { .. } => false,
         DiffOp::CreateDomain(_) => false,
         DiffOp::DropDomain(_) => false,
@@ -105,13 +123,20 @@ fn is_supported_diffop(op: &DiffOp) -> bool {
         DiffOp::DropExtension(_) => false,
         DiffOp::CreateSchema(_) => true,
         DiffOp::DropSchema(_) => true,
-        DiffOp::SetComment(_) => false,
-        DiffOp::DropComment { .. } => false,
+        DiffOp::SetComment(_) => true,
+        DiffOp::DropComment { .. } => true,
         DiffOp::Grant(_) => false,
         DiffOp::Revoke(_) => false,
         DiffOp::CreatePolicy(_) => false,
         DiffOp::DropPolicy { .. } => false,
-        DiffOp::AlterTableOptions { .. } => false,
+        DiffOp::SetPragma(_) => false,
+        DiffOp::AlterTableOptions { options, .. } => {
+            !options.extra.is_empty()
+                && options
+                    .extra
+                    .keys()
+                    .all(|key| MssqlDialect.diffed_table_options().contains(&key.as_str()))
+        }
     }
 }
 
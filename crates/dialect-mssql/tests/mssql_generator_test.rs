@@ -1,7 +1,9 @@
 use std::collections::BTreeMap;
 
 use stateql_core::{
-    Column, DataType, Dialect, DiffOp, Ident, IndexOwner, QualifiedName, Statement, Table,
+    Column, ColumnChange, Comment, CommentTarget, DataType, Dialect, DiffOp, Expr, ForeignKey,
+    Function, FunctionParam, GeneratedColumn, Ident, IndexColumn, IndexDef, IndexOwner, Literal,
+    PrimaryKey, QualifiedName, SequenceChange, Statement, Synonym, Table, Value, extra_keys,
 };
 use stateql_dialect_mssql::MssqlDialect;
 
@@ -46,6 +48,584 @@ fn create_and_rename_ops_emit_batch_boundaries_and_sp_rename() {
     );
 }
 
+#[test]
+fn alter_column_type_drops_and_recreates_blocking_index_key_and_default() {
+    let mut current = users_table();
+    current.primary_key = Some(PrimaryKey {
+        name: None,
+        columns: vec![Ident::unquoted("username")],
+    });
+    current.columns[1].default = Some(Expr::Literal(Literal::String(String::new())));
+    let mut target = current.clone();
+    target.columns[1].data_type = DataType::Varchar { length: Some(320) };
+    let index = IndexDef {
+        name: Some(Ident::unquoted("ix_users_username")),
+        owner: IndexOwner::Table(qualified(Some("dbo"), "users")),
+        columns: vec![IndexColumn {
+            expr: Expr::Ident(Ident::unquoted("username")),
+        }],
+        unique: false,
+        method: None,
+        where_clause: None,
+        concurrent: false,
+        extra: BTreeMap::new(),
+    };
+
+    let statements = MssqlDialect
+        .generate_ddl(&[
+            table_definition(current, target, vec![index]),
+            DiffOp::AlterColumn {
+                table: qualified(Some("dbo"), "users"),
+                column: Ident::unquoted("username"),
+                changes: vec![ColumnChange::SetType(DataType::Varchar {
+                    length: Some(320),
+                })],
+            },
+        ])
+        .expect("alter column should be generated");
+
+    assert_eq!(
+        sql_texts(&statements),
+        vec![
+            "DROP INDEX [ix_users_username] ON [dbo].[users];",
+            "DECLARE @constraint sysname;\n\
+             SELECT @constraint = name FROM sys.key_constraints WHERE type = 'PK' AND parent_object_id = OBJECT_ID(N'[dbo].[users]');\n\
+             IF @constraint IS NOT NULL EXEC(N'ALTER TABLE [dbo].[users] DROP CONSTRAINT ' + QUOTENAME(@constraint));",
+            "DECLARE @constraint sysname;\n\
+             SELECT @constraint = dc.name FROM sys.default_constraints AS dc JOIN sys.columns AS c ON c.object_id = dc.parent_object_id AND c.column_id = dc.parent_column_id WHERE dc.parent_object_id = OBJECT_ID(N'[dbo].[users]') AND c.name = N'username';\n\
+             IF @constraint IS NOT NULL EXEC(N'ALTER TABLE [dbo].[users] DROP CONSTRAINT ' + QUOTENAME(@constraint));",
+            "ALTER TABLE [dbo].[users] ALTER COLUMN [username] NVARCHAR(320) NOT NULL;",
+            "ALTER TABLE [dbo].[users] ADD DEFAULT '' FOR [username];",
+            "ALTER TABLE [dbo].[users] ADD PRIMARY KEY ([username]);",
+            "CREATE INDEX [ix_users_username] ON [dbo].[users] ([username]);",
+        ]
+    );
+}
+
+#[test]
+fn alter_column_type_recreates_unique_constraints_and_referencing_foreign_keys() {
+    let current = users_table();
+    let mut target = current.clone();
+    target.columns[1].data_type = DataType::Varchar { length: Some(320) };
+    let unique = IndexDef {
+        name: Some(Ident::unquoted("uq_users_username")),
+        owner: IndexOwner::Table(qualified(Some("dbo"), "users")),
+        columns: vec![IndexColumn {
+            expr: Expr::Ident(Ident::unquoted("username")),
+        }],
+        unique: true,
+        method: None,
+        where_clause: None,
+        concurrent: false,
+        extra: BTreeMap::from([("mssql.unique_constraint".to_string(), Value::Bool(true))]),
+    };
+    let sessions_fk = ForeignKey {
+        name: Some(Ident::unquoted("fk_sessions_username")),
+        columns: vec![Ident::unquoted("username")],
+        referenced_table: qualified(Some("dbo"), "users"),
+        referenced_columns: vec![Ident::unquoted("username")],
+        on_delete: None,
+        on_update: None,
+        deferrable: None,
+        extra: BTreeMap::new(),
+    };
+
+    let statements = MssqlDialect
+        .generate_ddl(&[
+            DiffOp::TableDefinition {
+                current: Box::new(current),
+                target: Box::new(target),
+                dependent_views: Vec::new(),
+                dependent_indexes: vec![unique],
+                declared_indexes: Vec::new(),
+                dependent_triggers: Vec::new(),
+                referencing_foreign_keys: vec![(qualified(Some("dbo"), "sessions"), sessions_fk)],
+            },
+            DiffOp::AlterColumn {
+                table: qualified(Some("dbo"), "users"),
+                column: Ident::unquoted("username"),
+                changes: vec![ColumnChange::SetType(DataType::Varchar {
+                    length: Some(320),
+                })],
+            },
+        ])
+        .expect("alter column should be generated");
+
+    assert_eq!(
+        sql_texts(&statements),
+        vec![
+            "ALTER TABLE [dbo].[sessions] DROP CONSTRAINT [fk_sessions_username];",
+            "ALTER TABLE [dbo].[users] DROP CONSTRAINT [uq_users_username];",
+            "ALTER TABLE [dbo].[users] ALTER COLUMN [username] NVARCHAR(320) NOT NULL;",
+            "ALTER TABLE [dbo].[users] ADD CONSTRAINT [uq_users_username] UNIQUE ([username]);",
+            "ALTER TABLE [dbo].[sessions] ADD CONSTRAINT [fk_sessions_username] FOREIGN KEY ([username]) REFERENCES [dbo].[users] ([username]);",
+        ]
+    );
+}
+
+#[test]
+fn alter_column_with_an_unnamed_dependent_index_is_unsupported() {
+    let current = users_table();
+    let mut target = current.clone();
+    target.columns[1].not_null = false;
+    let index = IndexDef {
+        name: None,
+        owner: IndexOwner::Table(qualified(Some("dbo"), "users")),
+        columns: vec![IndexColumn {
+            expr: Expr::Ident(Ident::unquoted("username")),
+        }],
+        unique: false,
+        method: None,
+        where_clause: None,
+        concurrent: false,
+        extra: BTreeMap::new(),
+    };
+
+    let error = MssqlDialect
+        .generate_ddl(&[
+            table_definition(current, target, vec![index]),
+            DiffOp::AlterColumn {
+                table: qualified(Some("dbo"), "users"),
+                column: Ident::unquoted("username"),
+                changes: vec![ColumnChange::SetNotNull(false)],
+            },
+        ])
+        .expect_err("the unnamed index cannot be recreated");
+
+    assert!(error.to_string().contains("unnamed index"), "{error}");
+}
+
+#[test]
+fn primary_key_is_dropped_by_its_discovered_name() {
+    let statements = MssqlDialect
+        .generate_ddl(&[
+            DiffOp::DropPrimaryKey {
+                table: qualified(Some("dbo"), "users"),
+            },
+            DiffOp::SetPrimaryKey {
+                table: qualified(Some("dbo"), "users"),
+                pk: PrimaryKey {
+                    name: Some(Ident::unquoted("pk_users")),
+                    columns: vec![Ident::unquoted("id"), Ident::unquoted("username")],
+                },
+            },
+        ])
+        .expect("primary key change should be generated");
+
+    assert_eq!(
+        sql_texts(&statements),
+        vec![
+            "DECLARE @constraint sysname;\n\
+             SELECT @constraint = name FROM sys.key_constraints WHERE type = 'PK' AND parent_object_id = OBJECT_ID(N'[dbo].[users]');\n\
+             IF @constraint IS NOT NULL EXEC(N'ALTER TABLE [dbo].[users] DROP CONSTRAINT ' + QUOTENAME(@constraint));",
+            "ALTER TABLE [dbo].[users] ADD CONSTRAINT [pk_users] PRIMARY KEY ([id], [username]);",
+        ]
+    );
+}
+
+#[test]
+fn alter_column_default_replaces_the_default_constraint() {
+    let mut current = users_table();
+    current.columns[1].default = Some(Expr::Literal(Literal::String("guest".to_string())));
+    let mut target = current.clone();
+    target.columns[1].default = Some(Expr::Literal(Literal::String("anonymous".to_string())));
+    target.columns[1].extra.insert(
+        extra_keys::mssql::DEFAULT_CONSTRAINT_NAME.to_string(),
+        Value::String("df_users_username".to_string()),
+    );
+
+    let statements = MssqlDialect
+        .generate_ddl(&[
+            table_definition(current, target, Vec::new()),
+            DiffOp::AlterColumn {
+                table: qualified(Some("dbo"), "users"),
+                column: Ident::unquoted("username"),
+                changes: vec![ColumnChange::SetDefault(Some(Expr::Literal(
+                    Literal::String("anonymous".to_string()),
+                )))],
+            },
+        ])
+        .expect("default change should be generated");
+
+    let texts = sql_texts(&statements);
+    assert_eq!(texts.len(), 2);
+    assert!(texts[0].contains("FROM sys.default_constraints"));
+    assert_eq!(
+        texts[1],
+        "ALTER TABLE [dbo].[users] ADD CONSTRAINT [df_users_username] DEFAULT 'anonymous' FOR [username];"
+    );
+}
+
+#[test]
+fn alter_column_without_table_definition_is_unsupported() {
+    let error = MssqlDialect
+        .generate_ddl(&[DiffOp::AlterColumn {
+            table: qualified(Some("dbo"), "users"),
+            column: Ident::unquoted("username"),
+            changes: vec![ColumnChange::SetNotNull(false)],
+        }])
+        .expect_err("the full column is unknown");
+
+    assert!(error.to_string().contains("AlterColumn"), "{error}");
+}
+
+#[test]
+fn unique_constraints_are_added_as_constraints_and_dropped_by_kind() {
+    let dialect = MssqlDialect;
+    let owner = IndexOwner::Table(qualified(Some("dbo"), "users"));
+    let unique = IndexDef {
+        name: Some(Ident::unquoted("uq_users_username")),
+        owner: owner.clone(),
+        columns: vec![IndexColumn {
+            expr: Expr::Ident(Ident::unquoted("username")),
+        }],
+        unique: true,
+        method: Some("NONCLUSTERED".to_string()),
+        where_clause: None,
+        concurrent: false,
+        extra: BTreeMap::from([("mssql.unique_constraint".to_string(), Value::Bool(true))]),
+    };
+
+    let statements = dialect
+        .generate_ddl(&[
+            DiffOp::DropIndex {
+                owner,
+                name: Ident::unquoted("uq_users_email"),
+            },
+            DiffOp::AddIndex(unique),
+        ])
+        .expect("unique constraint ops should be generated");
+
+    let texts = sql_texts(&statements);
+    assert!(
+        texts[0].contains("sys.key_constraints WHERE type = 'UQ' AND name = N'uq_users_email'"),
+        "{texts:?}"
+    );
+    assert!(texts[0].contains("ALTER TABLE [dbo].[users] DROP CONSTRAINT [uq_users_email];"));
+    assert!(texts[0].ends_with("DROP INDEX [uq_users_email] ON [dbo].[users];"));
+    assert_eq!(
+        texts[1],
+        "ALTER TABLE [dbo].[users] ADD CONSTRAINT [uq_users_username] UNIQUE NONCLUSTERED ([username]);"
+    );
+}
+
+#[test]
+fn computed_column_is_added_without_a_type() {
+    let dialect = MssqlDialect;
+    let mut column = users_table().columns.remove(1);
+    column.name = Ident::unquoted("username_upper");
+    column.data_type = DataType::Custom(String::new());
+    column.generated = Some(GeneratedColumn {
+        expr: Expr::Raw("UPPER([username])".to_string()),
+        stored: true,
+    });
+
+    let statements = dialect
+        .generate_ddl(&[DiffOp::AddColumn {
+            table: qualified(Some("dbo"), "users"),
+            column: Box::new(column),
+            position: None,
+        }])
+        .expect("computed column should be added");
+
+    assert_eq!(
+        sql_texts(&statements),
+        vec![
+            "ALTER TABLE [dbo].[users] ADD [username_upper] AS UPPER([username]) PERSISTED NOT NULL;"
+        ]
+    );
+}
+
+#[test]
+fn procedures_are_created_and_dropped_as_procedures() {
+    let dialect = MssqlDialect;
+    let procedure = Function {
+        name: qualified(Some("dbo"), "touch_user"),
+        params: vec![FunctionParam {
+            name: Some(Ident::unquoted("id")),
+            data_type: DataType::Integer,
+            mode: None,
+            default: None,
+        }],
+        return_type: None,
+        language: "tsql".to_string(),
+        body: "UPDATE dbo.users SET touched = 1 WHERE id = @id".to_string(),
+        volatility: None,
+        security: None,
+    };
+
+    let statements = dialect
+        .generate_ddl(&[
+            DiffOp::DropFunction {
+                name: qualified(Some("dbo"), "touch_user"),
+                procedure: true,
+            },
+            DiffOp::CreateFunction(procedure),
+        ])
+        .expect("procedure should be replaced");
+
+    assert_eq!(
+        sql_texts(&statements),
+        vec![
+            "IF OBJECT_ID(N'[dbo].[touch_user]', N'P') IS NOT NULL\n\
+             DROP PROCEDURE [dbo].[touch_user];\n\
+             ELSE\n\
+             DROP FUNCTION [dbo].[touch_user];",
+            "CREATE PROCEDURE [dbo].[touch_user] @id INT AS UPDATE dbo.users SET touched = 1 WHERE id = @id;",
+        ]
+    );
+}
+
+#[test]
+fn sequences_and_synonyms_are_altered_and_replaced() {
+    let dialect = MssqlDialect;
+    let synonym = Synonym {
+        name: qualified(Some("dbo"), "customers"),
+        target: vec![Ident::unquoted("crm"), Ident::unquoted("customers")],
+    };
+
+    let statements = dialect
+        .generate_ddl(&[
+            DiffOp::AlterSequence {
+                name: qualified(Some("dbo"), "order_numbers"),
+                changes: vec![
+                    SequenceChange::SetIncrement(5),
+                    SequenceChange::SetMaxValue(None),
+                    SequenceChange::SetStart(100),
+                    SequenceChange::SetCache(0),
+                ],
+            },
+            DiffOp::DropSynonym(qualified(Some("dbo"), "customers")),
+            DiffOp::CreateSynonym(synonym),
+        ])
+        .expect("sequence and synonym ops should be supported");
+
+    assert_eq!(
+        sql_texts(&statements),
+        vec![
+            "ALTER SEQUENCE [dbo].[order_numbers] INCREMENT BY 5 NO MAXVALUE RESTART WITH 100 NO CACHE;",
+            "DROP SYNONYM [dbo].[customers];",
+            "CREATE SYNONYM [dbo].[customers] FOR [crm].[customers];",
+        ]
+    );
+}
+
+#[test]
+fn column_changes_to_a_versioned_table_are_one_batch_around_versioning() {
+    let mut current = users_table();
+    current.options.extra.insert(
+        extra_keys::mssql::TABLE_SYSTEM_VERSIONING.to_string(),
+        Value::Bool(true),
+    );
+    current.options.extra.insert(
+        extra_keys::mssql::TABLE_HISTORY_TABLE.to_string(),
+        Value::String("dbo.users_history".to_string()),
+    );
+    let mut target = current.clone();
+    let email = Column {
+        name: Ident::unquoted("email"),
+        data_type: DataType::Varchar { length: Some(320) },
+        not_null: false,
+        ..target.columns[1].clone()
+    };
+    target.columns.push(email.clone());
+
+    let statements = MssqlDialect
+        .generate_ddl(&[
+            table_definition(current, target, Vec::new()),
+            DiffOp::AddColumn {
+                table: qualified(Some("dbo"), "users"),
+                column: Box::new(email),
+                position: None,
+            },
+            DiffOp::DropColumn {
+                table: qualified(Some("dbo"), "users"),
+                column: Ident::unquoted("username"),
+            },
+        ])
+        .expect("temporal column changes should be generated");
+
+    let texts = sql_texts(&statements);
+    assert_eq!(texts.len(), 1, "{texts:#?}");
+    let batch = texts[0];
+    assert!(
+        batch.starts_with(
+            "DECLARE @constraint sysname;\nALTER TABLE [dbo].[users] SET (SYSTEM_VERSIONING = OFF);\n"
+        ),
+        "{batch}"
+    );
+    assert!(
+        batch.contains("ALTER TABLE [dbo].[users_history] ADD [email] NVARCHAR(320) NULL;"),
+        "{batch}"
+    );
+    assert!(
+        batch.contains("ALTER TABLE [dbo].[users_history] DROP COLUMN [username];"),
+        "{batch}"
+    );
+    assert!(
+        batch.ends_with(
+            "ALTER TABLE [dbo].[users] SET (SYSTEM_VERSIONING = ON (HISTORY_TABLE = [dbo].[users_history]));"
+        ),
+        "{batch}"
+    );
+}
+
+#[test]
+fn a_versioned_batch_declares_the_constraint_variable_once() {
+    let mut current = users_table();
+    current.options.extra.insert(
+        extra_keys::mssql::TABLE_SYSTEM_VERSIONING.to_string(),
+        Value::Bool(true),
+    );
+    current.options.extra.insert(
+        extra_keys::mssql::TABLE_HISTORY_TABLE.to_string(),
+        Value::String("dbo.users_history".to_string()),
+    );
+    current.primary_key = Some(PrimaryKey {
+        name: None,
+        columns: vec![Ident::unquoted("username")],
+    });
+    current.columns[1].default = Some(Expr::Literal(Literal::String(String::new())));
+    let mut target = current.clone();
+    target.columns[1].data_type = DataType::Varchar { length: Some(320) };
+
+    let statements = MssqlDialect
+        .generate_ddl(&[
+            table_definition(current, target, Vec::new()),
+            DiffOp::AlterColumn {
+                table: qualified(Some("dbo"), "users"),
+                column: Ident::unquoted("username"),
+                changes: vec![ColumnChange::SetType(DataType::Varchar {
+                    length: Some(320),
+                })],
+            },
+        ])
+        .expect("temporal column changes should be generated");
+
+    let texts = sql_texts(&statements);
+    assert_eq!(texts.len(), 1, "{texts:#?}");
+    let batch = texts[0];
+    assert_eq!(batch.matches("DECLARE @constraint").count(), 1, "{batch}");
+    assert!(
+        batch.starts_with("DECLARE @constraint sysname;\n"),
+        "{batch}"
+    );
+    assert_eq!(
+        batch.matches("SET @constraint = NULL;").count(),
+        2,
+        "{batch}"
+    );
+}
+
+#[test]
+fn enabling_system_versioning_adds_the_period_first() {
+    let mut current = users_table();
+    let mut target = current.clone();
+    for (key, value) in [
+        (
+            extra_keys::mssql::TABLE_PERIOD_START,
+            Value::String("valid_from".to_string()),
+        ),
+        (
+            extra_keys::mssql::TABLE_PERIOD_END,
+            Value::String("valid_to".to_string()),
+        ),
+        (
+            extra_keys::mssql::TABLE_SYSTEM_VERSIONING,
+            Value::Bool(true),
+        ),
+    ] {
+        target.options.extra.insert(key.to_string(), value);
+    }
+    current.options.extra.clear();
+    let options = target.options.clone();
+
+    let statements = MssqlDialect
+        .generate_ddl(&[
+            table_definition(current, target, Vec::new()),
+            DiffOp::AlterTableOptions {
+                table: qualified(Some("dbo"), "users"),
+                options,
+            },
+        ])
+        .expect("temporal options should be generated");
+
+    assert_eq!(
+        sql_texts(&statements),
+        vec![
+            "ALTER TABLE [dbo].[users] ADD PERIOD FOR SYSTEM_TIME ([valid_from], [valid_to]);\n\
+             ALTER TABLE [dbo].[users] SET (SYSTEM_VERSIONING = ON);"
+        ]
+    );
+}
+
+#[test]
+fn descriptions_are_added_or_updated_and_dropped_when_present() {
+    let column = CommentTarget::Column {
+        table: qualified(Some("dbo"), "users"),
+        column: Ident::unquoted("email"),
+    };
+    let statements = MssqlDialect
+        .generate_ddl(&[
+            DiffOp::SetComment(Comment {
+                target: column.clone(),
+                text: Some("Login, it's unique".to_string()),
+            }),
+            DiffOp::DropComment {
+                target: CommentTarget::Function(qualified(Some("dbo"), "refresh_totals")),
+            },
+        ])
+        .expect("description changes should be generated");
+
+    let lookup = "SELECT 1 FROM sys.extended_properties WHERE class = 1 \
+                  AND major_id = OBJECT_ID(N'[dbo].[users]') \
+                  AND minor_id = COLUMNPROPERTY(OBJECT_ID(N'[dbo].[users]'), N'email', 'ColumnId') \
+                  AND name = N'MS_Description'";
+    let levels = "@level0type = N'SCHEMA', @level0name = N'dbo', \
+                  @level1type = N'TABLE', @level1name = N'users', \
+                  @level2type = N'COLUMN', @level2name = N'email'";
+    assert_eq!(
+        sql_texts(&statements),
+        vec![
+            format!(
+                "IF EXISTS ({lookup})\n\
+                 EXEC sys.sp_updateextendedproperty @name = N'MS_Description', @value = N'Login, it''s unique', {levels};\n\
+                 ELSE\n\
+                 EXEC sys.sp_addextendedproperty @name = N'MS_Description', @value = N'Login, it''s unique', {levels};"
+            )
+            .as_str(),
+            "IF EXISTS (SELECT 1 FROM sys.extended_properties WHERE class = 1 \
+             AND major_id = OBJECT_ID(N'[dbo].[refresh_totals]') AND minor_id = 0 \
+             AND name = N'MS_Description')\n\
+             EXEC sys.sp_dropextendedproperty @name = N'MS_Description', \
+             @level0type = N'SCHEMA', @level0name = N'dbo', \
+             @level1type = N'PROCEDURE', @level1name = N'refresh_totals';",
+        ]
+    );
+}
+
+fn table_definition(current: Table, target: Table, dependent_indexes: Vec<IndexDef>) -> DiffOp {
+    DiffOp::TableDefinition {
+        current: Box::new(current),
+        target: Box::new(target),
+        dependent_views: Vec::new(),
+        dependent_indexes,
+        declared_indexes: Vec::new(),
+        dependent_triggers: Vec::new(),
+        referencing_foreign_keys: Vec::new(),
+    }
+}
+
+fn sql_texts(statements: &[Statement]) -> Vec<&str> {
+    statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::Sql { sql, .. } => Some(sql.as_str()),
+            Statement::BatchBoundary => None,
+        })
+        .collect()
+}
+
 fn users_table() -> Table {
     let mut table = Table::named("users");
     table.name = qualified(Some("dbo"), "users");
@@ -1,4 +1,7 @@
-use stateql_core::{Dialect, DiffError, Error, Ident, ParseError, SchemaObject};
+use stateql_core::{
+    CommentTarget, DataType, Dialect, DiffError, Error, Expr, ForeignKeyAction, Ident, ParseError,
+    QualifiedName, SchemaObject, TypeKind, Value, extra_keys,
+};
 use stateql_dialect_mssql::MssqlDialect;
 
 #[test]
@@ -60,3 +63,753 @@ fn orphan_renamed_annotation_fails_fast() {
         other => panic!("expected orphan annotation mismatch, got {other:?}"),
     }
 }
+
+#[test]
+fn create_table_is_converted_into_columns_and_constraints() {
+    let dialect = MssqlDialect;
+    let sql = "\
+CREATE TABLE [dbo].[orders] (
+    [id] BIGINT IDENTITY(10, 5) NOT FOR REPLICATION NOT NULL,
+    [customer_id] INT NOT NULL CONSTRAINT [fk_orders_customer] REFERENCES [dbo].[customers] ([id]) ON DELETE CASCADE,
+    [code] VARCHAR(MAX) COLLATE Latin1_General_CI_AS NULL,
+    [amount] DECIMAL(12, 2) NOT NULL CONSTRAINT [df_orders_amount] DEFAULT (0),
+    [total] AS ([amount] * 2) PERSISTED NOT NULL,
+    CONSTRAINT [pk_orders] PRIMARY KEY NONCLUSTERED ([id] ASC),
+    CONSTRAINT [uq_orders_code] UNIQUE CLUSTERED ([customer_id], [amount]),
+    CONSTRAINT [ck_orders_amount] CHECK ([amount] >= 0),
+    CONSTRAINT [fk_orders_parent] FOREIGN KEY ([customer_id]) REFERENCES [dbo].[customers] ([id]) NOT FOR REPLICATION
+) ON [PRIMARY];";
+
+    let objects = dialect.parse(sql).expect("structured create table");
+
+    assert_eq!(objects.len(), 2);
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    let names = table
+        .columns
+        .iter()
+        .map(|column| column.name.value.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["id", "customer_id", "code", "amount", "total"]);
+
+    let id = &table.columns[0];
+    let identity = id.identity.as_ref().expect("identity column");
+    assert_eq!((identity.start, identity.increment), (Some(10), Some(5)));
+    assert_eq!(
+        id.extra.get("mssql.identity_not_for_replication"),
+        Some(&Value::Bool(true))
+    );
+    assert!(id.not_null);
+
+    let code = &table.columns[2];
+    assert_eq!(code.data_type, DataType::Custom("VARCHAR(MAX)".to_string()));
+    assert_eq!(code.collation.as_deref(), Some("Latin1_General_CI_AS"));
+    assert!(!code.not_null);
+
+    let amount = &table.columns[3];
+    assert_eq!(amount.default, Some(Expr::Raw("(0)".to_string())));
+    assert_eq!(
+        amount.extra.get("mssql.default_constraint_name"),
+        Some(&Value::String("df_orders_amount".to_string()))
+    );
+
+    let total = &table.columns[4];
+    let generated = total.generated.as_ref().expect("computed column");
+    assert_eq!(generated.expr, Expr::Raw("([amount] * 2)".to_string()));
+    assert!(generated.stored);
+    assert!(total.not_null);
+
+    let primary_key = table.primary_key.as_ref().expect("primary key");
+    assert_eq!(primary_key.name, Some(Ident::quoted("pk_orders")));
+    assert_eq!(primary_key.columns, vec![Ident::quoted("id")]);
+    assert_eq!(
+        table.options.extra.get("mssql.primary_key_clustered"),
+        Some(&Value::Bool(false))
+    );
+
+    assert_eq!(table.foreign_keys.len(), 2);
+    assert_eq!(
+        table.foreign_keys[0].name,
+        Some(Ident::quoted("fk_orders_customer"))
+    );
+    assert_eq!(
+        table.foreign_keys[0].on_delete,
+        Some(ForeignKeyAction::Cascade)
+    );
+    assert!(table.foreign_keys[0].extra.is_empty());
+    assert_eq!(
+        table.foreign_keys[1].extra.get("mssql.not_for_replication"),
+        Some(&Value::Bool(true))
+    );
+
+    assert_eq!(table.checks.len(), 1);
+    assert_eq!(
+        table.checks[0].name,
+        Some(Ident::quoted("ck_orders_amount"))
+    );
+
+    let SchemaObject::Index(unique) = &objects[1] else {
+        panic!("expected the unique constraint's index");
+    };
+    assert_eq!(unique.name, Some(Ident::quoted("uq_orders_code")));
+    assert!(unique.unique);
+    assert_eq!(unique.method.as_deref(), Some("CLUSTERED"));
+    assert_eq!(
+        unique.extra.get("mssql.unique_constraint"),
+        Some(&Value::Bool(true))
+    );
+}
+
+#[test]
+fn structured_table_round_trips_through_to_sql() {
+    let dialect = MssqlDialect;
+    let sql = "\
+CREATE TABLE [dbo].[orders] (
+    [id] BIGINT IDENTITY(1, 1) NOT FOR REPLICATION NOT NULL PRIMARY KEY,
+    [code] NVARCHAR(32) NOT NULL CONSTRAINT [uq_orders_code] UNIQUE,
+    [amount] NUMERIC(12, 2) NOT NULL DEFAULT ((0)),
+    [total] AS [amount] * 2
+);";
+
+    let first = canonical_objects(&dialect, sql);
+    let rendered = first
+        .iter()
+        .map(|object| dialect.to_sql(object).expect("render object"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let second = canonical_objects(&dialect, &rendered);
+
+    assert_eq!(first, second, "rendered SQL:\n{rendered}");
+}
+
+#[test]
+fn column_annotations_attach_to_plain_and_computed_columns() {
+    let dialect = MssqlDialect;
+    let sql = "\
+CREATE TABLE users (
+    id INT NOT NULL,
+    full_name NVARCHAR(200) NULL, -- @renamed from=name
+    display AS UPPER(full_name) -- @renamed from=label
+);";
+
+    let objects = dialect.parse(sql).expect("mssql parse pipeline");
+
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    assert_eq!(table.columns[1].renamed_from, Some(Ident::unquoted("name")));
+    assert_eq!(
+        table.columns[2].renamed_from,
+        Some(Ident::unquoted("label"))
+    );
+}
+
+#[test]
+fn unnamed_unique_constraint_is_rejected() {
+    let dialect = MssqlDialect;
+    let sql = "CREATE TABLE users (email NVARCHAR(320) NOT NULL UNIQUE);";
+
+    let error = dialect
+        .parse(sql)
+        .expect_err("unnamed unique constraints cannot be diffed");
+
+    match error {
+        Error::Parse(ParseError::StatementConversion { source, .. }) => {
+            assert!(source.to_string().contains("need a name"), "{source}");
+        }
+        other => panic!("expected parse statement conversion error, got {other:?}"),
+    }
+}
+
+#[test]
+fn exported_schema_with_indexes_and_modules_is_read_back() {
+    let dialect = MssqlDialect;
+    let sql = "\
+CREATE TABLE [dbo].[orders] (
+    [id] BIGINT NOT NULL IDENTITY(1,1),
+    [status] INT NOT NULL CONSTRAINT [df_orders_status] DEFAULT ((0)),
+    [customer_id] INT NOT NULL,
+    CONSTRAINT [pk_orders] PRIMARY KEY CLUSTERED ([id] ASC),
+    CONSTRAINT [uq_orders_customer] UNIQUE NONCLUSTERED ([customer_id]),
+    CONSTRAINT [fk_orders_customer] FOREIGN KEY ([customer_id]) REFERENCES [dbo].[customers] ([id]) ON DELETE CASCADE,
+    CONSTRAINT [ck_orders_status] CHECK ([status]>=(0))
+);
+
+CREATE NONCLUSTERED INDEX [ix_orders_status] ON [dbo].[orders] ([status] DESC, [customer_id]) INCLUDE ([id]) WHERE ([status]=(1));
+GO
+CREATE VIEW [dbo].[open_orders] AS
+SELECT [id] FROM [dbo].[orders] WHERE [status] = 1;
+GO
+CREATE PROCEDURE [dbo].[close_order] @id BIGINT, @reason NVARCHAR(200) = N'done' OUTPUT
+AS
+BEGIN
+    SET NOCOUNT ON;
+    UPDATE [dbo].[orders] SET [status] = 2 WHERE [id] = @id;
+END
+GO
+CREATE FUNCTION [dbo].[order_count](@status INT)
+RETURNS INT
+AS
+BEGIN
+    RETURN (SELECT COUNT(*) FROM [dbo].[orders] WHERE [status] = @status);
+END
+GO
+CREATE TRIGGER [dbo].[trg_orders_audit] ON [dbo].[orders] AFTER INSERT, UPDATE AS
+    INSERT INTO [dbo].[audit] ([order_id]) SELECT [id] FROM inserted;";
+
+    let objects = dialect.parse(sql).expect("exported schema");
+
+    assert_eq!(objects.len(), 7);
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    assert_eq!(
+        table.columns[1].extra.get("mssql.default_constraint_name"),
+        Some(&Value::String("df_orders_status".to_string()))
+    );
+    assert_eq!(table.foreign_keys.len(), 1);
+    assert_eq!(table.checks.len(), 1);
+
+    let SchemaObject::Index(unique) = &objects[1] else {
+        panic!("expected the unique constraint's index");
+    };
+    assert_eq!(unique.name, Some(Ident::quoted("uq_orders_customer")));
+
+    let SchemaObject::Index(index) = &objects[2] else {
+        panic!("expected index object");
+    };
+    assert_eq!(index.name, Some(Ident::quoted("ix_orders_status")));
+    assert_eq!(index.method.as_deref(), Some("NONCLUSTERED"));
+    assert_eq!(
+        index.columns[0].expr,
+        Expr::Raw("[status] DESC".to_string())
+    );
+    assert_eq!(
+        index.extra.get("mssql.include_columns"),
+        Some(&Value::String("id".to_string()))
+    );
+    assert!(index.where_clause.is_some());
+
+    let SchemaObject::View(view) = &objects[3] else {
+        panic!("expected view object");
+    };
+    assert_eq!(view.name.name, Ident::quoted("open_orders"));
+    assert_eq!(
+        view.query,
+        "SELECT [id] FROM [dbo].[orders] WHERE [status] = 1"
+    );
+
+    let SchemaObject::Function(procedure) = &objects[4] else {
+        panic!("expected procedure object");
+    };
+    assert_eq!(procedure.return_type, None);
+    assert_eq!(procedure.params.len(), 2);
+    assert_eq!(
+        procedure.params[1].default,
+        Some(Expr::Raw("N'done'".to_string()))
+    );
+    assert!(procedure.body.starts_with("BEGIN"));
+    assert!(procedure.body.ends_with("END"));
+
+    let SchemaObject::Function(function) = &objects[5] else {
+        panic!("expected function object");
+    };
+    assert_eq!(
+        function.return_type,
+        Some(DataType::Custom("INT".to_string()))
+    );
+    assert_eq!(function.params.len(), 1);
+
+    let SchemaObject::Trigger(trigger) = &objects[6] else {
+        panic!("expected trigger object");
+    };
+    assert_eq!(trigger.table.name, Ident::quoted("orders"));
+    assert_eq!(trigger.events.len(), 2);
+    assert_eq!(
+        trigger.body,
+        "INSERT INTO [dbo].[audit] ([order_id]) SELECT [id] FROM inserted"
+    );
+}
+
+#[test]
+fn modules_round_trip_through_to_sql() {
+    let dialect = MssqlDialect;
+    let sql = "\
+CREATE VIEW dbo.active_users (id) AS SELECT id FROM dbo.users WHERE active = 1
+GO
+CREATE PROC dbo.touch_user (@id INT) AS UPDATE dbo.users SET touched = 1 WHERE id = @id
+GO
+CREATE FUNCTION dbo.users_by_state(@state INT) RETURNS TABLE AS RETURN (SELECT id FROM dbo.users WHERE state = @state)
+GO
+CREATE TRIGGER dbo.trg_users ON dbo.users INSTEAD OF DELETE AS UPDATE dbo.users SET active = 0 WHERE id IN (SELECT id FROM deleted)";
+
+    let first = canonical_objects(&dialect, sql);
+    let rendered = first
+        .iter()
+        .map(|object| dialect.to_sql(object).expect("render object"))
+        .collect::<Vec<_>>()
+        .join("\nGO\n");
+    let second = canonical_objects(&dialect, &rendered);
+
+    assert_eq!(first, second, "rendered SQL:\n{rendered}");
+}
+
+#[test]
+fn module_errors_report_the_line_of_their_batch() {
+    let dialect = MssqlDialect;
+    let sql = "CREATE TABLE users (id INT);\nGO\nCREATE VIEW v WITH SCHEMABINDING AS SELECT id FROM dbo.users;";
+
+    let error = dialect
+        .parse(sql)
+        .expect_err("view options are not supported");
+
+    match error {
+        Error::Parse(ParseError::StatementConversion {
+            statement_index,
+            source_location,
+            source,
+            ..
+        }) => {
+            assert_eq!(statement_index, 1);
+            assert_eq!(
+                source_location.as_ref().map(|location| location.line),
+                Some(3)
+            );
+            assert!(source.to_string().contains("WITH"), "{source}");
+        }
+        other => panic!("expected parse statement conversion error, got {other:?}"),
+    }
+}
+
+#[test]
+fn columnstore_and_filtered_indexes_round_trip_through_to_sql() {
+    let dialect = MssqlDialect;
+    let sql = "\
+CREATE CLUSTERED COLUMNSTORE INDEX [cci_events] ON [dbo].[events];
+CREATE NONCLUSTERED COLUMNSTORE INDEX ncci_orders ON dbo.orders (status, total) WHERE status <> 0;
+CREATE UNIQUE INDEX ix_orders_open ON dbo.orders (customer_id, created_at DESC) INCLUDE (total) WHERE status = 1;";
+
+    let first = canonical_objects(&dialect, sql);
+    let SchemaObject::Index(clustered) = &first[0] else {
+        panic!("expected index object");
+    };
+    assert!(clustered.columns.is_empty());
+    assert_eq!(clustered.method.as_deref(), Some("CLUSTERED"));
+    assert_eq!(
+        clustered.extra.get("mssql.columnstore"),
+        Some(&Value::Bool(true))
+    );
+    let SchemaObject::Index(nonclustered) = &first[1] else {
+        panic!("expected index object");
+    };
+    assert_eq!(nonclustered.columns.len(), 2);
+    assert_eq!(
+        nonclustered.where_clause,
+        Some(Expr::Raw("status<>0".to_string()))
+    );
+
+    let rendered = first
+        .iter()
+        .map(|object| dialect.to_sql(object).expect("render object"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert!(
+        rendered.contains("CREATE CLUSTERED COLUMNSTORE INDEX [cci_events] ON [dbo].[events];"),
+        "{rendered}"
+    );
+    let second = canonical_objects(&dialect, &rendered);
+
+    assert_eq!(first, second, "rendered SQL:\n{rendered}");
+}
+
+#[test]
+fn exported_filtered_index_normalizes_like_the_hand_written_one() {
+    let dialect = MssqlDialect;
+    let hand_written = "CREATE INDEX ix_orders_status ON dbo.orders (Status DESC, customer_id) INCLUDE (Id) WHERE status = 1 AND note IS NOT NULL;";
+    let exported = "CREATE NONCLUSTERED INDEX [ix_orders_status] ON [dbo].[orders] ([status] DESC, [customer_id]) INCLUDE ([id]) WHERE ([status]=(1) AND [note] IS NOT NULL);";
+
+    assert_eq!(
+        canonical_objects(&dialect, hand_written),
+        canonical_objects(&dialect, exported)
+    );
+}
+
+#[test]
+fn sequences_synonyms_and_table_types_round_trip_through_to_sql() {
+    let dialect = MssqlDialect;
+    let sql = "\
+CREATE SEQUENCE dbo.order_numbers AS int INCREMENT BY 10 NO CACHE MINVALUE -100 START WITH 5 CYCLE;
+CREATE SYNONYM dbo.customers FOR crm.dbo.[customers];
+CREATE TYPE dbo.order_lines AS TABLE (
+    line_id int NOT NULL,
+    sku nvarchar(40) NOT NULL DEFAULT '',
+    quantity int NOT NULL,
+    PRIMARY KEY (line_id),
+    CHECK (quantity > 0)
+);";
+
+    let first = canonical_objects(&dialect, sql);
+    let SchemaObject::Sequence(sequence) = &first[0] else {
+        panic!("expected sequence object");
+    };
+    assert_eq!(sequence.data_type, Some(DataType::Integer));
+    assert_eq!(sequence.increment, Some(10));
+    assert_eq!(sequence.start, Some(5));
+    assert_eq!(sequence.min_value, Some(-100));
+    assert_eq!(sequence.max_value, Some(i64::from(i32::MAX)));
+    assert_eq!(sequence.cache, Some(0));
+    assert!(sequence.cycle);
+    let SchemaObject::Synonym(synonym) = &first[1] else {
+        panic!("expected synonym object");
+    };
+    assert_eq!(
+        synonym
+            .target
+            .iter()
+            .map(|part| part.value.as_str())
+            .collect::<Vec<_>>(),
+        vec!["crm", "dbo", "customers"]
+    );
+    let SchemaObject::Type(type_def) = &first[2] else {
+        panic!("expected table type object");
+    };
+    let TypeKind::Table {
+        columns,
+        primary_key,
+        checks,
+    } = &type_def.kind
+    else {
+        panic!("expected table type kind");
+    };
+    assert_eq!(columns.len(), 3);
+    assert!(primary_key.is_some());
+    assert_eq!(checks.len(), 1);
+
+    let rendered = first
+        .iter()
+        .map(|object| dialect.to_sql(object).expect("render object"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert!(
+        rendered.contains("CREATE SYNONYM [dbo].[customers] FOR [crm].[dbo].[customers];"),
+        "{rendered}"
+    );
+    let second = canonical_objects(&dialect, &rendered);
+
+    assert_eq!(first, second, "rendered SQL:\n{rendered}");
+}
+
+#[test]
+fn exported_sequence_normalizes_like_the_hand_written_one() {
+    let dialect = MssqlDialect;
+    let hand_written = "CREATE SEQUENCE dbo.invoice_numbers START WITH 1000;";
+    let exported = "CREATE SEQUENCE [dbo].[invoice_numbers] AS BIGINT START WITH 1000 INCREMENT BY 1 MINVALUE -9223372036854775808 MAXVALUE 9223372036854775807 NO CYCLE;";
+
+    assert_eq!(
+        canonical_objects(&dialect, hand_written),
+        canonical_objects(&dialect, exported)
+    );
+}
+
+#[test]
+fn named_table_type_constraints_are_rejected() {
+    let dialect = MssqlDialect;
+    let sql = "CREATE TYPE dbo.ids AS TABLE (id int NOT NULL, CONSTRAINT pk_ids PRIMARY KEY (id));";
+
+    let error = dialect
+        .parse(sql)
+        .expect_err("named table type constraints should fail fast");
+
+    assert!(
+        matches!(error, Error::Parse(ParseError::StatementConversion { .. })),
+        "unexpected error: {error:?}"
+    );
+}
+
+#[test]
+fn temporal_table_round_trips_through_to_sql() {
+    let dialect = MssqlDialect;
+    let sql = "\
+CREATE TABLE dbo.accounts (
+    id int NOT NULL PRIMARY KEY,
+    balance decimal(12, 2) NOT NULL,
+    valid_from datetime2 GENERATED ALWAYS AS ROW START HIDDEN NOT NULL,
+    valid_to datetime2 GENERATED ALWAYS AS ROW END NOT NULL,
+    PERIOD FOR SYSTEM_TIME (valid_from, valid_to)
+) WITH (SYSTEM_VERSIONING = ON (HISTORY_TABLE = dbo.accounts_history, DATA_CONSISTENCY_CHECK = ON));";
+
+    let first = canonical_objects(&dialect, sql);
+    let SchemaObject::Table(table) = &first[0] else {
+        panic!("expected table object");
+    };
+    assert_eq!(
+        table
+            .options
+            .extra
+            .get(extra_keys::mssql::TABLE_PERIOD_START),
+        Some(&Value::String("valid_from".to_string()))
+    );
+    assert_eq!(
+        table
+            .options
+            .extra
+            .get(extra_keys::mssql::TABLE_SYSTEM_VERSIONING),
+        Some(&Value::Bool(true))
+    );
+    assert_eq!(
+        table
+            .options
+            .extra
+            .get(extra_keys::mssql::TABLE_HISTORY_TABLE),
+        Some(&Value::String("dbo.accounts_history".to_string()))
+    );
+
+    let rendered = dialect.to_sql(&first[0]).expect("render table");
+    assert!(
+        rendered.contains("GENERATED ALWAYS AS ROW START HIDDEN"),
+        "{rendered}"
+    );
+    assert!(
+        rendered
+            .contains("WITH (SYSTEM_VERSIONING = ON (HISTORY_TABLE = [dbo].[accounts_history]))"),
+        "{rendered}"
+    );
+    let second = canonical_objects(&dialect, &rendered);
+
+    assert_eq!(first, second, "rendered SQL:\n{rendered}");
+}
+
+#[test]
+fn exported_temporal_table_normalizes_like_the_hand_written_one() {
+    let dialect = MssqlDialect;
+    let hand_written = "\
+CREATE TABLE dbo.accounts (
+    id int NOT NULL,
+    valid_from datetime2 GENERATED ALWAYS AS ROW START,
+    valid_to datetime2 GENERATED ALWAYS AS ROW END,
+    PERIOD FOR SYSTEM_TIME (Valid_From, Valid_To)
+) WITH (SYSTEM_VERSIONING = ON (HISTORY_TABLE = [Dbo].Accounts_History));";
+    let exported = "\
+CREATE TABLE [dbo].[accounts] (
+    [id] int NOT NULL,
+    [valid_from] datetime2 GENERATED ALWAYS AS ROW START NOT NULL,
+    [valid_to] datetime2 GENERATED ALWAYS AS ROW END NOT NULL,
+    PERIOD FOR SYSTEM_TIME ([valid_from], [valid_to])
+) WITH (SYSTEM_VERSIONING = ON (HISTORY_TABLE = [dbo].[accounts_history]));";
+
+    assert_eq!(
+        canonical_objects(&dialect, hand_written),
+        canonical_objects(&dialect, exported)
+    );
+}
+
+#[test]
+fn descriptions_are_read_from_extended_properties() {
+    let dialect = MssqlDialect;
+    let sql = "\
+CREATE TABLE dbo.users (id int NOT NULL, email nvarchar(320) NULL);
+GO
+EXEC sys.sp_addextendedproperty @name = N'MS_Description', @value = N'People who can sign in',
+    @level0type = N'SCHEMA', @level0name = N'dbo', @level1type = N'TABLE', @level1name = N'users';
+EXEC sp_addextendedproperty N'MS_Description', N'Login, it''s unique', N'SCHEMA', N'dbo',
+    N'TABLE', N'Users', N'COLUMN', N'Email';";
+
+    let first = canonical_objects(&dialect, sql);
+    let comments = first
+        .iter()
+        .filter_map(|object| match object {
+            SchemaObject::Comment(comment) => Some(comment),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(comments.len(), 2);
+    assert_eq!(
+        comments[0].target,
+        CommentTarget::Table(QualifiedName {
+            schema: Some(Ident::unquoted("dbo")),
+            name: Ident::unquoted("users"),
+        })
+    );
+    assert_eq!(
+        comments[1].target,
+        CommentTarget::Column {
+            table: QualifiedName {
+                schema: Some(Ident::unquoted("dbo")),
+                name: Ident::unquoted("users"),
+            },
+            column: Ident::unquoted("email"),
+        }
+    );
+    assert_eq!(comments[1].text.as_deref(), Some("Login, it's unique"));
+
+    let rendered = first
+        .iter()
+        .map(|object| dialect.to_sql(object).expect("render object"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let second = canonical_objects(&dialect, &rendered);
+
+    assert_eq!(first, second, "rendered SQL:\n{rendered}");
+}
+
+#[test]
+fn unsupported_extended_properties_are_rejected() {
+    let dialect = MssqlDialect;
+    for sql in [
+        "EXEC sp_addextendedproperty @name = N'Owner', @value = N'billing', @level0type = N'SCHEMA', @level0name = N'dbo', @level1type = N'TABLE', @level1name = N'users';",
+        "EXEC sp_addextendedproperty @name = N'MS_Description', @value = N'Tax', @level0type = N'SCHEMA', @level0name = N'dbo', @level1type = N'FUNCTION', @level1name = N'tax';",
+    ] {
+        let error = dialect
+            .parse(sql)
+            .expect_err("unsupported extended property should fail fast");
+
+        assert!(
+            matches!(error, Error::Parse(ParseError::StatementConversion { .. })),
+            "unexpected error: {error:?}"
+        );
+    }
+}
+
+#[test]
+fn scripted_batches_split_only_on_go_lines_outside_strings_and_comments() {
+    let dialect = MssqlDialect;
+    let sql = "\
+SET ANSI_NULLS ON
+GO
+SET QUOTED_IDENTIFIER ON
+GO -- scripted by SSMS
+/****** Object:  StoredProcedure [dbo].[archive_orders]
+GO
+******/
+CREATE PROCEDURE dbo.archive_orders
+AS
+BEGIN
+    SET NOCOUNT ON;
+    INSERT INTO dbo.audit (note) VALUES ('archived
+GO
+');
+    DELETE FROM dbo.orders WHERE archived = 1;
+END
+GO
+CREATE TABLE dbo.audit (note nvarchar(200) NULL);
+go
+";
+
+    let objects = dialect.parse(sql).expect("parse scripted batches");
+
+    assert_eq!(objects.len(), 2, "{objects:#?}");
+    let SchemaObject::Function(procedure) = &objects[0] else {
+        panic!("expected procedure object, got {:?}", objects[0]);
+    };
+    assert!(
+        procedure.body.contains("archived\nGO\n"),
+        "{}",
+        procedure.body
+    );
+    assert!(
+        procedure.body.contains("DELETE FROM dbo.orders"),
+        "{}",
+        procedure.body
+    );
+    assert!(matches!(objects[1], SchemaObject::Table(_)));
+}
+
+#[test]
+fn parse_errors_report_the_line_of_the_failing_statement() {
+    let dialect = MssqlDialect;
+    let sql = "CREATE TABLE a (id int);\n-- trailing note\nGO\n-- leading note\nCREATE TABLE b (id int,, name int);";
+
+    let error = dialect.parse(sql).expect_err("syntax error should fail");
+
+    match error {
+        Error::Parse(ParseError::StatementConversion {
+            statement_index,
+            source_sql,
+            source_location,
+            ..
+        }) => {
+            assert_eq!(statement_index, 1);
+            assert!(source_sql.contains("CREATE TABLE b"), "{source_sql}");
+            assert_eq!(
+                source_location.as_ref().map(|location| location.line),
+                Some(5)
+            );
+        }
+        other => panic!("expected parse statement conversion error, got {other:?}"),
+    }
+}
+
+#[test]
+fn module_after_another_statement_in_its_batch_is_rejected() {
+    let dialect = MssqlDialect;
+    let sql = "CREATE TABLE a (id int);\nCREATE VIEW v AS SELECT id FROM a;";
+
+    let error = dialect.parse(sql).expect_err("a view must start its batch");
+
+    match error {
+        Error::Parse(ParseError::StatementConversion {
+            source_location,
+            source,
+            ..
+        }) => {
+            assert_eq!(
+                source_location.as_ref().map(|location| location.line),
+                Some(2)
+            );
+            assert!(source.to_string().contains("GO"), "{source}");
+        }
+        other => panic!("expected parse statement conversion error, got {other:?}"),
+    }
+}
+
+fn canonical_objects(dialect: &MssqlDialect, sql: &str) -> Vec<SchemaObject> {
+    let mut objects = dialect.parse(sql).expect("parse mssql schema");
+    for object in &mut objects {
+        dialect.normalize(object);
+    }
+    objects
+}
+
+#[test]
+fn sequence_defaults_are_read_as_written() {
+    let dialect = MssqlDialect;
+    let sql = "CREATE TABLE [dbo].[Orders] (\n  [Id] INT NOT NULL CONSTRAINT [DF_Orders_Id] DEFAULT ((NEXT VALUE FOR [dbo].[order_numbers])),\n  [Ref] INT DEFAULT NEXT VALUE FOR order_refs NOT NULL\n);";
+
+    let objects = dialect.parse(sql).expect("mssql parse pipeline");
+
+    let [SchemaObject::Table(table)] = objects.as_slice() else {
+        panic!("expected one table, got {objects:?}");
+    };
+    let id = &table.columns[0];
+    assert!(id.not_null);
+    assert_eq!(
+        id.default,
+        Some(Expr::Raw(
+            "((NEXT VALUE FOR [dbo].[order_numbers]))".to_string()
+        ))
+    );
+    assert_eq!(
+        id.extra.get(extra_keys::mssql::DEFAULT_CONSTRAINT_NAME),
+        Some(&Value::String("DF_Orders_Id".to_string()))
+    );
+    let reference = &table.columns[1];
+    assert!(reference.not_null);
+    assert_eq!(
+        reference.default,
+        Some(Expr::Raw("NEXT VALUE FOR order_refs".to_string()))
+    );
+    assert_eq!(
+        reference
+            .extra
+            .get(extra_keys::mssql::DEFAULT_CONSTRAINT_NAME),
+        None
+    );
+
+    let rendered = dialect.to_sql(&objects[0]).expect("to_sql should succeed");
+    assert_eq!(
+        dialect.parse(&rendered).expect("rendered table reads back"),
+        objects,
+        "{rendered}"
+    );
+}
@@ -6,10 +6,11 @@ use std::{
 
 use mysql::{OptsBuilder, Pool, PooledConn, Row, prelude::Queryable};
 use stateql_core::{
-    ConnectionConfig, DatabaseAdapter, ExecutionError, Result, Transaction, Version,
+    ConnectionConfig, DatabaseAdapter, ExecutionError, Ident, Result, SchemaObject, Transaction,
+    Version,
 };
 
-use crate::export_queries;
+use crate::{DIALECT_NAME, export_queries, grants, to_sql};
 
 const BEGIN_SQL: &str = "BEGIN";
 const CONNECT_SQL: &str = "CONNECT mysql";
@@ -19,12 +20,15 @@ const MINIMUM_MYSQL_MAJOR_VERSION: u16 = 8;
 const MINIMUM_MYSQL_MINOR_VERSION: u16 = 0;
 const SERVER_VERSION_OVERRIDE_KEY: &str = "mysql.server_version";
 const LOWER_CASE_TABLE_NAMES_OVERRIDE_KEY: &str = "mysql.lower_case_table_names";
+const GRANT_ACCOUNTS_KEY: &str = "mysql.grant_accounts";
+const DEFINER_CLAUSE: &str = "DEFINER=";
 const POISONED_CONNECTION_MESSAGE: &str = "mysql connection state was poisoned";
 
 pub(crate) struct MysqlAdapter {
     connection: Mutex<PooledConn>,
     default_schema: String,
     server_version: Version,
+    grant_accounts: Vec<Ident>,
 }
 
 pub(crate) fn connect(config: &ConnectionConfig) -> Result<Box<dyn DatabaseAdapter>> {
@@ -34,6 +38,7 @@ pub(crate) fn connect(config: &ConnectionConfig) -> Result<Box<dyn DatabaseAdapt
         ensure_minimum_version(&version, raw_version)?;
     }
 
+    let grant_accounts = parse_grant_accounts(config)?;
     let mut connection = connect_connection(config)?;
     let server_version_raw =
         if let Some(raw_version) = config.extra.get(SERVER_VERSION_OVERRIDE_KEY) {
@@ -59,6 +64,7 @@ pub(crate) fn connect(config: &ConnectionConfig) -> Result<Box<dyn DatabaseAdapt
         connection: Mutex::new(connection),
         default_schema: config.database.clone(),
         server_version,
+        grant_accounts,
     }))
 }
 
@@ -79,8 +85,15 @@ impl DatabaseAdapter for MysqlAdapter {
         for table_name in table_names {
             statements.push(export_table_ddl(&mut connection, &table_name)?);
         }
+        statements.extend(export_routines(&mut connection)?);
         statements.extend(export_views(&mut connection)?);
         statements.extend(export_triggers(&mut connection)?);
+        statements.extend(export_events(&mut connection)?);
+        statements.extend(export_grants(
+            &mut connection,
+            &self.grant_accounts,
+            &self.default_schema,
+        )?);
 
         Ok(statements.join("\n\n"))
     }
@@ -175,6 +188,143 @@ fn export_table_ddl(connection: &mut PooledConn, table_name: &str) -> Result<Str
     Ok(ensure_statement_terminated(ddl))
 }
 
+fn export_routines(connection: &mut PooledConn) -> Result<Vec<String>> {
+    let query = export_queries::ROUTINES_QUERY;
+    let rows = connection
+        .query::<Row, _>(query)
+        .map_err(|source| execution_error(query, source))?;
+
+    let mut statements = Vec::with_capacity(rows.len());
+    for row in &rows {
+        let routine_name = row_string(row, 0, query, "ROUTINE_NAME")?;
+        let routine_type = row_string(row, 1, query, "ROUTINE_TYPE")?;
+        let routine_type = routine_type.trim().to_ascii_uppercase();
+        let show_query = format!(
+            "SHOW CREATE {routine_type} {}",
+            quote_identifier(routine_name.as_str())
+        );
+        let label = if routine_type == "PROCEDURE" {
+            "Create Procedure"
+        } else {
+            "Create Function"
+        };
+        statements.push(show_create_statement(connection, &show_query, 2, label)?);
+    }
+    Ok(statements)
+}
+
+fn export_events(connection: &mut PooledConn) -> Result<Vec<String>> {
+    let query = export_queries::EVENTS_QUERY;
+    let rows = connection
+        .query::<Row, _>(query)
+        .map_err(|source| execution_error(query, source))?;
+
+    let mut statements = Vec::with_capacity(rows.len());
+    for row in &rows {
+        let event_name = row_string(row, 0, query, "EVENT_NAME")?;
+        let show_query = format!(
+            "SHOW CREATE EVENT {}",
+            quote_identifier(event_name.as_str())
+        );
+        statements.push(show_create_statement(
+            connection,
+            &show_query,
+            3,
+            "Create Event",
+        )?);
+    }
+    Ok(statements)
+}
+
+fn show_create_statement(
+    connection: &mut PooledConn,
+    query: &str,
+    index: usize,
+    label: &str,
+) -> Result<String> {
+    let row = connection
+        .query_first::<Row, _>(query)
+        .map_err(|source| execution_error(query, source))?
+        .ok_or_else(|| execution_error(query, io::Error::other("query returned no rows")))?;
+    // The definition is NULL when the connected user lacks privileges on the object.
+    let ddl = row_string(&row, index, query, label)?;
+    Ok(ensure_statement_terminated(strip_definer_clause(&ddl)))
+}
+
+fn export_grants(
+    connection: &mut PooledConn,
+    accounts: &[Ident],
+    database: &str,
+) -> Result<Vec<String>> {
+    let mut statements = Vec::new();
+    for account in accounts {
+        let query = format!("SHOW GRANTS FOR {}", grants::render_account(account));
+        let rows = connection
+            .query::<Row, _>(query.as_str())
+            .map_err(|source| execution_error(&query, source))?;
+        for row in &rows {
+            let grant = row_string(row, 0, &query, "Grants")?;
+            let Some(privilege) = grants::privilege_from_show_grants(&grant, database) else {
+                continue;
+            };
+            statements.push(to_sql::render_object(
+                DIALECT_NAME,
+                &SchemaObject::Privilege(privilege),
+            )?);
+        }
+    }
+    Ok(statements)
+}
+
+/// Drops the `DEFINER=user@host` clause so exported routines and events do not
+/// pin the account that happened to create them.
+fn strip_definer_clause(sql: &str) -> String {
+    let sql = sql.trim();
+    let upper = sql.to_ascii_uppercase();
+    let Some(start) = upper.find(DEFINER_CLAUSE) else {
+        return sql.to_string();
+    };
+
+    let mut quote = None;
+    let mut end = sql.len();
+    for (offset, ch) in sql[start..].char_indices() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"' | '`') => quote = Some(ch),
+            (None, _) if ch.is_whitespace() => {
+                end = start + offset;
+                break;
+            }
+            (None, _) => {}
+        }
+    }
+
+    format!("{}{}", &sql[..start], sql[end..].trim_start())
+}
+
+fn parse_grant_accounts(config: &ConnectionConfig) -> Result<Vec<Ident>> {
+    let Some(raw_accounts) = config.extra.get(GRANT_ACCOUNTS_KEY) else {
+        return Ok(Vec::new());
+    };
+
+    raw_accounts
+        .split(',')
+        .filter(|raw| !raw.trim().is_empty())
+        .map(|raw| {
+            grants::parse_account(raw).ok_or_else(|| {
+                execution_error(
+                    CONNECT_SQL,
+                    io::Error::other(format!(
+                        "invalid mysql grant account `{}`; expected user@host",
+                        raw.trim()
+                    )),
+                )
+            })
+        })
+        .collect()
+}
+
 fn export_views(connection: &mut PooledConn) -> Result<Vec<String>> {
     let query = export_queries::VIEWS_QUERY;
     let rows = connection
@@ -24,3 +24,17 @@ FROM INFORMATION_SCHEMA.TRIGGERS
 WHERE TRIGGER_SCHEMA = DATABASE()
 ORDER BY TRIGGER_NAME ASC;
 "#;
+
+pub(crate) const ROUTINES_QUERY: &str = r#"
+SELECT ROUTINE_NAME, ROUTINE_TYPE
+FROM INFORMATION_SCHEMA.ROUTINES
+WHERE ROUTINE_SCHEMA = DATABASE()
+ORDER BY ROUTINE_TYPE ASC, ROUTINE_NAME ASC;
+"#;
+
+pub(crate) const EVENTS_QUERY: &str = r#"
+SELECT EVENT_NAME
+FROM INFORMATION_SCHEMA.EVENTS
+WHERE EVENT_SCHEMA = DATABASE()
+ORDER BY EVENT_NAME ASC;
+"#;
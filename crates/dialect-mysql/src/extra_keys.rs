@@ -1,6 +1,20 @@
+use stateql_core::extra_keys::mysql;
+
 pub(crate) const TABLE_SOURCE_SQL: &str = "mysql.source_sql";
 pub(crate) const TABLE_PARTITION_SQL: &str = "mysql.partition_sql";
 pub(crate) const TABLE_HAS_CHANGE_COLUMN: &str = "mysql.has_change_column";
 pub(crate) const TABLE_HAS_AFTER_CLAUSE: &str = "mysql.has_after_clause";
 pub(crate) const TABLE_HAS_AUTO_INCREMENT: &str = "mysql.has_auto_increment";
 pub(crate) const TABLE_HAS_PARTITIONING: &str = "mysql.has_partitioning";
+pub(crate) const TABLE_AUTO_INCREMENT_VALUE: &str = "mysql.table_auto_increment";
+pub(crate) const COLUMN_CHARSET: &str = "mysql.column_charset";
+pub(crate) const COLUMN_ON_UPDATE: &str = "mysql.column_on_update";
+
+/// Table options compared by the diff engine.
+pub(crate) const DIFFED_TABLE_OPTIONS: [&str; 5] = [
+    mysql::TABLE_ENGINE,
+    mysql::TABLE_CHARSET,
+    mysql::TABLE_COLLATE,
+    mysql::TABLE_ROW_FORMAT,
+    mysql::TABLE_COMMENT,
+];
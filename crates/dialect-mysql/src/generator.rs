@@ -1,13 +1,13 @@
-use std::fmt::Write as _;
+use std::{cmp::Ordering, fmt::Write as _};
 
 use stateql_core::{
     CheckConstraint, Column, ColumnChange, ColumnPosition, DataType, DiffOp, Expr, ForeignKey,
     ForeignKeyAction, GenerateError, GeneratedColumn, Ident, Identity, IndexDef, IndexOwner,
     Partition, PartitionBound, PartitionElement, PartitionStrategy, PrimaryKey, QualifiedName,
-    Result, SchemaObject, Statement, Value,
+    Result, SchemaObject, Statement, TableOptions, Value, extra_keys::mysql as mysql_keys,
 };
 
-use crate::to_sql;
+use crate::{extra_keys, normalize::table_options as table_option_defaults, to_sql};
 
 const GENERATOR_TARGET: &str = "mysql ddl generator";
 const MYSQL_SUBPARTITIONS_KEY: &str = "mysql.subpartitions";
@@ -92,6 +92,8 @@ fn table_scoped_target(op: &DiffOp) -> Option<&QualifiedName> {
         | DiffOp::DropPrimaryKey { table }
         | DiffOp::AddPartition { table, .. }
         | DiffOp::DropPartition { table, .. }
+        | DiffOp::ReorganizePartition { table, .. }
+        | DiffOp::ChangePartitionCount { table, .. }
         | DiffOp::AlterTableOptions { table, .. } => Some(table),
         DiffOp::AddIndex(IndexDef { owner, .. })
         | DiffOp::DropIndex { owner, .. }
@@ -111,6 +113,7 @@ fn emit_table_batch(
 ) -> Result<()> {
     let mut pre_pk_statements = Vec::new();
     let mut pk_statements = Vec::new();
+    let mut table_option_statements = Vec::new();
     let mut merged_alter_columns = Vec::new();
 
     for op in ops {
@@ -301,8 +304,53 @@ fn emit_table_batch(
                     true,
                 ));
             }
-            DiffOp::AlterTableOptions { .. } => {
-                return Err(unsupported_diff_op(dialect_name, op, GENERATOR_TARGET));
+            DiffOp::ReorganizePartition {
+                table: target_table,
+                from,
+                into,
+            } => {
+                ensure_same_table(op, table, target_table, dialect_name)?;
+                pre_pk_statements.push(sql_statement(
+                    render_reorganize_partition(target_table, from, into),
+                    true,
+                ));
+            }
+            DiffOp::ChangePartitionCount {
+                table: target_table,
+                from,
+                to,
+            } => {
+                ensure_same_table(op, table, target_table, dialect_name)?;
+                let target = to_sql::render_qualified_name(target_table);
+                let sql = match to.cmp(from) {
+                    Ordering::Greater => {
+                        format!(
+                            "ALTER TABLE {target} ADD PARTITION PARTITIONS {};",
+                            to - from
+                        )
+                    }
+                    Ordering::Less => {
+                        format!("ALTER TABLE {target} COALESCE PARTITION {};", from - to)
+                    }
+                    Ordering::Equal => {
+                        return Err(unsupported_diff_op(
+                            dialect_name,
+                            op,
+                            format!("{GENERATOR_TARGET}: {target} already has {to} partitions"),
+                        ));
+                    }
+                };
+                pre_pk_statements.push(sql_statement(sql, true));
+            }
+            DiffOp::AlterTableOptions {
+                table: target_table,
+                options,
+            } => {
+                ensure_same_table(op, table, target_table, dialect_name)?;
+                table_option_statements.push(sql_statement(
+                    render_alter_table_options(target_table, options, dialect_name, op)?,
+                    true,
+                ));
             }
             _ => return Err(unsupported_diff_op(dialect_name, op, GENERATOR_TARGET)),
         }
@@ -323,6 +371,7 @@ fn emit_table_batch(
     out.extend(regular_change_column);
     out.extend(pk_statements);
     out.extend(auto_increment_change_column);
+    out.extend(table_option_statements);
     Ok(())
 }
 
@@ -380,9 +429,48 @@ fn emit_non_table_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>)
                 true,
             ));
         }
-        DiffOp::DropFunction(name) => {
+        DiffOp::DropFunction { name, procedure } => {
+            let kind = if *procedure { "PROCEDURE" } else { "FUNCTION" };
+            out.push(sql_statement(
+                format!(
+                    "DROP {kind} IF EXISTS {};",
+                    to_sql::render_qualified_name(name)
+                ),
+                true,
+            ));
+        }
+        DiffOp::CreateEvent(event) => {
+            out.push(sql_statement(
+                render_object_sql(dialect_name, op, &SchemaObject::Event(event.clone()))?,
+                true,
+            ));
+        }
+        DiffOp::DropEvent(name) => {
+            out.push(sql_statement(
+                format!(
+                    "DROP EVENT IF EXISTS {};",
+                    to_sql::render_qualified_name(name)
+                ),
+                true,
+            ));
+        }
+        DiffOp::Grant(privilege) => {
+            out.push(sql_statement(
+                render_object_sql(
+                    dialect_name,
+                    op,
+                    &SchemaObject::Privilege(privilege.clone()),
+                )?,
+                true,
+            ));
+        }
+        DiffOp::Revoke(privilege) => {
             out.push(sql_statement(
-                format!("DROP FUNCTION {};", to_sql::render_qualified_name(name)),
+                rendered_for_op(
+                    dialect_name,
+                    op,
+                    to_sql::render_revoke(dialect_name, privilege),
+                )?,
                 true,
             ));
         }
@@ -450,18 +538,22 @@ fn render_add_column(
         render_column_definition(column)
     );
     if let Some(position) = position {
-        match position {
-            ColumnPosition::First => sql.push_str(" FIRST"),
-            ColumnPosition::After(column_name) => {
-                write!(sql, " AFTER {}", to_sql::render_ident(column_name))
-                    .expect("writing to String should not fail");
-            }
-        }
+        push_column_position(&mut sql, position);
     }
     sql.push(';');
     sql
 }
 
+fn push_column_position(sql: &mut String, position: &ColumnPosition) {
+    match position {
+        ColumnPosition::First => sql.push_str(" FIRST"),
+        ColumnPosition::After(column_name) => {
+            write!(sql, " AFTER {}", to_sql::render_ident(column_name))
+                .expect("writing to String should not fail");
+        }
+    }
+}
+
 fn render_set_primary_key(table: &QualifiedName, pk: &PrimaryKey) -> String {
     format!(
         "ALTER TABLE {} ADD PRIMARY KEY ({});",
@@ -474,6 +566,69 @@ fn render_set_primary_key(table: &QualifiedName, pk: &PrimaryKey) -> String {
     )
 }
 
+fn render_alter_table_options(
+    table: &QualifiedName,
+    options: &TableOptions,
+    dialect_name: &str,
+    op: &DiffOp,
+) -> Result<String> {
+    let mut clauses = Vec::new();
+
+    if let Some(engine) = options.extra.get(mysql_keys::TABLE_ENGINE) {
+        let engine = option_text(engine).unwrap_or("InnoDB");
+        clauses.push(format!("ENGINE={engine}"));
+    }
+
+    let charset = options.extra.get(mysql_keys::TABLE_CHARSET);
+    let collation = options.extra.get(mysql_keys::TABLE_COLLATE);
+    if charset.is_some() || collation.is_some() {
+        let collation = collation.and_then(option_text);
+        let charset = charset
+            .and_then(option_text)
+            .or_else(|| collation.map(table_option_defaults::charset_of_collation))
+            .unwrap_or(table_option_defaults::DEFAULT_CHARSET);
+        let mut clause = format!("CONVERT TO CHARACTER SET {charset}");
+        if let Some(collation) = collation {
+            write!(clause, " COLLATE {collation}").expect("writing to String should not fail");
+        }
+        clauses.push(clause);
+    }
+
+    if let Some(row_format) = options.extra.get(mysql_keys::TABLE_ROW_FORMAT) {
+        let row_format = option_text(row_format).unwrap_or("DEFAULT");
+        clauses.push(format!("ROW_FORMAT={row_format}"));
+    }
+
+    if let Some(comment) = options.extra.get(mysql_keys::TABLE_COMMENT) {
+        let comment = option_text(comment).unwrap_or_default();
+        clauses.push(format!(
+            "COMMENT={}",
+            to_sql::render_string_literal(comment)
+        ));
+    }
+
+    if clauses.is_empty() {
+        return Err(unsupported_diff_op(
+            dialect_name,
+            op,
+            "table options must include at least one mysql table option key",
+        ));
+    }
+
+    Ok(format!(
+        "ALTER TABLE {} {};",
+        to_sql::render_qualified_name(table),
+        clauses.join(", ")
+    ))
+}
+
+fn option_text(value: &Value) -> Option<&str> {
+    match value {
+        Value::String(text) => Some(text.as_str()),
+        _ => None,
+    }
+}
+
 fn render_add_foreign_key(
     table: &QualifiedName,
     fk: &ForeignKey,
@@ -574,6 +729,25 @@ fn render_add_partition(table: &QualifiedName, partition: &Partition) -> String
     sql
 }
 
+fn render_reorganize_partition(
+    table: &QualifiedName,
+    from: &[Ident],
+    into: &[PartitionElement],
+) -> String {
+    format!(
+        "ALTER TABLE {} REORGANIZE PARTITION {} INTO ({});",
+        to_sql::render_qualified_name(table),
+        from.iter()
+            .map(to_sql::render_ident)
+            .collect::<Vec<_>>()
+            .join(", "),
+        into.iter()
+            .map(render_partition_element)
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
 fn render_partition_strategy(strategy: &PartitionStrategy) -> &'static str {
     match strategy {
         PartitionStrategy::Range => "RANGE",
@@ -656,6 +830,9 @@ fn render_column_definition(column: &Column) -> String {
         to_sql::render_data_type(&column.data_type)
     );
 
+    if let Some(Value::String(charset)) = column.extra.get(extra_keys::COLUMN_CHARSET) {
+        write!(sql, " CHARACTER SET {charset}").expect("writing to String should not fail");
+    }
     if column.not_null {
         sql.push_str(" NOT NULL");
     }
@@ -663,6 +840,9 @@ fn render_column_definition(column: &Column) -> String {
         write!(sql, " DEFAULT {}", to_sql::render_expr(default))
             .expect("writing to String should not fail");
     }
+    if let Some(Value::String(on_update)) = column.extra.get(extra_keys::COLUMN_ON_UPDATE) {
+        write!(sql, " ON UPDATE {on_update}").expect("writing to String should not fail");
+    }
     if column
         .identity
         .as_ref()
@@ -691,6 +871,11 @@ fn render_column_definition(column: &Column) -> String {
         write!(sql, " COLLATE {}", collation.trim()).expect("writing to String should not fail");
     }
 
+    if let Some(comment) = &column.comment {
+        write!(sql, " COMMENT {}", to_sql::render_string_literal(comment))
+            .expect("writing to String should not fail");
+    }
+
     sql
 }
 
@@ -735,7 +920,16 @@ fn unsupported_diff_op(
 }
 
 fn render_object_sql(dialect_name: &str, op: &DiffOp, object: &SchemaObject) -> Result<String> {
-    match to_sql::render_object(dialect_name, object) {
+    rendered_for_op(
+        dialect_name,
+        op,
+        to_sql::render_object(dialect_name, object),
+    )
+}
+
+/// Reports a shape `to_sql` cannot render as an unsupported `op`.
+fn rendered_for_op(dialect_name: &str, op: &DiffOp, rendered: Result<String>) -> Result<String> {
+    match rendered {
         Ok(sql) => Ok(sql),
         Err(stateql_core::Error::Generate(GenerateError::UnsupportedDiffOp { target, .. })) => {
             Err(unsupported_diff_op(dialect_name, op, target))
@@ -749,6 +943,7 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::CreateTable(_) => "CreateTable",
         DiffOp::DropTable(_) => "DropTable",
         DiffOp::RenameTable { .. } => "RenameTable",
+        DiffOp::TableDefinition { .. } => "TableDefinition",
         DiffOp::AddColumn { .. } => "AddColumn",
         DiffOp::DropColumn { .. } => "DropColumn",
         DiffOp::AlterColumn { .. } => "AlterColumn",
@@ -766,6 +961,8 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::DropPrimaryKey { .. } => "DropPrimaryKey",
         DiffOp::AddPartition { .. } => "AddPartition",
         DiffOp::DropPartition { .. } => "DropPartition",
+        DiffOp::ReorganizePartition { .. } => "ReorganizePartition",
+        DiffOp::ChangePartitionCount { .. } => "ChangePartitionCount",
         DiffOp::CreateView(_) => "CreateView",
         DiffOp::DropView(_) => "DropView",
         DiffOp::CreateMaterializedView(_) => "CreateMaterializedView",
@@ -773,10 +970,14 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::CreateSequence(_) => "CreateSequence",
         DiffOp::DropSequence(_) => "DropSequence",
         DiffOp::AlterSequence { .. } => "AlterSequence",
+        DiffOp::CreateSynonym(_) => "CreateSynonym",
+        DiffOp::DropSynonym(_) => "DropSynonym",
         DiffOp::CreateTrigger(_) => "CreateTrigger",
         DiffOp::DropTrigger { .. } => "DropTrigger",
         DiffOp::CreateFunction(_) => "CreateFunction",
-        DiffOp::DropFunction(_) => "DropFunction",
+        DiffOp::DropFunction { .. } => "DropFunction",
+        DiffOp::CreateEvent(_) => "CreateEvent",
+        DiffOp::DropEvent(_) => "DropEvent",
         DiffOp::CreateType(_) => "CreateType",
         DiffOp::DropType(_) => "DropType",
         DiffOp::AlterType { .. } => "AlterType",
@@ -793,6 +994,7 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::Revoke(_) => "Revoke",
         DiffOp::CreatePolicy(_) => "CreatePolicy",
         DiffOp::DropPolicy { .. } => "DropPolicy",
+        DiffOp::SetPragma(_) => "SetPragma",
         DiffOp::AlterTableOptions { .. } => "AlterTableOptions",
     }
 }
@@ -806,6 +1008,7 @@ struct MergedColumnChange {
     identity: Option<Option<Identity>>,
     generated: Option<Option<GeneratedColumn>>,
     collation: Option<Option<String>>,
+    position: Option<ColumnPosition>,
 }
 
 impl MergedColumnChange {
@@ -818,6 +1021,7 @@ impl MergedColumnChange {
             identity: None,
             generated: None,
             collation: None,
+            position: None,
         }
     }
 
@@ -829,6 +1033,7 @@ impl MergedColumnChange {
             ColumnChange::SetIdentity(identity) => self.identity = Some(identity.clone()),
             ColumnChange::SetGenerated(generated) => self.generated = Some(generated.clone()),
             ColumnChange::SetCollation(collation) => self.collation = Some(collation.clone()),
+            ColumnChange::SetPosition(position) => self.position = Some(position.clone()),
         }
     }
 
@@ -848,13 +1053,23 @@ impl MergedColumnChange {
                 dialect: dialect_name.to_string(),
             })?;
 
-        let mut sql = format!(
-            "ALTER TABLE {} CHANGE COLUMN {} {} {}",
-            to_sql::render_qualified_name(table),
-            to_sql::render_ident(&self.column),
-            to_sql::render_ident(&self.column),
-            to_sql::render_data_type(&data_type)
-        );
+        // Repositioning keeps the column name, so it uses the shorter MODIFY form.
+        let mut sql = if self.position.is_some() {
+            format!(
+                "ALTER TABLE {} MODIFY COLUMN {} {}",
+                to_sql::render_qualified_name(table),
+                to_sql::render_ident(&self.column),
+                to_sql::render_data_type(&data_type)
+            )
+        } else {
+            format!(
+                "ALTER TABLE {} CHANGE COLUMN {} {} {}",
+                to_sql::render_qualified_name(table),
+                to_sql::render_ident(&self.column),
+                to_sql::render_ident(&self.column),
+                to_sql::render_data_type(&data_type)
+            )
+        };
 
         match self.not_null {
             Some(true) => sql.push_str(" NOT NULL"),
@@ -868,6 +1083,9 @@ impl MergedColumnChange {
                     write!(sql, " DEFAULT {}", to_sql::render_expr(expr))
                         .expect("writing to String should not fail");
                 }
+                // MySQL rejects DEFAULT NULL on NOT NULL columns, and MODIFY restates the
+                // whole column; in both cases leaving DEFAULT out clears the default.
+                None if self.position.is_some() || self.not_null == Some(true) => {}
                 None => sql.push_str(" DEFAULT NULL"),
             }
         }
@@ -899,6 +1117,10 @@ impl MergedColumnChange {
                 .expect("writing to String should not fail");
         }
 
+        if let Some(position) = &self.position {
+            push_column_position(&mut sql, position);
+        }
+
         sql.push(';');
         Ok(sql)
     }
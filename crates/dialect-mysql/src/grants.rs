@@ -0,0 +1,316 @@
+use std::io;
+
+use stateql_core::{Ident, Privilege, PrivilegeObject, PrivilegeOp, QualifiedName};
+
+use crate::header::conversion_error;
+
+type ConversionResult<T> = std::result::Result<T, io::Error>;
+
+const GRANT_KEYWORD: &str = "GRANT ";
+const WITH_GRANT_OPTION: &str = " WITH GRANT OPTION";
+
+/// Maps one `SHOW GRANTS` row onto a `Privilege` scoped to `database`.
+///
+/// Returns `None` for rows that do not describe privileges on `database` or its
+/// tables (global `*.*` grants, other databases, role and proxy grants), and for
+/// rows whose privileges have no `PrivilegeOp` counterpart. Privileges without a
+/// counterpart (e.g. `ALTER`, `INDEX`, column-level grants) are left out of the
+/// returned operations.
+pub(crate) fn privilege_from_show_grants(row: &str, database: &str) -> Option<Privilege> {
+    let row = row.trim().trim_end_matches(';').trim_end();
+    if !starts_with_keyword(row, GRANT_KEYWORD) {
+        return None;
+    }
+    let body = &row[GRANT_KEYWORD.len()..];
+
+    let (body, with_grant_option) = match strip_suffix_keyword(body, WITH_GRANT_OPTION) {
+        Some(rest) => (rest, true),
+        None => (body, false),
+    };
+    let (operations_sql, rest) = split_keyword(body, " ON ")?;
+    let (object_sql, grantee_sql) = split_keyword(rest, " TO ")?;
+
+    let on = parse_privilege_object(object_sql.trim(), database)?;
+    let grantee = parse_account(grantee_sql.trim())?;
+    let operations = split_top_level_commas(operations_sql)
+        .into_iter()
+        .filter_map(parse_privilege_op)
+        .filter(|operation| *operation != PrivilegeOp::Usage)
+        .collect::<Vec<_>>();
+    if operations.is_empty() {
+        return None;
+    }
+
+    Some(Privilege {
+        operations,
+        on,
+        grantee,
+        with_grant_option,
+    })
+}
+
+pub(crate) fn is_grant_statement(sql: &str) -> bool {
+    sql.split_whitespace()
+        .next()
+        .is_some_and(|word| word.eq_ignore_ascii_case(GRANT_KEYWORD.trim_end()))
+}
+
+/// Reads a `GRANT privileges ON object TO account [WITH GRANT OPTION]` statement, the
+/// form `to_sql` renders. Unlike `SHOW GRANTS` rows, nothing is skipped: a privilege or
+/// object without an IR counterpart is an error.
+pub(crate) fn privilege_from_grant_statement(sql: &str) -> ConversionResult<Privilege> {
+    let sql = sql.split_whitespace().collect::<Vec<_>>().join(" ");
+    let sql = sql.trim_end_matches(';').trim_end();
+    let body = sql
+        .get(GRANT_KEYWORD.len()..)
+        .filter(|_| starts_with_keyword(sql, GRANT_KEYWORD))
+        .ok_or_else(|| conversion_error("mysql statement is not a GRANT"))?;
+
+    let (body, with_grant_option) = match strip_suffix_keyword(body, WITH_GRANT_OPTION) {
+        Some(rest) => (rest, true),
+        None => (body, false),
+    };
+    let (operations_sql, rest) = split_keyword(body, " ON ")
+        .ok_or_else(|| conversion_error("mysql GRANT needs an ON clause"))?;
+    let (object_sql, grantee_sql) = split_keyword(rest, " TO ")
+        .ok_or_else(|| conversion_error("mysql GRANT needs a TO clause"))?;
+
+    let on = parse_grant_object(object_sql.trim())?;
+    let grantee = parse_account(grantee_sql.trim()).ok_or_else(|| {
+        conversion_error(format!(
+            "invalid mysql grant account `{}`; expected user@host",
+            grantee_sql.trim()
+        ))
+    })?;
+    let operations = split_top_level_commas(operations_sql)
+        .into_iter()
+        .map(|raw| {
+            parse_privilege_op(raw)
+                .filter(|operation| *operation != PrivilegeOp::Usage)
+                .ok_or_else(|| conversion_error(format!("unsupported mysql privilege: {raw}")))
+        })
+        .collect::<ConversionResult<Vec<_>>>()?;
+
+    Ok(Privilege {
+        operations,
+        on,
+        grantee,
+        with_grant_option,
+    })
+}
+
+/// Splits an account written as `user@host` into its quoted MySQL form.
+pub(crate) fn render_account(grantee: &Ident) -> String {
+    match grantee.value.rsplit_once('@') {
+        Some((user, host)) => format!("{}@{}", quote_string(user), quote_string(host)),
+        None => quote_string(&grantee.value),
+    }
+}
+
+/// Parses an account from `--grant-account` style input (`user@host`, `'user'@'host'`).
+/// A missing host defaults to `%`.
+pub(crate) fn parse_account(raw: &str) -> Option<Ident> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return None;
+    }
+
+    let (user, rest) = take_account_part(raw)?;
+    let host = match rest.strip_prefix('@') {
+        Some(host) => {
+            let (host, trailing) = take_account_part(host)?;
+            if !trailing.trim().is_empty() {
+                return None;
+            }
+            host
+        }
+        None if rest.trim().is_empty() => "%".to_string(),
+        None => return None,
+    };
+
+    Some(Ident::unquoted(format!("{user}@{host}")))
+}
+
+fn take_account_part(raw: &str) -> Option<(String, &str)> {
+    let quote = raw.chars().next()?;
+    if matches!(quote, '\'' | '"' | '`') {
+        let mut value = String::new();
+        let mut chars = raw[1..].char_indices().peekable();
+        while let Some((index, ch)) = chars.next() {
+            if ch == quote {
+                if chars.peek().is_some_and(|(_, next)| *next == quote) {
+                    value.push(quote);
+                    chars.next();
+                    continue;
+                }
+                return Some((value, &raw[index + 2..]));
+            }
+            value.push(ch);
+        }
+        return None;
+    }
+
+    let end = raw.find('@').unwrap_or(raw.len());
+    Some((raw[..end].trim().to_string(), &raw[end..]))
+}
+
+fn parse_privilege_object(raw: &str, database: &str) -> Option<PrivilegeObject> {
+    let raw = raw
+        .strip_prefix("TABLE ")
+        .or_else(|| raw.strip_prefix("table "))
+        .unwrap_or(raw)
+        .trim();
+    let (schema, object) = raw.split_once('.')?;
+    let schema = unquote_identifier(schema)?;
+    if schema != database {
+        return None;
+    }
+
+    if object.trim() == "*" {
+        return Some(PrivilegeObject::Database(Ident::unquoted(schema)));
+    }
+
+    let table = unquote_identifier(object)?;
+    Some(PrivilegeObject::Table(QualifiedName {
+        schema: None,
+        name: Ident::unquoted(table),
+    }))
+}
+
+/// `db.*` grants on a database, `tbl` and `db.tbl` on a table; global grants are refused.
+fn parse_grant_object(raw: &str) -> ConversionResult<PrivilegeObject> {
+    let raw = raw
+        .strip_prefix("TABLE ")
+        .or_else(|| raw.strip_prefix("table "))
+        .unwrap_or(raw)
+        .trim();
+    let unsupported = || conversion_error(format!("unsupported mysql grant object: {raw}"));
+    let (schema, object) = match raw.split_once('.') {
+        Some((schema, object)) => (Some(schema), object),
+        None => (None, raw),
+    };
+    let schema = schema
+        .map(|schema| unquote_identifier(schema).filter(|schema| schema != "*"))
+        .map(|schema| schema.ok_or_else(unsupported))
+        .transpose()?;
+
+    if object.trim() == "*" {
+        let database = schema.ok_or_else(unsupported)?;
+        return Ok(PrivilegeObject::Database(Ident::unquoted(database)));
+    }
+
+    let table = unquote_identifier(object).ok_or_else(unsupported)?;
+    Ok(PrivilegeObject::Table(QualifiedName {
+        schema: schema.map(Ident::unquoted),
+        name: Ident::unquoted(table),
+    }))
+}
+
+fn parse_privilege_op(raw: &str) -> Option<PrivilegeOp> {
+    let normalized = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+    if normalized.contains('(') {
+        return None;
+    }
+
+    match normalized.to_ascii_uppercase().as_str() {
+        "SELECT" => Some(PrivilegeOp::Select),
+        "INSERT" => Some(PrivilegeOp::Insert),
+        "UPDATE" => Some(PrivilegeOp::Update),
+        "DELETE" => Some(PrivilegeOp::Delete),
+        "REFERENCES" => Some(PrivilegeOp::References),
+        "TRIGGER" => Some(PrivilegeOp::Trigger),
+        "CREATE" => Some(PrivilegeOp::Create),
+        "CREATE TEMPORARY TABLES" => Some(PrivilegeOp::Temporary),
+        "EXECUTE" => Some(PrivilegeOp::Execute),
+        "USAGE" => Some(PrivilegeOp::Usage),
+        "ALL" | "ALL PRIVILEGES" => Some(PrivilegeOp::All),
+        _ => None,
+    }
+}
+
+pub(crate) fn render_privilege_op(operation: PrivilegeOp) -> Option<&'static str> {
+    match operation {
+        PrivilegeOp::Select => Some("SELECT"),
+        PrivilegeOp::Insert => Some("INSERT"),
+        PrivilegeOp::Update => Some("UPDATE"),
+        PrivilegeOp::Delete => Some("DELETE"),
+        PrivilegeOp::References => Some("REFERENCES"),
+        PrivilegeOp::Trigger => Some("TRIGGER"),
+        PrivilegeOp::Create => Some("CREATE"),
+        PrivilegeOp::Temporary => Some("CREATE TEMPORARY TABLES"),
+        PrivilegeOp::Execute => Some("EXECUTE"),
+        PrivilegeOp::Usage => Some("USAGE"),
+        PrivilegeOp::All => Some("ALL PRIVILEGES"),
+        PrivilegeOp::Truncate | PrivilegeOp::Connect => None,
+    }
+}
+
+fn unquote_identifier(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return None;
+    }
+    match raw
+        .strip_prefix('`')
+        .and_then(|rest| rest.strip_suffix('`'))
+    {
+        Some(inner) => Some(inner.replace("``", "`")),
+        None => Some(raw.to_string()),
+    }
+}
+
+fn quote_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn starts_with_keyword(sql: &str, keyword: &str) -> bool {
+    sql.len() >= keyword.len() && sql[..keyword.len()].eq_ignore_ascii_case(keyword)
+}
+
+fn strip_suffix_keyword<'a>(sql: &'a str, keyword: &str) -> Option<&'a str> {
+    let start = sql.len().checked_sub(keyword.len())?;
+    (sql.is_char_boundary(start) && sql[start..].eq_ignore_ascii_case(keyword))
+        .then(|| &sql[..start])
+}
+
+/// Splits at the first occurrence of `keyword` outside quotes and parentheses.
+fn split_keyword<'a>(sql: &'a str, keyword: &str) -> Option<(&'a str, &'a str)> {
+    let mut depth = 0usize;
+    let mut quote = None;
+    for (index, ch) in sql.char_indices() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"' | '`') => quote = Some(ch),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, _) if depth == 0 => {
+                let end = index + keyword.len();
+                if sql.is_char_boundary(end) && sql[index..end].eq_ignore_ascii_case(keyword) {
+                    return Some((&sql[..index], &sql[end..]));
+                }
+            }
+            (None, _) => {}
+        }
+    }
+    None
+}
+
+fn split_top_level_commas(sql: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0usize;
+    for (index, ch) in sql.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(sql[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(sql[start..].trim());
+    parts
+}
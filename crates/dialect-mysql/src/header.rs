@@ -0,0 +1,317 @@
+//! A small cursor over the tokens of a statement sqlparser cannot read, shared by the
+//! routine and grant readers and by the statement splitter.
+
+use std::io;
+
+use stateql_core::{Ident, QualifiedName};
+
+type ConversionResult<T> = std::result::Result<T, io::Error>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenKind {
+    Word,
+    /// A backquoted identifier.
+    Quoted,
+    /// A single- or double-quoted string.
+    String,
+    Punct(u8),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Token {
+    pub(crate) kind: TokenKind,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+impl Token {
+    pub(crate) fn text<'a>(&self, sql: &'a str) -> &'a str {
+        &sql[self.start..self.end]
+    }
+
+    pub(crate) fn is_word(&self, sql: &str, keyword: &str) -> bool {
+        self.kind == TokenKind::Word && self.text(sql).eq_ignore_ascii_case(keyword)
+    }
+}
+
+/// Words, quoted names, strings and punctuation, skipping whitespace and `--`, `#` and
+/// `/* */` comments.
+pub(crate) fn scan_tokens(sql: &str) -> Vec<Token> {
+    let bytes = sql.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0usize;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+
+        if byte.is_ascii_whitespace() {
+            index += 1;
+            continue;
+        }
+
+        if byte == b'#' || (byte == b'-' && bytes.get(index + 1) == Some(&b'-')) {
+            while index < bytes.len() && bytes[index] != b'\n' {
+                index += 1;
+            }
+            continue;
+        }
+
+        if byte == b'/' && bytes.get(index + 1) == Some(&b'*') {
+            index += 2;
+            while index < bytes.len()
+                && !(bytes[index] == b'*' && bytes.get(index + 1) == Some(&b'/'))
+            {
+                index += 1;
+            }
+            index = (index + 2).min(bytes.len());
+            continue;
+        }
+
+        let kind = match byte {
+            b'`' => Some(TokenKind::Quoted),
+            b'\'' | b'"' => Some(TokenKind::String),
+            _ => None,
+        };
+        if let Some(kind) = kind {
+            let start = index;
+            index += 1;
+            while index < bytes.len() {
+                if bytes[index] == b'\\' && kind == TokenKind::String {
+                    index += 2;
+                    continue;
+                }
+                if bytes[index] == byte {
+                    if bytes.get(index + 1) == Some(&byte) {
+                        index += 2;
+                        continue;
+                    }
+                    break;
+                }
+                index += 1;
+            }
+            index = (index + 1).min(bytes.len());
+            tokens.push(Token {
+                kind,
+                start,
+                end: index,
+            });
+            continue;
+        }
+
+        if is_word_byte(byte) {
+            let start = index;
+            while index < bytes.len() && is_word_byte(bytes[index]) {
+                index += 1;
+            }
+            tokens.push(Token {
+                kind: TokenKind::Word,
+                start,
+                end: index,
+            });
+            continue;
+        }
+
+        tokens.push(Token {
+            kind: TokenKind::Punct(byte),
+            start: index,
+            end: index + 1,
+        });
+        index += 1;
+    }
+
+    tokens
+}
+
+pub(crate) fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'$' || !byte.is_ascii()
+}
+
+/// A cursor over the header tokens of a statement read by hand.
+pub(crate) struct Header<'a> {
+    pub(crate) sql: &'a str,
+    tokens: Vec<Token>,
+    cursor: usize,
+}
+
+impl<'a> Header<'a> {
+    pub(crate) fn new(sql: &'a str) -> Self {
+        Self {
+            sql,
+            tokens: scan_tokens(sql),
+            cursor: 0,
+        }
+    }
+
+    pub(crate) fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.cursor)
+    }
+
+    pub(crate) fn peek_word(&self, keyword: &str) -> bool {
+        self.peek()
+            .is_some_and(|token| token.is_word(self.sql, keyword))
+    }
+
+    /// Whether the tokens from the cursor on are the given words.
+    pub(crate) fn peek_words(&self, keywords: &[&str]) -> bool {
+        keywords.iter().enumerate().all(|(offset, keyword)| {
+            self.tokens
+                .get(self.cursor + offset)
+                .is_some_and(|token| token.is_word(self.sql, keyword))
+        })
+    }
+
+    pub(crate) fn eat_word(&mut self, keyword: &str) -> bool {
+        let matched = self.peek_word(keyword);
+        if matched {
+            self.cursor += 1;
+        }
+        matched
+    }
+
+    pub(crate) fn eat_punct(&mut self, punct: u8) -> bool {
+        let matched = self
+            .peek()
+            .is_some_and(|token| token.kind == TokenKind::Punct(punct));
+        if matched {
+            self.cursor += 1;
+        }
+        matched
+    }
+
+    pub(crate) fn expect_word(&mut self, keyword: &str) -> ConversionResult<()> {
+        if self.eat_word(keyword) {
+            return Ok(());
+        }
+        Err(self.unexpected(keyword))
+    }
+
+    pub(crate) fn expect_punct(&mut self, punct: u8) -> ConversionResult<()> {
+        if self.eat_punct(punct) {
+            return Ok(());
+        }
+        Err(self.unexpected(&char::from(punct).to_string()))
+    }
+
+    pub(crate) fn next_token(&mut self, expected: &str) -> ConversionResult<Token> {
+        let token = self
+            .peek()
+            .copied()
+            .filter(|token| !matches!(token.kind, TokenKind::Punct(_)))
+            .ok_or_else(|| self.unexpected(expected))?;
+        self.cursor += 1;
+        Ok(token)
+    }
+
+    pub(crate) fn unexpected(&self, expected: &str) -> io::Error {
+        let found = self
+            .peek()
+            .map_or("end of statement", |token| token.text(self.sql));
+        conversion_error(format!(
+            "expected {expected} in mysql statement header, found `{found}`"
+        ))
+    }
+
+    /// `name` or `schema.name`.
+    pub(crate) fn object_name(&mut self) -> ConversionResult<QualifiedName> {
+        let first = self.ident("object name")?;
+        if !self.eat_punct(b'.') {
+            return Ok(QualifiedName {
+                schema: None,
+                name: first,
+            });
+        }
+        let second = self.ident("object name")?;
+        if self
+            .peek()
+            .is_some_and(|token| token.kind == TokenKind::Punct(b'.'))
+        {
+            return Err(conversion_error(
+                "unsupported qualified name in mysql statement header",
+            ));
+        }
+        Ok(QualifiedName {
+            schema: Some(first),
+            name: second,
+        })
+    }
+
+    pub(crate) fn ident(&mut self, expected: &str) -> ConversionResult<Ident> {
+        let token = self
+            .peek()
+            .copied()
+            .filter(|token| matches!(token.kind, TokenKind::Word | TokenKind::Quoted))
+            .ok_or_else(|| self.unexpected(expected))?;
+        self.cursor += 1;
+        let text = token.text(self.sql);
+        Ok(match token.kind {
+            TokenKind::Quoted => Ident::quoted(text[1..text.len() - 1].replace("``", "`")),
+            _ => Ident::unquoted(text),
+        })
+    }
+
+    /// A quoted string, with its backslash escapes and doubled quotes undone.
+    pub(crate) fn string(&mut self, expected: &str) -> ConversionResult<String> {
+        let token = self
+            .peek()
+            .copied()
+            .filter(|token| token.kind == TokenKind::String)
+            .ok_or_else(|| self.unexpected(expected))?;
+        self.cursor += 1;
+        let text = token.text(self.sql);
+        let quote = text.chars().next().unwrap_or('\'');
+        let mut value = String::new();
+        let mut chars = text.get(1..text.len() - 1).unwrap_or_default().chars();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' => value.extend(chars.next()),
+                ch if ch == quote => value.extend(chars.next()),
+                ch => value.push(ch),
+            }
+        }
+        Ok(value)
+    }
+
+    /// Source text from the cursor up to the first token at parenthesis depth zero that
+    /// `stop` accepts.
+    pub(crate) fn text_until(
+        &mut self,
+        expected: &str,
+        stop: impl Fn(&Self, &Token) -> bool,
+    ) -> ConversionResult<&'a str> {
+        let start = self.cursor;
+        let mut depth = 0usize;
+        while let Some(token) = self.peek().copied() {
+            if depth == 0 && stop(self, &token) {
+                break;
+            }
+            match token.kind {
+                TokenKind::Punct(b'(') => depth += 1,
+                TokenKind::Punct(b')') => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            self.cursor += 1;
+        }
+        if self.cursor == start {
+            return Err(self.unexpected(expected));
+        }
+        Ok(&self.sql[self.tokens[start].start..self.tokens[self.cursor - 1].end])
+    }
+
+    /// Everything after the header, without a trailing `;`.
+    pub(crate) fn body(&self, statement: &str) -> ConversionResult<String> {
+        let body = self
+            .peek()
+            .map(|token| self.sql[token.start..].trim().trim_end_matches(';').trim())
+            .unwrap_or_default();
+        if body.is_empty() {
+            return Err(conversion_error(format!(
+                "mysql {statement} has an empty body"
+            )));
+        }
+        Ok(body.to_string())
+    }
+}
+
+pub(crate) fn conversion_error(message: impl Into<String>) -> io::Error {
+    io::Error::other(message.into())
+}
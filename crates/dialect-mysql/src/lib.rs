@@ -3,13 +3,16 @@ mod equivalence;
 mod export_queries;
 mod extra_keys;
 mod generator;
+mod grants;
+mod header;
 mod normalize;
 mod parser;
+mod routines;
 mod to_sql;
 
 use stateql_core::{
-    ConnectionConfig, DatabaseAdapter, Dialect, DiffOp, EquivalencePolicy, Ident, Result,
-    SchemaObject, Statement,
+    ConnectionConfig, DatabaseAdapter, Dialect, DiffOp, EquivalencePolicy, Ident,
+    PartitionChangeStrategy, Privilege, Result, SchemaObject, Statement,
 };
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -25,6 +28,18 @@ pub fn lower_case_table_names_query() -> &'static str {
     export_queries::LOWER_CASE_TABLE_NAMES_QUERY
 }
 
+pub fn routines_query() -> &'static str {
+    export_queries::ROUTINES_QUERY
+}
+
+pub fn events_query() -> &'static str {
+    export_queries::EVENTS_QUERY
+}
+
+pub fn privilege_from_show_grants(row: &str, database: &str) -> Option<Privilege> {
+    grants::privilege_from_show_grants(row, database)
+}
+
 impl Dialect for MysqlDialect {
     fn name(&self) -> &str {
         DIALECT_NAME
@@ -50,6 +65,14 @@ impl Dialect for MysqlDialect {
         &equivalence::MYSQL_EQUIVALENCE_POLICY
     }
 
+    fn partition_change_strategy(&self) -> PartitionChangeStrategy {
+        PartitionChangeStrategy::Reorganize
+    }
+
+    fn diffed_table_options(&self) -> &'static [&'static str] {
+        &extra_keys::DIFFED_TABLE_OPTIONS
+    }
+
     fn quote_ident(&self, ident: &Ident) -> String {
         format!("`{}`", ident.value)
     }
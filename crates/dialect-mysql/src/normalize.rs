@@ -3,7 +3,7 @@ use stateql_core::{
     QualifiedName, SchemaObject, Sequence, Table, TypeDef, TypeKind, Value,
 };
 
-use crate::extra_keys;
+use crate::{extra_keys, routines};
 
 pub(crate) fn normalize_object(object: &mut SchemaObject) {
     normalize_object_types(object);
@@ -22,6 +22,7 @@ fn normalize_object_types(object: &mut SchemaObject) {
             }
             normalize_source_sql_hint(table);
             normalize_partition_hint(table);
+            table_options::normalize_table_options(table);
         }
         SchemaObject::View(_) => {}
         SchemaObject::MaterializedView(materialized_view) => {
@@ -29,8 +30,10 @@ fn normalize_object_types(object: &mut SchemaObject) {
         }
         SchemaObject::Index(_) => {}
         SchemaObject::Sequence(sequence) => normalize_sequence_type(sequence),
+        SchemaObject::Synonym(_) => {}
         SchemaObject::Trigger(_) => {}
         SchemaObject::Function(function) => normalize_function_types(function),
+        SchemaObject::Event(_) => {}
         SchemaObject::Type(type_def) => normalize_type_def(type_def),
         SchemaObject::Domain(domain) => types::normalize_data_type(&mut domain.data_type),
         SchemaObject::Extension(_) => {}
@@ -38,6 +41,7 @@ fn normalize_object_types(object: &mut SchemaObject) {
         SchemaObject::Comment(_) => {}
         SchemaObject::Privilege(_) => {}
         SchemaObject::Policy(_) => {}
+        SchemaObject::Pragma(_) => {}
     }
 }
 
@@ -93,6 +97,12 @@ fn normalize_type_def(type_def: &mut TypeDef) {
             }
         }
         TypeKind::Range { subtype } => types::normalize_data_type(subtype),
+        TypeKind::Table { columns, .. } => {
+            for column in columns {
+                normalize_ident(&mut column.name);
+                types::normalize_data_type(&mut column.data_type);
+            }
+        }
     }
 }
 
@@ -182,10 +192,17 @@ fn normalize_identifier_case(object: &mut SchemaObject) {
                 normalize_ident(column);
             }
         }
+        SchemaObject::Synonym(synonym) => {
+            normalize_qualified_name(&mut synonym.name);
+            for part in &mut synonym.target {
+                normalize_ident(part);
+            }
+        }
         SchemaObject::Trigger(trigger) => {
             normalize_qualified_name(&mut trigger.name);
             normalize_qualified_name(&mut trigger.table);
         }
+        SchemaObject::Event(event) => normalize_qualified_name(&mut event.name),
         SchemaObject::Function(function) => {
             normalize_qualified_name(&mut function.name);
             for param in &mut function.params {
@@ -208,6 +225,7 @@ fn normalize_identifier_case(object: &mut SchemaObject) {
             normalize_privilege_object(&mut privilege.on);
             normalize_ident(&mut privilege.grantee);
         }
+        SchemaObject::Pragma(_) => {}
         SchemaObject::Policy(policy) => {
             normalize_ident(&mut policy.name);
             normalize_qualified_name(&mut policy.table);
@@ -277,6 +295,10 @@ fn normalize_object_exprs(object: &mut SchemaObject) {
                 if let Some(default) = &mut column.default {
                     normalize_expr(default);
                 }
+                // `SHOW CREATE TABLE` spells out `DEFAULT NULL` for columns without a default.
+                if column.default.as_ref().is_some_and(is_null_default) {
+                    column.default = None;
+                }
                 if let Some(generated) = &mut column.generated {
                     normalize_expr(&mut generated.expr);
                 }
@@ -314,12 +336,17 @@ fn normalize_object_exprs(object: &mut SchemaObject) {
         }
         SchemaObject::Index(index) => normalize_index_exprs(index),
         SchemaObject::Sequence(_) => {}
+        SchemaObject::Synonym(_) => {}
         SchemaObject::Trigger(trigger) => {
             if let Some(when_clause) = &mut trigger.when_clause {
                 normalize_expr(when_clause);
             }
             trigger.body = trigger.body.trim().to_string();
         }
+        SchemaObject::Event(event) => {
+            event.schedule = routines::normalize_event_schedule(&event.schedule);
+            event.body = event.body.trim().to_string();
+        }
         SchemaObject::Function(function) => {
             function.body = function.body.trim().to_string();
             for param in &mut function.params {
@@ -341,6 +368,7 @@ fn normalize_object_exprs(object: &mut SchemaObject) {
         SchemaObject::Schema(_) => {}
         SchemaObject::Comment(_) => {}
         SchemaObject::Privilege(_) => {}
+        SchemaObject::Pragma(_) => {}
         SchemaObject::Policy(policy) => {
             if let Some(using_expr) = &mut policy.using_expr {
                 normalize_expr(using_expr);
@@ -362,6 +390,14 @@ fn normalize_index_exprs(index: &mut IndexDef) {
     }
 }
 
+fn is_null_default(expr: &Expr) -> bool {
+    match expr {
+        Expr::Null => true,
+        Expr::Raw(raw) => raw.eq_ignore_ascii_case("NULL"),
+        _ => false,
+    }
+}
+
 fn normalize_expr(expr: &mut Expr) {
     match expr {
         Expr::Literal(_) | Expr::Null => {}
@@ -460,6 +496,125 @@ fn normalize_expr(expr: &mut Expr) {
     }
 }
 
+pub(crate) mod table_options {
+    use std::collections::BTreeMap;
+
+    use stateql_core::{Table, Value, extra_keys::mysql as mysql_keys};
+
+    pub(crate) const DEFAULT_ENGINE: &str = "innodb";
+    pub(crate) const DEFAULT_CHARSET: &str = "utf8mb4";
+    pub(crate) const DEFAULT_COLLATION: &str = "utf8mb4_0900_ai_ci";
+
+    // Default collations for the character sets that commonly appear in exported DDL.
+    const CHARSET_DEFAULT_COLLATIONS: [(&str, &str); 6] = [
+        (DEFAULT_CHARSET, DEFAULT_COLLATION),
+        ("utf8mb3", "utf8mb3_general_ci"),
+        ("latin1", "latin1_swedish_ci"),
+        ("ascii", "ascii_general_ci"),
+        ("ucs2", "ucs2_general_ci"),
+        ("binary", "binary"),
+    ];
+
+    pub(super) fn normalize_table_options(table: &mut Table) {
+        let extra = &mut table.options.extra;
+
+        map_string_option(extra, mysql_keys::TABLE_ENGINE, |engine| {
+            let engine = engine.trim().to_ascii_lowercase();
+            (engine != DEFAULT_ENGINE).then_some(engine)
+        });
+        map_string_option(extra, mysql_keys::TABLE_CHARSET, |charset| {
+            Some(canonical_charset(charset))
+        });
+        map_string_option(extra, mysql_keys::TABLE_COLLATE, |collation| {
+            Some(canonical_collation(collation))
+        });
+        map_string_option(extra, mysql_keys::TABLE_ROW_FORMAT, |row_format| {
+            let row_format = row_format.trim().to_ascii_uppercase();
+            (row_format != "DEFAULT").then_some(row_format)
+        });
+        map_string_option(extra, mysql_keys::TABLE_COMMENT, |comment| {
+            (!comment.is_empty()).then(|| comment.to_string())
+        });
+
+        let collation = string_option(extra, mysql_keys::TABLE_COLLATE).map(str::to_string);
+        if let Some(collation) = &collation
+            && !extra.contains_key(mysql_keys::TABLE_CHARSET)
+        {
+            extra.insert(
+                mysql_keys::TABLE_CHARSET.to_string(),
+                Value::String(charset_of_collation(collation).to_string()),
+            );
+        }
+
+        let charset = string_option(extra, mysql_keys::TABLE_CHARSET).map(str::to_string);
+        if let (Some(charset), Some(collation)) = (&charset, &collation)
+            && default_collation_for(charset) == Some(collation.as_str())
+        {
+            extra.remove(mysql_keys::TABLE_COLLATE);
+        }
+        if charset.as_deref() == Some(DEFAULT_CHARSET)
+            && !extra.contains_key(mysql_keys::TABLE_COLLATE)
+        {
+            extra.remove(mysql_keys::TABLE_CHARSET);
+        }
+    }
+
+    pub(crate) fn charset_of_collation(collation: &str) -> &str {
+        collation
+            .split_once('_')
+            .map_or(collation, |(charset, _)| charset)
+    }
+
+    fn default_collation_for(charset: &str) -> Option<&'static str> {
+        CHARSET_DEFAULT_COLLATIONS
+            .iter()
+            .find(|(known_charset, _)| *known_charset == charset)
+            .map(|(_, collation)| *collation)
+    }
+
+    fn canonical_charset(raw: &str) -> String {
+        let charset = raw.trim().to_ascii_lowercase();
+        if charset == "utf8" {
+            "utf8mb3".to_string()
+        } else {
+            charset
+        }
+    }
+
+    fn canonical_collation(raw: &str) -> String {
+        let collation = raw.trim().to_ascii_lowercase();
+        match collation.strip_prefix("utf8_") {
+            Some(rest) => format!("utf8mb3_{rest}"),
+            None => collation,
+        }
+    }
+
+    fn string_option<'a>(extra: &'a BTreeMap<String, Value>, key: &str) -> Option<&'a str> {
+        match extra.get(key) {
+            Some(Value::String(value)) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+
+    fn map_string_option(
+        extra: &mut BTreeMap<String, Value>,
+        key: &str,
+        normalize: impl FnOnce(&str) -> Option<String>,
+    ) {
+        let Some(Value::String(value)) = extra.get(key) else {
+            return;
+        };
+        match normalize(value) {
+            Some(normalized) => {
+                extra.insert(key.to_string(), Value::String(normalized));
+            }
+            None => {
+                extra.remove(key);
+            }
+        }
+    }
+}
+
 mod types {
     use stateql_core::DataType;
 
@@ -474,6 +629,14 @@ mod types {
     }
 
     fn normalize_custom_type(raw: &str) -> DataType {
+        // Labels are case-sensitive, so they are read before the type name is lowercased.
+        if let Some(labels) = parse_label_list(raw, "enum") {
+            return DataType::Enum(labels);
+        }
+        if let Some(labels) = parse_label_list(raw, "set") {
+            return DataType::Set(labels);
+        }
+
         let canonical = normalize_custom(raw);
         if canonical.is_empty() {
             return DataType::Custom(String::new());
@@ -570,6 +733,45 @@ mod types {
         inner.trim().parse::<u32>().ok()
     }
 
+    fn parse_label_list(raw: &str, keyword: &str) -> Option<Vec<String>> {
+        let trimmed = raw.trim();
+        let open_paren = trimmed.find('(')?;
+        if !trimmed[..open_paren].trim().eq_ignore_ascii_case(keyword) {
+            return None;
+        }
+        let body = trimmed[open_paren + 1..].strip_suffix(')')?;
+
+        let mut labels = Vec::new();
+        let mut chars = body.chars().peekable();
+        loop {
+            while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+            let quote = chars.next_if(|ch| *ch == '\'' || *ch == '"')?;
+
+            let mut label = String::new();
+            loop {
+                match chars.next()? {
+                    '\\' => label.push(chars.next()?),
+                    ch if ch == quote => {
+                        if chars.next_if_eq(&quote).is_some() {
+                            label.push(quote);
+                        } else {
+                            break;
+                        }
+                    }
+                    ch => label.push(ch),
+                }
+            }
+            labels.push(label);
+
+            while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+            match chars.next() {
+                Some(',') => {}
+                None => return Some(labels),
+                Some(_) => return None,
+            }
+        }
+    }
+
     fn collapse_spaces(input: &str) -> String {
         input.split_whitespace().collect::<Vec<_>>().join(" ")
     }
@@ -1,22 +1,70 @@
-use std::io;
+use std::{collections::BTreeMap, io};
 
 use sqlparser::{
-    ast::{CreateView, CreateViewSecurity, ObjectName, Statement},
+    ast::{
+        ColumnDef, ColumnOption, CommentDef, CreateTable, CreateTableOptions, CreateView,
+        CreateViewSecurity, DataType as SqlDataType, EnumMember, Expr as SqlExpr,
+        ForeignKeyConstraint, GeneratedExpressionMode, Ident as SqlIdent,
+        IndexColumn as SqlIndexColumn, IndexType, ObjectName, ReferentialAction, SqlOption,
+        Statement, TableConstraint, Value as SqlValue,
+    },
     dialect::MySqlDialect,
+    keywords::Keyword,
     parser::Parser,
+    tokenizer::Token,
 };
 use stateql_core::{
-    AnnotationAttachment, AnnotationExtractor, AnnotationTarget, Ident, ParseError, QualifiedName,
-    Result, SchemaObject, SourceLocation, Table, Value, View, ViewSecurity, attach_annotations,
+    AnnotationAttachment, AnnotationExtractor, AnnotationTarget, CheckConstraint, Column, DataType,
+    Expr, ForeignKey, ForeignKeyAction, GeneratedColumn, Ident, IndexColumn, IndexDef, IndexOwner,
+    ParseError, PrimaryKey, QualifiedName, Result, SchemaObject, SourceLocation, Table, Value,
+    View, ViewSecurity, attach_annotations, extra_keys::mysql as mysql_keys,
 };
 
-use crate::extra_keys;
+use crate::{
+    extra_keys, grants,
+    header::{is_word_byte, scan_tokens},
+    routines,
+};
 
 type ConversionResult<T> = std::result::Result<T, io::Error>;
 
 pub(crate) fn parse_schema(sql: &str) -> Result<Vec<SchemaObject>> {
     let (clean_sql, annotations) = AnnotationExtractor::extract(sql)?;
-    let ast = Parser::parse_sql(&MySqlDialect {}, &clean_sql).map_err(|source| {
+    let mut parser_sql = String::with_capacity(clean_sql.len());
+    let mut statement_spans = Vec::new();
+    let mut hand_spans = Vec::new();
+    for span in split_statement_spans(&clean_sql) {
+        let fragment = &clean_sql[span.start..span.end];
+        if span.kind != SpanKind::Statement {
+            // Blanked rather than dropped so sqlparser still reports file line numbers.
+            parser_sql.push_str(&blank_out(fragment));
+            continue;
+        }
+        let statement = &clean_sql[span.start..span.body_end];
+        let code = &statement[leading_trivia_len(statement)..];
+        let hand_parsed = if routines::routine_kind(code).is_some() {
+            Some(HandParsed::Routine)
+        } else if grants::is_grant_statement(code) {
+            Some(HandParsed::Grant)
+        } else {
+            None
+        };
+        if let Some(kind) = hand_parsed {
+            parser_sql.push_str(&blank_out(fragment));
+            hand_spans.push((kind, span.start, span.body_end));
+            continue;
+        }
+        parser_sql.push_str(statement);
+        // A `DELIMITER` terminator becomes `;`, padded so offsets still line up.
+        let terminator = &clean_sql[span.body_end..span.end];
+        if !terminator.is_empty() {
+            parser_sql.push(';');
+            parser_sql.push_str(&" ".repeat(terminator.len() - 1));
+        }
+        statement_spans.push((span.start, span.end));
+    }
+
+    let ast = Parser::parse_sql(&MySqlDialect {}, &parser_sql).map_err(|source| {
         ParseError::StatementConversion {
             statement_index: 0,
             source_sql: clean_sql.clone(),
@@ -28,16 +76,47 @@ pub(crate) fn parse_schema(sql: &str) -> Result<Vec<SchemaObject>> {
         }
     })?;
 
-    let metadata = statement_metadata(&clean_sql);
-    let mut objects = Vec::with_capacity(ast.len());
-    let mut attachments = Vec::with_capacity(ast.len());
-
-    for (statement_index, statement) in ast.iter().enumerate() {
-        let metadata = metadata
-            .get(statement_index)
-            .cloned()
-            .unwrap_or_else(|| fallback_metadata(statement));
-        let converted = convert_statement(statement, metadata.line).map_err(|source| {
+    let metadata = statement_spans
+        .into_iter()
+        .map(|(start, end)| span_metadata(&clean_sql, start, end))
+        .collect::<Vec<_>>();
+    let mut units = ast
+        .iter()
+        .enumerate()
+        .map(|(statement_index, statement)| {
+            let metadata = metadata
+                .get(statement_index)
+                .cloned()
+                .unwrap_or_else(|| fallback_metadata(statement));
+            (metadata.offset, Unit::Statement(statement, metadata))
+        })
+        .collect::<Vec<_>>();
+    units.extend(hand_spans.into_iter().map(|(kind, start, end)| {
+        let metadata = span_metadata(&clean_sql, start, end);
+        (metadata.offset, Unit::HandParsed(kind, metadata))
+    }));
+    units.sort_by_key(|(offset, _)| *offset);
+
+    let mut objects = Vec::with_capacity(units.len());
+    let mut attachments = Vec::with_capacity(units.len());
+
+    for (statement_index, (_, unit)) in units.into_iter().enumerate() {
+        let (converted, metadata) = match unit {
+            Unit::Statement(statement, metadata) => {
+                (convert_statement(statement, metadata.line), metadata)
+            }
+            Unit::HandParsed(HandParsed::Routine, metadata) => (
+                routines::convert_routine(&metadata.source_sql).map(ConvertedStatement::object),
+                metadata,
+            ),
+            Unit::HandParsed(HandParsed::Grant, metadata) => (
+                grants::privilege_from_grant_statement(&metadata.source_sql).map(|privilege| {
+                    ConvertedStatement::object(SchemaObject::Privilege(privilege))
+                }),
+                metadata,
+            ),
+        };
+        let converted = converted.map_err(|source| {
             statement_conversion_error(
                 statement_index,
                 metadata.source_sql,
@@ -46,24 +125,49 @@ pub(crate) fn parse_schema(sql: &str) -> Result<Vec<SchemaObject>> {
             )
         })?;
 
-        objects.push(converted.object);
-        attachments.push(converted.attachment);
+        objects.extend(converted.objects);
+        attachments.extend(converted.attachments);
     }
 
     attach_annotations(&mut objects, &annotations, &attachments)?;
     Ok(objects)
 }
 
+/// A statement sqlparser read, or one read by hand, in source order.
+enum Unit<'a> {
+    Statement(&'a Statement, StatementMetadata),
+    HandParsed(HandParsed, StatementMetadata),
+}
+
+/// Statements sqlparser cannot read, blanked out of its input and converted by hand.
+#[derive(Debug, Clone, Copy)]
+enum HandParsed {
+    /// A stored function, procedure or trigger.
+    Routine,
+    Grant,
+}
+
 #[derive(Clone)]
 struct StatementMetadata {
     source_sql: String,
     source_location: Option<SourceLocation>,
+    /// Where the statement's first token sits in the file, to keep statements in order.
+    offset: usize,
     line: usize,
 }
 
 struct ConvertedStatement {
-    object: SchemaObject,
-    attachment: AnnotationAttachment,
+    objects: Vec<SchemaObject>,
+    attachments: Vec<AnnotationAttachment>,
+}
+
+impl ConvertedStatement {
+    fn object(object: SchemaObject) -> Self {
+        Self {
+            objects: vec![object],
+            attachments: Vec::new(),
+        }
+    }
 }
 
 fn statement_conversion_error(
@@ -81,30 +185,46 @@ fn statement_conversion_error(
     .into()
 }
 
-fn statement_metadata(sql: &str) -> Vec<StatementMetadata> {
-    split_statement_spans(sql)
-        .into_iter()
-        .filter_map(|(start, end)| {
-            let fragment = sql.get(start..end).unwrap_or(sql);
-            let source_sql = fragment.trim();
-            if source_sql.is_empty() {
-                return None;
-            }
-            let line_offset = start.saturating_add(leading_whitespace_len(fragment));
-            let line = offset_to_line(sql, line_offset);
-            Some(StatementMetadata {
-                source_sql: source_sql.to_string(),
-                source_location: Some(SourceLocation { line, column: None }),
-                line,
-            })
-        })
-        .collect()
+fn span_metadata(sql: &str, start: usize, end: usize) -> StatementMetadata {
+    let fragment = &sql[start..end];
+    let offset = start + leading_trivia_len(fragment);
+    let line = offset_to_line(sql, offset);
+    StatementMetadata {
+        source_sql: sql[offset..end].trim().to_string(),
+        source_location: Some(SourceLocation { line, column: None }),
+        offset,
+        line,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpanKind {
+    Statement,
+    /// Whitespace, comments or a stray terminator.
+    Blank,
+    /// A `DELIMITER` line.
+    Delimiter,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct StatementSpan {
+    start: usize,
+    end: usize,
+    /// Where the statement ends without its terminator.
+    body_end: usize,
+    kind: SpanKind,
 }
 
-fn split_statement_spans(sql: &str) -> Vec<(usize, usize)> {
+/// Splits `sql` into statements the way the mysql client does: `DELIMITER` lines change
+/// the terminator, and a routine's `BEGIN ... END` blocks hold `;` of their own.
+fn split_statement_spans(sql: &str) -> Vec<StatementSpan> {
     let bytes = sql.as_bytes();
     let mut spans = Vec::new();
+    let mut delimiter = ";".to_string();
     let mut start = 0usize;
+    let mut has_code = false;
+    // Open `BEGIN` and `CASE` blocks while inside a routine.
+    let mut block_depth = 0usize;
     let mut index = 0usize;
     let mut in_single_quote = false;
     let mut in_double_quote = false;
@@ -186,9 +306,9 @@ fn split_statement_spans(sql: &str) -> Vec<(usize, usize)> {
             continue;
         }
 
-        if byte == b'-' && bytes.get(index + 1) == Some(&b'-') {
+        if byte == b'#' || (byte == b'-' && bytes.get(index + 1) == Some(&b'-')) {
             in_line_comment = true;
-            index += 2;
+            index += 1;
             continue;
         }
 
@@ -198,6 +318,74 @@ fn split_statement_spans(sql: &str) -> Vec<(usize, usize)> {
             continue;
         }
 
+        if byte.is_ascii_whitespace() {
+            index += 1;
+            continue;
+        }
+
+        if bytes[index..].starts_with(delimiter.as_bytes())
+            && (delimiter != ";" || block_depth == 0)
+        {
+            let end = index + delimiter.len();
+            spans.push(StatementSpan {
+                start,
+                end,
+                body_end: index,
+                kind: if has_code {
+                    SpanKind::Statement
+                } else {
+                    SpanKind::Blank
+                },
+            });
+            start = end;
+            has_code = false;
+            block_depth = 0;
+            index = end;
+            continue;
+        }
+
+        if is_word_byte(byte) && (index == 0 || !is_word_byte(bytes[index - 1])) {
+            let end_of_word = word_end(sql, index, &delimiter);
+            let word = &sql[index..end_of_word];
+            if !has_code && word.eq_ignore_ascii_case("DELIMITER") {
+                let line_end = sql[end_of_word..]
+                    .find('\n')
+                    .map_or(sql.len(), |offset| end_of_word + offset + 1);
+                if let Some(next) = sql[end_of_word..line_end].split_whitespace().next() {
+                    delimiter = next.to_string();
+                }
+                spans.push(StatementSpan {
+                    start,
+                    end: line_end,
+                    body_end: line_end,
+                    kind: SpanKind::Delimiter,
+                });
+                start = line_end;
+                index = line_end;
+                continue;
+            }
+            has_code = true;
+            if word.eq_ignore_ascii_case("BEGIN") || word.eq_ignore_ascii_case("CASE") {
+                if routines::routine_kind(&sql[start..index]).is_some() {
+                    block_depth += 1;
+                }
+            } else if word.eq_ignore_ascii_case("END") && block_depth > 0 {
+                // `END IF`, `END LOOP`, `END WHILE` and `END REPEAT` close blocks that
+                // were not counted.
+                let next_start = end_of_word + leading_whitespace_len(&sql[end_of_word..]);
+                let next = &sql[next_start..word_end(sql, next_start, &delimiter)];
+                if !["IF", "LOOP", "WHILE", "REPEAT"]
+                    .iter()
+                    .any(|keyword| next.eq_ignore_ascii_case(keyword))
+                {
+                    block_depth -= 1;
+                }
+            }
+            index = end_of_word;
+            continue;
+        }
+
+        has_code = true;
         match byte {
             b'\'' => {
                 in_single_quote = true;
@@ -211,10 +399,6 @@ fn split_statement_spans(sql: &str) -> Vec<(usize, usize)> {
             b'[' => {
                 in_bracket_ident = true;
             }
-            b';' => {
-                spans.push((start, index + 1));
-                start = index + 1;
-            }
             _ => {}
         }
 
@@ -222,16 +406,34 @@ fn split_statement_spans(sql: &str) -> Vec<(usize, usize)> {
     }
 
     if start < bytes.len() {
-        spans.push((start, bytes.len()));
-    }
-
-    if spans.is_empty() && !sql.trim().is_empty() {
-        spans.push((0, bytes.len()));
+        spans.push(StatementSpan {
+            start,
+            end: bytes.len(),
+            body_end: bytes.len(),
+            kind: if has_code {
+                SpanKind::Statement
+            } else {
+                SpanKind::Blank
+            },
+        });
     }
 
     spans
 }
 
+/// Where the word at `start` ends; a custom delimiter such as `$$` ends it too.
+fn word_end(sql: &str, start: usize, delimiter: &str) -> usize {
+    let bytes = sql.as_bytes();
+    let mut end = start;
+    while end < bytes.len()
+        && is_word_byte(bytes[end])
+        && !bytes[end..].starts_with(delimiter.as_bytes())
+    {
+        end += 1;
+    }
+    end
+}
+
 fn fallback_metadata(statement: &Statement) -> StatementMetadata {
     StatementMetadata {
         source_sql: statement.to_string(),
@@ -239,10 +441,29 @@ fn fallback_metadata(statement: &Statement) -> StatementMetadata {
             line: 1,
             column: None,
         }),
+        offset: usize::MAX,
         line: 1,
     }
 }
 
+fn leading_trivia_len(fragment: &str) -> usize {
+    scan_tokens(fragment)
+        .first()
+        .map_or(fragment.len(), |token| token.start)
+}
+
+fn blank_out(text: &str) -> String {
+    text.chars()
+        .map(|ch| {
+            if ch == '\n' {
+                "\n".to_string()
+            } else {
+                " ".repeat(ch.len_utf8())
+            }
+        })
+        .collect()
+}
+
 fn leading_whitespace_len(fragment: &str) -> usize {
     fragment
         .char_indices()
@@ -266,7 +487,9 @@ fn offset_to_line(sql: &str, offset: usize) -> usize {
 fn convert_statement(statement: &Statement, line: usize) -> ConversionResult<ConvertedStatement> {
     let statement_sql = statement.to_string();
     match statement {
-        Statement::CreateTable(_) => convert_create_table_statement(&statement_sql, line),
+        Statement::CreateTable(create_table) => {
+            convert_create_table_statement(create_table, &statement_sql, line)
+        }
         Statement::CreateView(create_view) => convert_create_view_statement(create_view, line),
         _ => Err(conversion_error(format!(
             "unsupported mysql statement kind: {}",
@@ -276,6 +499,7 @@ fn convert_statement(statement: &Statement, line: usize) -> ConversionResult<Con
 }
 
 fn convert_create_table_statement(
+    create_table: &CreateTable,
     statement_sql: &str,
     line: usize,
 ) -> ConversionResult<ConvertedStatement> {
@@ -284,16 +508,339 @@ fn convert_create_table_statement(
     let mut table = Table::named(table_name.name.value.as_str());
     table.name = table_name.clone();
     apply_preconversion_hints(statement_sql, &mut table);
+    apply_table_options(create_table, &mut table)?;
+
+    let mut indexes = Vec::new();
+    let mut attachments = vec![AnnotationAttachment {
+        line,
+        target: AnnotationTarget::Table(table_name.clone()),
+    }];
+    for column_def in &create_table.columns {
+        let column = convert_column(column_def, &mut table, &mut indexes)?;
+        // A column sharing the `CREATE TABLE` line leaves that line to the table.
+        let column_line = usize::try_from(column_def.name.span.start.line).unwrap_or(0);
+        if column_line != line {
+            attachments.push(AnnotationAttachment {
+                line: column_line,
+                target: AnnotationTarget::TableColumn {
+                    table: table_name.clone(),
+                    column: column.name.clone(),
+                },
+            });
+        }
+        table.columns.push(column);
+    }
+    for constraint in &create_table.constraints {
+        convert_table_constraint(constraint, &mut table, &mut indexes)?;
+    }
 
+    let mut objects = vec![SchemaObject::Table(table)];
+    objects.extend(indexes.into_iter().map(SchemaObject::Index));
     Ok(ConvertedStatement {
-        object: SchemaObject::Table(table),
-        attachment: AnnotationAttachment {
-            line,
-            target: AnnotationTarget::Table(table_name),
-        },
+        objects,
+        attachments,
     })
 }
 
+fn convert_column(
+    column_def: &ColumnDef,
+    table: &mut Table,
+    indexes: &mut Vec<IndexDef>,
+) -> ConversionResult<Column> {
+    let mut column = Column {
+        name: parse_sqlparser_ident(&column_def.name),
+        data_type: convert_data_type(&column_def.data_type),
+        not_null: false,
+        default: None,
+        identity: None,
+        generated: None,
+        comment: None,
+        collation: None,
+        renamed_from: None,
+        extra: BTreeMap::new(),
+    };
+
+    for option_def in &column_def.options {
+        let constraint_name = option_def.name.as_ref();
+        match &option_def.option {
+            ColumnOption::Null => column.not_null = false,
+            ColumnOption::NotNull => column.not_null = true,
+            ColumnOption::Default(expr) => column.default = Some(Expr::Raw(expr.to_string())),
+            ColumnOption::Collation(collation) => column.collation = Some(collation.to_string()),
+            ColumnOption::CharacterSet(charset) => {
+                column.extra.insert(
+                    extra_keys::COLUMN_CHARSET.to_string(),
+                    Value::String(charset.to_string()),
+                );
+            }
+            ColumnOption::Comment(comment) => column.comment = Some(comment.clone()),
+            ColumnOption::OnUpdate(expr) => {
+                column.extra.insert(
+                    extra_keys::COLUMN_ON_UPDATE.to_string(),
+                    Value::String(expr.to_string()),
+                );
+            }
+            ColumnOption::DialectSpecific(tokens) if is_auto_increment(tokens) => {
+                column
+                    .extra
+                    .insert(mysql_keys::AUTO_INCREMENT.to_string(), Value::Bool(true));
+            }
+            ColumnOption::Generated {
+                generation_expr: Some(expr),
+                generation_expr_mode,
+                ..
+            } => {
+                column.generated = Some(GeneratedColumn {
+                    expr: Expr::Raw(expr.to_string()),
+                    stored: matches!(generation_expr_mode, Some(GeneratedExpressionMode::Stored)),
+                });
+            }
+            ColumnOption::PrimaryKey(_) => {
+                set_primary_key(
+                    table,
+                    PrimaryKey {
+                        name: None,
+                        columns: vec![column.name.clone()],
+                    },
+                )?;
+            }
+            ColumnOption::Unique(unique) => {
+                indexes.push(table_index(
+                    &table.name,
+                    constraint_name
+                        .or(unique.name.as_ref())
+                        .map(parse_sqlparser_ident)
+                        .unwrap_or_else(|| column.name.clone()),
+                    vec![IndexColumn {
+                        expr: Expr::Ident(column.name.clone()),
+                    }],
+                    true,
+                    None,
+                ));
+            }
+            ColumnOption::ForeignKey(references) => {
+                table.foreign_keys.push(convert_foreign_key(
+                    constraint_name.or(references.name.as_ref()),
+                    vec![column.name.clone()],
+                    references,
+                )?);
+            }
+            ColumnOption::Check(check) => {
+                table.checks.push(CheckConstraint {
+                    name: constraint_name
+                        .or(check.name.as_ref())
+                        .map(parse_sqlparser_ident),
+                    expr: Expr::Raw(check.expr.to_string()),
+                    no_inherit: false,
+                });
+            }
+            other => {
+                return Err(conversion_error(format!(
+                    "unsupported mysql column option `{other}` on column {}",
+                    column_def.name
+                )));
+            }
+        }
+    }
+
+    Ok(column)
+}
+
+// ENUM and SET labels come straight from sqlparser, which has already unescaped
+// them; printing the type back out would not re-escape backslashes.
+fn convert_data_type(data_type: &SqlDataType) -> DataType {
+    let labels = match data_type {
+        SqlDataType::Enum(members, None) => members
+            .iter()
+            .map(|member| match member {
+                EnumMember::Name(name) => Some(name.clone()),
+                EnumMember::NamedValue(..) => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(DataType::Enum),
+        SqlDataType::Set(labels) => Some(DataType::Set(labels.clone())),
+        _ => None,
+    };
+    labels.unwrap_or_else(|| DataType::Custom(data_type.to_string()))
+}
+
+fn is_auto_increment(tokens: &[Token]) -> bool {
+    matches!(
+        tokens,
+        [Token::Word(word)] if word.keyword == Keyword::AUTO_INCREMENT
+    )
+}
+
+fn convert_table_constraint(
+    constraint: &TableConstraint,
+    table: &mut Table,
+    indexes: &mut Vec<IndexDef>,
+) -> ConversionResult<()> {
+    match constraint {
+        TableConstraint::PrimaryKey(primary_key) => set_primary_key(
+            table,
+            PrimaryKey {
+                name: None,
+                columns: index_column_names(&primary_key.columns)?,
+            },
+        ),
+        TableConstraint::Unique(unique) => {
+            let columns = index_columns(&unique.columns);
+            let name = index_name(
+                unique.index_name.as_ref().or(unique.name.as_ref()),
+                &columns,
+            )?;
+            indexes.push(table_index(
+                &table.name,
+                name,
+                columns,
+                true,
+                unique.index_type.as_ref(),
+            ));
+            Ok(())
+        }
+        TableConstraint::Index(index) => {
+            let columns = index_columns(&index.columns);
+            let name = index_name(index.name.as_ref(), &columns)?;
+            indexes.push(table_index(
+                &table.name,
+                name,
+                columns,
+                false,
+                index.index_type.as_ref(),
+            ));
+            Ok(())
+        }
+        TableConstraint::ForeignKey(foreign_key) => {
+            table.foreign_keys.push(convert_foreign_key(
+                foreign_key.name.as_ref(),
+                foreign_key
+                    .columns
+                    .iter()
+                    .map(parse_sqlparser_ident)
+                    .collect(),
+                foreign_key,
+            )?);
+            Ok(())
+        }
+        TableConstraint::Check(check) => {
+            table.checks.push(CheckConstraint {
+                name: check.name.as_ref().map(parse_sqlparser_ident),
+                expr: Expr::Raw(check.expr.to_string()),
+                no_inherit: false,
+            });
+            Ok(())
+        }
+        TableConstraint::FulltextOrSpatial(_) => Err(conversion_error(format!(
+            "unsupported mysql table constraint: {constraint}"
+        ))),
+    }
+}
+
+fn set_primary_key(table: &mut Table, primary_key: PrimaryKey) -> ConversionResult<()> {
+    if table.primary_key.is_some() {
+        return Err(conversion_error(format!(
+            "table {} has more than one primary key",
+            table.name.name.value
+        )));
+    }
+    table.primary_key = Some(primary_key);
+    Ok(())
+}
+
+/// A key declared inside `CREATE TABLE` becomes an index object of its own, which is how
+/// the generator adds and drops it.
+fn table_index(
+    table: &QualifiedName,
+    name: Ident,
+    columns: Vec<IndexColumn>,
+    unique: bool,
+    index_type: Option<&IndexType>,
+) -> IndexDef {
+    IndexDef {
+        name: Some(name),
+        owner: IndexOwner::Table(table.clone()),
+        columns,
+        unique,
+        method: index_type.map(ToString::to_string),
+        where_clause: None,
+        concurrent: false,
+        extra: BTreeMap::new(),
+    }
+}
+
+/// MySQL names an unnamed key after its first column.
+fn index_name(name: Option<&SqlIdent>, columns: &[IndexColumn]) -> ConversionResult<Ident> {
+    if let Some(name) = name {
+        return Ok(parse_sqlparser_ident(name));
+    }
+    match columns.first().map(|column| &column.expr) {
+        Some(Expr::Ident(column)) => Ok(column.clone()),
+        _ => Err(conversion_error(
+            "mysql keys on expressions need a name: KEY <name> ((<expr>))",
+        )),
+    }
+}
+
+fn index_columns(columns: &[SqlIndexColumn]) -> Vec<IndexColumn> {
+    columns
+        .iter()
+        .map(|column| IndexColumn {
+            expr: match &column.column.expr {
+                SqlExpr::Identifier(ident)
+                    if column.column.options.asc.is_none() && column.operator_class.is_none() =>
+                {
+                    Expr::Ident(parse_sqlparser_ident(ident))
+                }
+                _ => Expr::Raw(column.column.to_string()),
+            },
+        })
+        .collect()
+}
+
+fn index_column_names(columns: &[SqlIndexColumn]) -> ConversionResult<Vec<Ident>> {
+    columns
+        .iter()
+        .map(|column| match &column.column.expr {
+            SqlExpr::Identifier(ident) => Ok(parse_sqlparser_ident(ident)),
+            other => Err(conversion_error(format!(
+                "unsupported mysql primary key column: {other}"
+            ))),
+        })
+        .collect()
+}
+
+fn convert_foreign_key(
+    name: Option<&SqlIdent>,
+    columns: Vec<Ident>,
+    foreign_key: &ForeignKeyConstraint,
+) -> ConversionResult<ForeignKey> {
+    Ok(ForeignKey {
+        name: name.map(parse_sqlparser_ident),
+        columns,
+        referenced_table: parse_object_name(&foreign_key.foreign_table)?,
+        referenced_columns: foreign_key
+            .referred_columns
+            .iter()
+            .map(parse_sqlparser_ident)
+            .collect(),
+        on_delete: foreign_key.on_delete.map(convert_referential_action),
+        on_update: foreign_key.on_update.map(convert_referential_action),
+        deferrable: None,
+        extra: BTreeMap::new(),
+    })
+}
+
+fn convert_referential_action(action: ReferentialAction) -> ForeignKeyAction {
+    match action {
+        ReferentialAction::Restrict => ForeignKeyAction::Restrict,
+        ReferentialAction::Cascade => ForeignKeyAction::Cascade,
+        ReferentialAction::SetNull => ForeignKeyAction::SetNull,
+        ReferentialAction::NoAction => ForeignKeyAction::NoAction,
+        ReferentialAction::SetDefault => ForeignKeyAction::SetDefault,
+    }
+}
+
 fn convert_create_view_statement(
     create_view: &CreateView,
     line: usize,
@@ -332,11 +879,11 @@ fn convert_create_view_statement(
         .map(parse_view_security);
 
     Ok(ConvertedStatement {
-        object: SchemaObject::View(view),
-        attachment: AnnotationAttachment {
+        objects: vec![SchemaObject::View(view)],
+        attachments: vec![AnnotationAttachment {
             line,
             target: AnnotationTarget::View(name),
-        },
+        }],
     })
 }
 
@@ -383,6 +930,86 @@ fn apply_preconversion_hints(statement_sql: &str, table: &mut Table) {
     }
 }
 
+fn apply_table_options(create_table: &CreateTable, table: &mut Table) -> ConversionResult<()> {
+    let options = match &create_table.table_options {
+        CreateTableOptions::None => return Ok(()),
+        CreateTableOptions::Plain(options) => options,
+        other => {
+            return Err(conversion_error(format!(
+                "unsupported mysql table options clause: {other}"
+            )));
+        }
+    };
+
+    for option in options {
+        match option {
+            SqlOption::NamedParenthesizedList(list) if list.key.value == "ENGINE" => {
+                if let Some(engine) = &list.name {
+                    insert_table_option(table, mysql_keys::TABLE_ENGINE, engine.value.clone());
+                }
+            }
+            SqlOption::Comment(CommentDef::WithEq(comment) | CommentDef::WithoutEq(comment)) => {
+                insert_table_option(table, mysql_keys::TABLE_COMMENT, comment.clone());
+            }
+            SqlOption::KeyValue { key, value } => {
+                let Some(key) = table_option_key(&key.value) else {
+                    continue;
+                };
+                let value = table_option_value(value).ok_or_else(|| {
+                    conversion_error(format!("unsupported value for table option {key}: {value}"))
+                })?;
+                insert_table_option(table, key, value);
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(CommentDef::WithEq(comment) | CommentDef::WithoutEq(comment)) =
+        &create_table.comment
+    {
+        insert_table_option(table, mysql_keys::TABLE_COMMENT, comment.clone());
+    }
+
+    Ok(())
+}
+
+fn table_option_key(raw_key: &str) -> Option<&'static str> {
+    match raw_key {
+        "DEFAULT CHARSET" | "CHARSET" | "DEFAULT CHARACTER SET" | "CHARACTER SET" => {
+            Some(mysql_keys::TABLE_CHARSET)
+        }
+        "DEFAULT COLLATE" | "COLLATE" => Some(mysql_keys::TABLE_COLLATE),
+        "ROW_FORMAT" => Some(mysql_keys::TABLE_ROW_FORMAT),
+        "AUTO_INCREMENT" => Some(extra_keys::TABLE_AUTO_INCREMENT_VALUE),
+        _ => None,
+    }
+}
+
+fn table_option_value(value: &SqlExpr) -> Option<String> {
+    match value {
+        SqlExpr::Identifier(ident) => Some(ident.value.clone()),
+        SqlExpr::Value(value) => match &value.value {
+            SqlValue::Number(number, _) => Some(number.to_string()),
+            SqlValue::SingleQuotedString(text) | SqlValue::DoubleQuotedString(text) => {
+                Some(text.clone())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn insert_table_option(table: &mut Table, key: &str, value: String) {
+    let value = if key == extra_keys::TABLE_AUTO_INCREMENT_VALUE {
+        value
+            .parse::<i64>()
+            .map_or(Value::String(value), Value::Integer)
+    } else {
+        Value::String(value)
+    };
+    table.options.extra.insert(key.to_string(), value);
+}
+
 fn extract_partition_clause(statement_sql: &str) -> Option<String> {
     let marker = "PARTITION BY";
     let index = statement_sql
@@ -445,7 +1072,7 @@ fn parse_create_table_name(statement_sql: &str) -> Option<QualifiedName> {
 fn parse_object_name(name: &ObjectName) -> ConversionResult<QualifiedName> {
     if name.0.is_empty() || name.0.len() > 2 {
         return Err(conversion_error(format!(
-            "unsupported qualified name in CREATE VIEW: {}",
+            "unsupported qualified name in mysql statement: {}",
             name
         )));
     }
@@ -456,7 +1083,7 @@ fn parse_object_name(name: &ObjectName) -> ConversionResult<QualifiedName> {
         .map(|part| {
             part.as_ident().ok_or_else(|| {
                 conversion_error(format!(
-                    "unsupported object name part in CREATE VIEW: {}",
+                    "unsupported object name part in mysql statement: {}",
                     part
                 ))
             })
@@ -0,0 +1,339 @@
+//! Stored functions, procedures, triggers and events are compound statements: their bodies
+//! may hold `;`-terminated statements inside `BEGIN ... END`, which sqlparser cannot read.
+//! Only the header is read here; the body is kept as written, as `SHOW CREATE` reports it.
+
+use std::io;
+
+use stateql_core::{
+    DataType, Event, Function, FunctionParam, FunctionParamMode, FunctionSecurity, QualifiedName,
+    SchemaObject, Trigger, TriggerEvent, TriggerForEach, TriggerTiming,
+};
+
+use crate::header::{Header, TokenKind, conversion_error, scan_tokens};
+
+type ConversionResult<T> = std::result::Result<T, io::Error>;
+
+const ROUTINE_LANGUAGE: &str = "sql";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RoutineKind {
+    Function,
+    Procedure,
+    Trigger,
+    Event,
+}
+
+/// The routine a statement creates, if it starts with `CREATE [DEFINER = ...] FUNCTION`,
+/// `PROCEDURE`, `TRIGGER` or `EVENT`.
+pub(crate) fn routine_kind(sql: &str) -> Option<RoutineKind> {
+    routine_header(&mut Header::new(sql))
+}
+
+fn routine_header(header: &mut Header<'_>) -> Option<RoutineKind> {
+    if !header.eat_word("CREATE") {
+        return None;
+    }
+    // The definer is the account that happened to create the routine; `export_schema`
+    // strips it as well.
+    if header.eat_word("DEFINER") {
+        if !header.eat_punct(b'=') {
+            return None;
+        }
+        while header.peek().is_some_and(|token| {
+            !["FUNCTION", "PROCEDURE", "TRIGGER", "EVENT", "AGGREGATE"]
+                .iter()
+                .any(|keyword| token.is_word(header.sql, keyword))
+        }) {
+            header.next_token("definer").ok()?;
+            header.eat_punct(b'@');
+            header.eat_punct(b'(');
+            header.eat_punct(b')');
+        }
+    }
+    if header.eat_word("FUNCTION") {
+        Some(RoutineKind::Function)
+    } else if header.eat_word("PROCEDURE") {
+        Some(RoutineKind::Procedure)
+    } else if header.eat_word("TRIGGER") {
+        Some(RoutineKind::Trigger)
+    } else if header.eat_word("EVENT") {
+        Some(RoutineKind::Event)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn convert_routine(sql: &str) -> ConversionResult<SchemaObject> {
+    let mut header = Header::new(sql);
+    let kind = routine_header(&mut header)
+        .ok_or_else(|| conversion_error("mysql statement does not create a routine"))?;
+    if header.eat_word("IF") {
+        return Err(conversion_error(
+            "unsupported mysql routine option: IF NOT EXISTS",
+        ));
+    }
+    let name = header.object_name()?;
+
+    match kind {
+        RoutineKind::Function => convert_function(header, name),
+        RoutineKind::Procedure => convert_procedure(header, name),
+        RoutineKind::Trigger => convert_trigger(header, name),
+        RoutineKind::Event => convert_event(header, name),
+    }
+}
+
+fn convert_function(mut header: Header<'_>, name: QualifiedName) -> ConversionResult<SchemaObject> {
+    let params = params(&mut header, false)?;
+    header.expect_word("RETURNS")?;
+    let return_type = header.text_until("return type", |header, token| {
+        starts_characteristic(header)
+            || ["BEGIN", "RETURN"]
+                .iter()
+                .any(|keyword| token.is_word(header.sql, keyword))
+    })?;
+    let (security, body) = routine_body(header, "function")?;
+
+    Ok(SchemaObject::Function(Function {
+        name,
+        params,
+        return_type: Some(DataType::Custom(return_type.to_string())),
+        language: ROUTINE_LANGUAGE.to_string(),
+        body,
+        volatility: None,
+        security,
+    }))
+}
+
+/// Procedures have no IR of their own: they are functions without a return type.
+fn convert_procedure(
+    mut header: Header<'_>,
+    name: QualifiedName,
+) -> ConversionResult<SchemaObject> {
+    let params = params(&mut header, true)?;
+    let (security, body) = routine_body(header, "procedure")?;
+
+    Ok(SchemaObject::Function(Function {
+        name,
+        params,
+        return_type: None,
+        language: ROUTINE_LANGUAGE.to_string(),
+        body,
+        volatility: None,
+        security,
+    }))
+}
+
+fn convert_trigger(mut header: Header<'_>, name: QualifiedName) -> ConversionResult<SchemaObject> {
+    let timing = if header.eat_word("BEFORE") {
+        TriggerTiming::Before
+    } else if header.eat_word("AFTER") {
+        TriggerTiming::After
+    } else {
+        return Err(header.unexpected("BEFORE or AFTER"));
+    };
+    let event = if header.eat_word("INSERT") {
+        TriggerEvent::Insert
+    } else if header.eat_word("UPDATE") {
+        TriggerEvent::Update
+    } else if header.eat_word("DELETE") {
+        TriggerEvent::Delete
+    } else {
+        return Err(header.unexpected("INSERT, UPDATE or DELETE"));
+    };
+    header.expect_word("ON")?;
+    let table = header.object_name()?;
+    header.expect_word("FOR")?;
+    header.expect_word("EACH")?;
+    header.expect_word("ROW")?;
+    if header.peek_word("FOLLOWS") || header.peek_word("PRECEDES") {
+        return Err(conversion_error(
+            "unsupported mysql trigger option: FOLLOWS and PRECEDES are not supported",
+        ));
+    }
+
+    Ok(SchemaObject::Trigger(Trigger {
+        name,
+        table,
+        timing,
+        events: vec![event],
+        update_columns: Vec::new(),
+        for_each: TriggerForEach::Row,
+        when_clause: None,
+        body: header.body("trigger")?,
+    }))
+}
+
+/// `ON SCHEDULE ... [ON COMPLETION [NOT] PRESERVE] [ENABLE | DISABLE] [COMMENT '...'] DO body`.
+fn convert_event(mut header: Header<'_>, name: QualifiedName) -> ConversionResult<SchemaObject> {
+    header.expect_word("ON")?;
+    header.expect_word("SCHEDULE")?;
+    let schedule = header.text_until("event schedule", |header, token| {
+        ["ON", "ENABLE", "DISABLE", "COMMENT", "DO"]
+            .iter()
+            .any(|keyword| token.is_word(header.sql, keyword))
+    })?;
+
+    let mut preserve = false;
+    if header.eat_word("ON") {
+        header.expect_word("COMPLETION")?;
+        preserve = !header.eat_word("NOT");
+        header.expect_word("PRESERVE")?;
+    }
+    let mut enabled = true;
+    if header.eat_word("DISABLE") {
+        if header.peek_word("ON") {
+            return Err(conversion_error(
+                "unsupported mysql event option: DISABLE ON REPLICA",
+            ));
+        }
+        enabled = false;
+    } else {
+        header.eat_word("ENABLE");
+    }
+    let comment = if header.eat_word("COMMENT") {
+        Some(header.string("event comment")?)
+    } else {
+        None
+    };
+    header.expect_word("DO")?;
+
+    Ok(SchemaObject::Event(Event {
+        name,
+        schedule: schedule.to_string(),
+        preserve,
+        enabled,
+        comment,
+        body: header.body("event")?,
+    }))
+}
+
+/// `SHOW CREATE EVENT` restates the schedule with uppercase keywords and single spaces;
+/// strings are kept as written.
+pub(crate) fn normalize_event_schedule(schedule: &str) -> String {
+    let mut normalized = String::with_capacity(schedule.len());
+    let mut previous_end = None;
+    for token in scan_tokens(schedule) {
+        if previous_end.is_some_and(|end| end < token.start) {
+            normalized.push(' ');
+        }
+        let text = token.text(schedule);
+        match token.kind {
+            TokenKind::Word => normalized.push_str(&text.to_ascii_uppercase()),
+            _ => normalized.push_str(text),
+        }
+        previous_end = Some(token.end);
+    }
+    normalized
+}
+
+/// `([IN | OUT | INOUT] name type, ...)`; only procedures take a mode.
+fn params(header: &mut Header<'_>, with_mode: bool) -> ConversionResult<Vec<FunctionParam>> {
+    header.expect_punct(b'(')?;
+    let mut params = Vec::new();
+    if header.eat_punct(b')') {
+        return Ok(params);
+    }
+    loop {
+        let mode = if !with_mode {
+            None
+        } else if header.eat_word("INOUT") {
+            Some(FunctionParamMode::InOut)
+        } else if header.eat_word("OUT") {
+            Some(FunctionParamMode::Out)
+        } else if header.eat_word("IN") {
+            Some(FunctionParamMode::In)
+        } else {
+            None
+        };
+        let name = header.ident("parameter name")?;
+        let data_type = header.text_until("parameter type", |_, token| {
+            matches!(token.kind, TokenKind::Punct(b',' | b')'))
+        })?;
+        params.push(FunctionParam {
+            name: Some(name),
+            data_type: DataType::Custom(data_type.to_string()),
+            mode,
+            default: None,
+        });
+        if header.eat_punct(b')') {
+            return Ok(params);
+        }
+        header.expect_punct(b',')?;
+    }
+}
+
+/// The characteristics and the body that follow a routine's signature. `SQL SECURITY` is
+/// lifted into the IR; the others stay in front of the body, one space apart, so
+/// `to_sql` restates them as written.
+fn routine_body(
+    mut header: Header<'_>,
+    routine: &str,
+) -> ConversionResult<(Option<FunctionSecurity>, String)> {
+    let mut security = None;
+    let mut characteristics = Vec::new();
+    while starts_characteristic(&header) {
+        if header.eat_word("SQL") {
+            header.expect_word("SECURITY")?;
+            security = Some(if header.eat_word("DEFINER") {
+                FunctionSecurity::Definer
+            } else if header.eat_word("INVOKER") {
+                FunctionSecurity::Invoker
+            } else {
+                return Err(header.unexpected("DEFINER or INVOKER"));
+            });
+            continue;
+        }
+        for _ in 0..characteristic_len(&header) {
+            let token = header.next_token("routine characteristic")?;
+            let text = token.text(header.sql);
+            characteristics.push(match token.kind {
+                TokenKind::Word => text.to_ascii_uppercase(),
+                _ => text.to_string(),
+            });
+        }
+    }
+
+    let body = header.body(routine)?;
+    if characteristics.is_empty() {
+        return Ok((security, body));
+    }
+    Ok((security, format!("{} {body}", characteristics.join(" "))))
+}
+
+/// Whether a stored function body is restated as written: it opens with a compound
+/// statement, a `RETURN` or a routine characteristic. Any other body is an expression.
+pub(crate) fn is_complete_function_body(body: &str) -> bool {
+    let header = Header::new(body);
+    header.peek().is_some_and(|token| {
+        token.is_word(body, "BEGIN")
+            || token.is_word(body, "RETURN")
+            || starts_characteristic(&header)
+    })
+}
+
+fn starts_characteristic(header: &Header<'_>) -> bool {
+    characteristic_len(header) > 0
+}
+
+/// How many tokens the characteristic under the cursor spans, or zero.
+fn characteristic_len(header: &Header<'_>) -> usize {
+    const CHARACTERISTICS: [&[&str]; 9] = [
+        &["COMMENT"],
+        &["LANGUAGE", "SQL"],
+        &["NOT", "DETERMINISTIC"],
+        &["DETERMINISTIC"],
+        &["CONTAINS", "SQL"],
+        &["NO", "SQL"],
+        &["READS", "SQL", "DATA"],
+        &["MODIFIES", "SQL", "DATA"],
+        &["SQL", "SECURITY"],
+    ];
+    CHARACTERISTICS
+        .iter()
+        .find(|words| header.peek_words(words))
+        // `COMMENT` is followed by its string, `SQL SECURITY` by the security kind.
+        .map_or(0, |words| match words {
+            ["COMMENT"] | ["SQL", "SECURITY"] => words.len() + 1,
+            _ => words.len(),
+        })
+}
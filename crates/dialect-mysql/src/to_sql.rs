@@ -1,19 +1,22 @@
 use std::fmt::Write as _;
 
 use stateql_core::{
-    BinaryOperator, CheckConstraint, CheckOption, ComparisonOp, DataType, Expr, ForeignKey,
+    BinaryOperator, CheckConstraint, CheckOption, ComparisonOp, DataType, Event, Expr, ForeignKey,
     ForeignKeyAction, Function, FunctionParamMode, FunctionSecurity, GenerateError, Ident,
-    IndexDef, IndexOwner, IsTest, Literal, SchemaObject, Trigger, TriggerEvent, TriggerForEach,
-    TriggerTiming, UnaryOperator, Value, ViewSecurity,
+    IndexDef, IndexOwner, IsTest, Literal, Privilege, PrivilegeObject, SchemaObject, Trigger,
+    TriggerEvent, TriggerForEach, TriggerTiming, UnaryOperator, Value, ViewSecurity,
+    extra_keys::mysql as mysql_keys,
 };
 
-use crate::extra_keys;
+use crate::{extra_keys, grants, routines};
 
 const TO_SQL_TARGET: &str = "dialect export renderer";
 const UNSUPPORTED_TABLE_VARIANT: &str = "CreateTableUnsupportedShape";
 const UNSUPPORTED_INDEX_VARIANT: &str = "CreateIndexUnsupportedShape";
 const UNSUPPORTED_TRIGGER_VARIANT: &str = "CreateTriggerUnsupportedShape";
 const UNSUPPORTED_FUNCTION_VARIANT: &str = "CreateFunctionUnsupportedShape";
+const UNSUPPORTED_EVENT_VARIANT: &str = "CreateEventUnsupportedShape";
+const UNSUPPORTED_PRIVILEGE_VARIANT: &str = "GrantUnsupportedShape";
 
 pub(crate) fn render_object(
     dialect_name: &str,
@@ -25,17 +28,20 @@ pub(crate) fn render_object(
         SchemaObject::Index(index) => render_index(dialect_name, index),
         SchemaObject::Trigger(trigger) => render_trigger(dialect_name, trigger),
         SchemaObject::Function(function) => render_function(dialect_name, function),
+        SchemaObject::Event(event) => render_event(dialect_name, event),
         SchemaObject::MaterializedView(_) => {
             unsupported_variant_error(dialect_name, "MaterializedView")
         }
         SchemaObject::Sequence(_) => unsupported_variant_error(dialect_name, "Sequence"),
+        SchemaObject::Synonym(_) => unsupported_variant_error(dialect_name, "Synonym"),
         SchemaObject::Type(_) => unsupported_variant_error(dialect_name, "Type"),
         SchemaObject::Domain(_) => unsupported_variant_error(dialect_name, "Domain"),
         SchemaObject::Extension(_) => unsupported_variant_error(dialect_name, "Extension"),
         SchemaObject::Schema(_) => unsupported_variant_error(dialect_name, "Schema"),
         SchemaObject::Comment(_) => unsupported_variant_error(dialect_name, "Comment"),
-        SchemaObject::Privilege(_) => unsupported_variant_error(dialect_name, "Privilege"),
+        SchemaObject::Privilege(privilege) => render_privilege(dialect_name, privilege),
         SchemaObject::Policy(_) => unsupported_variant_error(dialect_name, "Policy"),
+        SchemaObject::Pragma(_) => unsupported_variant_error(dialect_name, "Pragma"),
     }
 }
 
@@ -92,6 +98,10 @@ fn render_table(dialect_name: &str, table: &stateql_core::Table) -> stateql_core
     sql.push_str(&definitions.join(", "));
     sql.push(')');
 
+    for option in render_table_options(table) {
+        write!(sql, " {option}").expect("writing to String should not fail");
+    }
+
     if let Some(partition_hint) = partition_hint(table) {
         write!(sql, " {partition_hint}").expect("writing to String should not fail");
     }
@@ -100,6 +110,31 @@ fn render_table(dialect_name: &str, table: &stateql_core::Table) -> stateql_core
     Ok(sql)
 }
 
+fn render_table_options(table: &stateql_core::Table) -> Vec<String> {
+    let option = |key: &str| match table.options.extra.get(key) {
+        Some(Value::String(value)) => Some(value.as_str()),
+        _ => None,
+    };
+
+    let mut options = Vec::new();
+    if let Some(engine) = option(mysql_keys::TABLE_ENGINE) {
+        options.push(format!("ENGINE={engine}"));
+    }
+    if let Some(charset) = option(mysql_keys::TABLE_CHARSET) {
+        options.push(format!("DEFAULT CHARSET={charset}"));
+    }
+    if let Some(collation) = option(mysql_keys::TABLE_COLLATE) {
+        options.push(format!("COLLATE={collation}"));
+    }
+    if let Some(row_format) = option(mysql_keys::TABLE_ROW_FORMAT) {
+        options.push(format!("ROW_FORMAT={row_format}"));
+    }
+    if let Some(comment) = option(mysql_keys::TABLE_COMMENT) {
+        options.push(format!("COMMENT={}", render_string_literal(comment)));
+    }
+    options
+}
+
 fn source_table_sql_hint(table: &stateql_core::Table) -> Option<&str> {
     match table.options.extra.get(extra_keys::TABLE_SOURCE_SQL) {
         Some(Value::String(value)) if !value.trim().is_empty() => Some(value.as_str()),
@@ -121,6 +156,9 @@ fn render_column(column: &stateql_core::Column) -> String {
         render_data_type(&column.data_type)
     );
 
+    if let Some(Value::String(charset)) = column.extra.get(extra_keys::COLUMN_CHARSET) {
+        write!(sql, " CHARACTER SET {charset}").expect("writing to String should not fail");
+    }
     if column.not_null {
         sql.push_str(" NOT NULL");
     }
@@ -128,6 +166,9 @@ fn render_column(column: &stateql_core::Column) -> String {
         write!(sql, " DEFAULT {}", render_expr(default))
             .expect("writing to String should not fail");
     }
+    if let Some(Value::String(on_update)) = column.extra.get(extra_keys::COLUMN_ON_UPDATE) {
+        write!(sql, " ON UPDATE {on_update}").expect("writing to String should not fail");
+    }
     if let Some(identity) = &column.identity
         && (identity.always
             || identity.start.is_some()
@@ -161,6 +202,11 @@ fn render_column(column: &stateql_core::Column) -> String {
         write!(sql, " COLLATE {}", collation.trim()).expect("writing to String should not fail");
     }
 
+    if let Some(comment) = &column.comment {
+        write!(sql, " COMMENT {}", render_string_literal(comment))
+            .expect("writing to String should not fail");
+    }
+
     sql
 }
 
@@ -293,7 +339,7 @@ fn render_trigger(dialect_name: &str, trigger: &Trigger) -> stateql_core::Result
     if trigger.events.is_empty() || trigger.for_each != TriggerForEach::Row {
         return unsupported_shape_error(dialect_name, UNSUPPORTED_TRIGGER_VARIANT);
     }
-    if trigger.when_clause.is_some() {
+    if trigger.when_clause.is_some() || !trigger.update_columns.is_empty() {
         return unsupported_shape_error(dialect_name, UNSUPPORTED_TRIGGER_VARIANT);
     }
 
@@ -321,22 +367,25 @@ fn render_trigger(dialect_name: &str, trigger: &Trigger) -> stateql_core::Result
 }
 
 fn render_function(dialect_name: &str, function: &Function) -> stateql_core::Result<String> {
-    let Some(return_type) = &function.return_type else {
-        return unsupported_shape_error(dialect_name, UNSUPPORTED_FUNCTION_VARIANT);
+    let params = function
+        .params
+        .iter()
+        .map(render_function_param)
+        .collect::<Vec<_>>()
+        .join(", ");
+    // Procedures are functions without a return type.
+    let mut sql = match &function.return_type {
+        Some(return_type) => format!(
+            "CREATE FUNCTION {}({params}) RETURNS {}",
+            render_qualified_name(&function.name),
+            render_data_type(return_type)
+        ),
+        None => format!(
+            "CREATE PROCEDURE {}({params})",
+            render_qualified_name(&function.name)
+        ),
     };
 
-    let mut sql = format!(
-        "CREATE FUNCTION {}({}) RETURNS {}",
-        render_qualified_name(&function.name),
-        function
-            .params
-            .iter()
-            .map(render_function_param)
-            .collect::<Vec<_>>()
-            .join(", "),
-        render_data_type(return_type)
-    );
-
     if let Some(security) = function.security {
         write!(sql, " SQL SECURITY {}", render_function_security(security))
             .expect("writing to String should not fail");
@@ -347,7 +396,7 @@ fn render_function(dialect_name: &str, function: &Function) -> stateql_core::Res
         return unsupported_shape_error(dialect_name, UNSUPPORTED_FUNCTION_VARIANT);
     }
 
-    if body.to_ascii_uppercase().starts_with("BEGIN") {
+    if function.return_type.is_none() || routines::is_complete_function_body(body) {
         write!(sql, " {body}").expect("writing to String should not fail");
     } else {
         write!(sql, " RETURN {body}").expect("writing to String should not fail");
@@ -357,6 +406,87 @@ fn render_function(dialect_name: &str, function: &Function) -> stateql_core::Res
     Ok(sql)
 }
 
+/// Options that match the server defaults (`NOT PRESERVE`, `ENABLE`) are left out.
+fn render_event(dialect_name: &str, event: &Event) -> stateql_core::Result<String> {
+    let schedule = event.schedule.trim();
+    let body = event.body.trim().trim_end_matches(';').trim();
+    if schedule.is_empty() || body.is_empty() {
+        return unsupported_shape_error(dialect_name, UNSUPPORTED_EVENT_VARIANT);
+    }
+
+    let mut sql = format!(
+        "CREATE EVENT {} ON SCHEDULE {schedule}",
+        render_qualified_name(&event.name)
+    );
+    if event.preserve {
+        sql.push_str(" ON COMPLETION PRESERVE");
+    }
+    if !event.enabled {
+        sql.push_str(" DISABLE");
+    }
+    if let Some(comment) = &event.comment {
+        write!(sql, " COMMENT {}", render_string_literal(comment))
+            .expect("writing to String should not fail");
+    }
+    write!(sql, " DO {body};").expect("writing to String should not fail");
+
+    Ok(sql)
+}
+
+fn render_privilege(dialect_name: &str, privilege: &Privilege) -> stateql_core::Result<String> {
+    let (operations, target) = render_privilege_parts(dialect_name, privilege)?;
+    let mut sql = format!(
+        "GRANT {operations} ON {target} TO {}",
+        grants::render_account(&privilege.grantee)
+    );
+    if privilege.with_grant_option {
+        sql.push_str(" WITH GRANT OPTION");
+    }
+    sql.push(';');
+
+    Ok(sql)
+}
+
+/// `REVOKE` for the privilege's operations, or for the grant option alone when
+/// `with_grant_option` is set: MySQL holds one grant option per object, not per operation.
+pub(crate) fn render_revoke(
+    dialect_name: &str,
+    privilege: &Privilege,
+) -> stateql_core::Result<String> {
+    let (operations, target) = render_privilege_parts(dialect_name, privilege)?;
+    let operations = if privilege.with_grant_option {
+        "GRANT OPTION".to_string()
+    } else {
+        operations
+    };
+    Ok(format!(
+        "REVOKE {operations} ON {target} FROM {};",
+        grants::render_account(&privilege.grantee)
+    ))
+}
+
+fn render_privilege_parts(
+    dialect_name: &str,
+    privilege: &Privilege,
+) -> stateql_core::Result<(String, String)> {
+    let target = match &privilege.on {
+        PrivilegeObject::Table(name) => render_qualified_name(name),
+        PrivilegeObject::Database(database) => format!("{}.*", render_ident(database)),
+        _ => return unsupported_shape_error(dialect_name, UNSUPPORTED_PRIVILEGE_VARIANT),
+    };
+    let Some(operations) = privilege
+        .operations
+        .iter()
+        .map(|operation| grants::render_privilege_op(*operation))
+        .collect::<Option<Vec<_>>>()
+        .filter(|operations| !operations.is_empty())
+    else {
+        return unsupported_shape_error(dialect_name, UNSUPPORTED_PRIVILEGE_VARIANT);
+    };
+
+    Ok((operations.join(", "), target))
+}
+
 fn render_function_param(param: &stateql_core::FunctionParam) -> String {
     let mut sql = String::new();
     if let Some(mode) = param.mode {
@@ -403,10 +533,20 @@ pub(crate) fn render_data_type(data_type: &DataType) -> String {
         DataType::Json | DataType::Jsonb => "json".to_string(),
         DataType::Uuid => "char(36)".to_string(),
         DataType::Array(inner) => format!("json /* array<{}> */", render_data_type(inner)),
+        DataType::Enum(labels) => format!("enum({})", render_labels(labels)),
+        DataType::Set(labels) => format!("set({})", render_labels(labels)),
         DataType::Custom(custom) => custom.trim().to_string(),
     }
 }
 
+fn render_labels(labels: &[String]) -> String {
+    labels
+        .iter()
+        .map(|label| render_string_literal(label))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 pub(crate) fn render_expr(expr: &Expr) -> String {
     match expr {
         Expr::Literal(literal) => render_literal(literal),
@@ -603,9 +743,15 @@ pub(crate) fn render_expr(expr: &Expr) -> String {
     }
 }
 
+/// Quotes a string literal. MySQL reads backslashes inside quotes as escapes,
+/// so they are doubled along with the quote character.
+pub(crate) fn render_string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+}
+
 fn render_literal(literal: &Literal) -> String {
     match literal {
-        Literal::String(value) => format!("'{}'", value.replace('\'', "''")),
+        Literal::String(value) => render_string_literal(value),
         Literal::Integer(value) => value.to_string(),
         Literal::Float(value) => value.to_string(),
         Literal::Boolean(value) => {
@@ -621,7 +767,7 @@ fn render_literal(literal: &Literal) -> String {
 
 fn render_value(value: &Value) -> String {
     match value {
-        Value::String(value) => format!("'{}'", value.replace('\'', "''")),
+        Value::String(value) => render_string_literal(value),
         Value::Integer(value) => value.to_string(),
         Value::Float(value) => value.to_string(),
         Value::Bool(value) => {
@@ -761,7 +907,7 @@ fn ensure_sql_terminated(sql: &str) -> String {
     }
 }
 
-fn unsupported_shape_error(dialect_name: &str, diff_op: &str) -> stateql_core::Result<String> {
+fn unsupported_shape_error<T>(dialect_name: &str, diff_op: &str) -> stateql_core::Result<T> {
     Err(GenerateError::UnsupportedDiffOp {
         diff_op: diff_op.to_string(),
         target: TO_SQL_TARGET.to_string(),
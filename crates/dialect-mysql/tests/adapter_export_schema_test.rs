@@ -1,7 +1,13 @@
 use std::collections::BTreeMap;
 
-use stateql_core::{ConnectionConfig, Dialect};
-use stateql_dialect_mysql::{MysqlDialect, lower_case_table_names_query, table_names_query};
+use stateql_core::{
+    ConnectionConfig, DataType, Dialect, FunctionParamMode, FunctionSecurity, Ident, Privilege,
+    PrivilegeObject, PrivilegeOp, QualifiedName, SchemaObject, TriggerEvent, TriggerTiming,
+};
+use stateql_dialect_mysql::{
+    MysqlDialect, events_query, lower_case_table_names_query, privilege_from_show_grants,
+    routines_query, table_names_query,
+};
 
 #[test]
 fn export_queries_keep_view_filter_without_order_by_and_lower_case_variable_probe() {
@@ -14,6 +20,218 @@ fn export_queries_keep_view_filter_without_order_by_and_lower_case_variable_prob
     assert!(lower_case_variable.contains("lower_case_table_names"));
 }
 
+#[test]
+fn routine_query_is_scoped_to_the_current_database() {
+    assert!(routines_query().contains("ROUTINE_SCHEMA = DATABASE()"));
+    assert!(routines_query().contains("ROUTINE_TYPE"));
+}
+
+#[test]
+fn event_query_is_scoped_to_the_current_database() {
+    assert!(events_query().contains("EVENT_SCHEMA = DATABASE()"));
+}
+
+#[test]
+fn show_grants_rows_map_to_privileges_on_the_current_database() {
+    let table_grant = privilege_from_show_grants(
+        "GRANT SELECT, INSERT, ALTER ON `app`.`orders` TO `reporter`@`%` WITH GRANT OPTION",
+        "app",
+    )
+    .expect("table grant should map");
+    assert_eq!(
+        table_grant,
+        Privilege {
+            operations: vec![PrivilegeOp::Select, PrivilegeOp::Insert],
+            on: PrivilegeObject::Table(QualifiedName {
+                schema: None,
+                name: Ident::unquoted("orders"),
+            }),
+            grantee: Ident::unquoted("reporter@%"),
+            with_grant_option: true,
+        }
+    );
+
+    let database_grant = privilege_from_show_grants(
+        "GRANT ALL PRIVILEGES ON `app`.* TO `owner`@`localhost`",
+        "app",
+    )
+    .expect("database grant should map");
+    assert_eq!(database_grant.operations, vec![PrivilegeOp::All]);
+    assert_eq!(
+        database_grant.on,
+        PrivilegeObject::Database(Ident::unquoted("app"))
+    );
+
+    assert_eq!(
+        privilege_from_show_grants("GRANT USAGE ON *.* TO `reporter`@`%`", "app"),
+        None
+    );
+    assert_eq!(
+        privilege_from_show_grants("GRANT SELECT ON `other`.* TO `reporter`@`%`", "app"),
+        None
+    );
+    assert_eq!(
+        privilege_from_show_grants("GRANT `analyst`@`%` TO `reporter`@`%`", "app"),
+        None
+    );
+}
+
+#[test]
+fn exported_privilege_renders_as_grant_statement() {
+    let sql = MysqlDialect
+        .to_sql(&SchemaObject::Privilege(Privilege {
+            operations: vec![PrivilegeOp::Select, PrivilegeOp::Update],
+            on: PrivilegeObject::Table(QualifiedName {
+                schema: None,
+                name: Ident::unquoted("orders"),
+            }),
+            grantee: Ident::unquoted("reporter@10.0.%"),
+            with_grant_option: false,
+        }))
+        .expect("privilege should render");
+
+    assert_eq!(
+        sql,
+        "GRANT SELECT, UPDATE ON `orders` TO 'reporter'@'10.0.%';"
+    );
+}
+
+/// What `export_schema` writes: `SHOW CREATE` output without the definer, triggers
+/// rebuilt from `INFORMATION_SCHEMA.TRIGGERS` and grants rendered by `to_sql`.
+const EXPORTED_SCHEMA: &str = r#"CREATE TABLE `orders` (
+  `id` bigint NOT NULL,
+  `total` int NOT NULL,
+  PRIMARY KEY (`id`)
+) ENGINE=InnoDB;
+
+CREATE FUNCTION `order_total`(order_id bigint) RETURNS int
+    READS SQL DATA
+    DETERMINISTIC
+BEGIN
+  DECLARE result int;
+  SELECT total INTO result FROM orders WHERE id = order_id;
+  RETURN result;
+END;
+
+CREATE PROCEDURE `archive_orders`(IN cutoff bigint, OUT archived int)
+    SQL SECURITY INVOKER
+BEGIN
+  DELETE FROM orders WHERE id < cutoff;
+  SET archived = ROW_COUNT();
+END;
+
+CREATE SQL SECURITY DEFINER VIEW `big_orders` AS select `orders`.`id` AS `id` from `orders` where (`orders`.`total` > 100);
+
+CREATE TRIGGER `orders_bi` BEFORE INSERT ON `orders` FOR EACH ROW BEGIN
+  IF NEW.total < 0 THEN
+    SET NEW.total = 0;
+  END IF;
+END;
+
+CREATE EVENT `purge_orders` ON SCHEDULE EVERY 1 DAY STARTS '2026-01-01 00:00:00' ON COMPLETION NOT PRESERVE DISABLE COMMENT 'drops \\empty\\ orders' DO DELETE FROM orders WHERE total = 0;
+
+GRANT SELECT, UPDATE ON `orders` TO 'reporter'@'10.0.%';
+
+GRANT ALL PRIVILEGES ON `app`.* TO 'owner'@'localhost' WITH GRANT OPTION;"#;
+
+fn parse_and_normalize(sql: &str) -> Vec<SchemaObject> {
+    let dialect = MysqlDialect;
+    let mut objects = dialect.parse(sql).expect("schema should parse");
+    for object in &mut objects {
+        dialect.normalize(object);
+    }
+    objects
+}
+
+#[test]
+fn exported_routines_triggers_events_and_grants_parse_back() {
+    let objects = parse_and_normalize(EXPORTED_SCHEMA);
+
+    let [
+        SchemaObject::Table(_),
+        SchemaObject::Function(function),
+        SchemaObject::Function(procedure),
+        SchemaObject::View(_),
+        SchemaObject::Trigger(trigger),
+        SchemaObject::Event(event),
+        SchemaObject::Privilege(table_grant),
+        SchemaObject::Privilege(database_grant),
+    ] = objects.as_slice()
+    else {
+        panic!("unexpected objects: {objects:#?}");
+    };
+
+    assert_eq!(function.name.name.value, "order_total");
+    assert_eq!(function.return_type, Some(DataType::Integer));
+    assert_eq!(function.params[0].data_type, DataType::BigInt);
+    assert!(
+        function
+            .body
+            .starts_with("READS SQL DATA DETERMINISTIC BEGIN\n  DECLARE result int;"),
+        "characteristics should stay in front of the body: {}",
+        function.body
+    );
+    assert!(function.body.ends_with("RETURN result;\nEND"));
+
+    assert_eq!(procedure.return_type, None);
+    assert_eq!(procedure.security, Some(FunctionSecurity::Invoker));
+    assert_eq!(
+        procedure
+            .params
+            .iter()
+            .map(|param| param.mode)
+            .collect::<Vec<_>>(),
+        vec![Some(FunctionParamMode::In), Some(FunctionParamMode::Out)]
+    );
+    assert!(procedure.body.starts_with("BEGIN") && procedure.body.ends_with("END"));
+
+    assert_eq!(trigger.timing, TriggerTiming::Before);
+    assert_eq!(trigger.events, vec![TriggerEvent::Insert]);
+    assert!(trigger.body.contains("END IF;\nEND"));
+
+    assert_eq!(event.name.name.value, "purge_orders");
+    assert_eq!(event.schedule, "EVERY 1 DAY STARTS '2026-01-01 00:00:00'");
+    assert!(!event.preserve);
+    assert!(!event.enabled);
+    assert_eq!(event.comment.as_deref(), Some(r"drops \empty\ orders"));
+    assert_eq!(event.body, "DELETE FROM orders WHERE total = 0");
+
+    assert_eq!(
+        table_grant.on,
+        PrivilegeObject::Table(QualifiedName {
+            schema: None,
+            name: Ident::unquoted("orders"),
+        })
+    );
+    assert_eq!(table_grant.grantee, Ident::unquoted("reporter@10.0.%"));
+    assert_eq!(
+        database_grant.operations,
+        vec![PrivilegeOp::All],
+        "ALL PRIVILEGES should map to a single operation"
+    );
+    assert!(database_grant.with_grant_option);
+}
+
+#[test]
+fn rendered_routines_triggers_events_and_grants_parse_back_unchanged() {
+    let dialect = MysqlDialect;
+    let objects = parse_and_normalize(EXPORTED_SCHEMA)
+        .into_iter()
+        .filter(|object| !matches!(object, SchemaObject::Table(_)))
+        .collect::<Vec<_>>();
+    let rendered = objects
+        .iter()
+        .map(|object| dialect.to_sql(object).expect("object should render"))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    assert_eq!(
+        parse_and_normalize(&rendered),
+        objects,
+        "rendered:\n{rendered}"
+    );
+}
+
 #[test]
 #[ignore = "requires mysql container runtime"]
 fn export_schema_smoke_test_with_container_runtime() {
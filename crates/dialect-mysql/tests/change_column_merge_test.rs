@@ -1,5 +1,6 @@
 use stateql_core::{
-    ColumnChange, DataType, Dialect, DiffOp, Expr, Ident, Literal, QualifiedName, Statement,
+    ColumnChange, ColumnPosition, DataType, Dialect, DiffOp, Expr, Ident, Literal, QualifiedName,
+    Statement,
 };
 use stateql_dialect_mysql::MysqlDialect;
 
@@ -46,6 +47,41 @@ fn merges_same_column_alterations_into_single_change_column_statement() {
     );
 }
 
+#[test]
+fn dropped_default_renders_default_null_only_where_mysql_needs_it() {
+    let dialect = MysqlDialect;
+    let drop_default = |not_null: bool, position: Option<ColumnPosition>| {
+        let mut changes = vec![
+            ColumnChange::SetType(DataType::BigInt),
+            ColumnChange::SetNotNull(not_null),
+            ColumnChange::SetDefault(None),
+        ];
+        changes.extend(position.map(ColumnChange::SetPosition));
+        let ops = vec![DiffOp::AlterColumn {
+            table: qualified_name("users"),
+            column: ident("age"),
+            changes,
+        }];
+        let statements = dialect
+            .generate_ddl(&ops)
+            .expect("mysql generator should render the dropped default");
+        joined_sql(&statements)
+    };
+
+    assert_eq!(
+        drop_default(true, None),
+        "ALTER TABLE `users` CHANGE COLUMN `age` `age` bigint NOT NULL;"
+    );
+    assert_eq!(
+        drop_default(false, None),
+        "ALTER TABLE `users` CHANGE COLUMN `age` `age` bigint NULL DEFAULT NULL;"
+    );
+    assert_eq!(
+        drop_default(false, Some(ColumnPosition::First)),
+        "ALTER TABLE `users` MODIFY COLUMN `age` bigint NULL FIRST;"
+    );
+}
+
 fn joined_sql(statements: &[Statement]) -> String {
     statements
         .iter()
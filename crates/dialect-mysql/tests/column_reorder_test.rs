@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+
+use stateql_core::{
+    Column, DataType, Dialect, DiffConfig, DiffEngine, Expr, Ident, Literal, SchemaObject,
+    Statement, Table,
+};
+use stateql_dialect_mysql::MysqlDialect;
+
+#[test]
+fn moved_column_is_rendered_as_modify_with_full_definition() {
+    let dialect = MysqlDialect;
+    let desired = vec![users_table(vec![
+        sample_column("id", DataType::BigInt, true, None),
+        sample_column(
+            "status",
+            DataType::Varchar { length: Some(16) },
+            true,
+            Some(Expr::Literal(Literal::String("active".to_string()))),
+        ),
+        sample_column(
+            "email",
+            DataType::Varchar { length: Some(255) },
+            false,
+            None,
+        ),
+    ])];
+    let current = vec![users_table(vec![
+        sample_column("id", DataType::BigInt, true, None),
+        sample_column(
+            "email",
+            DataType::Varchar { length: Some(255) },
+            false,
+            None,
+        ),
+        sample_column(
+            "status",
+            DataType::Varchar { length: Some(16) },
+            true,
+            Some(Expr::Literal(Literal::String("active".to_string()))),
+        ),
+    ])];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorder_config())
+        .expect("diff should succeed");
+    let statements = dialect
+        .generate_ddl(&ops)
+        .expect("column move should generate SQL");
+
+    assert_eq!(
+        joined_sql(&statements),
+        "ALTER TABLE `users` MODIFY COLUMN `status` varchar(16) NOT NULL DEFAULT 'active' AFTER `id`;"
+    );
+}
+
+#[test]
+fn column_moved_to_front_uses_first() {
+    let dialect = MysqlDialect;
+    let desired = vec![users_table(vec![
+        sample_column("id", DataType::BigInt, true, None),
+        sample_column("email", DataType::Text, false, None),
+    ])];
+    let current = vec![users_table(vec![
+        sample_column("email", DataType::Text, false, None),
+        sample_column("id", DataType::BigInt, true, None),
+    ])];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorder_config())
+        .expect("diff should succeed");
+    let statements = dialect
+        .generate_ddl(&ops)
+        .expect("column move should generate SQL");
+
+    assert_eq!(
+        joined_sql(&statements),
+        "ALTER TABLE `users` MODIFY COLUMN `id` bigint NOT NULL FIRST;"
+    );
+}
+
+#[test]
+fn added_column_keeps_desired_position_when_reorder_is_enabled() {
+    let dialect = MysqlDialect;
+    let desired = vec![users_table(vec![
+        sample_column("id", DataType::BigInt, true, None),
+        sample_column("nickname", DataType::Text, false, None),
+        sample_column("email", DataType::Text, false, None),
+    ])];
+    let current = vec![users_table(vec![
+        sample_column("id", DataType::BigInt, true, None),
+        sample_column("email", DataType::Text, false, None),
+    ])];
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorder_config())
+        .expect("diff should succeed");
+    let statements = dialect
+        .generate_ddl(&ops)
+        .expect("positioned add column should generate SQL");
+
+    let sql = joined_sql(&statements);
+    assert!(
+        sql.contains("ADD COLUMN `nickname` text") && sql.ends_with("AFTER `id`;"),
+        "added column should be placed after its desired predecessor: {sql}"
+    );
+}
+
+#[test]
+fn column_order_change_in_sql_is_rendered_as_modify_after() {
+    let dialect = MysqlDialect;
+    let desired = parse_and_normalize(
+        "CREATE TABLE users (id bigint NOT NULL, status varchar(16) NOT NULL DEFAULT 'active', email varchar(255));",
+    );
+    let current = parse_and_normalize(
+        "CREATE TABLE `users` (`id` bigint NOT NULL, `email` varchar(255) DEFAULT NULL, `status` varchar(16) NOT NULL DEFAULT 'active');",
+    );
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &reorder_config())
+        .expect("diff should succeed");
+    let statements = dialect
+        .generate_ddl(&ops)
+        .expect("column move should generate SQL");
+
+    assert_eq!(
+        joined_sql(&statements),
+        "ALTER TABLE `users` MODIFY COLUMN `status` varchar(16) NOT NULL DEFAULT 'active' AFTER `id`;"
+    );
+}
+
+fn parse_and_normalize(sql: &str) -> Vec<SchemaObject> {
+    let dialect = MysqlDialect;
+    let mut objects = dialect.parse(sql).expect("mysql parse should succeed");
+    for object in &mut objects {
+        dialect.normalize(object);
+    }
+    objects
+}
+
+fn reorder_config() -> DiffConfig {
+    DiffConfig {
+        enable_column_reorder: true,
+        ..DiffConfig::default()
+    }
+}
+
+fn users_table(columns: Vec<Column>) -> SchemaObject {
+    let mut table = Table::named("users");
+    table.columns = columns;
+    SchemaObject::Table(table)
+}
+
+fn sample_column(name: &str, data_type: DataType, not_null: bool, default: Option<Expr>) -> Column {
+    Column {
+        name: Ident::unquoted(name),
+        data_type,
+        not_null,
+        default,
+        identity: None,
+        generated: None,
+        comment: None,
+        collation: None,
+        renamed_from: None,
+        extra: BTreeMap::new(),
+    }
+}
+
+fn joined_sql(statements: &[Statement]) -> String {
+    statements
+        .iter()
+        .map(|statement| match statement {
+            Statement::Sql { sql, .. } => sql.as_str(),
+            Statement::BatchBoundary => "<BATCH>",
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
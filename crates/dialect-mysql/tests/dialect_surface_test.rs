@@ -1,11 +1,11 @@
 use std::collections::BTreeMap;
 
 use stateql_core::{
-    Column, Comment, CommentTarget, ConnectionConfig, DataType, Dialect, Domain, Error,
-    ExecutionError, Expr, Extension, Function, FunctionParam, FunctionSecurity, GenerateError,
-    Ident, IndexColumn, IndexDef, IndexOwner, Policy, PolicyCommand, Privilege, PrivilegeObject,
-    QualifiedName, SchemaDef, SchemaObject, Table, Trigger, TriggerEvent, TriggerForEach,
-    TriggerTiming,
+    Column, Comment, CommentTarget, ConnectionConfig, DataType, Dialect, DiffConfig, DiffEngine,
+    DiffOp, Domain, Error, ExecutionError, Expr, Extension, Function, FunctionParam,
+    FunctionSecurity, GenerateError, Ident, IndexColumn, IndexDef, IndexOwner, Policy,
+    PolicyCommand, Pragma, Privilege, PrivilegeObject, QualifiedName, SchemaDef, SchemaObject,
+    Table, Trigger, TriggerEvent, TriggerForEach, TriggerTiming, Value,
 };
 use stateql_dialect_mysql::MysqlDialect;
 
@@ -75,6 +75,51 @@ fn connect_rejects_mysql_versions_below_8_0() {
     );
 }
 
+#[test]
+fn primary_and_foreign_keys_are_not_diffed() {
+    let dialect = MysqlDialect;
+    assert!(!dialect.diffs_table_constraints());
+
+    let ops = diff_sql(
+        &dialect,
+        "CREATE TABLE teams (id INT NOT NULL PRIMARY KEY);\n\
+         CREATE TABLE users (id INT NOT NULL, team_id INT, PRIMARY KEY (id), CONSTRAINT users_team_fk FOREIGN KEY (team_id) REFERENCES teams (id));",
+        "CREATE TABLE teams (id INT NOT NULL PRIMARY KEY);\n\
+         CREATE TABLE users (id INT NOT NULL, team_id INT);",
+    );
+
+    assert!(
+        !ops.iter().any(|op| matches!(
+            op,
+            DiffOp::SetPrimaryKey { .. }
+                | DiffOp::DropPrimaryKey { .. }
+                | DiffOp::AddForeignKey { .. }
+                | DiffOp::DropForeignKey { .. }
+        )),
+        "{ops:?}"
+    );
+}
+
+/// Diffs two schemas with the settings the orchestrator derives from the dialect.
+fn diff_sql(dialect: &MysqlDialect, desired: &str, current: &str) -> Vec<DiffOp> {
+    let parse = |sql: &str| {
+        let mut objects = dialect.parse(sql).expect("schema should parse");
+        for object in &mut objects {
+            dialect.normalize(object);
+        }
+        objects
+    };
+    let config = DiffConfig {
+        emit_table_definitions: dialect.requires_table_definitions(),
+        diff_table_constraints: dialect.diffs_table_constraints(),
+        diffed_table_options: dialect.diffed_table_options(),
+        ..DiffConfig::default()
+    };
+    DiffEngine::new()
+        .diff(&parse(desired), &parse(current), &config)
+        .expect("diff should succeed")
+}
+
 fn canonical_export_sql(dialect: &MysqlDialect, sql: &str) -> String {
     let mut objects = dialect.parse(sql).expect("parse should succeed");
     for object in &mut objects {
@@ -149,6 +194,7 @@ fn supported_objects() -> Vec<SchemaObject> {
         table: qualified(None, "users"),
         timing: TriggerTiming::After,
         events: vec![TriggerEvent::Insert],
+        update_columns: Vec::new(),
         for_each: TriggerForEach::Row,
         when_clause: None,
         body: "SET NEW.id = NEW.id".to_string(),
@@ -175,6 +221,12 @@ fn supported_objects() -> Vec<SchemaObject> {
         SchemaObject::Index(index),
         SchemaObject::Trigger(trigger),
         SchemaObject::Function(function),
+        SchemaObject::Privilege(Privilege {
+            operations: vec![stateql_core::PrivilegeOp::Select],
+            on: PrivilegeObject::Table(qualified(None, "users")),
+            grantee: Ident::unquoted("app@%"),
+            with_grant_option: false,
+        }),
     ]
 }
 
@@ -201,7 +253,7 @@ fn unsupported_objects() -> Vec<SchemaObject> {
         }),
         SchemaObject::Privilege(Privilege {
             operations: vec![stateql_core::PrivilegeOp::Select],
-            on: PrivilegeObject::Table(qualified(None, "users")),
+            on: PrivilegeObject::Sequence(qualified(None, "users_id_seq")),
             grantee: Ident::unquoted("app"),
             with_grant_option: false,
         }),
@@ -214,5 +266,9 @@ fn unsupported_objects() -> Vec<SchemaObject> {
             roles: vec![Ident::unquoted("app")],
             permissive: true,
         }),
+        SchemaObject::Pragma(Pragma {
+            name: qualified(None, "user_version"),
+            value: Value::Integer(7),
+        }),
     ]
 }
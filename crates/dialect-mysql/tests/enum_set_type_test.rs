@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+
+use stateql_core::{
+    Column, ColumnChange, DataType, Dialect, DiffConfig, DiffEngine, DiffOp, Ident, QualifiedName,
+    SchemaObject, SkippedOpKind, Statement, Table,
+};
+use stateql_dialect_mysql::MysqlDialect;
+
+#[test]
+fn enum_and_set_custom_types_normalize_to_label_lists() {
+    let dialect = MysqlDialect;
+    let mut object = table_with_columns(vec![
+        sample_column(
+            "status",
+            DataType::Custom("ENUM('New', 'it''s', \"x\")".to_string()),
+        ),
+        sample_column("flags", DataType::Custom("set('a','b')".to_string())),
+    ]);
+
+    dialect.normalize(&mut object);
+
+    let SchemaObject::Table(table) = &object else {
+        panic!("expected table object");
+    };
+    assert_eq!(
+        table.columns[0].data_type,
+        DataType::Enum(vec!["New".to_string(), "it's".to_string(), "x".to_string()])
+    );
+    assert_eq!(
+        table.columns[1].data_type,
+        DataType::Set(vec!["a".to_string(), "b".to_string()])
+    );
+}
+
+#[test]
+fn enum_label_change_renders_change_column_with_quoted_labels() {
+    let dialect = MysqlDialect;
+    let ops = vec![DiffOp::AlterColumn {
+        table: QualifiedName {
+            schema: None,
+            name: Ident::unquoted("orders"),
+        },
+        column: Ident::unquoted("status"),
+        changes: vec![ColumnChange::SetType(DataType::Enum(vec![
+            "new".to_string(),
+            "it's".to_string(),
+        ]))],
+    }];
+
+    let statements = dialect
+        .generate_ddl(&ops)
+        .expect("enum type change should generate SQL");
+
+    assert_eq!(
+        joined_sql(&statements),
+        "ALTER TABLE `orders` CHANGE COLUMN `status` `status` enum('new','it''s');"
+    );
+}
+
+#[test]
+fn enum_labels_with_backslashes_round_trip_through_sql() {
+    let dialect = MysqlDialect;
+    let labels = vec![r"C:\tmp".to_string(), r"it's \'quoted\'".to_string()];
+    let object = table_with_columns(vec![sample_column(
+        "status",
+        DataType::Enum(labels.clone()),
+    )]);
+
+    let sql = dialect.to_sql(&object).expect("enum column should render");
+    let parsed = parse_and_normalize(&sql);
+
+    let SchemaObject::Table(table) = &parsed[0] else {
+        panic!("expected table object");
+    };
+    assert_eq!(table.columns[0].data_type, DataType::Enum(labels));
+}
+
+#[test]
+fn appended_enum_label_in_sql_renders_change_column() {
+    let dialect = MysqlDialect;
+    let desired =
+        parse_and_normalize("CREATE TABLE orders (status ENUM('new', 'paid', 'shipped'));");
+    let current = parse_and_normalize(
+        "CREATE TABLE `orders` (`status` enum('new','paid') DEFAULT NULL) ENGINE=InnoDB;",
+    );
+
+    let outcome = DiffEngine::new()
+        .diff_with_diagnostics(&desired, &current, &DiffConfig::default())
+        .expect("diff should succeed");
+    assert!(
+        outcome.diagnostics.is_empty(),
+        "appending labels is safe: {:?}",
+        outcome.diagnostics
+    );
+
+    let statements = dialect
+        .generate_ddl(&outcome.ops)
+        .expect("enum label change should generate SQL");
+    assert_eq!(
+        joined_sql(&statements),
+        "ALTER TABLE `orders` CHANGE COLUMN `status` `status` enum('new','paid','shipped');"
+    );
+}
+
+#[test]
+fn removed_set_label_in_sql_waits_for_enable_drop() {
+    let desired = parse_and_normalize("CREATE TABLE orders (tags SET('a', 'b'));");
+    let current = parse_and_normalize("CREATE TABLE `orders` (`tags` set('a','b','c'));");
+
+    let outcome = DiffEngine::new()
+        .diff_with_diagnostics(&desired, &current, &DiffConfig::default())
+        .expect("diff should succeed");
+
+    assert!(
+        outcome.ops.is_empty(),
+        "label removal must be suppressed without enable_drop: {:?}",
+        outcome.ops
+    );
+    let kinds = outcome
+        .diagnostics
+        .skipped_ops
+        .iter()
+        .map(|diagnostic| diagnostic.kind)
+        .collect::<Vec<_>>();
+    assert_eq!(kinds, vec![SkippedOpKind::DropEnumLabel]);
+}
+
+fn parse_and_normalize(sql: &str) -> Vec<SchemaObject> {
+    let dialect = MysqlDialect;
+    let mut objects = dialect.parse(sql).expect("mysql parse should succeed");
+    for object in &mut objects {
+        dialect.normalize(object);
+    }
+    objects
+}
+
+fn table_with_columns(columns: Vec<Column>) -> SchemaObject {
+    let mut table = Table::named("orders");
+    table.columns = columns;
+    SchemaObject::Table(table)
+}
+
+fn sample_column(name: &str, data_type: DataType) -> Column {
+    Column {
+        name: Ident::unquoted(name),
+        data_type,
+        not_null: false,
+        default: None,
+        identity: None,
+        generated: None,
+        comment: None,
+        collation: None,
+        renamed_from: None,
+        extra: BTreeMap::new(),
+    }
+}
+
+fn joined_sql(statements: &[Statement]) -> String {
+    statements
+        .iter()
+        .map(|statement| match statement {
+            Statement::Sql { sql, .. } => sql.as_str(),
+            Statement::BatchBoundary => "<BATCH>",
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -6,7 +6,8 @@ mod diffop_fixtures;
 
 use diffop_fixtures::{EXPECTED_DIFFOP_VARIANT_COUNT, all_diffop_variants, diffop_variant_tag};
 use stateql_core::{
-    ColumnChange, Dialect, DiffOp, Error, GenerateError, IndexOwner, TriggerForEach,
+    ColumnChange, Dialect, DiffOp, Error, GenerateError, IndexOwner, PrivilegeObject,
+    TriggerForEach,
 };
 use stateql_dialect_mysql::MysqlDialect;
 
@@ -61,6 +62,7 @@ fn is_supported_diffop(op: &DiffOp) -> bool {
         DiffOp::CreateTable(table) => table.exclusions.is_empty(),
         DiffOp::DropTable(_) => true,
         DiffOp::RenameTable { .. } => true,
+        DiffOp::TableDefinition { .. } => false,
         DiffOp::AddColumn { .. } => true,
         DiffOp::DropColumn { .. } => true,
         DiffOp::AlterColumn { changes, .. } => {
@@ -87,6 +89,8 @@ fn is_supported_diffop(op: &DiffOp) -> bool {
             !partition.columns.is_empty() && !partition.partitions.is_empty()
         }
         DiffOp::DropPartition { .. } => true,
+        DiffOp::ReorganizePartition { .. } => true,
+        DiffOp::ChangePartitionCount { .. } => true,
         DiffOp::CreateView(_) => true,
         DiffOp::DropView(_) => true,
         DiffOp::CreateMaterializedView(_) => false,
@@ -94,16 +98,18 @@ fn is_supported_diffop(op: &DiffOp) -> bool {
         DiffOp::CreateSequence(_) => false,
         DiffOp::DropSequence(_) => false,
         DiffOp::AlterSequence { .. } => false,
+        DiffOp::CreateSynonym(_) => false,
+        DiffOp::DropSynonym(_) => false,
         DiffOp::CreateTrigger(trigger) => {
             trigger.for_each == TriggerForEach::Row
                 && trigger.when_clause.is_none()
                 && !trigger.events.is_empty()
         }
         DiffOp::DropTrigger { .. } => true,
-        DiffOp::CreateFunction(function) => {
-            function.return_type.is_some() && !function.body.trim().is_empty()
-        }
-        DiffOp::DropFunction(_) => true,
+        DiffOp::CreateFunction(function) => !function.body.trim().is_empty(),
+        DiffOp::DropFunction { .. } => true,
+        DiffOp::CreateEvent(_) => true,
+        DiffOp::DropEvent(_) => true,
         DiffOp::CreateType(_) => false,
         DiffOp::DropType(_) => false,
         DiffOp::AlterType { .. } => false,
@@ -116,11 +122,17 @@ fn is_supported_diffop(op: &DiffOp) -> bool {
         DiffOp::DropSchema(_) => false,
         DiffOp::SetComment(_) => false,
         DiffOp::DropComment { .. } => false,
-        DiffOp::Grant(_) => false,
-        DiffOp::Revoke(_) => false,
+        DiffOp::Grant(privilege) | DiffOp::Revoke(privilege) => matches!(
+            privilege.on,
+            PrivilegeObject::Table(_) | PrivilegeObject::Database(_)
+        ),
         DiffOp::CreatePolicy(_) => false,
         DiffOp::DropPolicy { .. } => false,
-        DiffOp::AlterTableOptions { .. } => false,
+        DiffOp::SetPragma(_) => false,
+        DiffOp::AlterTableOptions { options, .. } => options
+            .extra
+            .keys()
+            .any(|key| MysqlDialect.diffed_table_options().contains(&key.as_str())),
     }
 }
 
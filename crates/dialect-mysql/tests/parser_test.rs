@@ -61,6 +61,66 @@ fn orphan_renamed_annotation_fails_fast() {
     }
 }
 
+#[test]
+fn create_table_columns_keys_and_constraints_are_parsed() {
+    let dialect = MysqlDialect;
+    let sql = "CREATE TABLE `users` (
+  `id` bigint NOT NULL AUTO_INCREMENT,
+  `email` varchar(255) NOT NULL COMMENT 'login',
+  `team_id` bigint DEFAULT NULL, -- @renamed from=group_id
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `users_email` (`email`),
+  KEY `team_id` (`team_id`),
+  CONSTRAINT `users_team` FOREIGN KEY (`team_id`) REFERENCES `teams` (`id`) ON DELETE CASCADE
+) ENGINE=InnoDB;";
+
+    let objects = dialect.parse(sql).expect("mysql parse pipeline");
+
+    assert_eq!(objects.len(), 3);
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    let column_names = table
+        .columns
+        .iter()
+        .map(|column| column.name.value.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(column_names, ["id", "email", "team_id"]);
+    assert!(table.columns[0].not_null);
+    assert_eq!(table.columns[1].comment.as_deref(), Some("login"));
+    assert_eq!(
+        table.columns[2].renamed_from,
+        Some(Ident::unquoted("group_id"))
+    );
+    assert_eq!(
+        table
+            .primary_key
+            .as_ref()
+            .map(|primary_key| &primary_key.columns),
+        Some(&vec![Ident::quoted("id")])
+    );
+    assert_eq!(table.foreign_keys.len(), 1);
+    assert_eq!(
+        table.foreign_keys[0].name,
+        Some(Ident::quoted("users_team"))
+    );
+
+    let indexes = objects[1..]
+        .iter()
+        .map(|object| match object {
+            SchemaObject::Index(index) => (index.name.clone(), index.unique),
+            other => panic!("expected index object, got {other:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        indexes,
+        [
+            (Some(Ident::quoted("users_email")), true),
+            (Some(Ident::quoted("team_id")), false),
+        ]
+    );
+}
+
 #[test]
 fn create_sql_security_view_is_supported() {
     let dialect = MysqlDialect;
@@ -94,3 +154,75 @@ fn trailing_renamed_annotation_is_attached_to_view() {
         Some(Ident::unquoted("legacy_users_view"))
     );
 }
+
+#[test]
+fn delimiter_lines_split_routines_in_source_order() {
+    let dialect = MysqlDialect;
+    let sql = "DELIMITER $$\n\
+               CREATE DEFINER=`root`@`%` PROCEDURE touch_users()\n\
+               BEGIN\n  UPDATE users SET seen = 1;\nEND$$\n\
+               DELIMITER ;\n\
+               CREATE TABLE users (id bigint, seen int);\n";
+
+    let objects = dialect.parse(sql).expect("mysql parse pipeline");
+
+    let [
+        SchemaObject::Function(procedure),
+        SchemaObject::Table(table),
+    ] = objects.as_slice()
+    else {
+        panic!("expected a procedure then a table, got {objects:?}");
+    };
+    assert_eq!(procedure.name.name, Ident::unquoted("touch_users"));
+    assert_eq!(procedure.return_type, None);
+    assert_eq!(procedure.body, "BEGIN\n  UPDATE users SET seen = 1;\nEND");
+    assert_eq!(table.columns.len(), 2);
+}
+
+#[test]
+fn create_event_is_read_from_its_header() {
+    let dialect = MysqlDialect;
+    let sql = "CREATE TABLE users (id bigint);\n\
+               DELIMITER $$\n\
+               CREATE DEFINER=`root`@`%` EVENT purge ON SCHEDULE every 1 day\n\
+               ON COMPLETION PRESERVE COMMENT 'it''s nightly'\n\
+               DO BEGIN DELETE FROM users; END$$\n\
+               DELIMITER ;\n";
+
+    let objects = dialect.parse(sql).expect("mysql parse pipeline");
+
+    let [SchemaObject::Table(_), SchemaObject::Event(event)] = objects.as_slice() else {
+        panic!("expected a table then an event, got {objects:?}");
+    };
+    assert_eq!(event.name.name, Ident::unquoted("purge"));
+    assert_eq!(event.schedule, "every 1 day");
+    assert!(event.preserve);
+    assert!(event.enabled);
+    assert_eq!(event.comment.as_deref(), Some("it's nightly"));
+    assert_eq!(event.body, "BEGIN DELETE FROM users; END");
+}
+
+#[test]
+fn create_event_disabled_on_replica_is_rejected_with_statement_context() {
+    let dialect = MysqlDialect;
+    let sql = "CREATE TABLE users (id bigint);\n\
+               CREATE EVENT purge ON SCHEDULE EVERY 1 DAY DISABLE ON REPLICA DO DELETE FROM users;";
+
+    let error = dialect
+        .parse(sql)
+        .expect_err("replica-only events should be rejected");
+
+    match error {
+        Error::Parse(ParseError::StatementConversion {
+            statement_index,
+            source_sql,
+            source,
+            ..
+        }) => {
+            assert_eq!(statement_index, 1);
+            assert!(source_sql.starts_with("CREATE EVENT purge"));
+            assert!(source.to_string().contains("DISABLE ON REPLICA"));
+        }
+        other => panic!("expected parse statement conversion error, got {other:?}"),
+    }
+}
@@ -1,8 +1,8 @@
 use std::collections::BTreeMap;
 
 use stateql_core::{
-    Dialect, DiffOp, Expr, Ident, Literal, Partition, PartitionBound, PartitionElement,
-    PartitionStrategy, QualifiedName, SchemaObject, Statement, View,
+    Dialect, DiffOp, Error, Expr, GenerateError, Ident, Literal, Partition, PartitionBound,
+    PartitionElement, PartitionStrategy, QualifiedName, SchemaObject, Statement, View,
 };
 use stateql_dialect_mysql::MysqlDialect;
 
@@ -40,6 +40,91 @@ fn add_partition_renders_partition_sql() {
     );
 }
 
+#[test]
+fn reorganize_partition_splits_maxvalue_in_place() {
+    let dialect = MysqlDialect;
+
+    let ops = vec![DiffOp::ReorganizePartition {
+        table: qualified_name("orders"),
+        from: vec![ident("pmax")],
+        into: vec![
+            PartitionElement {
+                name: ident("p2026"),
+                bound: Some(PartitionBound::LessThan(vec![Expr::Literal(
+                    Literal::Integer(2027),
+                )])),
+                extra: BTreeMap::new(),
+            },
+            PartitionElement {
+                name: ident("pmax"),
+                bound: Some(PartitionBound::MaxValue),
+                extra: BTreeMap::new(),
+            },
+        ],
+    }];
+
+    let statements = dialect
+        .generate_ddl(&ops)
+        .expect("mysql partition reorganize should generate SQL");
+
+    assert_eq!(
+        joined_sql(&statements),
+        "ALTER TABLE `orders` REORGANIZE PARTITION `pmax` INTO (PARTITION `p2026` VALUES LESS THAN (2027), PARTITION `pmax` VALUES LESS THAN (MAXVALUE));"
+    );
+}
+
+#[test]
+fn partition_count_changes_render_add_partitions_and_coalesce() {
+    let dialect = MysqlDialect;
+
+    let grow = dialect
+        .generate_ddl(&[DiffOp::ChangePartitionCount {
+            table: qualified_name("events"),
+            from: 4,
+            to: 6,
+        }])
+        .expect("growing hash partitions should generate SQL");
+    let shrink = dialect
+        .generate_ddl(&[DiffOp::ChangePartitionCount {
+            table: qualified_name("events"),
+            from: 6,
+            to: 4,
+        }])
+        .expect("shrinking hash partitions should generate SQL");
+
+    assert_eq!(
+        joined_sql(&grow),
+        "ALTER TABLE `events` ADD PARTITION PARTITIONS 2;"
+    );
+    assert_eq!(
+        joined_sql(&shrink),
+        "ALTER TABLE `events` COALESCE PARTITION 2;"
+    );
+}
+
+#[test]
+fn unchanged_partition_count_is_rejected_with_a_reason() {
+    let dialect = MysqlDialect;
+
+    let error = dialect
+        .generate_ddl(&[DiffOp::ChangePartitionCount {
+            table: qualified_name("events"),
+            from: 4,
+            to: 4,
+        }])
+        .expect_err("an unchanged partition count has nothing to render");
+
+    match error {
+        Error::Generate(GenerateError::UnsupportedDiffOp { target, .. }) => {
+            assert_eq!(
+                target,
+                "mysql ddl generator: `events` already has 4 partitions"
+            );
+        }
+        other => panic!("expected unsupported diff op, got {other:?}"),
+    }
+}
+
 #[test]
 fn drop_and_create_view_uses_create_or_replace_for_same_view_name() {
     let dialect = MysqlDialect;
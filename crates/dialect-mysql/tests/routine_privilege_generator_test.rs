@@ -0,0 +1,80 @@
+use stateql_core::{
+    Dialect, DiffOp, Ident, Privilege, PrivilegeObject, PrivilegeOp, QualifiedName, Statement,
+};
+use stateql_dialect_mysql::MysqlDialect;
+
+fn sql_texts(statements: &[Statement]) -> Vec<&str> {
+    statements
+        .iter()
+        .map(|statement| match statement {
+            Statement::Sql { sql, .. } => sql.as_str(),
+            other => panic!("expected SQL statement, got {other:?}"),
+        })
+        .collect()
+}
+
+fn orders_grant(with_grant_option: bool) -> Privilege {
+    Privilege {
+        operations: vec![PrivilegeOp::Select, PrivilegeOp::Update],
+        on: PrivilegeObject::Table(QualifiedName {
+            schema: None,
+            name: Ident::unquoted("orders"),
+        }),
+        grantee: Ident::unquoted("reporter@%"),
+        with_grant_option,
+    }
+}
+
+#[test]
+fn dropped_routine_is_dropped_by_its_kind() {
+    let drop = |procedure: bool| {
+        let statements = MysqlDialect
+            .generate_ddl(&[DiffOp::DropFunction {
+                name: QualifiedName {
+                    schema: None,
+                    name: Ident::unquoted("archive_orders"),
+                },
+                procedure,
+            }])
+            .expect("drop should generate");
+        sql_texts(&statements).join("\n")
+    };
+
+    assert_eq!(drop(true), "DROP PROCEDURE IF EXISTS `archive_orders`;");
+    assert_eq!(drop(false), "DROP FUNCTION IF EXISTS `archive_orders`;");
+}
+
+#[test]
+fn dropped_event_is_dropped_as_event() {
+    let statements = MysqlDialect
+        .generate_ddl(&[DiffOp::DropEvent(QualifiedName {
+            schema: None,
+            name: Ident::unquoted("purge_orders"),
+        })])
+        .expect("drop should generate");
+
+    assert_eq!(
+        sql_texts(&statements),
+        vec!["DROP EVENT IF EXISTS `purge_orders`;"]
+    );
+}
+
+#[test]
+fn grant_and_revoke_render_account_and_target() {
+    let statements = MysqlDialect
+        .generate_ddl(&[
+            DiffOp::Grant(orders_grant(false)),
+            DiffOp::Revoke(orders_grant(false)),
+            DiffOp::Revoke(orders_grant(true)),
+        ])
+        .expect("privilege ops should generate");
+
+    assert_eq!(
+        sql_texts(&statements),
+        vec![
+            "GRANT SELECT, UPDATE ON `orders` TO 'reporter'@'%';",
+            "REVOKE SELECT, UPDATE ON `orders` FROM 'reporter'@'%';",
+            "REVOKE GRANT OPTION ON `orders` FROM 'reporter'@'%';",
+        ]
+    );
+}
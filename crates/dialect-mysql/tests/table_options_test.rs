@@ -0,0 +1,171 @@
+use std::collections::BTreeMap;
+
+use stateql_core::{
+    Dialect, DiffConfig, DiffEngine, DiffOp, Ident, QualifiedName, SchemaObject, Statement,
+    TableOptions, Value, extra_keys::mysql as mysql_keys,
+};
+use stateql_dialect_mysql::MysqlDialect;
+
+#[test]
+fn table_options_are_parsed_into_normalized_keys() {
+    let objects = parse_and_normalize(
+        "CREATE TABLE users (id bigint) ENGINE=MyISAM DEFAULT CHARSET=latin1 COLLATE=latin1_bin ROW_FORMAT=compact COMMENT='user accounts' AUTO_INCREMENT=42;",
+    );
+
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    let extra = &table.options.extra;
+    assert_eq!(
+        extra.get(mysql_keys::TABLE_ENGINE),
+        Some(&Value::String("myisam".to_string()))
+    );
+    assert_eq!(
+        extra.get(mysql_keys::TABLE_CHARSET),
+        Some(&Value::String("latin1".to_string()))
+    );
+    assert_eq!(
+        extra.get(mysql_keys::TABLE_COLLATE),
+        Some(&Value::String("latin1_bin".to_string()))
+    );
+    assert_eq!(
+        extra.get(mysql_keys::TABLE_ROW_FORMAT),
+        Some(&Value::String("COMPACT".to_string()))
+    );
+    assert_eq!(
+        extra.get(mysql_keys::TABLE_COMMENT),
+        Some(&Value::String("user accounts".to_string()))
+    );
+}
+
+#[test]
+fn server_default_table_options_do_not_produce_diffs() {
+    let desired = parse_and_normalize("CREATE TABLE users (id bigint);");
+    let current = parse_and_normalize(
+        "CREATE TABLE `users` (`id` bigint) ENGINE=InnoDB AUTO_INCREMENT=1000 DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;",
+    );
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &diff_config())
+        .expect("diff should succeed");
+
+    assert!(
+        ops.is_empty(),
+        "server defaults and AUTO_INCREMENT must not produce diffs, got: {ops:?}"
+    );
+}
+
+#[test]
+fn changed_table_options_render_single_alter_table() {
+    let dialect = MysqlDialect;
+    let desired = parse_and_normalize(
+        "CREATE TABLE users (id bigint) ENGINE=InnoDB COLLATE=utf8mb4_bin COMMENT='accounts';",
+    );
+    let current = parse_and_normalize(
+        "CREATE TABLE `users` (`id` bigint) ENGINE=MyISAM DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;",
+    );
+
+    let ops = DiffEngine::new()
+        .diff(&desired, &current, &diff_config())
+        .expect("diff should succeed");
+    assert_eq!(ops.len(), 1, "expected one table options op: {ops:?}");
+
+    let statements = dialect
+        .generate_ddl(&ops)
+        .expect("table options should generate SQL");
+    assert_eq!(
+        joined_sql(&statements),
+        "ALTER TABLE `users` ENGINE=InnoDB, CONVERT TO CHARACTER SET utf8mb4 COLLATE utf8mb4_bin, COMMENT='accounts';"
+    );
+}
+
+#[test]
+fn removed_table_options_reset_to_server_defaults() {
+    let dialect = MysqlDialect;
+    let ops = vec![DiffOp::AlterTableOptions {
+        table: qualified_name("users"),
+        options: TableOptions {
+            extra: BTreeMap::from([
+                (mysql_keys::TABLE_CHARSET.to_string(), Value::Null),
+                (mysql_keys::TABLE_ROW_FORMAT.to_string(), Value::Null),
+                (mysql_keys::TABLE_COMMENT.to_string(), Value::Null),
+            ]),
+        },
+    }];
+
+    let statements = dialect
+        .generate_ddl(&ops)
+        .expect("table option reset should generate SQL");
+
+    assert_eq!(
+        joined_sql(&statements),
+        "ALTER TABLE `users` CONVERT TO CHARACTER SET utf8mb4, ROW_FORMAT=DEFAULT, COMMENT='';"
+    );
+}
+
+#[test]
+fn table_comment_escapes_quotes_and_backslashes() {
+    let dialect = MysqlDialect;
+    let ops = vec![DiffOp::AlterTableOptions {
+        table: qualified_name("users"),
+        options: TableOptions {
+            extra: BTreeMap::from([(
+                mysql_keys::TABLE_COMMENT.to_string(),
+                Value::String(r"it's C:\users".to_string()),
+            )]),
+        },
+    }];
+
+    let statements = dialect
+        .generate_ddl(&ops)
+        .expect("table comment should generate SQL");
+    let sql = joined_sql(&statements);
+    assert_eq!(sql, r"ALTER TABLE `users` COMMENT='it''s C:\\users';");
+
+    let objects = parse_and_normalize(&format!(
+        "CREATE TABLE users (id bigint) {};",
+        sql.trim_start_matches("ALTER TABLE `users` ")
+            .trim_end_matches(';')
+    ));
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    assert_eq!(
+        table.options.extra.get(mysql_keys::TABLE_COMMENT),
+        Some(&Value::String(r"it's C:\users".to_string()))
+    );
+}
+
+fn diff_config() -> DiffConfig {
+    DiffConfig {
+        diffed_table_options: MysqlDialect.diffed_table_options(),
+        ..DiffConfig::default()
+    }
+}
+
+fn parse_and_normalize(sql: &str) -> Vec<SchemaObject> {
+    let dialect = MysqlDialect;
+    let mut objects = dialect.parse(sql).expect("mysql parse should succeed");
+    for object in &mut objects {
+        dialect.normalize(object);
+    }
+    objects
+}
+
+fn joined_sql(statements: &[Statement]) -> String {
+    statements
+        .iter()
+        .map(|statement| match statement {
+            Statement::Sql { sql, .. } => sql.as_str(),
+            Statement::BatchBoundary => "<BATCH>",
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn qualified_name(name: &str) -> QualifiedName {
+    QualifiedName {
+        schema: None,
+        name: Ident::unquoted(name),
+    }
+}
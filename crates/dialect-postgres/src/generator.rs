@@ -114,7 +114,7 @@ fn emit_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) -> Result<
         } => {
             for change in changes {
                 out.push(sql_statement(
-                    render_alter_column_change(table, column, change),
+                    render_alter_column_change(table, column, change, dialect_name, op)?,
                     true,
                 ));
             }
@@ -201,6 +201,20 @@ fn emit_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) -> Result<
         DiffOp::DropPartition { table, name } => {
             out.push(sql_statement(render_drop_partition(table, name), true));
         }
+        DiffOp::TableDefinition { target, .. } => {
+            return Err(unsupported_diff_op(
+                dialect_name,
+                op,
+                render_qualified_name(&target.name),
+            ));
+        }
+        DiffOp::ReorganizePartition { table, .. } | DiffOp::ChangePartitionCount { table, .. } => {
+            return Err(unsupported_diff_op(
+                dialect_name,
+                op,
+                render_qualified_name(table),
+            ));
+        }
         DiffOp::CreateView(view) => {
             out.push(sql_statement(render_create_view(view, false)?, true));
         }
@@ -250,14 +264,21 @@ fn emit_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) -> Result<
         DiffOp::CreateFunction(function) => {
             out.push(sql_statement(render_create_function(function), true));
         }
-        DiffOp::DropFunction(name) => {
+        DiffOp::DropFunction { name, .. } => {
             out.push(sql_statement(
                 format!("DROP FUNCTION {}", render_qualified_name(name)),
                 true,
             ));
         }
         DiffOp::CreateType(ty) => {
-            out.push(sql_statement(render_create_type(ty), true));
+            let Some(sql) = render_create_type(ty) else {
+                return Err(unsupported_diff_op(
+                    dialect_name,
+                    op,
+                    render_qualified_name(&ty.name),
+                ));
+            };
+            out.push(sql_statement(sql, true));
         }
         DiffOp::DropType(name) => {
             out.push(sql_statement(
@@ -329,6 +350,41 @@ fn emit_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) -> Result<
                 true,
             ));
         }
+        DiffOp::CreateSynonym(synonym) => {
+            return Err(unsupported_diff_op(
+                dialect_name,
+                op,
+                render_qualified_name(&synonym.name),
+            ));
+        }
+        DiffOp::DropSynonym(name) => {
+            return Err(unsupported_diff_op(
+                dialect_name,
+                op,
+                render_qualified_name(name),
+            ));
+        }
+        DiffOp::CreateEvent(event) => {
+            return Err(unsupported_diff_op(
+                dialect_name,
+                op,
+                render_qualified_name(&event.name),
+            ));
+        }
+        DiffOp::DropEvent(name) => {
+            return Err(unsupported_diff_op(
+                dialect_name,
+                op,
+                render_qualified_name(name),
+            ));
+        }
+        DiffOp::SetPragma(pragma) => {
+            return Err(unsupported_diff_op(
+                dialect_name,
+                op,
+                render_qualified_name(&pragma.name),
+            ));
+        }
         DiffOp::AlterTableOptions { table, options } => {
             out.push(sql_statement(
                 render_alter_table_options(table, options, dialect_name, op)?,
@@ -840,12 +896,7 @@ fn render_create_trigger(trigger: &Trigger) -> String {
         "CREATE TRIGGER {} {} {} ON {} FOR EACH {}",
         render_ident(&trigger.name.name),
         render_trigger_timing(trigger.timing),
-        trigger
-            .events
-            .iter()
-            .map(render_trigger_event)
-            .collect::<Vec<_>>()
-            .join(" OR "),
+        render_trigger_events(trigger),
         render_qualified_name(&trigger.table),
         render_trigger_for_each(trigger.for_each)
     );
@@ -914,8 +965,9 @@ fn render_function_param(param: &FunctionParam) -> String {
     sql
 }
 
-fn render_create_type(ty: &TypeDef) -> String {
-    match &ty.kind {
+/// `None` for a SQL Server table type, which has no PostgreSQL counterpart.
+fn render_create_type(ty: &TypeDef) -> Option<String> {
+    let sql = match &ty.kind {
         TypeKind::Enum { labels } => {
             let labels = labels
                 .iter()
@@ -945,7 +997,9 @@ fn render_create_type(ty: &TypeDef) -> String {
             render_qualified_name(&ty.name),
             render_data_type(subtype)
         ),
-    }
+        TypeKind::Table { .. } => return None,
+    };
+    Some(sql)
 }
 
 fn render_alter_type(name: &QualifiedName, change: &TypeChange) -> String {
@@ -1245,14 +1299,16 @@ fn render_alter_column_change(
     table: &QualifiedName,
     column: &Ident,
     change: &ColumnChange,
-) -> String {
+    dialect_name: &str,
+    op: &DiffOp,
+) -> Result<String> {
     let prefix = format!(
         "ALTER TABLE {} ALTER COLUMN {}",
         render_qualified_name(table),
         render_ident(column)
     );
 
-    match change {
+    let sql = match change {
         ColumnChange::SetType(data_type) => {
             format!("{prefix} TYPE {}", render_data_type(data_type))
         }
@@ -1285,7 +1341,15 @@ fn render_alter_column_change(
                 )
             })
             .unwrap_or_else(|| format!("{prefix} TYPE TEXT")),
-    }
+        ColumnChange::SetPosition(_) => {
+            return Err(unsupported_diff_op(
+                dialect_name,
+                op,
+                "postgres cannot reposition existing columns",
+            ));
+        }
+    };
+    Ok(sql)
 }
 
 fn render_add_index(index: &IndexDef, dialect_name: &str, op: &DiffOp) -> Result<String> {
@@ -1429,6 +1493,7 @@ fn render_data_type(data_type: &DataType) -> String {
         DataType::Jsonb => "jsonb".to_string(),
         DataType::Uuid => "uuid".to_string(),
         DataType::Array(inner) => format!("{}[]", render_data_type(inner)),
+        DataType::Enum(_) | DataType::Set(_) => "text".to_string(),
         DataType::Custom(custom) => custom.clone(),
     }
 }
@@ -1775,6 +1840,21 @@ fn render_view_security(security: ViewSecurity) -> &'static str {
     }
 }
 
+fn render_trigger_events(trigger: &Trigger) -> String {
+    trigger
+        .events
+        .iter()
+        .map(|event| {
+            if *event == TriggerEvent::Update && !trigger.update_columns.is_empty() {
+                format!("UPDATE OF {}", render_ident_list(&trigger.update_columns))
+            } else {
+                render_trigger_event(event).to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" OR ")
+}
+
 fn render_trigger_timing(timing: TriggerTiming) -> &'static str {
     match timing {
         TriggerTiming::Before => "BEFORE",
@@ -1910,6 +1990,7 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::CreateTable(_) => "CreateTable",
         DiffOp::DropTable(_) => "DropTable",
         DiffOp::RenameTable { .. } => "RenameTable",
+        DiffOp::TableDefinition { .. } => "TableDefinition",
         DiffOp::AddColumn { .. } => "AddColumn",
         DiffOp::DropColumn { .. } => "DropColumn",
         DiffOp::AlterColumn { .. } => "AlterColumn",
@@ -1927,6 +2008,8 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::DropPrimaryKey { .. } => "DropPrimaryKey",
         DiffOp::AddPartition { .. } => "AddPartition",
         DiffOp::DropPartition { .. } => "DropPartition",
+        DiffOp::ReorganizePartition { .. } => "ReorganizePartition",
+        DiffOp::ChangePartitionCount { .. } => "ChangePartitionCount",
         DiffOp::CreateView(_) => "CreateView",
         DiffOp::DropView(_) => "DropView",
         DiffOp::CreateMaterializedView(_) => "CreateMaterializedView",
@@ -1934,10 +2017,14 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::CreateSequence(_) => "CreateSequence",
         DiffOp::DropSequence(_) => "DropSequence",
         DiffOp::AlterSequence { .. } => "AlterSequence",
+        DiffOp::CreateSynonym(_) => "CreateSynonym",
+        DiffOp::DropSynonym(_) => "DropSynonym",
         DiffOp::CreateTrigger(_) => "CreateTrigger",
         DiffOp::DropTrigger { .. } => "DropTrigger",
         DiffOp::CreateFunction(_) => "CreateFunction",
-        DiffOp::DropFunction(_) => "DropFunction",
+        DiffOp::DropFunction { .. } => "DropFunction",
+        DiffOp::CreateEvent(_) => "CreateEvent",
+        DiffOp::DropEvent(_) => "DropEvent",
         DiffOp::CreateType(_) => "CreateType",
         DiffOp::DropType(_) => "DropType",
         DiffOp::AlterType { .. } => "AlterType",
@@ -1954,6 +2041,7 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::Revoke(_) => "Revoke",
         DiffOp::CreatePolicy(_) => "CreatePolicy",
         DiffOp::DropPolicy { .. } => "DropPolicy",
+        DiffOp::SetPragma(_) => "SetPragma",
         DiffOp::AlterTableOptions { .. } => "AlterTableOptions",
     }
 }
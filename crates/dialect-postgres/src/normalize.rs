@@ -58,6 +58,8 @@ mod types {
             }
             SchemaObject::Index(_) => {}
             SchemaObject::Sequence(sequence) => normalize_sequence_type(sequence),
+            SchemaObject::Synonym(_) => {}
+            SchemaObject::Event(_) => {}
             SchemaObject::Trigger(_) => {}
             SchemaObject::Function(function) => normalize_function_types(function),
             SchemaObject::Type(type_def) => normalize_type_def(type_def),
@@ -67,6 +69,7 @@ mod types {
             SchemaObject::Comment(_) => {}
             SchemaObject::Privilege(_) => {}
             SchemaObject::Policy(_) => {}
+            SchemaObject::Pragma(_) => {}
         }
     }
 
@@ -101,6 +104,11 @@ mod types {
                 }
             }
             TypeKind::Range { subtype } => normalize_data_type(subtype),
+            TypeKind::Table { columns, .. } => {
+                for column in columns {
+                    normalize_data_type(&mut column.data_type);
+                }
+            }
         }
     }
 
@@ -260,6 +268,8 @@ mod expr {
             }
             SchemaObject::Index(index) => normalize_index_exprs(index),
             SchemaObject::Sequence(_) => {}
+            SchemaObject::Synonym(_) => {}
+            SchemaObject::Event(_) => {}
             SchemaObject::Trigger(trigger) => {
                 if let Some(when_clause) = &mut trigger.when_clause {
                     normalize_expr(when_clause);
@@ -285,6 +295,7 @@ mod expr {
             SchemaObject::Schema(_) => {}
             SchemaObject::Comment(_) => {}
             SchemaObject::Privilege(_) => {}
+            SchemaObject::Pragma(_) => {}
             SchemaObject::Policy(policy) => {
                 if let Some(using_expr) = &mut policy.using_expr {
                     normalize_expr(using_expr);
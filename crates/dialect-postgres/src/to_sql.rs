@@ -31,6 +31,8 @@ fn create_ops(object: &SchemaObject) -> Vec<DiffOp> {
         }
         SchemaObject::Index(index) => vec![DiffOp::AddIndex(index.clone())],
         SchemaObject::Sequence(sequence) => vec![DiffOp::CreateSequence(sequence.clone())],
+        SchemaObject::Synonym(synonym) => vec![DiffOp::CreateSynonym(synonym.clone())],
+        SchemaObject::Event(event) => vec![DiffOp::CreateEvent(event.clone())],
         SchemaObject::Trigger(trigger) => vec![DiffOp::CreateTrigger(trigger.clone())],
         SchemaObject::Function(function) => vec![DiffOp::CreateFunction(function.clone())],
         SchemaObject::Type(ty) => vec![DiffOp::CreateType(ty.clone())],
@@ -40,6 +42,7 @@ fn create_ops(object: &SchemaObject) -> Vec<DiffOp> {
         SchemaObject::Comment(comment) => vec![DiffOp::SetComment(comment.clone())],
         SchemaObject::Privilege(privilege) => vec![DiffOp::Grant(privilege.clone())],
         SchemaObject::Policy(policy) => vec![DiffOp::CreatePolicy(policy.clone())],
+        SchemaObject::Pragma(pragma) => vec![DiffOp::SetPragma(pragma.clone())],
     }
 }
 
@@ -1,7 +1,8 @@
 use std::collections::BTreeMap;
 
 use stateql_core::{
-    Column, ConnectionConfig, DataType, Dialect, Error, ExecutionError, Ident, SchemaObject, Table,
+    Column, ConnectionConfig, DataType, Dialect, DiffConfig, DiffEngine, DiffOp, Error,
+    ExecutionError, Ident, SchemaObject, Table,
 };
 use stateql_dialect_postgres::PostgresDialect;
 
@@ -77,6 +78,51 @@ fn connect_rejects_postgres_versions_below_13() {
     );
 }
 
+#[test]
+fn primary_and_foreign_keys_are_not_diffed() {
+    let dialect = PostgresDialect;
+    assert!(!dialect.diffs_table_constraints());
+
+    let ops = diff_sql(
+        &dialect,
+        "CREATE TABLE teams (id integer PRIMARY KEY);\n\
+         CREATE TABLE users (id integer NOT NULL, team_id integer, PRIMARY KEY (id), CONSTRAINT users_team_fk FOREIGN KEY (team_id) REFERENCES teams (id));",
+        "CREATE TABLE teams (id integer PRIMARY KEY);\n\
+         CREATE TABLE users (id integer NOT NULL, team_id integer);",
+    );
+
+    assert!(
+        !ops.iter().any(|op| matches!(
+            op,
+            DiffOp::SetPrimaryKey { .. }
+                | DiffOp::DropPrimaryKey { .. }
+                | DiffOp::AddForeignKey { .. }
+                | DiffOp::DropForeignKey { .. }
+        )),
+        "{ops:?}"
+    );
+}
+
+/// Diffs two schemas with the settings the orchestrator derives from the dialect.
+fn diff_sql(dialect: &PostgresDialect, desired: &str, current: &str) -> Vec<DiffOp> {
+    let parse = |sql: &str| {
+        let mut objects = dialect.parse(sql).expect("schema should parse");
+        for object in &mut objects {
+            dialect.normalize(object);
+        }
+        objects
+    };
+    let config = DiffConfig {
+        emit_table_definitions: dialect.requires_table_definitions(),
+        diff_table_constraints: dialect.diffs_table_constraints(),
+        diffed_table_options: dialect.diffed_table_options(),
+        ..DiffConfig::default()
+    };
+    DiffEngine::new()
+        .diff(&parse(desired), &parse(current), &config)
+        .expect("diff should succeed")
+}
+
 fn canonical_export_sql(dialect: &PostgresDialect, sql: &str) -> String {
     dialect
         .parse(sql)
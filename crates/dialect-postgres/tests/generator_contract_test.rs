@@ -6,7 +6,7 @@ mod diffop_fixtures;
 
 use diffop_fixtures::{EXPECTED_DIFFOP_VARIANT_COUNT, all_diffop_variants, diffop_variant_tag};
 use stateql_core::{
-    Dialect, DiffOp, Error, GenerateError, Ident, IndexDef, IndexOwner, QualifiedName,
+    ColumnChange, Dialect, DiffOp, Error, GenerateError, Ident, IndexDef, IndexOwner, QualifiedName,
 };
 use stateql_dialect_postgres::PostgresDialect;
 
@@ -79,12 +79,22 @@ fn malformed_payload_is_rejected_with_generate_error() {
 
 fn is_supported_diffop(op: &DiffOp) -> bool {
     match op {
+        DiffOp::TableDefinition { .. }
+        | DiffOp::ReorganizePartition { .. }
+        | DiffOp::ChangePartitionCount { .. }
+        | DiffOp::CreateSynonym(_)
+        | DiffOp::DropSynonym(_)
+        | DiffOp::CreateEvent(_)
+        | DiffOp::DropEvent(_)
+        | DiffOp::SetPragma(_) => false,
+        DiffOp::AlterColumn { changes, .. } => !changes
+            .iter()
+            .any(|change| matches!(change, ColumnChange::SetPosition(_))),
         DiffOp::CreateTable(_)
         | DiffOp::DropTable(_)
         | DiffOp::RenameTable { .. }
         | DiffOp::AddColumn { .. }
         | DiffOp::DropColumn { .. }
-        | DiffOp::AlterColumn { .. }
         | DiffOp::RenameColumn { .. }
         | DiffOp::AddIndex(_)
         | DiffOp::DropIndex { .. }
@@ -109,7 +119,7 @@ fn is_supported_diffop(op: &DiffOp) -> bool {
         | DiffOp::CreateTrigger(_)
         | DiffOp::DropTrigger { .. }
         | DiffOp::CreateFunction(_)
-        | DiffOp::DropFunction(_)
+        | DiffOp::DropFunction { .. }
         | DiffOp::CreateType(_)
         | DiffOp::DropType(_)
         | DiffOp::AlterType { .. }
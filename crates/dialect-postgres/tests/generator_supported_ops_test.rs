@@ -83,6 +83,14 @@ fn drop_create_view_pair_keeps_drop_create_when_not_compatible() {
 
 fn is_supported_diffop(op: &DiffOp) -> bool {
     match op {
+        DiffOp::TableDefinition { .. }
+        | DiffOp::ReorganizePartition { .. }
+        | DiffOp::ChangePartitionCount { .. }
+        | DiffOp::CreateSynonym(_)
+        | DiffOp::DropSynonym(_)
+        | DiffOp::CreateEvent(_)
+        | DiffOp::DropEvent(_)
+        | DiffOp::SetPragma(_) => false,
         DiffOp::CreateTable(_)
         | DiffOp::DropTable(_)
         | DiffOp::RenameTable { .. }
@@ -113,7 +121,7 @@ fn is_supported_diffop(op: &DiffOp) -> bool {
         | DiffOp::CreateTrigger(_)
         | DiffOp::DropTrigger { .. }
         | DiffOp::CreateFunction(_)
-        | DiffOp::DropFunction(_)
+        | DiffOp::DropFunction { .. }
         | DiffOp::CreateType(_)
         | DiffOp::DropType(_)
         | DiffOp::AlterType { .. }
@@ -4,12 +4,18 @@ use std::{
     sync::{Mutex, MutexGuard},
 };
 
-use rusqlite::Connection;
+use rusqlite::{Connection, types::Value as SqlValue};
 use stateql_core::{
-    ConnectionConfig, DatabaseAdapter, ExecutionError, Result, Transaction, Version,
+    ConnectionConfig, DatabaseAdapter, ExecutionError, Executor, Ident, Pragma, QualifiedName,
+    Result, SqliteRebuildStep, Statement, StatementContext, Transaction, Value, Version,
 };
 
-use crate::export_queries;
+use crate::{
+    export_queries, pragma,
+    safe_apply::{self, SafeApply, SafeApplyError, Snapshot, SnapshotKind},
+    table_clauses::is_word_byte,
+    to_sql,
+};
 
 const BEGIN_SQL: &str = "BEGIN";
 const CONNECT_SQL: &str = "CONNECT sqlite";
@@ -17,11 +23,26 @@ const DEFAULT_SQLITE_SCHEMA: &str = "main";
 const MINIMUM_SQLITE_MAJOR_VERSION: u16 = 3;
 const MINIMUM_SQLITE_MINOR_VERSION: u16 = 35;
 const SERVER_VERSION_OVERRIDE_KEY: &str = "sqlite.server_version";
+// `sqlite.attach.<schema>=<path>` attaches another database file under `<schema>`.
+const ATTACH_KEY_PREFIX: &str = "sqlite.attach.";
+const ATTACH_SQL: &str = "ATTACH DATABASE ?1 AS ?2";
+const RESERVED_SCHEMAS: [&str; 2] = [DEFAULT_SQLITE_SCHEMA, "temp"];
+const BACKUP_SQL: &str = "BACKUP sqlite";
+const REHEARSE_SQL: &str = "REHEARSE sqlite";
 const POISONED_CONNECTION_MESSAGE: &str = "sqlite connection state was poisoned";
+const FOREIGN_KEYS_QUERY: &str = "PRAGMA foreign_keys";
+const REPORTED_FOREIGN_KEY_VIOLATIONS: usize = 10;
+
+pub(crate) const FOREIGN_KEY_CHECK_SQL: &str = "PRAGMA foreign_key_check;";
 
 pub(crate) struct SqliteAdapter {
     connection: Mutex<Connection>,
     server_version: Version,
+    attached_schemas: Vec<String>,
+    foreign_keys_before_rebuild: Mutex<Option<bool>>,
+    safe_apply: Option<SafeApply>,
+    // Backups taken by `prepare_apply`, restored or removed by `finish_apply`.
+    snapshots: Vec<Snapshot>,
 }
 
 pub(crate) fn connect(config: &ConnectionConfig) -> Result<Box<dyn DatabaseAdapter>> {
@@ -30,6 +51,7 @@ pub(crate) fn connect(config: &ConnectionConfig) -> Result<Box<dyn DatabaseAdapt
             .ok_or_else(|| invalid_server_version_error(raw_version))?;
         ensure_minimum_version(&version, raw_version)?;
     }
+    let safe_apply = SafeApply::from_config(config)?;
 
     let connection = Connection::open(config.database.as_str())
         .map_err(|source| execution_error(CONNECT_SQL, source))?;
@@ -43,55 +65,166 @@ pub(crate) fn connect(config: &ConnectionConfig) -> Result<Box<dyn DatabaseAdapt
     let server_version = parse_server_version(&server_version_raw)
         .ok_or_else(|| invalid_server_version_error(&server_version_raw))?;
     ensure_minimum_version(&server_version, &server_version_raw)?;
+    let attached_schemas = attach_databases(&connection, config)?;
 
     Ok(Box::new(SqliteAdapter {
         connection: Mutex::new(connection),
         server_version,
+        attached_schemas,
+        foreign_keys_before_rebuild: Mutex::new(None),
+        safe_apply,
+        snapshots: Vec::new(),
     }))
 }
 
+fn attach_databases(connection: &Connection, config: &ConnectionConfig) -> Result<Vec<String>> {
+    let mut schemas = Vec::new();
+    for (key, path) in &config.extra {
+        let Some(schema) = key.strip_prefix(ATTACH_KEY_PREFIX) else {
+            continue;
+        };
+        if schema.is_empty()
+            || RESERVED_SCHEMAS
+                .iter()
+                .any(|reserved| schema.eq_ignore_ascii_case(reserved))
+        {
+            return Err(execution_error(
+                ATTACH_SQL,
+                io::Error::other(format!(
+                    "`{key}` must name a schema other than main and temp"
+                )),
+            ));
+        }
+        connection
+            .execute(ATTACH_SQL, [path.as_str(), schema])
+            .map_err(|source| execution_error(ATTACH_SQL, source))?;
+        schemas.push(schema.to_string());
+    }
+    Ok(schemas)
+}
+
 impl SqliteAdapter {
     fn lock_connection(&self, sql: &str) -> Result<MutexGuard<'_, Connection>> {
         self.connection
             .lock()
             .map_err(|_| execution_error(sql, io::Error::other(POISONED_CONNECTION_MESSAGE)))
     }
+
+    fn lock_foreign_keys_before_rebuild(&self, sql: &str) -> Result<MutexGuard<'_, Option<bool>>> {
+        self.foreign_keys_before_rebuild
+            .lock()
+            .map_err(|_| execution_error(sql, io::Error::other(POISONED_CONNECTION_MESSAGE)))
+    }
+
+    /// Runs `statements` against throwaway copies of every schema, attached under the
+    /// same names, so a failing plan is reported before the real files are touched.
+    fn rehearse(&self, statements: &[Statement]) -> Result<()> {
+        let copies = safe_apply::take_snapshots(
+            &*self.lock_connection(REHEARSE_SQL)?,
+            &self.schema_search_path(),
+            SnapshotKind::Rehearsal,
+        )?;
+        let outcome = self.rehearse_on(&copies, statements);
+        safe_apply::remove_snapshots(&copies);
+        outcome.map_err(|error| {
+            safe_apply::explain_failure(error, |source| SafeApplyError::Rehearsal { source })
+        })
+    }
+
+    fn rehearse_on(&self, copies: &[Snapshot], statements: &[Statement]) -> Result<()> {
+        let Some((main, attached)) = copies.split_first() else {
+            return Ok(());
+        };
+        let connection =
+            Connection::open(&main.path).map_err(|source| execution_error(REHEARSE_SQL, source))?;
+        for copy in attached {
+            let path = copy.path.to_string_lossy();
+            connection
+                .execute(ATTACH_SQL, [path.as_ref(), copy.schema.as_str()])
+                .map_err(|source| execution_error(ATTACH_SQL, source))?;
+        }
+        let mut rehearsal = SqliteAdapter {
+            connection: Mutex::new(connection),
+            server_version: self.server_version.clone(),
+            attached_schemas: self.attached_schemas.clone(),
+            foreign_keys_before_rebuild: Mutex::new(None),
+            safe_apply: None,
+            snapshots: Vec::new(),
+        };
+        Executor::new(&mut rehearsal).execute_plan(statements)
+    }
 }
 
 impl DatabaseAdapter for SqliteAdapter {
     fn export_schema(&self) -> Result<String> {
         let connection = self.lock_connection(export_queries::TABLE_NAMES_QUERY)?;
-        let table_names = query_string_rows(&connection, export_queries::TABLE_NAMES_QUERY)?;
+        let schemas = self.schema_search_path();
 
         let mut statements = Vec::new();
+        for schema in &schemas {
+            statements.extend(query_pragmas(&connection, schema)?);
+        }
 
-        for table_name in table_names {
-            let table_sql: String = connection
-                .query_row(export_queries::TABLE_DDL_QUERY, [&table_name], |row| {
-                    row.get(0)
-                })
-                .map_err(|source| execution_error(export_queries::TABLE_DDL_QUERY, source))?;
-            statements.push(ensure_statement_terminated(table_sql));
+        for schema in &schemas {
+            let table_names_query = schema_query(export_queries::TABLE_NAMES_QUERY, schema);
+            let table_ddl_query = schema_query(export_queries::TABLE_DDL_QUERY, schema);
+            for table_name in query_string_rows(&connection, &table_names_query)? {
+                let table_sql: String = connection
+                    .query_row(&table_ddl_query, [&table_name], |row| row.get(0))
+                    .map_err(|source| execution_error(&table_ddl_query, source))?;
+                statements.push(qualify_created_object(
+                    &ensure_statement_terminated(table_sql),
+                    schema,
+                ));
+            }
         }
 
-        statements.extend(query_sql_statements(
-            &connection,
+        for query in [
             export_queries::VIEW_DDLS_QUERY,
-        )?);
-        statements.extend(query_sql_statements(
-            &connection,
             export_queries::INDEX_DDLS_QUERY,
-        )?);
-        statements.extend(query_sql_statements(
-            &connection,
             export_queries::TRIGGER_DDLS_QUERY,
-        )?);
+        ] {
+            for schema in &schemas {
+                statements.extend(
+                    query_sql_statements(&connection, &schema_query(query, schema))?
+                        .iter()
+                        .map(|sql| qualify_created_object(sql, schema)),
+                );
+            }
+        }
 
         Ok(statements.join("\n\n"))
     }
 
     fn execute(&self, sql: &str) -> Result<()> {
+        self.lock_connection(sql)?
+            .execute_batch(sql)
+            .map_err(|source| execution_error(sql, source))
+    }
+
+    /// The foreign key steps of a table rebuild are recognized by their step: the
+    /// enforcement state is recorded when it is switched off so the restore only
+    /// re-enables it when it was on, and the check fails with the offending rows.
+    fn execute_with_context(&self, sql: &str, context: Option<&StatementContext>) -> Result<()> {
+        let Some(StatementContext::SqliteTableRebuild { step, .. }) = context else {
+            return self.execute(sql);
+        };
         let connection = self.lock_connection(sql)?;
+        match step {
+            SqliteRebuildStep::DisableForeignKeys => {
+                let enabled = connection
+                    .query_row(FOREIGN_KEYS_QUERY, [], |row| row.get::<_, bool>(0))
+                    .map_err(|source| execution_error(FOREIGN_KEYS_QUERY, source))?;
+                *self.lock_foreign_keys_before_rebuild(sql)? = Some(enabled);
+            }
+            SqliteRebuildStep::CheckForeignKeys => return check_foreign_keys(&connection),
+            SqliteRebuildStep::RestoreForeignKeys => {
+                if self.lock_foreign_keys_before_rebuild(sql)?.take() == Some(false) {
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
         connection
             .execute_batch(sql)
             .map_err(|source| execution_error(sql, source))
@@ -102,8 +235,59 @@ impl DatabaseAdapter for SqliteAdapter {
         Ok(Transaction::new(self))
     }
 
+    fn prepare_apply(&mut self, statements: &[Statement]) -> Result<()> {
+        let Some(mode) = self.safe_apply else {
+            return Ok(());
+        };
+        if mode == SafeApply::Rehearse {
+            self.rehearse(statements)?;
+        }
+        let snapshots = safe_apply::take_snapshots(
+            &*self.lock_connection(BACKUP_SQL)?,
+            &self.schema_search_path(),
+            SnapshotKind::Backup,
+        )?;
+        self.snapshots = snapshots;
+        Ok(())
+    }
+
+    fn finish_apply(&mut self, outcome: Result<()>) -> Result<()> {
+        let snapshots = std::mem::take(&mut self.snapshots);
+        let Err(error) = outcome else {
+            safe_apply::remove_snapshots(&snapshots);
+            return Ok(());
+        };
+        if snapshots.is_empty() {
+            return Err(error);
+        }
+
+        // The failed statement's transaction has already been rolled back; the restore
+        // undoes whatever committed before it. The snapshots are kept either way.
+        let paths = safe_apply::snapshot_paths(&snapshots);
+        let restored = match self.connection.get_mut() {
+            Ok(connection) => safe_apply::restore_snapshots(connection, &snapshots),
+            Err(_) => return Err(error),
+        };
+        Err(safe_apply::explain_failure(
+            error,
+            |source| match restored {
+                Ok(()) => SafeApplyError::Restored {
+                    snapshots: paths,
+                    source,
+                },
+                Err(restore_error) => SafeApplyError::NotRestored {
+                    snapshots: paths,
+                    restore_error,
+                    source,
+                },
+            },
+        ))
+    }
+
     fn schema_search_path(&self) -> Vec<String> {
-        vec![DEFAULT_SQLITE_SCHEMA.to_string()]
+        std::iter::once(DEFAULT_SQLITE_SCHEMA.to_string())
+            .chain(self.attached_schemas.iter().cloned())
+            .collect()
     }
 
     fn server_version(&self) -> Result<Version> {
@@ -154,6 +338,132 @@ fn query_string_rows(connection: &Connection, query: &str) -> Result<Vec<String>
     Ok(values)
 }
 
+fn check_foreign_keys(connection: &Connection) -> Result<()> {
+    let sql = FOREIGN_KEY_CHECK_SQL;
+    let mut statement = connection
+        .prepare(sql)
+        .map_err(|source| execution_error(sql, source))?;
+    let violations = statement
+        .query_map([], |row| {
+            let table: String = row.get(0)?;
+            let rowid: Option<i64> = row.get(1)?;
+            let parent: String = row.get(2)?;
+            Ok(match rowid {
+                Some(rowid) => format!("{table} rowid {rowid} references missing {parent} row"),
+                None => format!("{table} row references missing {parent} row"),
+            })
+        })
+        .map_err(|source| execution_error(sql, source))?
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .map_err(|source| execution_error(sql, source))?;
+
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    let mut message = format!(
+        "foreign key check failed with {} violation(s): {}",
+        violations.len(),
+        violations
+            .iter()
+            .take(REPORTED_FOREIGN_KEY_VIOLATIONS)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join("; ")
+    );
+    if violations.len() > REPORTED_FOREIGN_KEY_VIOLATIONS {
+        message.push_str("; ...");
+    }
+    Err(execution_error(sql, io::Error::other(message)))
+}
+
+/// The managed pragmas are always exported, so a declared value equal to the current one
+/// diffs as unchanged.
+fn query_pragmas(connection: &Connection, schema: &str) -> Result<Vec<String>> {
+    pragma::MANAGED_PRAGMAS
+        .iter()
+        .map(|name| {
+            let query = format!("PRAGMA {}.{name}", quote_ident(schema));
+            let value = match connection
+                .query_row(&query, [], |row| row.get::<_, SqlValue>(0))
+                .map_err(|source| execution_error(&query, source))?
+            {
+                SqlValue::Integer(value) => Value::Integer(value),
+                SqlValue::Text(value) => Value::String(value.to_ascii_lowercase()),
+                other => {
+                    return Err(execution_error(
+                        &query,
+                        io::Error::other(format!("unexpected pragma value {other:?}")),
+                    ));
+                }
+            };
+            Ok(to_sql::render_pragma(&Pragma {
+                name: QualifiedName {
+                    schema: attached_schema(schema).map(Ident::unquoted),
+                    name: Ident::unquoted(*name),
+                },
+                value,
+            }))
+        })
+        .collect()
+}
+
+/// The export queries read `main`; an attached schema has its own `sqlite_master`.
+fn schema_query(query: &str, schema: &str) -> String {
+    match attached_schema(schema) {
+        Some(schema) => query.replace(
+            "sqlite_master",
+            &format!("{}.sqlite_master", quote_ident(schema)),
+        ),
+        None => query.to_string(),
+    }
+}
+
+fn attached_schema(schema: &str) -> Option<&str> {
+    (schema != DEFAULT_SQLITE_SCHEMA).then_some(schema)
+}
+
+/// `sqlite_master` keeps CREATE statements without the schema of the created object, so
+/// objects of an attached database get it back in front of their name. Index and trigger
+/// tables stay unqualified: SQLite only accepts them in the schema of the created object.
+fn qualify_created_object(sql: &str, schema: &str) -> String {
+    let Some(schema) = attached_schema(schema) else {
+        return sql.to_string();
+    };
+    let bytes = sql.as_bytes();
+    let mut index = 0usize;
+    let mut seen_object_keyword = false;
+    loop {
+        while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+            index += 1;
+        }
+        let word_start = index;
+        while index < bytes.len() && is_word_byte(bytes[index]) {
+            index += 1;
+        }
+        let word = &sql[word_start..index];
+        let is = |keyword: &str| word.eq_ignore_ascii_case(keyword);
+        let keyword = if seen_object_keyword {
+            is("IF") || is("NOT") || is("EXISTS")
+        } else {
+            seen_object_keyword = is("TABLE") || is("INDEX") || is("VIEW") || is("TRIGGER");
+            seen_object_keyword || is("CREATE") || is("UNIQUE") || is("VIRTUAL")
+        };
+        if !keyword || word.is_empty() {
+            return format!(
+                "{}{}.{}",
+                &sql[..word_start],
+                quote_ident(schema),
+                &sql[word_start..]
+            );
+        }
+    }
+}
+
+fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
 fn query_sql_statements(connection: &Connection, query: &str) -> Result<Vec<String>> {
     query_string_rows(connection, query)
         .map(|rows| rows.into_iter().map(ensure_statement_terminated).collect())
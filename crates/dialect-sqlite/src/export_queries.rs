@@ -2,10 +2,25 @@
 // clauses added for stable export output in stateql tests.
 
 pub(crate) const SHOW_SERVER_VERSION_QUERY: &str = "SELECT sqlite_version()";
+// Virtual table modules keep their data in shadow tables named `<table>_<suffix>`
+// (FTS3/4, FTS5, R*Tree); they belong to the module, not the schema.
 pub(crate) const TABLE_NAMES_QUERY: &str = r#"
 SELECT tbl_name
-FROM sqlite_master
+FROM sqlite_master AS candidate
 WHERE type = 'table' AND tbl_name NOT LIKE 'sqlite_%'
+  AND NOT EXISTS (
+    SELECT 1
+    FROM sqlite_master AS virtual_table
+    WHERE virtual_table.type = 'table'
+      AND virtual_table.sql LIKE 'CREATE VIRTUAL TABLE%'
+      AND candidate.sql NOT LIKE 'CREATE VIRTUAL TABLE%'
+      AND substr(candidate.tbl_name, 1, length(virtual_table.tbl_name) + 1)
+        = virtual_table.tbl_name || '_'
+      AND substr(candidate.tbl_name, length(virtual_table.tbl_name) + 2) IN (
+        'content', 'segments', 'segdir', 'docsize', 'stat', 'data', 'idx', 'config',
+        'node', 'rowid', 'parent'
+      )
+  )
 ORDER BY tbl_name ASC;
 "#;
 
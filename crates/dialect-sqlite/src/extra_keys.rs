@@ -1,3 +1,21 @@
-pub(crate) const TABLE_WITHOUT_ROWID: &str = "sqlite.without_rowid";
-pub(crate) const TABLE_STRICT: &str = "sqlite.strict";
+use stateql_core::extra_keys::sqlite;
+
 pub(crate) const TABLE_SOURCE_SQL: &str = "sqlite.source_sql";
+/// The column type as written, kept so rebuilt tables declare the same type.
+pub(crate) const COLUMN_DECLARED_TYPE: &str = "sqlite.declared_type";
+pub(crate) const COLUMN_NOT_NULL_CONFLICT: &str = "sqlite.not_null_conflict";
+/// Expression from a `-- @backfill` hint that fills a new `NOT NULL` column when its table
+/// is rebuilt.
+pub(crate) const COLUMN_BACKFILL: &str = "sqlite.backfill";
+/// Set on the index of a `UNIQUE` constraint written without `CONSTRAINT <name>`, whose
+/// index name is generated from its table and columns.
+pub(crate) const INDEX_UNNAMED_CONSTRAINT: &str = "sqlite.unnamed_constraint";
+/// `ON CONFLICT` resolution of a `UNIQUE` constraint.
+pub(crate) const INDEX_CONFLICT: &str = "sqlite.conflict";
+
+/// Table options compared by the diff engine.
+pub(crate) const DIFFED_TABLE_OPTIONS: [&str; 3] = [
+    sqlite::TABLE_WITHOUT_ROWID,
+    sqlite::TABLE_STRICT,
+    sqlite::TABLE_PRIMARY_KEY_CONFLICT,
+];
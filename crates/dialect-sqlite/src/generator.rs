@@ -1,40 +1,78 @@
-use std::fmt::Write as _;
-
 use stateql_core::{
-    Column, ColumnChange, DataType, DiffOp, Error, Expr, GenerateError, Ident, IndexOwner, Literal,
-    QualifiedName, Result, SchemaObject, SqliteRebuildStep, Statement, StatementContext, Value,
+    Column, ColumnChange, DiffOp, Error, Expr, GenerateError, Ident, IndexDef, IndexOwner,
+    QualifiedName, Result, SchemaObject, SqliteRebuildStep, Statement, StatementContext, Table,
+    Value,
 };
 
-use crate::to_sql;
+use crate::{
+    adapter::FOREIGN_KEY_CHECK_SQL, extra_keys, normalize::type_affinity,
+    table_clauses::mentions_identifier, to_sql,
+};
 
 const GENERATOR_TARGET: &str = "sqlite ddl generator";
 const SHADOW_TABLE_PREFIX: &str = "__stateql_rebuild_";
+// Foreign key statements a table rebuild is wrapped in; the adapter tells them apart by
+// their `SqliteRebuildStep`, not their text.
+const DISABLE_FOREIGN_KEYS_SQL: &str = "PRAGMA foreign_keys = OFF;";
+const RESTORE_FOREIGN_KEYS_SQL: &str = "PRAGMA foreign_keys = ON;";
 
 pub(crate) fn generate_ddl(dialect_name: &str, ops: &[DiffOp]) -> Result<Vec<Statement>> {
+    let rebuilds = planned_rebuilds(ops);
+    let defined = ops
+        .iter()
+        .filter_map(|op| match op {
+            DiffOp::TableDefinition { target, .. } => Some(&target.name),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
     let mut statements = Vec::new();
     let mut index = 0usize;
 
     while index < ops.len() {
-        if let Some(table) = rebuild_table(&ops[index]).cloned() {
-            let start = index;
+        if let DiffOp::TableDefinition { target, .. } = &ops[index] {
+            if rebuilds.contains(&target.name) {
+                let table_ops = ops
+                    .iter()
+                    .filter(|op| definition_table(op) == Some(&target.name));
+                statements.extend(build_definition_rebuild_plan(
+                    dialect_name,
+                    &ops[index],
+                    table_ops,
+                )?);
+            }
             index += 1;
+            continue;
+        }
 
-            while let Some(next_op) = ops.get(index) {
-                if rebuild_table(next_op) == Some(&table) {
-                    index += 1;
-                } else {
-                    break;
-                }
-            }
+        // Ops of a table rebuilt from its definition are covered by the rebuild; only
+        // the table rename runs natively, ahead of it.
+        if let Some(table) = definition_table(&ops[index])
+            && rebuilds.contains(table)
+            && !matches!(ops[index], DiffOp::RenameTable { .. })
+        {
+            index += 1;
+            continue;
+        }
 
-            statements.extend(build_sqlite_rebuild_plan(
-                dialect_name,
-                &table,
-                &ops[start..index],
-            )?);
+        // A dropped column SQLite can drop natively leaves its table unrebuilt.
+        if let DiffOp::DropColumn { table, .. } = &ops[index]
+            && defined.contains(&table)
+        {
+            emit_simple_op(dialect_name, &ops[index], &mut statements)?;
+            index += 1;
             continue;
         }
 
+        // Without the table's definition a rebuild could not restate its constraints,
+        // indexes and triggers.
+        if rebuild_table(&ops[index]).is_some() {
+            return Err(unsupported_diff_op(
+                dialect_name,
+                &ops[index],
+                "sqlite table rebuilds need the table's DiffOp::TableDefinition",
+            ));
+        }
+
         emit_simple_op(dialect_name, &ops[index], &mut statements)?;
         index += 1;
     }
@@ -42,6 +80,347 @@ pub(crate) fn generate_ddl(dialect_name: &str, ops: &[DiffOp]) -> Result<Vec<Sta
     Ok(statements)
 }
 
+/// Tables whose `DiffOp::TableDefinition` has to be applied by rebuilding the table:
+/// some op has no `ALTER TABLE` form, or the definition changed where no op reaches.
+fn planned_rebuilds(ops: &[DiffOp]) -> Vec<QualifiedName> {
+    ops.iter()
+        .filter_map(|op| match op {
+            DiffOp::TableDefinition {
+                current,
+                target,
+                dependent_indexes,
+                declared_indexes,
+                ..
+            } => Some((current, target, dependent_indexes, declared_indexes)),
+            _ => None,
+        })
+        .filter(|(current, target, dependent_indexes, declared_indexes)| {
+            let current_unique = unique_constraints(dependent_indexes);
+            let mut table_ops = ops
+                .iter()
+                .filter(|op| definition_table(op) == Some(&target.name))
+                .peekable();
+            table_ops.peek().is_none()
+                || table_ops.any(|op| !applies_natively(op, current, target, &current_unique))
+                || changed_without_ops(current, target)
+                || unique_constraints_changed(&current_unique, declared_indexes)
+        })
+        .map(|(_, target, ..)| target.name.clone())
+        .collect()
+}
+
+fn applies_natively(
+    op: &DiffOp,
+    current: &Table,
+    target: &Table,
+    unique_constraints: &[&IndexDef],
+) -> bool {
+    match op {
+        DiffOp::RenameTable { .. } | DiffOp::RenameColumn { .. } => true,
+        DiffOp::AddColumn {
+            column, position, ..
+        } => position.is_none() && column_addable(column, target),
+        DiffOp::DropColumn { column, .. } => column_droppable(column, current, unique_constraints),
+        _ => false,
+    }
+}
+
+/// The `UNIQUE` constraints among a table's indexes; the table definition declares them.
+fn unique_constraints(indexes: &[IndexDef]) -> Vec<&IndexDef> {
+    indexes
+        .iter()
+        .filter(|index| to_sql::is_unique_constraint(index))
+        .collect()
+}
+
+/// `UNIQUE` constraints only change by rebuilding the table that declares them.
+fn unique_constraints_changed(current: &[&IndexDef], target: &[IndexDef]) -> bool {
+    let shape = |index: &IndexDef| {
+        (
+            index
+                .name
+                .as_ref()
+                .map(|name| name.value.to_ascii_lowercase()),
+            format!("{:?}", index.columns),
+            format!("{:?}", index.extra),
+        )
+    };
+    let mut current = current.iter().map(|index| shape(index)).collect::<Vec<_>>();
+    let mut target = target.iter().map(shape).collect::<Vec<_>>();
+    current.sort();
+    target.sort();
+    current != target
+}
+
+/// Mirrors the restrictions SQLite puts on `ALTER TABLE ... ADD COLUMN`.
+fn column_addable(column: &Column, target: &Table) -> bool {
+    let in_primary_key = target
+        .primary_key
+        .as_ref()
+        .is_some_and(|primary_key| primary_key.columns.contains(&column.name));
+    let stored_generated = column
+        .generated
+        .as_ref()
+        .is_some_and(|generated| generated.stored);
+    let default_addable = match &column.default {
+        None => !column.not_null || column.generated.is_some(),
+        Some(default) => constant_default(default, column.not_null),
+    };
+
+    !in_primary_key && column.identity.is_none() && !stored_generated && default_addable
+}
+
+/// Mirrors the restrictions SQLite puts on `ALTER TABLE ... DROP COLUMN`. Indexes,
+/// triggers and views over a dropped column are gone by then: the diff drops them
+/// ahead of the table's ops.
+fn column_droppable(column: &Ident, current: &Table, unique_constraints: &[&IndexDef]) -> bool {
+    let Some(dropped) = current
+        .columns
+        .iter()
+        .find(|candidate| same_ident(&candidate.name, column))
+    else {
+        return false;
+    };
+    let in_primary_key = current.primary_key.as_ref().is_some_and(|primary_key| {
+        primary_key
+            .columns
+            .iter()
+            .any(|name| same_ident(name, column))
+    });
+    let in_unique = unique_constraints.iter().any(|index| {
+        index
+            .columns
+            .iter()
+            .any(|indexed| matches!(&indexed.expr, Expr::Ident(name) if same_ident(name, column)))
+    });
+    let in_foreign_key = current.foreign_keys.iter().any(|foreign_key| {
+        foreign_key
+            .columns
+            .iter()
+            .any(|name| same_ident(name, column))
+    });
+    let in_check = current
+        .checks
+        .iter()
+        .any(|check| mentions_identifier(&to_sql::render_expr(&check.expr), &column.value));
+    let in_generated = current.columns.iter().any(|other| {
+        other.generated.as_ref().is_some_and(|generated| {
+            mentions_identifier(&to_sql::render_expr(&generated.expr), &column.value)
+        })
+    });
+
+    dropped.generated.is_none()
+        && !in_primary_key
+        && !in_unique
+        && !in_foreign_key
+        && !in_check
+        && !in_generated
+}
+
+fn constant_default(default: &Expr, not_null: bool) -> bool {
+    match default {
+        Expr::Null => !not_null,
+        Expr::Literal(_) => true,
+        Expr::Raw(raw) => {
+            let raw = raw.trim();
+            let keyword = raw.to_ascii_uppercase();
+            let null = keyword == "NULL";
+            !(raw.starts_with('(') || keyword.starts_with("CURRENT_") || (not_null && null))
+        }
+        _ => false,
+    }
+}
+
+/// Changes no op describes: unnamed constraints and column attributes.
+fn changed_without_ops(current: &Table, target: &Table) -> bool {
+    let unnamed_checks = |table: &Table| {
+        let mut checks = table
+            .checks
+            .iter()
+            .filter(|check| check.name.is_none())
+            .map(|check| format!("{:?}", check.expr))
+            .collect::<Vec<_>>();
+        checks.sort();
+        checks
+    };
+    let unnamed_foreign_keys = |table: &Table| {
+        let mut foreign_keys = table
+            .foreign_keys
+            .iter()
+            .filter(|foreign_key| foreign_key.name.is_none())
+            .map(|foreign_key| format!("{foreign_key:?}"))
+            .collect::<Vec<_>>();
+        foreign_keys.sort();
+        foreign_keys
+    };
+    if unnamed_checks(current) != unnamed_checks(target)
+        || unnamed_foreign_keys(current) != unnamed_foreign_keys(target)
+    {
+        return true;
+    }
+
+    target.columns.iter().any(|target_column| {
+        current_column(current, target_column).is_some_and(|current_column| {
+            current_column.generated != target_column.generated
+                || current_column.collation != target_column.collation
+                || current_column.identity != target_column.identity
+                || current_column
+                    .extra
+                    .get(extra_keys::COLUMN_NOT_NULL_CONFLICT)
+                    != target_column
+                        .extra
+                        .get(extra_keys::COLUMN_NOT_NULL_CONFLICT)
+        })
+    })
+}
+
+fn current_column<'a>(current: &'a Table, target_column: &Column) -> Option<&'a Column> {
+    current.columns.iter().find(|current_column| {
+        same_ident(&current_column.name, &target_column.name)
+            || target_column
+                .renamed_from
+                .as_ref()
+                .is_some_and(|from| same_ident(&current_column.name, from))
+    })
+}
+
+/// SQLite resolves identifiers case-insensitively, quoted or not.
+fn same_ident(left: &Ident, right: &Ident) -> bool {
+    left.value.eq_ignore_ascii_case(&right.value)
+}
+
+fn build_definition_rebuild_plan<'a>(
+    dialect_name: &str,
+    op: &DiffOp,
+    table_ops: impl IntoIterator<Item = &'a DiffOp>,
+) -> Result<Vec<Statement>> {
+    let DiffOp::TableDefinition {
+        current,
+        target,
+        dependent_views,
+        dependent_indexes,
+        dependent_triggers,
+        declared_indexes,
+        ..
+    } = op
+    else {
+        return Err(unsupported_diff_op(dialect_name, op, GENERATOR_TARGET));
+    };
+    let table = &target.name;
+    let shadow_table = shadow_table_name(table);
+    let create_sql =
+        to_sql::render_table_definition(dialect_name, target, &shadow_table, declared_indexes)
+            .map_err(|error| match error {
+                Error::Generate(GenerateError::UnsupportedDiffOp { target, .. }) => {
+                    unsupported_diff_op(dialect_name, op, target)
+                }
+                other => other,
+            })?;
+    let view_sql = dependent_views
+        .iter()
+        .map(|view| render_schema_object(dialect_name, op, SchemaObject::View(view.clone())))
+        .collect::<Result<Vec<_>>>()?;
+    let index_sql = dependent_indexes
+        .iter()
+        .filter(|index| !to_sql::is_unique_constraint(index))
+        .map(|index| render_schema_object(dialect_name, op, SchemaObject::Index(index.clone())))
+        .collect::<Result<Vec<_>>>()?;
+    let trigger_sql = dependent_triggers
+        .iter()
+        .map(|trigger| {
+            render_schema_object(dialect_name, op, SchemaObject::Trigger(trigger.clone()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let (copied_columns, projections): (Vec<_>, Vec<_>) =
+        copy_mapping(current, target, table_ops).into_iter().unzip();
+    let copied_columns = copied_columns.join(", ");
+    let projections = projections.join(", ");
+    let copy_sql = (!copied_columns.is_empty()).then(|| {
+        format!(
+            "INSERT INTO {} ({copied_columns}) SELECT {projections} FROM {};",
+            render_qualified_name(&shadow_table),
+            render_qualified_name(table)
+        )
+    });
+
+    Ok(rebuild_steps(
+        table,
+        &shadow_table,
+        create_sql,
+        copy_sql,
+        dependent_views.iter().map(|view| &view.name).zip(view_sql),
+        index_sql,
+        trigger_sql,
+    ))
+}
+
+/// Target column and source projection pairs for `SqliteRebuildStep::CopyData`.
+///
+/// Renamed columns read from their old name, columns whose type changes affinity are
+/// cast, and new columns are filled from their `@backfill` expression when they have
+/// one. Generated columns are never copied.
+fn copy_mapping<'a>(
+    current: &Table,
+    target: &Table,
+    table_ops: impl IntoIterator<Item = &'a DiffOp>,
+) -> Vec<(String, String)> {
+    let mut renames = Vec::new();
+    let mut retyped = Vec::new();
+    for op in table_ops {
+        match op {
+            DiffOp::RenameColumn { from, to, .. } => renames.push((to, from)),
+            DiffOp::AlterColumn {
+                column, changes, ..
+            } if changes
+                .iter()
+                .any(|change| matches!(change, ColumnChange::SetType(_))) =>
+            {
+                retyped.push(column);
+            }
+            _ => {}
+        }
+    }
+
+    target
+        .columns
+        .iter()
+        .filter(|column| column.generated.is_none())
+        .filter_map(|column| {
+            let source = renames
+                .iter()
+                .find(|(to, _)| same_ident(to, &column.name))
+                .and_then(|(_, from)| {
+                    current
+                        .columns
+                        .iter()
+                        .find(|current_column| same_ident(&current_column.name, from))
+                })
+                .or_else(|| current_column(current, column));
+            let projection = match source {
+                Some(source) => {
+                    let declared_type = to_sql::declared_type(column);
+                    let changes_affinity =
+                        retyped.iter().any(|name| same_ident(name, &column.name))
+                            && !declared_type.is_empty()
+                            && type_affinity(&to_sql::declared_type(source))
+                                != type_affinity(&declared_type);
+                    if changes_affinity {
+                        format!("CAST({} AS {declared_type})", render_ident(&source.name))
+                    } else {
+                        render_ident(&source.name)
+                    }
+                }
+                None => match column.extra.get(extra_keys::COLUMN_BACKFILL) {
+                    Some(Value::String(expr)) => expr.clone(),
+                    _ => return None,
+                },
+            };
+            Some((render_ident(&column.name), projection))
+        })
+        .collect()
+}
+
 fn emit_simple_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) -> Result<()> {
     match op {
         DiffOp::CreateTable(table) => {
@@ -85,11 +464,22 @@ fn emit_simple_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) ->
                     "sqlite add-column supports append only",
                 ));
             }
-            let column_definition = render_column_definition(column, dialect_name, op)?;
             out.push(sql_statement(
                 format!(
-                    "ALTER TABLE {} ADD COLUMN {column_definition};",
-                    render_qualified_name(table)
+                    "ALTER TABLE {} ADD COLUMN {};",
+                    render_qualified_name(table),
+                    to_sql::render_column(column)
+                ),
+                true,
+                None,
+            ));
+        }
+        DiffOp::DropColumn { table, column } => {
+            out.push(sql_statement(
+                format!(
+                    "ALTER TABLE {} DROP COLUMN {};",
+                    render_qualified_name(table),
+                    render_ident(column)
                 ),
                 true,
                 None,
@@ -115,19 +505,30 @@ fn emit_simple_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) ->
                     "sqlite indexes are table-scoped",
                 ));
             }
+            if to_sql::is_unique_constraint(index) {
+                return Err(unsupported_diff_op(
+                    dialect_name,
+                    op,
+                    "sqlite UNIQUE constraints change with their table definition",
+                ));
+            }
             let sql = render_schema_object(dialect_name, op, SchemaObject::Index(index.clone()))?;
             out.push(sql_statement(sql, true, None));
         }
         DiffOp::DropIndex { owner, name } => {
-            if !matches!(owner, IndexOwner::Table(_)) {
+            let IndexOwner::Table(table) = owner else {
                 return Err(unsupported_diff_op(
                     dialect_name,
                     op,
                     "sqlite indexes are table-scoped",
                 ));
-            }
+            };
+            let index_name = QualifiedName {
+                schema: table.schema.clone(),
+                name: name.clone(),
+            };
             out.push(sql_statement(
-                format!("DROP INDEX {};", render_ident(name)),
+                format!("DROP INDEX {};", render_qualified_name(&index_name)),
                 true,
                 None,
             ));
@@ -155,8 +556,12 @@ fn emit_simple_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) ->
                 None,
             ));
         }
+        DiffOp::SetPragma(pragma) => {
+            // `journal_mode` cannot change inside a transaction; the header pragmas run
+            // beside it so every setting lands together.
+            out.push(sql_statement(to_sql::render_pragma(pragma), false, None));
+        }
         DiffOp::AlterColumn { .. }
-        | DiffOp::DropColumn { .. }
         | DiffOp::AddForeignKey { .. }
         | DiffOp::DropForeignKey { .. }
         | DiffOp::AddCheck { .. }
@@ -179,66 +584,47 @@ fn emit_simple_op(dialect_name: &str, op: &DiffOp, out: &mut Vec<Statement>) ->
     Ok(())
 }
 
-fn build_sqlite_rebuild_plan(
-    dialect_name: &str,
+/// `dependent_views` pairs each view reading from the table with its `CREATE VIEW`, in
+/// creation order; they are dropped before the old table goes away and recreated once
+/// the shadow table carries its name. Dropping the old table drops its indexes and
+/// triggers too, so `index_sql` and `trigger_sql` recreate them on the renamed shadow
+/// table.
+fn rebuild_steps<'a>(
     table: &QualifiedName,
-    ops: &[DiffOp],
-) -> Result<Vec<Statement>> {
-    let first_op = ops
-        .first()
-        .ok_or_else(|| GenerateError::UnsupportedDiffOp {
-            diff_op: "SqliteRebuild".to_string(),
-            target: GENERATOR_TARGET.to_string(),
-            dialect: dialect_name.to_string(),
-        })?;
-
-    let rebuilt_columns = collect_rebuild_columns(dialect_name, table, ops)?;
-    let shadow_table = shadow_table_name(table);
-    let create_sql = if rebuilt_columns.is_empty() {
-        format!(
-            "CREATE TABLE {} AS SELECT * FROM {} WHERE 0;",
-            render_qualified_name(&shadow_table),
-            render_qualified_name(table)
-        )
-    } else {
-        let column_sql = rebuilt_columns
-            .iter()
-            .map(|column| render_rebuild_column(column, dialect_name, first_op))
-            .collect::<Result<Vec<_>>>()?;
-        format!(
-            "CREATE TABLE {} ({});",
-            render_qualified_name(&shadow_table),
-            column_sql.join(", ")
-        )
-    };
-
-    let copy_sql = if rebuilt_columns.is_empty() {
-        format!(
-            "INSERT INTO {} SELECT * FROM {};",
-            render_qualified_name(&shadow_table),
-            render_qualified_name(table)
-        )
-    } else {
-        let column_list = rebuilt_columns
-            .iter()
-            .map(|column| render_ident(&column.name))
-            .collect::<Vec<_>>()
-            .join(", ");
-        let select_list = rebuilt_columns
-            .iter()
-            .map(render_copy_projection)
-            .collect::<Vec<_>>()
-            .join(", ");
-        format!(
-            "INSERT INTO {} ({column_list}) SELECT {select_list} FROM {};",
-            render_qualified_name(&shadow_table),
-            render_qualified_name(table)
-        )
-    };
-
-    Ok(vec![
+    shadow_table: &QualifiedName,
+    create_sql: String,
+    copy_sql: Option<String>,
+    dependent_views: impl IntoIterator<Item = (&'a QualifiedName, String)>,
+    index_sql: Vec<String>,
+    trigger_sql: Vec<String>,
+) -> Vec<Statement> {
+    let (view_names, view_sql): (Vec<_>, Vec<_>) = dependent_views.into_iter().unzip();
+    // SQLite's documented procedure for table changes ALTER TABLE cannot make: foreign
+    // key enforcement is off while the table is swapped, and the result is checked
+    // before commit.
+    let mut steps = vec![
+        rebuild_statement(
+            DISABLE_FOREIGN_KEYS_SQL.to_string(),
+            table,
+            SqliteRebuildStep::DisableForeignKeys,
+        ),
         rebuild_statement(create_sql, table, SqliteRebuildStep::CreateShadowTable),
-        rebuild_statement(copy_sql, table, SqliteRebuildStep::CopyData),
+    ];
+    if let Some(copy_sql) = copy_sql {
+        steps.push(rebuild_statement(
+            copy_sql,
+            table,
+            SqliteRebuildStep::CopyData,
+        ));
+    }
+    steps.extend(view_names.iter().rev().map(|view| {
+        rebuild_statement(
+            format!("DROP VIEW {};", render_qualified_name(view)),
+            table,
+            SqliteRebuildStep::DropDependentViews,
+        )
+    }));
+    steps.extend([
         rebuild_statement(
             format!("DROP TABLE {};", render_qualified_name(table)),
             table,
@@ -247,270 +633,41 @@ fn build_sqlite_rebuild_plan(
         rebuild_statement(
             format!(
                 "ALTER TABLE {} RENAME TO {};",
-                render_qualified_name(&shadow_table),
+                render_qualified_name(shadow_table),
                 render_ident(&table.name)
             ),
             table,
             SqliteRebuildStep::RenameShadowTable,
         ),
+    ]);
+    steps.extend(
+        view_sql
+            .into_iter()
+            .map(|sql| rebuild_statement(sql, table, SqliteRebuildStep::RecreateDependentViews)),
+    );
+    steps.extend(
+        index_sql
+            .into_iter()
+            .map(|sql| rebuild_statement(sql, table, SqliteRebuildStep::RecreateIndexes)),
+    );
+    steps.extend(
+        trigger_sql
+            .into_iter()
+            .map(|sql| rebuild_statement(sql, table, SqliteRebuildStep::RecreateTriggers)),
+    );
+    steps.extend([
         rebuild_statement(
-            format!(
-                "-- sqlite rebuild: recreate indexes for {}",
-                render_qualified_name(table)
-            ),
+            FOREIGN_KEY_CHECK_SQL.to_string(),
             table,
-            SqliteRebuildStep::RecreateIndexes,
+            SqliteRebuildStep::CheckForeignKeys,
         ),
         rebuild_statement(
-            format!(
-                "-- sqlite rebuild: recreate triggers for {}",
-                render_qualified_name(table)
-            ),
+            RESTORE_FOREIGN_KEYS_SQL.to_string(),
             table,
-            SqliteRebuildStep::RecreateTriggers,
+            SqliteRebuildStep::RestoreForeignKeys,
         ),
-    ])
-}
-
-fn collect_rebuild_columns(
-    dialect_name: &str,
-    table: &QualifiedName,
-    ops: &[DiffOp],
-) -> Result<Vec<RebuildColumnSpec>> {
-    let mut columns = Vec::new();
-
-    for op in ops {
-        match op {
-            DiffOp::AlterColumn {
-                table: target_table,
-                column,
-                changes,
-            } => {
-                ensure_same_table(dialect_name, op, table, target_table)?;
-
-                let spec = upsert_rebuild_column(&mut columns, column.clone());
-                for change in changes {
-                    match change {
-                        ColumnChange::SetType(data_type) => {
-                            spec.data_type = Some(data_type.clone());
-                        }
-                        ColumnChange::SetNotNull(not_null) => {
-                            spec.not_null = Some(*not_null);
-                        }
-                        ColumnChange::SetDefault(default) => {
-                            spec.default = Some(default.clone());
-                        }
-                        ColumnChange::SetIdentity(_)
-                        | ColumnChange::SetGenerated(_)
-                        | ColumnChange::SetCollation(_) => {}
-                    }
-                }
-            }
-            DiffOp::DropColumn {
-                table: target_table,
-                ..
-            }
-            | DiffOp::AddForeignKey {
-                table: target_table,
-                ..
-            }
-            | DiffOp::DropForeignKey {
-                table: target_table,
-                ..
-            }
-            | DiffOp::AddCheck {
-                table: target_table,
-                ..
-            }
-            | DiffOp::DropCheck {
-                table: target_table,
-                ..
-            }
-            | DiffOp::AddExclusion {
-                table: target_table,
-                ..
-            }
-            | DiffOp::DropExclusion {
-                table: target_table,
-                ..
-            }
-            | DiffOp::SetPrimaryKey {
-                table: target_table,
-                ..
-            }
-            | DiffOp::DropPrimaryKey {
-                table: target_table,
-                ..
-            } => {
-                ensure_same_table(dialect_name, op, table, target_table)?;
-            }
-            _ => {
-                return Err(unsupported_diff_op(
-                    dialect_name,
-                    op,
-                    "unexpected op in sqlite rebuild batch",
-                ));
-            }
-        }
-    }
-
-    Ok(columns)
-}
-
-fn ensure_same_table(
-    dialect_name: &str,
-    op: &DiffOp,
-    expected: &QualifiedName,
-    actual: &QualifiedName,
-) -> Result<()> {
-    if expected == actual {
-        return Ok(());
-    }
-    Err(unsupported_diff_op(
-        dialect_name,
-        op,
-        "sqlite rebuild batch mixed multiple tables",
-    ))
-}
-
-fn render_rebuild_column(
-    column: &RebuildColumnSpec,
-    dialect_name: &str,
-    op: &DiffOp,
-) -> Result<String> {
-    let mut rendered = format!(
-        "{} {}",
-        render_ident(&column.name),
-        render_data_type(column.data_type.as_ref().unwrap_or(&DataType::Text))
-    );
-
-    if column.not_null.unwrap_or(false) {
-        rendered.push_str(" NOT NULL");
-    }
-
-    if let Some(default) = &column.default
-        && let Some(default_expr) = default
-    {
-        write!(
-            rendered,
-            " DEFAULT {}",
-            render_default_expr(default_expr, dialect_name, op)?
-        )
-        .expect("writing to String should not fail");
-    }
-
-    Ok(rendered)
-}
-
-fn render_copy_projection(column: &RebuildColumnSpec) -> String {
-    let source_column = render_ident(&column.name);
-    if let Some(data_type) = &column.data_type {
-        format!("CAST({source_column} AS {})", render_data_type(data_type))
-    } else {
-        source_column
-    }
-}
-
-fn render_column_definition(column: &Column, dialect_name: &str, op: &DiffOp) -> Result<String> {
-    let mut sql = format!(
-        "{} {}",
-        render_ident(&column.name),
-        render_data_type(&column.data_type)
-    );
-
-    if column.not_null {
-        sql.push_str(" NOT NULL");
-    }
-
-    if let Some(default) = &column.default {
-        write!(
-            sql,
-            " DEFAULT {}",
-            render_default_expr(default, dialect_name, op)?
-        )
-        .expect("writing to String should not fail");
-    }
-
-    Ok(sql)
-}
-
-fn render_default_expr(expr: &Expr, dialect_name: &str, op: &DiffOp) -> Result<String> {
-    match expr {
-        Expr::Literal(literal) => Ok(render_literal(literal)),
-        Expr::Raw(raw) => Ok(raw.trim().to_string()),
-        Expr::Null => Ok("NULL".to_string()),
-        Expr::Ident(ident) => Ok(render_ident(ident)),
-        Expr::QualifiedIdent { qualifier, name } => Ok(format!(
-            "{}.{}",
-            render_ident(qualifier),
-            render_ident(name)
-        )),
-        Expr::Cast { expr, data_type } => Ok(format!(
-            "CAST({} AS {})",
-            render_default_expr(expr, dialect_name, op)?,
-            render_data_type(data_type)
-        )),
-        Expr::Paren(inner) => Ok(format!(
-            "({})",
-            render_default_expr(inner, dialect_name, op)?
-        )),
-        _ => Err(unsupported_diff_op(
-            dialect_name,
-            op,
-            "unsupported expression in sqlite default value",
-        )),
-    }
-}
-
-fn render_literal(literal: &Literal) -> String {
-    match literal {
-        Literal::String(value) => format!("'{}'", value.replace('\'', "''")),
-        Literal::Integer(value) => value.to_string(),
-        Literal::Float(value) => value.to_string(),
-        Literal::Boolean(value) => {
-            if *value {
-                "1".to_string()
-            } else {
-                "0".to_string()
-            }
-        }
-        Literal::Value(value) => render_value(value),
-    }
-}
-
-fn render_value(value: &Value) -> String {
-    match value {
-        Value::String(value) => format!("'{}'", value.replace('\'', "''")),
-        Value::Integer(value) => value.to_string(),
-        Value::Float(value) => value.to_string(),
-        Value::Bool(value) => {
-            if *value {
-                "1".to_string()
-            } else {
-                "0".to_string()
-            }
-        }
-        Value::Null => "NULL".to_string(),
-    }
-}
-
-fn render_data_type(data_type: &DataType) -> String {
-    match data_type {
-        DataType::Boolean => "INTEGER".to_string(),
-        DataType::SmallInt | DataType::Integer | DataType::BigInt => "INTEGER".to_string(),
-        DataType::Real | DataType::DoublePrecision => "REAL".to_string(),
-        DataType::Numeric { precision, scale } => match (precision, scale) {
-            (Some(precision), Some(scale)) => format!("NUMERIC({precision}, {scale})"),
-            (Some(precision), None) => format!("NUMERIC({precision})"),
-            _ => "NUMERIC".to_string(),
-        },
-        DataType::Text | DataType::Varchar { .. } | DataType::Char { .. } => "TEXT".to_string(),
-        DataType::Blob => "BLOB".to_string(),
-        DataType::Date | DataType::Time { .. } | DataType::Timestamp { .. } => "TEXT".to_string(),
-        DataType::Json | DataType::Jsonb | DataType::Uuid => "TEXT".to_string(),
-        DataType::Array(_) => "TEXT".to_string(),
-        DataType::Custom(custom) => custom.trim().to_ascii_uppercase(),
-    }
+    ]);
+    steps
 }
 
 fn render_schema_object(dialect_name: &str, op: &DiffOp, object: SchemaObject) -> Result<String> {
@@ -538,6 +695,17 @@ fn rebuild_table(op: &DiffOp) -> Option<&QualifiedName> {
     }
 }
 
+/// The table an op changes, for the ops a definition rebuild can take over.
+fn definition_table(op: &DiffOp) -> Option<&QualifiedName> {
+    match op {
+        DiffOp::RenameTable { to, .. } => Some(to),
+        DiffOp::AddColumn { table, .. }
+        | DiffOp::RenameColumn { table, .. }
+        | DiffOp::AlterTableOptions { table, .. } => Some(table),
+        _ => rebuild_table(op),
+    }
+}
+
 fn shadow_table_name(table: &QualifiedName) -> QualifiedName {
     QualifiedName {
         schema: table.schema.clone(),
@@ -546,9 +714,14 @@ fn shadow_table_name(table: &QualifiedName) -> QualifiedName {
 }
 
 fn rebuild_statement(sql: String, table: &QualifiedName, step: SqliteRebuildStep) -> Statement {
+    // `PRAGMA foreign_keys` is a no-op inside a transaction.
+    let transactional = !matches!(
+        step,
+        SqliteRebuildStep::DisableForeignKeys | SqliteRebuildStep::RestoreForeignKeys
+    );
     sql_statement(
         sql,
-        true,
+        transactional,
         Some(StatementContext::SqliteTableRebuild {
             table: table.clone(),
             step,
@@ -589,6 +762,7 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::CreateTable(_) => "CreateTable",
         DiffOp::DropTable(_) => "DropTable",
         DiffOp::RenameTable { .. } => "RenameTable",
+        DiffOp::TableDefinition { .. } => "TableDefinition",
         DiffOp::AddColumn { .. } => "AddColumn",
         DiffOp::DropColumn { .. } => "DropColumn",
         DiffOp::AlterColumn { .. } => "AlterColumn",
@@ -606,6 +780,8 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::DropPrimaryKey { .. } => "DropPrimaryKey",
         DiffOp::AddPartition { .. } => "AddPartition",
         DiffOp::DropPartition { .. } => "DropPartition",
+        DiffOp::ReorganizePartition { .. } => "ReorganizePartition",
+        DiffOp::ChangePartitionCount { .. } => "ChangePartitionCount",
         DiffOp::CreateView(_) => "CreateView",
         DiffOp::DropView(_) => "DropView",
         DiffOp::CreateMaterializedView(_) => "CreateMaterializedView",
@@ -613,10 +789,14 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::CreateSequence(_) => "CreateSequence",
         DiffOp::DropSequence(_) => "DropSequence",
         DiffOp::AlterSequence { .. } => "AlterSequence",
+        DiffOp::CreateSynonym(_) => "CreateSynonym",
+        DiffOp::DropSynonym(_) => "DropSynonym",
         DiffOp::CreateTrigger(_) => "CreateTrigger",
         DiffOp::DropTrigger { .. } => "DropTrigger",
         DiffOp::CreateFunction(_) => "CreateFunction",
-        DiffOp::DropFunction(_) => "DropFunction",
+        DiffOp::DropFunction { .. } => "DropFunction",
+        DiffOp::CreateEvent(_) => "CreateEvent",
+        DiffOp::DropEvent(_) => "DropEvent",
         DiffOp::CreateType(_) => "CreateType",
         DiffOp::DropType(_) => "DropType",
         DiffOp::AlterType { .. } => "AlterType",
@@ -633,33 +813,7 @@ fn diff_op_tag(op: &DiffOp) -> &'static str {
         DiffOp::Revoke(_) => "Revoke",
         DiffOp::CreatePolicy(_) => "CreatePolicy",
         DiffOp::DropPolicy { .. } => "DropPolicy",
+        DiffOp::SetPragma(_) => "SetPragma",
         DiffOp::AlterTableOptions { .. } => "AlterTableOptions",
     }
 }
-
-#[derive(Debug, Clone)]
-struct RebuildColumnSpec {
-    name: Ident,
-    data_type: Option<DataType>,
-    not_null: Option<bool>,
-    default: Option<Option<Expr>>,
-}
-
-fn upsert_rebuild_column(
-    columns: &mut Vec<RebuildColumnSpec>,
-    name: Ident,
-) -> &mut RebuildColumnSpec {
-    if let Some(index) = columns.iter().position(|column| column.name == name) {
-        return &mut columns[index];
-    }
-
-    columns.push(RebuildColumnSpec {
-        name,
-        data_type: None,
-        not_null: None,
-        default: None,
-    });
-    columns
-        .last_mut()
-        .expect("rebuild column vector must contain inserted element")
-}
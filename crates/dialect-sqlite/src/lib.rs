@@ -5,6 +5,9 @@ mod extra_keys;
 mod generator;
 mod normalize;
 mod parser;
+mod pragma;
+mod safe_apply;
+mod table_clauses;
 mod to_sql;
 
 use stateql_core::{
@@ -34,6 +37,18 @@ impl Dialect for SqliteDialect {
         generator::generate_ddl(self.name(), ops)
     }
 
+    fn requires_table_definitions(&self) -> bool {
+        true
+    }
+
+    fn diffs_table_constraints(&self) -> bool {
+        true
+    }
+
+    fn diffed_table_options(&self) -> &'static [&'static str] {
+        &extra_keys::DIFFED_TABLE_OPTIONS
+    }
+
     fn to_sql(&self, obj: &SchemaObject) -> Result<String> {
         to_sql::render_object(self.name(), obj)
     }
@@ -1,6 +1,6 @@
 use stateql_core::{
-    DataType, Expr, Function, Ident, IndexDef, MaterializedView, SchemaObject, Sequence, Table,
-    TypeDef, TypeKind,
+    DataType, Expr, Function, Ident, IndexDef, IndexOwner, MaterializedView, QualifiedName,
+    SchemaObject, Sequence, Table, TypeDef, TypeKind, extra_keys::sqlite as sqlite_keys,
 };
 
 use crate::extra_keys;
@@ -20,14 +20,22 @@ fn normalize_object_types(object: &mut SchemaObject) {
                 }
             }
             normalize_source_sql_hint(table);
+            normalize_virtual_module(table);
+            normalize_table_idents(table);
         }
-        SchemaObject::View(_) => {}
+        SchemaObject::View(view) => unquote_qualified_name(&mut view.name),
         SchemaObject::MaterializedView(materialized_view) => {
             normalize_materialized_view_types(materialized_view);
         }
-        SchemaObject::Index(_) => {}
+        SchemaObject::Index(index) => normalize_index_idents(index),
         SchemaObject::Sequence(sequence) => normalize_sequence_type(sequence),
-        SchemaObject::Trigger(_) => {}
+        SchemaObject::Synonym(_) => {}
+        SchemaObject::Event(_) => {}
+        SchemaObject::Trigger(trigger) => {
+            unquote_qualified_name(&mut trigger.name);
+            unquote_qualified_name(&mut trigger.table);
+            trigger.update_columns.iter_mut().for_each(unquote);
+        }
         SchemaObject::Function(function) => normalize_function_types(function),
         SchemaObject::Type(type_def) => normalize_type_def(type_def),
         SchemaObject::Domain(domain) => normalize_data_type(&mut domain.data_type),
@@ -36,6 +44,18 @@ fn normalize_object_types(object: &mut SchemaObject) {
         SchemaObject::Comment(_) => {}
         SchemaObject::Privilege(_) => {}
         SchemaObject::Policy(_) => {}
+        SchemaObject::Pragma(pragma) => unquote_qualified_name(&mut pragma.name),
+    }
+}
+
+/// SQLite looks virtual table modules up case-insensitively.
+fn normalize_virtual_module(table: &mut Table) {
+    if let Some(stateql_core::Value::String(module)) = table
+        .options
+        .extra
+        .get_mut(sqlite_keys::TABLE_VIRTUAL_MODULE)
+    {
+        *module = module.to_ascii_lowercase();
     }
 }
 
@@ -47,6 +67,53 @@ fn normalize_source_sql_hint(table: &mut Table) {
     }
 }
 
+/// SQLite resolves identifiers the same way whether or not they are quoted, so quoting
+/// must not make `users` and `"users"` different tables or columns.
+fn normalize_table_idents(table: &mut Table) {
+    unquote_qualified_name(&mut table.name);
+    for column in &mut table.columns {
+        unquote(&mut column.name);
+        if let Some(renamed_from) = &mut column.renamed_from {
+            unquote(renamed_from);
+        }
+    }
+    if let Some(primary_key) = &mut table.primary_key {
+        primary_key.columns.iter_mut().for_each(unquote);
+    }
+    for foreign_key in &mut table.foreign_keys {
+        foreign_key.columns.iter_mut().for_each(unquote);
+        unquote_qualified_name(&mut foreign_key.referenced_table);
+        foreign_key.referenced_columns.iter_mut().for_each(unquote);
+    }
+}
+
+fn normalize_index_idents(index: &mut IndexDef) {
+    if let Some(name) = &mut index.name {
+        unquote(name);
+    }
+    match &mut index.owner {
+        IndexOwner::Table(name) | IndexOwner::View(name) | IndexOwner::MaterializedView(name) => {
+            unquote_qualified_name(name);
+        }
+    }
+    for column in &mut index.columns {
+        if let Expr::Ident(ident) = &mut column.expr {
+            unquote(ident);
+        }
+    }
+}
+
+fn unquote_qualified_name(name: &mut QualifiedName) {
+    if let Some(schema) = &mut name.schema {
+        unquote(schema);
+    }
+    unquote(&mut name.name);
+}
+
+fn unquote(ident: &mut Ident) {
+    ident.quoted = false;
+}
+
 fn normalize_materialized_view_types(materialized_view: &mut MaterializedView) {
     for column in &mut materialized_view.columns {
         normalize_data_type(&mut column.data_type);
@@ -78,6 +145,11 @@ fn normalize_type_def(type_def: &mut TypeDef) {
             }
         }
         TypeKind::Range { subtype } => normalize_data_type(subtype),
+        TypeKind::Table { columns, .. } => {
+            for column in columns {
+                normalize_data_type(&mut column.data_type);
+            }
+        }
     }
 }
 
@@ -125,6 +197,32 @@ fn normalize_custom_type(raw: &str) -> DataType {
     DataType::Custom(canonical)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TypeAffinity {
+    Integer,
+    Text,
+    Blob,
+    Real,
+    Numeric,
+}
+
+/// Column affinity of a declared type, by the rules of SQLite's `CREATE TABLE`.
+pub(crate) fn type_affinity(declared_type: &str) -> TypeAffinity {
+    let canonical = declared_type.to_ascii_lowercase();
+    if is_integer_affinity(&canonical) {
+        TypeAffinity::Integer
+    } else if is_text_affinity(&canonical) {
+        TypeAffinity::Text
+    } else if canonical.contains("blob") || canonical.trim().is_empty() {
+        TypeAffinity::Blob
+    } else if canonical.contains("real") || canonical.contains("floa") || canonical.contains("doub")
+    {
+        TypeAffinity::Real
+    } else {
+        TypeAffinity::Numeric
+    }
+}
+
 fn is_integer_affinity(canonical: &str) -> bool {
     canonical.contains("int")
 }
@@ -181,6 +279,8 @@ fn normalize_object_exprs(object: &mut SchemaObject) {
         }
         SchemaObject::Index(index) => normalize_index_exprs(index),
         SchemaObject::Sequence(_) => {}
+        SchemaObject::Synonym(_) => {}
+        SchemaObject::Event(_) => {}
         SchemaObject::Trigger(trigger) => {
             if let Some(when_clause) = &mut trigger.when_clause {
                 normalize_expr(when_clause);
@@ -208,6 +308,7 @@ fn normalize_object_exprs(object: &mut SchemaObject) {
         SchemaObject::Schema(_) => {}
         SchemaObject::Comment(_) => {}
         SchemaObject::Privilege(_) => {}
+        SchemaObject::Pragma(_) => {}
         SchemaObject::Policy(policy) => {
             if let Some(using_expr) = &mut policy.using_expr {
                 normalize_expr(using_expr);
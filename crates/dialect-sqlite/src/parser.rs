@@ -1,30 +1,66 @@
-use std::io;
-
-use sqlparser::{dialect::SQLiteDialect, parser::Parser};
+use std::{collections::BTreeMap, io};
+
+use sqlparser::{
+    ast::{
+        ColumnDef, ColumnOption, CreateIndex, CreateTable, CreateTrigger, CreateView,
+        DataType as SqlDataType, DeferrableInitial, Expr as SqlExpr, ForeignKeyConstraint,
+        GeneratedExpressionMode, Ident as SqlIdent, IndexColumn as SqlIndexColumn, ObjectName,
+        ReferentialAction, Statement as SqlStatement, TableConstraint,
+        TriggerEvent as SqlTriggerEvent, TriggerPeriod,
+    },
+    dialect::SQLiteDialect,
+    keywords::Keyword,
+    parser::Parser,
+};
 use stateql_core::{
-    AnnotationAttachment, AnnotationExtractor, AnnotationTarget, Ident, ParseError, QualifiedName,
-    Result, SchemaObject, SourceLocation, Table, Value, attach_annotations,
+    AnnotationAttachment, AnnotationExtractor, AnnotationTarget, CheckConstraint, Column, DataType,
+    Deferrable, Expr, ForeignKey, ForeignKeyAction, GeneratedColumn, Ident, Identity, IndexColumn,
+    IndexDef, IndexOwner, ParseError, PrimaryKey, QualifiedName, Result, SchemaObject,
+    SourceLocation, Table, Trigger, TriggerEvent, TriggerForEach, TriggerTiming, Value, View,
+    attach_annotations, attach_backfills, extra_keys::sqlite as sqlite_keys,
 };
 
-use crate::extra_keys;
+use crate::{
+    extra_keys, pragma,
+    table_clauses::{TableClauses, is_word_byte, lift_table_clauses},
+};
 
 type ConversionResult<T> = std::result::Result<T, io::Error>;
 
 pub(crate) fn parse_schema(sql: &str) -> Result<Vec<SchemaObject>> {
     let (clean_sql, annotations) = AnnotationExtractor::extract(sql)?;
-    let ast = Parser::parse_sql(&SQLiteDialect {}, &clean_sql).map_err(|source| {
-        ParseError::StatementConversion {
+    let (clean_sql, backfills) = AnnotationExtractor::extract_backfills(&clean_sql)?;
+    let mut parser_sql = String::with_capacity(clean_sql.len());
+    let mut spans = Vec::new();
+    for (start, end) in split_statement_spans(&clean_sql) {
+        let fragment = &clean_sql[start..end];
+        if pragma::is_pragma_statement(strip_leading_comments(fragment)) {
+            // Blanked rather than dropped so sqlparser still reports file line numbers.
+            parser_sql.extend(fragment.chars().map(|ch| if ch == '\n' { ch } else { ' ' }));
+            spans.push(((start, end), None));
+        } else {
+            let lifted = lift_table_clauses(fragment);
+            parser_sql.push_str(&lifted.sql);
+            spans.push(((start, end), Some(lifted.clauses)));
+        }
+    }
+
+    let whole_input_error =
+        |source: Box<dyn std::error::Error + Send + Sync>| ParseError::StatementConversion {
             statement_index: 0,
             source_sql: clean_sql.clone(),
             source_location: Some(SourceLocation {
                 line: 1,
                 column: None,
             }),
-            source: Box::new(source),
-        }
-    })?;
+            source,
+        };
+    let ast = Parser::parse_sql(&SQLiteDialect {}, &parser_sql)
+        .map_err(|source| whole_input_error(Box::new(source)))?;
 
-    let metadata = statement_metadata(&clean_sql);
+    let (pragmas, metadata): (Vec<_>, Vec<_>) = statement_metadata(&clean_sql, spans)
+        .into_iter()
+        .partition(|metadata| metadata.pragma);
     let mut objects = Vec::with_capacity(ast.len());
     let mut attachments = Vec::with_capacity(ast.len());
 
@@ -32,34 +68,61 @@ pub(crate) fn parse_schema(sql: &str) -> Result<Vec<SchemaObject>> {
         let metadata = metadata
             .get(statement_index)
             .cloned()
-            .unwrap_or_else(|| fallback_metadata(statement));
-        let converted = convert_statement(statement, metadata.line).map_err(|source| {
+            .unwrap_or_else(|| fallback_metadata(statement_index, statement));
+        let converted = convert_statement(statement, &metadata).map_err(|source| {
             statement_conversion_error(
-                statement_index,
+                metadata.statement_index,
                 metadata.source_sql,
                 metadata.source_location,
                 source,
             )
         })?;
 
-        objects.push(converted.object);
-        attachments.push(converted.attachment);
+        objects.extend(converted.objects);
+        attachments.extend(converted.attachments);
+    }
+    for metadata in pragmas {
+        let pragma = pragma::parse_pragma(strip_leading_comments(&metadata.source_sql)).map_err(
+            |source| {
+                statement_conversion_error(
+                    metadata.statement_index,
+                    metadata.source_sql,
+                    metadata.source_location,
+                    source,
+                )
+            },
+        )?;
+        objects.push(SchemaObject::Pragma(pragma));
     }
 
     attach_annotations(&mut objects, &annotations, &attachments)?;
+    attach_backfills(
+        &mut objects,
+        &backfills,
+        &attachments,
+        extra_keys::COLUMN_BACKFILL,
+    )?;
     Ok(objects)
 }
 
 #[derive(Clone)]
 struct StatementMetadata {
+    /// Position among the statements of the input, `PRAGMA`s included.
+    statement_index: usize,
+    /// `PRAGMA` statements never reach sqlparser.
+    pragma: bool,
     source_sql: String,
     source_location: Option<SourceLocation>,
     line: usize,
+    table_clauses: TableClauses,
 }
 
 struct ConvertedStatement {
-    object: SchemaObject,
-    attachment: AnnotationAttachment,
+    /// The created object, followed by the indexes a table declares.
+    objects: Vec<SchemaObject>,
+    /// Tables and their columns, and views; indexes and triggers cannot carry `@renamed`
+    /// annotations.
+    attachments: Vec<AnnotationAttachment>,
 }
 
 fn statement_conversion_error(
@@ -77,10 +140,13 @@ fn statement_conversion_error(
     .into()
 }
 
-fn statement_metadata(sql: &str) -> Vec<StatementMetadata> {
-    split_statement_spans(sql)
+fn statement_metadata(
+    sql: &str,
+    spans: impl IntoIterator<Item = ((usize, usize), Option<TableClauses>)>,
+) -> Vec<StatementMetadata> {
+    spans
         .into_iter()
-        .filter_map(|(start, end)| {
+        .filter_map(|((start, end), table_clauses)| {
             let fragment = sql.get(start..end).unwrap_or(sql);
             let source_sql = fragment.trim();
             if source_sql.is_empty() {
@@ -88,12 +154,19 @@ fn statement_metadata(sql: &str) -> Vec<StatementMetadata> {
             }
             let line_offset = start.saturating_add(leading_whitespace_len(fragment));
             let line = offset_to_line(sql, line_offset);
-            Some(StatementMetadata {
+            Some((source_sql, line, table_clauses))
+        })
+        .enumerate()
+        .map(
+            |(statement_index, (source_sql, line, table_clauses))| StatementMetadata {
+                statement_index,
+                pragma: table_clauses.is_none(),
                 source_sql: source_sql.to_string(),
                 source_location: Some(SourceLocation { line, column: None }),
                 line,
-            })
-        })
+                table_clauses: table_clauses.unwrap_or_default(),
+            },
+        )
         .collect()
 }
 
@@ -108,6 +181,7 @@ fn split_statement_spans(sql: &str) -> Vec<(usize, usize)> {
     let mut in_bracket_ident = false;
     let mut in_line_comment = false;
     let mut in_block_comment = false;
+    let mut trigger = TriggerScan::default();
 
     while index < bytes.len() {
         let byte = bytes[index];
@@ -207,9 +281,18 @@ fn split_statement_spans(sql: &str) -> Vec<(usize, usize)> {
             b'[' => {
                 in_bracket_ident = true;
             }
-            b';' => {
+            b';' if !trigger.in_body() => {
                 spans.push((start, index + 1));
                 start = index + 1;
+                trigger = TriggerScan::default();
+            }
+            _ if is_word_byte(byte) => {
+                let word_start = index;
+                while index < bytes.len() && is_word_byte(bytes[index]) {
+                    index += 1;
+                }
+                trigger.observe(&sql[word_start..index]);
+                continue;
             }
             _ => {}
         }
@@ -228,14 +311,63 @@ fn split_statement_spans(sql: &str) -> Vec<(usize, usize)> {
     spans
 }
 
-fn fallback_metadata(statement: &sqlparser::ast::Statement) -> StatementMetadata {
+/// Tracks the words of a `CREATE TRIGGER` statement so the `;` separators inside its
+/// `BEGIN ... END` body do not end the statement. `CASE ... END` expressions in the body
+/// are counted so their `END` is not mistaken for the end of the body.
+#[derive(Default)]
+struct TriggerScan {
+    words_seen: usize,
+    created: bool,
+    is_trigger: bool,
+    begun: bool,
+    case_depth: usize,
+    ended: bool,
+}
+
+impl TriggerScan {
+    fn observe(&mut self, word: &str) {
+        let is = |keyword: &str| word.eq_ignore_ascii_case(keyword);
+        let position = self.words_seen;
+        self.words_seen += 1;
+
+        // CREATE [TEMP | TEMPORARY] TRIGGER
+        match position {
+            0 => self.created = is("CREATE"),
+            1 if self.created && (is("TEMP") || is("TEMPORARY")) => {}
+            1 | 2 if self.created => {
+                self.is_trigger = is("TRIGGER");
+                self.created = false;
+            }
+            _ if !self.is_trigger || self.ended => {}
+            _ if !self.begun => self.begun = is("BEGIN"),
+            _ if is("CASE") => self.case_depth += 1,
+            _ if is("END") => match self.case_depth.checked_sub(1) {
+                Some(depth) => self.case_depth = depth,
+                None => self.ended = true,
+            },
+            _ => {}
+        }
+    }
+
+    fn in_body(&self) -> bool {
+        self.is_trigger && !self.ended
+    }
+}
+
+fn fallback_metadata(
+    statement_index: usize,
+    statement: &sqlparser::ast::Statement,
+) -> StatementMetadata {
     StatementMetadata {
+        statement_index,
+        pragma: false,
         source_sql: statement.to_string(),
         source_location: Some(SourceLocation {
             line: 1,
             column: None,
         }),
         line: 1,
+        table_clauses: TableClauses::default(),
     }
 }
 
@@ -260,218 +392,701 @@ fn offset_to_line(sql: &str, offset: usize) -> usize {
 }
 
 fn convert_statement(
-    statement: &sqlparser::ast::Statement,
-    line: usize,
+    statement: &SqlStatement,
+    metadata: &StatementMetadata,
 ) -> ConversionResult<ConvertedStatement> {
-    let statement_sql = statement.to_string();
-    let table_name = parse_create_table_name(&statement_sql).ok_or_else(|| {
-        conversion_error(format!(
-            "unsupported sqlite statement kind: {}",
-            statement_kind(&statement_sql)
-        ))
-    })?;
-
-    let mut table = Table::named(table_name.name.value.as_str());
-    table.name = table_name.clone();
-    apply_preconversion_hints(&statement_sql, &mut table);
-
-    let attachment = AnnotationAttachment {
-        line,
-        target: AnnotationTarget::Table(table_name),
+    let attach = |target| AnnotationAttachment {
+        line: metadata.line,
+        target,
+    };
+    let (objects, attachments) = match statement {
+        SqlStatement::CreateTable(create_table) => {
+            let (table, unique_constraints) =
+                convert_create_table_statement(create_table, metadata)?;
+            let mut attachments = vec![attach(AnnotationTarget::Table(table.name.clone()))];
+            // A column sharing the `CREATE TABLE` line leaves that line to the table.
+            for (column_def, column) in create_table.columns.iter().zip(&table.columns) {
+                let line = usize::try_from(column_def.name.span.start.line).unwrap_or(0);
+                if line != metadata.line {
+                    attachments.push(AnnotationAttachment {
+                        line,
+                        target: AnnotationTarget::TableColumn {
+                            table: table.name.clone(),
+                            column: column.name.clone(),
+                        },
+                    });
+                }
+            }
+            let objects = std::iter::once(SchemaObject::Table(table))
+                .chain(unique_constraints.into_iter().map(SchemaObject::Index))
+                .collect();
+            (objects, attachments)
+        }
+        SqlStatement::CreateIndex(create_index) => {
+            let index = convert_create_index(create_index)?;
+            (vec![SchemaObject::Index(index)], Vec::new())
+        }
+        SqlStatement::CreateView(create_view) => {
+            let view = convert_create_view(create_view)?;
+            let attachments = vec![attach(AnnotationTarget::View(view.name.clone()))];
+            (vec![SchemaObject::View(view)], attachments)
+        }
+        SqlStatement::CreateVirtualTable {
+            name, module_name, ..
+        } => {
+            let table = convert_create_virtual_table(name, module_name, metadata)?;
+            let attachments = vec![attach(AnnotationTarget::Table(table.name.clone()))];
+            (vec![SchemaObject::Table(table)], attachments)
+        }
+        SqlStatement::CreateTrigger(create_trigger) => {
+            let trigger = convert_create_trigger(create_trigger)?;
+            (vec![SchemaObject::Trigger(trigger)], Vec::new())
+        }
+        _ => {
+            return Err(conversion_error(format!(
+                "unsupported sqlite statement kind: {}",
+                statement_kind(&statement.to_string())
+            )));
+        }
     };
 
     Ok(ConvertedStatement {
-        object: SchemaObject::Table(table),
-        attachment,
+        objects,
+        attachments,
     })
 }
 
-fn apply_preconversion_hints(statement_sql: &str, table: &mut Table) {
-    table.options.extra.insert(
-        extra_keys::TABLE_SOURCE_SQL.to_string(),
-        Value::String(statement_sql.trim().to_string()),
-    );
+fn convert_create_index(create_index: &CreateIndex) -> ConversionResult<IndexDef> {
+    let Some(name) = &create_index.name else {
+        return Err(conversion_error(
+            "unsupported sqlite CREATE INDEX without a name",
+        ));
+    };
 
-    let normalized = statement_sql.to_ascii_uppercase();
+    let columns = create_index
+        .columns
+        .iter()
+        .map(|column| {
+            let order = &column.column;
+            let expr = match &order.expr {
+                SqlExpr::Identifier(ident)
+                    if order.options.asc.is_none() && order.options.nulls_first.is_none() =>
+                {
+                    Expr::Ident(parse_sqlparser_ident(ident))
+                }
+                _ => Expr::Raw(order.to_string()),
+            };
+            IndexColumn { expr }
+        })
+        .collect();
 
-    if normalized.contains("WITHOUT ROWID") {
-        table.options.extra.insert(
-            extra_keys::TABLE_WITHOUT_ROWID.to_string(),
-            Value::Bool(true),
-        );
+    // The schema of an index goes on its name; the table is named without one.
+    let name = parse_object_name(name)?;
+    let mut table = parse_object_name(&create_index.table_name)?;
+    if table.schema.is_none() {
+        table.schema = name.schema;
     }
 
-    if normalized.contains(" STRICT") {
-        table
-            .options
-            .extra
-            .insert(extra_keys::TABLE_STRICT.to_string(), Value::Bool(true));
+    Ok(IndexDef {
+        name: Some(name.name),
+        owner: IndexOwner::Table(table),
+        columns,
+        unique: create_index.unique,
+        method: None,
+        where_clause: create_index
+            .predicate
+            .as_ref()
+            .map(|predicate| Expr::Raw(predicate.to_string())),
+        concurrent: false,
+        extra: BTreeMap::new(),
+    })
+}
+
+fn convert_create_view(create_view: &CreateView) -> ConversionResult<View> {
+    if create_view.materialized || create_view.temporary {
+        return Err(conversion_error(format!(
+            "unsupported sqlite CREATE VIEW shape for {}: only persistent views are supported",
+            create_view.name
+        )));
     }
+
+    let mut view = View::new(
+        parse_object_name(&create_view.name)?,
+        create_view.query.to_string(),
+    );
+    view.columns = create_view
+        .columns
+        .iter()
+        .map(|column| parse_sqlparser_ident(&column.name))
+        .collect();
+    Ok(view)
 }
 
-fn parse_create_table_name(statement_sql: &str) -> Option<QualifiedName> {
-    let tokens: Vec<&str> = statement_sql.split_whitespace().collect();
-    if !tokens
-        .first()
-        .is_some_and(|token| eq_keyword(token, "CREATE"))
-    {
-        return None;
+fn convert_create_trigger(create_trigger: &CreateTrigger) -> ConversionResult<Trigger> {
+    let unsupported = |detail: &str| {
+        conversion_error(format!(
+            "unsupported sqlite CREATE TRIGGER shape for {}: {detail}",
+            create_trigger.name
+        ))
+    };
+    if create_trigger.temporary {
+        return Err(unsupported("temporary triggers are not part of the schema"));
     }
+    let Some(statements) = &create_trigger.statements else {
+        return Err(unsupported("expected a BEGIN ... END body"));
+    };
 
-    let mut cursor = 1usize;
-    if tokens
-        .get(cursor)
-        .is_some_and(|token| eq_keyword(token, "TEMP") || eq_keyword(token, "TEMPORARY"))
-    {
-        cursor += 1;
+    // SQLite triggers default to BEFORE and only ever fire FOR EACH ROW.
+    let timing = match create_trigger.period {
+        None | Some(TriggerPeriod::Before) => TriggerTiming::Before,
+        Some(TriggerPeriod::After) => TriggerTiming::After,
+        Some(TriggerPeriod::InsteadOf) => TriggerTiming::InsteadOf,
+        Some(TriggerPeriod::For) => return Err(unsupported("FOR is not a sqlite trigger timing")),
+    };
+
+    let mut events = Vec::with_capacity(create_trigger.events.len());
+    let mut update_columns = Vec::new();
+    for event in &create_trigger.events {
+        events.push(match event {
+            SqlTriggerEvent::Insert => TriggerEvent::Insert,
+            SqlTriggerEvent::Update(columns) => {
+                update_columns = columns.iter().map(parse_sqlparser_ident).collect();
+                TriggerEvent::Update
+            }
+            SqlTriggerEvent::Delete => TriggerEvent::Delete,
+            SqlTriggerEvent::Truncate => return Err(unsupported("TRUNCATE events")),
+        });
     }
 
-    if !tokens
-        .get(cursor)
-        .is_some_and(|token| eq_keyword(token, "TABLE"))
-    {
-        return None;
-    }
-    cursor += 1;
-
-    if tokens
-        .get(cursor)
-        .is_some_and(|token| eq_keyword(token, "IF"))
-        && tokens
-            .get(cursor + 1)
-            .is_some_and(|token| eq_keyword(token, "NOT"))
-        && tokens
-            .get(cursor + 2)
-            .is_some_and(|token| eq_keyword(token, "EXISTS"))
-    {
-        cursor += 3;
+    let name = parse_object_name(&create_trigger.name)?;
+    let mut table = parse_object_name(&create_trigger.table_name)?;
+    if table.schema.is_none() {
+        table.schema.clone_from(&name.schema);
     }
 
-    parse_qualified_name_token(tokens.get(cursor)?)
+    Ok(Trigger {
+        name,
+        table,
+        timing,
+        events,
+        update_columns,
+        for_each: TriggerForEach::Row,
+        when_clause: create_trigger
+            .condition
+            .as_ref()
+            .map(|condition| Expr::Raw(condition.to_string())),
+        body: statements.to_string(),
+    })
 }
 
-fn parse_qualified_name_token(raw: &str) -> Option<QualifiedName> {
-    let token = trim_identifier_token(raw);
-    if token.is_empty() {
-        return None;
+/// The table, and the index behind each of its `UNIQUE` constraints.
+fn convert_create_table_statement(
+    create_table: &CreateTable,
+    metadata: &StatementMetadata,
+) -> ConversionResult<(Table, Vec<IndexDef>)> {
+    if create_table.query.is_some() || create_table.like.is_some() {
+        return Err(conversion_error(format!(
+            "unsupported sqlite CREATE TABLE shape for {}: only column definitions are supported",
+            create_table.name
+        )));
     }
 
-    let parts = split_qualified_name(token);
-    if parts.is_empty() || parts.len() > 2 {
-        return None;
+    let name = parse_object_name(&create_table.name)?;
+    let mut table = Table::named(name.name.value.as_str());
+    table.name = name;
+
+    let mut unique_constraints = Vec::new();
+    for column_def in &create_table.columns {
+        let column = convert_column(column_def, &mut table, &mut unique_constraints)?;
+        table.columns.push(column);
     }
 
-    if parts.len() == 1 {
-        let name = parse_ident_token(&parts[0])?;
-        return Some(QualifiedName { schema: None, name });
+    // The definition list holds the columns first, then the table constraints.
+    for (position, constraint) in create_table.constraints.iter().enumerate() {
+        let conflict = metadata
+            .table_clauses
+            .conflicts
+            .get(&(create_table.columns.len() + position))
+            .map(String::as_str);
+        convert_table_constraint(constraint, conflict, &mut table, &mut unique_constraints)?;
     }
 
-    let schema = parse_ident_token(&parts[0])?;
-    let name = parse_ident_token(&parts[1])?;
-    Some(QualifiedName {
-        schema: Some(schema),
-        name,
-    })
+    apply_table_hints(metadata, &mut table);
+    Ok((table, unique_constraints))
 }
 
-fn split_qualified_name(token: &str) -> Vec<String> {
-    let mut parts = Vec::new();
-    let mut current = String::new();
-    let mut in_double_quote = false;
-    let mut in_backtick_quote = false;
-    let mut in_bracket_ident = false;
+/// A virtual table keeps its module and arguments as table options; its columns belong
+/// to the module.
+fn convert_create_virtual_table(
+    name: &ObjectName,
+    module_name: &SqlIdent,
+    metadata: &StatementMetadata,
+) -> ConversionResult<Table> {
+    let name = parse_object_name(name)?;
+    let mut table = Table::named(name.name.value.as_str());
+    table.name = name;
+    table.options.extra.insert(
+        sqlite_keys::TABLE_VIRTUAL_MODULE.to_string(),
+        Value::String(module_name.value.clone()),
+    );
+    table.options.extra.insert(
+        sqlite_keys::TABLE_VIRTUAL_ARGUMENTS.to_string(),
+        Value::String(
+            metadata
+                .table_clauses
+                .virtual_arguments
+                .clone()
+                .unwrap_or_default(),
+        ),
+    );
+    apply_table_hints(metadata, &mut table);
+    Ok(table)
+}
 
-    for ch in token.chars() {
-        if in_double_quote {
-            current.push(ch);
-            if ch == '"' {
-                in_double_quote = false;
-            }
-            continue;
-        }
+fn convert_column(
+    column_def: &ColumnDef,
+    table: &mut Table,
+    unique_constraints: &mut Vec<IndexDef>,
+) -> ConversionResult<Column> {
+    let declared_type = match &column_def.data_type {
+        SqlDataType::Unspecified => String::new(),
+        data_type => data_type.to_string(),
+    };
 
-        if in_backtick_quote {
-            current.push(ch);
-            if ch == '`' {
-                in_backtick_quote = false;
-            }
-            continue;
-        }
+    let mut column = Column {
+        name: parse_sqlparser_ident(&column_def.name),
+        data_type: DataType::Custom(declared_type.clone()),
+        not_null: false,
+        default: None,
+        identity: None,
+        generated: None,
+        comment: None,
+        collation: None,
+        renamed_from: None,
+        extra: BTreeMap::new(),
+    };
+    if !declared_type.is_empty() {
+        column.extra.insert(
+            extra_keys::COLUMN_DECLARED_TYPE.to_string(),
+            Value::String(declared_type),
+        );
+    }
 
-        if in_bracket_ident {
-            current.push(ch);
-            if ch == ']' {
-                in_bracket_ident = false;
+    let mut previous: Option<&ColumnOption> = None;
+    for option_def in &column_def.options {
+        match &option_def.option {
+            ColumnOption::NotNull => column.not_null = true,
+            ColumnOption::Null => column.not_null = false,
+            ColumnOption::Default(expr) => column.default = Some(Expr::Raw(expr.to_string())),
+            ColumnOption::Collation(collation) => {
+                column.collation = collation
+                    .0
+                    .last()
+                    .and_then(|part| part.as_ident())
+                    .map(|ident| ident.value.clone());
             }
-            continue;
-        }
-
-        match ch {
-            '.' => {
-                parts.push(current.trim().to_string());
-                current.clear();
+            ColumnOption::Generated {
+                generation_expr: Some(expr),
+                generation_expr_mode,
+                ..
+            } => {
+                column.generated = Some(GeneratedColumn {
+                    expr: Expr::Raw(expr.to_string()),
+                    stored: matches!(generation_expr_mode, Some(GeneratedExpressionMode::Stored)),
+                });
             }
-            '"' => {
-                in_double_quote = true;
-                current.push(ch);
+            ColumnOption::PrimaryKey(_) => {
+                set_primary_key(
+                    table,
+                    PrimaryKey {
+                        name: option_def.name.as_ref().map(parse_sqlparser_ident),
+                        columns: vec![column.name.clone()],
+                    },
+                )?;
             }
-            '`' => {
-                in_backtick_quote = true;
-                current.push(ch);
+            ColumnOption::DialectSpecific(tokens) => {
+                for token in tokens {
+                    let keyword = token.to_string();
+                    if keyword.eq_ignore_ascii_case("AUTOINCREMENT") {
+                        column.identity = Some(autoincrement_identity());
+                    } else if !keyword.eq_ignore_ascii_case("ASC") {
+                        return Err(conversion_error(format!(
+                            "unsupported sqlite column option `{keyword}` on column {}",
+                            column_def.name
+                        )));
+                    }
+                }
             }
-            '[' => {
-                in_bracket_ident = true;
-                current.push(ch);
+            ColumnOption::Unique(_) => {
+                push_unique_constraint(
+                    unique_constraints,
+                    &table.name,
+                    option_def.name.as_ref(),
+                    vec![column.name.clone()],
+                    None,
+                );
             }
-            _ => {
-                current.push(ch);
+            ColumnOption::ForeignKey(foreign_key) => {
+                table.foreign_keys.push(convert_foreign_key(
+                    option_def.name.as_ref().or(foreign_key.name.as_ref()),
+                    vec![column.name.clone()],
+                    foreign_key,
+                )?);
+            }
+            ColumnOption::Check(check) => {
+                table.checks.push(CheckConstraint {
+                    name: option_def
+                        .name
+                        .as_ref()
+                        .or(check.name.as_ref())
+                        .map(parse_sqlparser_ident),
+                    expr: Expr::Raw(check.expr.to_string()),
+                    no_inherit: false,
+                });
+            }
+            ColumnOption::OnConflict(keyword) => {
+                let conflict = conflict_resolution(*keyword)
+                    .ok_or_else(|| {
+                        conversion_error(format!(
+                            "unsupported ON CONFLICT resolution {keyword} on column {}",
+                            column_def.name
+                        ))
+                    })?
+                    .to_string();
+                match previous {
+                    Some(ColumnOption::NotNull) => {
+                        column.extra.insert(
+                            extra_keys::COLUMN_NOT_NULL_CONFLICT.to_string(),
+                            Value::String(conflict),
+                        );
+                    }
+                    Some(ColumnOption::PrimaryKey(_) | ColumnOption::DialectSpecific(_)) => {
+                        table.options.extra.insert(
+                            sqlite_keys::TABLE_PRIMARY_KEY_CONFLICT.to_string(),
+                            Value::String(conflict),
+                        );
+                    }
+                    Some(ColumnOption::Unique(_)) => {
+                        if let Some(index) = unique_constraints.last_mut() {
+                            index.extra.insert(
+                                extra_keys::INDEX_CONFLICT.to_string(),
+                                Value::String(conflict),
+                            );
+                        }
+                    }
+                    _ => {
+                        return Err(conversion_error(format!(
+                            "ON CONFLICT must follow NOT NULL, PRIMARY KEY or UNIQUE on column {}",
+                            column_def.name
+                        )));
+                    }
+                }
+            }
+            other => {
+                return Err(conversion_error(format!(
+                    "unsupported sqlite column option `{other}` on column {}",
+                    column_def.name
+                )));
             }
         }
+        previous = Some(&option_def.option);
     }
 
-    if !current.is_empty() {
-        parts.push(current.trim().to_string());
+    Ok(column)
+}
+
+fn convert_table_constraint(
+    constraint: &TableConstraint,
+    conflict: Option<&str>,
+    table: &mut Table,
+    unique_constraints: &mut Vec<IndexDef>,
+) -> ConversionResult<()> {
+    match constraint {
+        TableConstraint::PrimaryKey(primary_key) => {
+            set_primary_key(
+                table,
+                PrimaryKey {
+                    name: primary_key.name.as_ref().map(parse_sqlparser_ident),
+                    columns: index_column_names(&primary_key.columns)?,
+                },
+            )?;
+            if let Some(conflict) = conflict {
+                table.options.extra.insert(
+                    sqlite_keys::TABLE_PRIMARY_KEY_CONFLICT.to_string(),
+                    Value::String(conflict.to_string()),
+                );
+            }
+        }
+        TableConstraint::Unique(unique) => {
+            push_unique_constraint(
+                unique_constraints,
+                &table.name,
+                unique.name.as_ref(),
+                index_column_names(&unique.columns)?,
+                conflict,
+            );
+        }
+        TableConstraint::ForeignKey(foreign_key) => {
+            table.foreign_keys.push(convert_foreign_key(
+                foreign_key.name.as_ref(),
+                foreign_key
+                    .columns
+                    .iter()
+                    .map(parse_sqlparser_ident)
+                    .collect(),
+                foreign_key,
+            )?);
+        }
+        TableConstraint::Check(check) => {
+            table.checks.push(CheckConstraint {
+                name: check.name.as_ref().map(parse_sqlparser_ident),
+                expr: Expr::Raw(check.expr.to_string()),
+                no_inherit: false,
+            });
+        }
+        other => {
+            return Err(conversion_error(format!(
+                "unsupported sqlite table constraint: {other}"
+            )));
+        }
     }
 
-    parts
+    Ok(())
 }
 
-fn parse_ident_token(raw: &str) -> Option<Ident> {
-    let token = trim_identifier_token(raw);
-    if token.is_empty() {
-        return None;
+fn apply_table_hints(metadata: &StatementMetadata, table: &mut Table) {
+    table.options.extra.insert(
+        extra_keys::TABLE_SOURCE_SQL.to_string(),
+        Value::String(strip_leading_comments(&metadata.source_sql).to_string()),
+    );
+
+    if metadata.table_clauses.without_rowid {
+        table.options.extra.insert(
+            sqlite_keys::TABLE_WITHOUT_ROWID.to_string(),
+            Value::Bool(true),
+        );
     }
 
-    if let Some(inner) = token
-        .strip_prefix('`')
-        .and_then(|rest| rest.strip_suffix('`'))
-    {
-        return Some(Ident::quoted(inner.replace("``", "`")));
+    if metadata.table_clauses.strict {
+        table
+            .options
+            .extra
+            .insert(sqlite_keys::TABLE_STRICT.to_string(), Value::Bool(true));
     }
+}
 
-    if let Some(inner) = token
-        .strip_prefix('"')
-        .and_then(|rest| rest.strip_suffix('"'))
-    {
-        return Some(Ident::quoted(inner.replace("\"\"", "\"")));
+fn set_primary_key(table: &mut Table, primary_key: PrimaryKey) -> ConversionResult<()> {
+    if table.primary_key.is_some() {
+        return Err(conversion_error(format!(
+            "table {} has more than one primary key",
+            table.name.name.value
+        )));
+    }
+    table.primary_key = Some(primary_key);
+    Ok(())
+}
+
+fn autoincrement_identity() -> Identity {
+    Identity {
+        always: false,
+        start: None,
+        increment: None,
+        min_value: None,
+        max_value: None,
+        cache: None,
+        cycle: false,
+    }
+}
+
+fn convert_foreign_key(
+    name: Option<&SqlIdent>,
+    columns: Vec<Ident>,
+    foreign_key: &ForeignKeyConstraint,
+) -> ConversionResult<ForeignKey> {
+    let deferrable = foreign_key
+        .characteristics
+        .as_ref()
+        .and_then(|characteristics| match characteristics.deferrable {
+            Some(true) => Some(Deferrable::Deferrable {
+                initially_deferred: matches!(
+                    characteristics.initially,
+                    Some(DeferrableInitial::Deferred)
+                ),
+            }),
+            Some(false) => Some(Deferrable::NotDeferrable),
+            None => None,
+        });
+
+    Ok(ForeignKey {
+        name: name.map(parse_sqlparser_ident),
+        columns,
+        referenced_table: parse_object_name(&foreign_key.foreign_table)?,
+        referenced_columns: foreign_key
+            .referred_columns
+            .iter()
+            .map(parse_sqlparser_ident)
+            .collect(),
+        on_delete: foreign_key.on_delete.map(convert_referential_action),
+        on_update: foreign_key.on_update.map(convert_referential_action),
+        deferrable,
+        extra: BTreeMap::new(),
+    })
+}
+
+fn convert_referential_action(action: ReferentialAction) -> ForeignKeyAction {
+    match action {
+        ReferentialAction::Restrict => ForeignKeyAction::Restrict,
+        ReferentialAction::Cascade => ForeignKeyAction::Cascade,
+        ReferentialAction::SetNull => ForeignKeyAction::SetNull,
+        ReferentialAction::NoAction => ForeignKeyAction::NoAction,
+        ReferentialAction::SetDefault => ForeignKeyAction::SetDefault,
     }
+}
 
-    if let Some(inner) = token
-        .strip_prefix('[')
-        .and_then(|rest| rest.strip_suffix(']'))
+fn index_column_names(columns: &[SqlIndexColumn]) -> ConversionResult<Vec<Ident>> {
+    columns
+        .iter()
+        .map(|column| match &column.column.expr {
+            SqlExpr::Identifier(ident) => Ok(parse_sqlparser_ident(ident)),
+            other => Err(conversion_error(format!(
+                "unsupported sqlite constraint column: {other}"
+            ))),
+        })
+        .collect()
+}
+
+/// Adds the index SQLite creates for a `UNIQUE` constraint. An unnamed constraint is
+/// keyed by its columns under a `sqlite_` name, which no user index can take; SQLite
+/// itself ignores a second unnamed constraint over the same columns.
+fn push_unique_constraint(
+    unique_constraints: &mut Vec<IndexDef>,
+    table: &QualifiedName,
+    name: Option<&SqlIdent>,
+    columns: Vec<Ident>,
+    conflict: Option<&str>,
+) {
+    let mut extra = BTreeMap::from([(
+        sqlite_keys::INDEX_UNIQUE_CONSTRAINT.to_string(),
+        Value::Bool(true),
+    )]);
+    let name = match name {
+        Some(name) => parse_sqlparser_ident(name),
+        None => {
+            extra.insert(
+                extra_keys::INDEX_UNNAMED_CONSTRAINT.to_string(),
+                Value::Bool(true),
+            );
+            let column_names = columns
+                .iter()
+                .map(|column| column.value.as_str())
+                .collect::<Vec<_>>();
+            Ident::unquoted(format!(
+                "sqlite_unique_{}_{}",
+                table.name.value,
+                column_names.join("_")
+            ))
+        }
+    };
+    if let Some(conflict) = conflict {
+        extra.insert(
+            extra_keys::INDEX_CONFLICT.to_string(),
+            Value::String(conflict.to_string()),
+        );
+    }
+    if unique_constraints
+        .iter()
+        .any(|existing| existing.name.as_ref() == Some(&name))
     {
-        return Some(Ident::quoted(inner.replace("]]", "]")));
+        return;
     }
 
-    Some(Ident::unquoted(token))
+    unique_constraints.push(IndexDef {
+        name: Some(name),
+        owner: IndexOwner::Table(table.clone()),
+        columns: columns
+            .into_iter()
+            .map(|column| IndexColumn {
+                expr: Expr::Ident(column),
+            })
+            .collect(),
+        unique: true,
+        method: None,
+        where_clause: None,
+        concurrent: false,
+        extra,
+    });
+}
+
+/// The resolutions SQLite accepts after `ON CONFLICT`.
+fn conflict_resolution(keyword: Keyword) -> Option<&'static str> {
+    match keyword {
+        Keyword::ROLLBACK => Some("ROLLBACK"),
+        Keyword::ABORT => Some("ABORT"),
+        Keyword::FAIL => Some("FAIL"),
+        Keyword::IGNORE => Some("IGNORE"),
+        Keyword::REPLACE => Some("REPLACE"),
+        _ => None,
+    }
+}
+
+fn parse_object_name(name: &ObjectName) -> ConversionResult<QualifiedName> {
+    if name.0.is_empty() || name.0.len() > 2 {
+        return Err(conversion_error(format!(
+            "unsupported qualified name in sqlite statement: {name}"
+        )));
+    }
+
+    let identifiers = name
+        .0
+        .iter()
+        .map(|part| {
+            part.as_ident().ok_or_else(|| {
+                conversion_error(format!(
+                    "unsupported object name part in sqlite statement: {part}"
+                ))
+            })
+        })
+        .collect::<ConversionResult<Vec<_>>>()?;
+
+    if identifiers.len() == 1 {
+        return Ok(QualifiedName {
+            schema: None,
+            name: parse_sqlparser_ident(identifiers[0]),
+        });
+    }
+
+    Ok(QualifiedName {
+        schema: Some(parse_sqlparser_ident(identifiers[0])),
+        name: parse_sqlparser_ident(identifiers[1]),
+    })
 }
 
-fn trim_identifier_token(raw: &str) -> &str {
-    raw.trim()
-        .trim_start_matches('(')
-        .trim_end_matches(';')
-        .trim_end_matches(',')
-        .trim_end_matches(')')
+fn parse_sqlparser_ident(ident: &SqlIdent) -> Ident {
+    if ident.quote_style.is_some() {
+        Ident::quoted(ident.value.clone())
+    } else {
+        Ident::unquoted(ident.value.clone())
+    }
+}
+
+/// Comments between the previous statement and this one end up in its fragment.
+fn strip_leading_comments(source_sql: &str) -> &str {
+    let mut rest = source_sql.trim_start();
+    loop {
+        if let Some(line_comment) = rest.strip_prefix("--") {
+            rest = line_comment
+                .split_once('\n')
+                .map_or("", |(_, after)| after)
+                .trim_start();
+        } else if let Some(block_comment) = rest.strip_prefix("/*") {
+            rest = block_comment
+                .split_once("*/")
+                .map_or("", |(_, after)| after)
+                .trim_start();
+        } else {
+            return rest;
+        }
+    }
 }
 
 fn statement_kind(statement_sql: &str) -> String {
@@ -484,10 +1099,6 @@ fn statement_kind(statement_sql: &str) -> String {
     }
 }
 
-fn eq_keyword(token: &str, keyword: &str) -> bool {
-    trim_identifier_token(token).eq_ignore_ascii_case(keyword)
-}
-
 fn conversion_error(message: impl Into<String>) -> io::Error {
     io::Error::other(message.into())
 }
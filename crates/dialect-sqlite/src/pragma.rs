@@ -0,0 +1,117 @@
+//! `PRAGMA name = value;` declarations for the settings SQLite keeps in the database file.
+//! sqlparser only accepts numeric and quoted pragma values, so these statements are read
+//! here and kept away from it.
+
+use std::io;
+
+use stateql_core::{Ident, Pragma, QualifiedName, Value};
+
+const PRAGMA_KEYWORD: &str = "PRAGMA";
+const DEFAULT_SCHEMA: &str = "main";
+const APPLICATION_ID: &str = "application_id";
+const JOURNAL_MODE: &str = "journal_mode";
+const USER_VERSION: &str = "user_version";
+
+/// The pragmas a schema file may declare, in export order. Everything else is connection
+/// state that does not survive closing the database.
+pub(crate) const MANAGED_PRAGMAS: [&str; 3] = [APPLICATION_ID, JOURNAL_MODE, USER_VERSION];
+
+const JOURNAL_MODES: [&str; 6] = ["delete", "truncate", "persist", "memory", "wal", "off"];
+
+pub(crate) fn is_pragma_statement(statement_sql: &str) -> bool {
+    statement_sql
+        .get(..PRAGMA_KEYWORD.len())
+        .is_some_and(|keyword| keyword.eq_ignore_ascii_case(PRAGMA_KEYWORD))
+        && statement_sql[PRAGMA_KEYWORD.len()..].starts_with(|ch: char| ch.is_ascii_whitespace())
+}
+
+/// Parses `PRAGMA [schema.]name = value` or `PRAGMA [schema.]name(value)`.
+pub(crate) fn parse_pragma(statement_sql: &str) -> io::Result<Pragma> {
+    let body = statement_sql[PRAGMA_KEYWORD.len()..]
+        .trim()
+        .trim_end_matches(';')
+        .trim_end();
+    let (target, value) = match body.split_once('=') {
+        Some((target, value)) => (target.trim(), value.trim()),
+        None => match body.split_once('(') {
+            Some((target, value)) => (
+                target.trim(),
+                value
+                    .trim_end()
+                    .strip_suffix(')')
+                    .ok_or_else(|| invalid_pragma(statement_sql, "unbalanced parentheses"))?
+                    .trim(),
+            ),
+            None => return Err(invalid_pragma(statement_sql, "a value is required")),
+        },
+    };
+
+    let (schema, name) = match target.split_once('.') {
+        Some((schema, name)) => (Some(unquote(schema.trim())), unquote(name.trim())),
+        None => (None, unquote(target)),
+    };
+    let name = name.to_ascii_lowercase();
+    if !MANAGED_PRAGMAS.contains(&name.as_str()) {
+        return Err(invalid_pragma(
+            statement_sql,
+            &format!("only {} can be declared", MANAGED_PRAGMAS.join(", ")),
+        ));
+    }
+
+    let value = unquote(value);
+    let value = if name == JOURNAL_MODE {
+        let mode = value.to_ascii_lowercase();
+        if !JOURNAL_MODES.contains(&mode.as_str()) {
+            return Err(invalid_pragma(
+                statement_sql,
+                &format!("journal_mode must be one of {}", JOURNAL_MODES.join(", ")),
+            ));
+        }
+        Value::String(mode)
+    } else {
+        Value::Integer(
+            parse_integer(&value)
+                .ok_or_else(|| invalid_pragma(statement_sql, "expected a 32-bit integer"))?,
+        )
+    };
+
+    Ok(Pragma {
+        name: QualifiedName {
+            schema: schema
+                .filter(|schema| !schema.eq_ignore_ascii_case(DEFAULT_SCHEMA))
+                .map(Ident::unquoted),
+            name: Ident::unquoted(name),
+        },
+        value,
+    })
+}
+
+/// `application_id` and `user_version` are 32-bit header fields; hex literals cover the
+/// whole unsigned range, the way `application_id` values are usually written.
+fn parse_integer(raw: &str) -> Option<i64> {
+    if let Some(hex) = raw.strip_prefix("0x").or_else(|| raw.strip_prefix("0X")) {
+        let bits = u32::from_str_radix(hex, 16).ok()?;
+        return Some(i64::from(i32::from_ne_bytes(bits.to_ne_bytes())));
+    }
+    raw.strip_prefix('+')
+        .unwrap_or(raw)
+        .parse::<i32>()
+        .ok()
+        .map(i64::from)
+}
+
+fn unquote(raw: &str) -> String {
+    for (open, close) in [('"', '"'), ('\'', '\''), ('`', '`'), ('[', ']')] {
+        if let Some(inner) = raw
+            .strip_prefix(open)
+            .and_then(|rest| rest.strip_suffix(close))
+        {
+            return inner.to_string();
+        }
+    }
+    raw.to_string()
+}
+
+fn invalid_pragma(statement_sql: &str, reason: &str) -> io::Error {
+    io::Error::other(format!("unsupported pragma `{statement_sql}`: {reason}"))
+}
@@ -0,0 +1,232 @@
+//! Opt-in protection for an apply, chosen with `sqlite.safe_apply`:
+//!
+//! - `backup` snapshots every schema with `VACUUM INTO` before the plan runs and restores
+//!   the snapshots if a statement fails;
+//! - `rehearse` first runs the whole plan against snapshot copies, then applies it to the
+//!   real files with the backup.
+
+use std::{
+    error::Error as StdError,
+    fmt, fs, io,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use rusqlite::{Connection, backup::Progress};
+use stateql_core::{ConnectionConfig, Error, ExecutionError, Result};
+
+pub(crate) const SAFE_APPLY_KEY: &str = "sqlite.safe_apply";
+const VACUUM_INTO_SQL: &str = "VACUUM INTO";
+const DATABASE_FILE_QUERY: &str = "SELECT file FROM pragma_database_list WHERE name = ?1";
+const SNAPSHOT_SIDE_FILE_SUFFIXES: [&str; 3] = ["-wal", "-shm", "-journal"];
+
+type BoxedError = Box<dyn StdError + Send + Sync + 'static>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SafeApply {
+    Backup,
+    Rehearse,
+}
+
+impl SafeApply {
+    pub(crate) fn from_config(config: &ConnectionConfig) -> Result<Option<Self>> {
+        match config.extra.get(SAFE_APPLY_KEY).map(String::as_str) {
+            None => Ok(None),
+            Some("backup") => Ok(Some(Self::Backup)),
+            Some("rehearse") => Ok(Some(Self::Rehearse)),
+            Some(other) => Err(snapshot_error(
+                SAFE_APPLY_KEY,
+                io::Error::other(format!(
+                    "`{SAFE_APPLY_KEY}` must be `backup` or `rehearse`, got `{other}`"
+                )),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum SnapshotKind {
+    /// Kept next to the database file, so it survives the process if the restore
+    /// cannot run.
+    Backup,
+    /// A throwaway copy in the temporary directory.
+    Rehearsal,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Snapshot {
+    pub(crate) schema: String,
+    pub(crate) path: PathBuf,
+}
+
+pub(crate) fn take_snapshots(
+    connection: &Connection,
+    schemas: &[String],
+    kind: SnapshotKind,
+) -> Result<Vec<Snapshot>> {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis());
+    let mut snapshots = Vec::with_capacity(schemas.len());
+    for schema in schemas {
+        let file: String = connection
+            .query_row(DATABASE_FILE_QUERY, [schema], |row| row.get(0))
+            .map_err(|source| snapshot_error(DATABASE_FILE_QUERY, source))?;
+        let unique = format!("{stamp}-{}", std::process::id());
+        let path = match kind {
+            SnapshotKind::Backup if !file.is_empty() => {
+                PathBuf::from(format!("{file}.stateql-backup-{unique}"))
+            }
+            SnapshotKind::Backup => {
+                std::env::temp_dir().join(format!("stateql-backup-{schema}-{unique}.db"))
+            }
+            SnapshotKind::Rehearsal => {
+                std::env::temp_dir().join(format!("stateql-rehearsal-{schema}-{unique}.db"))
+            }
+        };
+        let sql = format!("VACUUM \"{}\" INTO ?1", schema.replace('"', "\"\""));
+        let target = path.to_str().ok_or_else(|| {
+            snapshot_error(
+                VACUUM_INTO_SQL,
+                io::Error::other(format!("snapshot path {} is not UTF-8", path.display())),
+            )
+        })?;
+        if let Err(source) = connection.execute(&sql, [target]) {
+            remove_snapshots(&snapshots);
+            return Err(snapshot_error(&sql, source));
+        }
+        snapshots.push(Snapshot {
+            schema: schema.clone(),
+            path,
+        });
+    }
+    Ok(snapshots)
+}
+
+/// Copies every snapshot back over its schema with the online backup API, which keeps
+/// the connection, and a WAL database, consistent.
+pub(crate) fn restore_snapshots(
+    connection: &mut Connection,
+    snapshots: &[Snapshot],
+) -> rusqlite::Result<()> {
+    for snapshot in snapshots {
+        connection.restore(
+            snapshot.schema.as_str(),
+            &snapshot.path,
+            None::<fn(Progress)>,
+        )?;
+    }
+    Ok(())
+}
+
+/// Removes each snapshot with the `-wal`, `-shm` and `-journal` files a plan may have
+/// left beside it, e.g. by switching its `journal_mode`.
+pub(crate) fn remove_snapshots(snapshots: &[Snapshot]) {
+    for snapshot in snapshots {
+        let _ = fs::remove_file(&snapshot.path);
+        for suffix in SNAPSHOT_SIDE_FILE_SUFFIXES {
+            let mut side_file = snapshot.path.clone().into_os_string();
+            side_file.push(suffix);
+            let _ = fs::remove_file(side_file);
+        }
+    }
+}
+
+/// What happened to the database after a protected plan failed; it becomes the source
+/// of the failed statement's error so the report keeps the statement and its cause.
+#[derive(Debug)]
+pub(crate) enum SafeApplyError {
+    Restored {
+        snapshots: Vec<PathBuf>,
+        source: BoxedError,
+    },
+    NotRestored {
+        snapshots: Vec<PathBuf>,
+        restore_error: rusqlite::Error,
+        source: BoxedError,
+    },
+    Rehearsal {
+        source: BoxedError,
+    },
+}
+
+impl fmt::Display for SafeApplyError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Restored { snapshots, .. } => write!(
+                formatter,
+                "the database was restored from {}",
+                display_paths(snapshots)
+            ),
+            Self::NotRestored {
+                snapshots,
+                restore_error,
+                ..
+            } => write!(
+                formatter,
+                "restoring {} failed ({restore_error}); copy the snapshots back by hand",
+                display_paths(snapshots)
+            ),
+            Self::Rehearsal { .. } => formatter.write_str(
+                "the plan failed against a temporary copy; the database was not changed",
+            ),
+        }
+    }
+}
+
+impl StdError for SafeApplyError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Restored { source, .. }
+            | Self::NotRestored { source, .. }
+            | Self::Rehearsal { source } => Some(source.as_ref()),
+        }
+    }
+}
+
+/// Puts `outcome(cause)` between a failed statement and its cause.
+pub(crate) fn explain_failure(
+    error: Error,
+    outcome: impl FnOnce(BoxedError) -> SafeApplyError,
+) -> Error {
+    match error {
+        Error::Execute(ExecutionError::StatementFailed {
+            statement_index,
+            sql,
+            executed_statements,
+            source_location,
+            statement_context,
+            source,
+        }) => Error::Execute(ExecutionError::StatementFailed {
+            statement_index,
+            sql,
+            executed_statements,
+            source_location,
+            statement_context,
+            source: Box::new(outcome(source)),
+        }),
+        other => other,
+    }
+}
+
+fn display_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub(crate) fn snapshot_paths(snapshots: &[Snapshot]) -> Vec<PathBuf> {
+    snapshots
+        .iter()
+        .map(|snapshot| snapshot.path.clone())
+        .collect()
+}
+
+fn snapshot_error<E>(sql: &str, source: E) -> Error
+where
+    E: StdError + Send + Sync + 'static,
+{
+    ExecutionError::statement_failed(0, sql, 0, None, None, source).into()
+}
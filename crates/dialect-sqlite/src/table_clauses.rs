@@ -0,0 +1,330 @@
+//! SQLite accepts a few `CREATE TABLE` clauses sqlparser rejects: `ON CONFLICT` on
+//! table-level `PRIMARY KEY`/`UNIQUE` constraints, `WITHOUT ROWID, STRICT` together and
+//! free-form virtual table module arguments. They are lifted out of the statement text (blanked with spaces so offsets and line
+//! numbers stay put) before the statement reaches sqlparser.
+
+use std::collections::BTreeMap;
+
+/// The resolutions `ON CONFLICT` accepts; any other word is left for sqlparser to reject.
+pub(crate) const CONFLICT_RESOLUTIONS: [&str; 5] =
+    ["ROLLBACK", "ABORT", "FAIL", "IGNORE", "REPLACE"];
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TableClauses {
+    /// Conflict resolution keyword per element of the definition list, keyed by the
+    /// element's position (columns first, then table constraints).
+    pub(crate) conflicts: BTreeMap<usize, String>,
+    pub(crate) without_rowid: bool,
+    pub(crate) strict: bool,
+    /// Module arguments of a `CREATE VIRTUAL TABLE`, each as written, joined by `, `.
+    pub(crate) virtual_arguments: Option<String>,
+}
+
+pub(crate) struct LiftedStatement {
+    pub(crate) sql: String,
+    pub(crate) clauses: TableClauses,
+}
+
+pub(crate) fn lift_table_clauses(fragment: &str) -> LiftedStatement {
+    let mut lifted = LiftedStatement {
+        sql: fragment.to_string(),
+        clauses: TableClauses::default(),
+    };
+
+    let tokens = scan_tokens(fragment);
+    if let Some((open, close)) = virtual_arguments_span(fragment, &tokens) {
+        // Commas nested in parentheses stay inside their argument.
+        let mut arguments = Vec::new();
+        let mut argument_start = open + 1;
+        let mut depth = 0usize;
+        for cursor in open + 1..=close {
+            match tokens[cursor].kind {
+                TokenKind::Punct(b'(') => depth += 1,
+                TokenKind::Punct(b')') if depth > 0 => depth -= 1,
+                TokenKind::Punct(b',' | b')') if depth == 0 => {
+                    if let (Some(first), Some(last)) =
+                        (tokens.get(argument_start), tokens.get(cursor - 1))
+                        && argument_start < cursor
+                    {
+                        arguments.push(&fragment[first.start..last.end]);
+                    }
+                    argument_start = cursor + 1;
+                }
+                _ => {}
+            }
+        }
+        lifted.clauses.virtual_arguments = Some(arguments.join(", "));
+        let (start, end) = (tokens[open].start, tokens[close].end);
+        lifted
+            .sql
+            .replace_range(start..end, &" ".repeat(end - start));
+        return lifted;
+    }
+    let Some(open) = definition_list_start(fragment, &tokens) else {
+        return lifted;
+    };
+
+    let mut blanked = Vec::new();
+    let mut depth = 0usize;
+    let mut element = 0usize;
+    let mut close = None;
+    let mut cursor = open;
+    while cursor < tokens.len() {
+        let token = &tokens[cursor];
+        match token.kind {
+            TokenKind::Punct(b'(') => depth += 1,
+            TokenKind::Punct(b')') => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(cursor);
+                    break;
+                }
+            }
+            TokenKind::Punct(b',') if depth == 1 => element += 1,
+            TokenKind::Word if depth == 1 && token.is_word(fragment, "ON") => {
+                let follows_paren = cursor
+                    .checked_sub(1)
+                    .is_some_and(|previous| tokens[previous].kind == TokenKind::Punct(b')'));
+                let conflict = tokens.get(cursor + 1);
+                let action = tokens.get(cursor + 2);
+                if follows_paren
+                    && let (Some(conflict), Some(action)) = (conflict, action)
+                    && conflict.is_word(fragment, "CONFLICT")
+                    && CONFLICT_RESOLUTIONS
+                        .iter()
+                        .any(|resolution| action.is_word(fragment, resolution))
+                {
+                    lifted
+                        .clauses
+                        .conflicts
+                        .insert(element, action.text(fragment).to_ascii_uppercase());
+                    blanked.push((token.start, action.end));
+                    cursor += 3;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        cursor += 1;
+    }
+
+    if let Some(close) = close {
+        let trailing = &tokens[close + 1..];
+        let only_table_options = trailing.iter().all(|token| {
+            matches!(token.kind, TokenKind::Punct(b',') | TokenKind::Punct(b';'))
+                || token.is_word(fragment, "WITHOUT")
+                || token.is_word(fragment, "ROWID")
+                || token.is_word(fragment, "STRICT")
+        });
+        if only_table_options {
+            for token in trailing {
+                if token.kind == TokenKind::Punct(b';') {
+                    continue;
+                }
+                lifted.clauses.without_rowid |= token.is_word(fragment, "ROWID");
+                lifted.clauses.strict |= token.is_word(fragment, "STRICT");
+                blanked.push((token.start, token.end));
+            }
+        }
+    }
+
+    for (start, end) in blanked {
+        lifted
+            .sql
+            .replace_range(start..end, &" ".repeat(end - start));
+    }
+    lifted
+}
+
+/// Indexes of the parentheses around the module arguments of a `CREATE VIRTUAL TABLE`.
+fn virtual_arguments_span(fragment: &str, tokens: &[Token]) -> Option<(usize, usize)> {
+    let [create, kind, table, ..] = tokens else {
+        return None;
+    };
+    if !(create.is_word(fragment, "CREATE")
+        && kind.is_word(fragment, "VIRTUAL")
+        && table.is_word(fragment, "TABLE"))
+    {
+        return None;
+    }
+
+    let using = tokens
+        .iter()
+        .position(|token| token.is_word(fragment, "USING"))?;
+    let open = using + 2;
+    if tokens.get(open)?.kind != TokenKind::Punct(b'(') {
+        return None;
+    }
+    let mut depth = 0usize;
+    for (cursor, token) in tokens.iter().enumerate().skip(open) {
+        match token.kind {
+            TokenKind::Punct(b'(') => depth += 1,
+            TokenKind::Punct(b')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((open, cursor));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Index of the `(` opening the definition list of a `CREATE TABLE`, or `None` for any
+/// other statement, including `CREATE TABLE ... AS SELECT`.
+fn definition_list_start(fragment: &str, tokens: &[Token]) -> Option<usize> {
+    let mut words = tokens.iter();
+    if !words.next()?.is_word(fragment, "CREATE") {
+        return None;
+    }
+    let mut next = words.next()?;
+    if next.is_word(fragment, "TEMP") || next.is_word(fragment, "TEMPORARY") {
+        next = words.next()?;
+    }
+    if !next.is_word(fragment, "TABLE") {
+        return None;
+    }
+
+    let open = tokens
+        .iter()
+        .position(|token| token.kind == TokenKind::Punct(b'('))?;
+    if tokens[..open]
+        .iter()
+        .any(|token| token.is_word(fragment, "AS"))
+    {
+        return None;
+    }
+    Some(open)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Word,
+    Quoted,
+    Punct(u8),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token {
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+}
+
+impl Token {
+    fn text<'a>(&self, fragment: &'a str) -> &'a str {
+        &fragment[self.start..self.end]
+    }
+
+    fn is_word(&self, fragment: &str, keyword: &str) -> bool {
+        self.kind == TokenKind::Word && self.text(fragment).eq_ignore_ascii_case(keyword)
+    }
+}
+
+fn scan_tokens(fragment: &str) -> Vec<Token> {
+    let bytes = fragment.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0usize;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+
+        if byte.is_ascii_whitespace() {
+            index += 1;
+            continue;
+        }
+
+        if byte == b'-' && bytes.get(index + 1) == Some(&b'-') {
+            while index < bytes.len() && bytes[index] != b'\n' {
+                index += 1;
+            }
+            continue;
+        }
+
+        if byte == b'/' && bytes.get(index + 1) == Some(&b'*') {
+            index += 2;
+            while index < bytes.len()
+                && !(bytes[index] == b'*' && bytes.get(index + 1) == Some(&b'/'))
+            {
+                index += 1;
+            }
+            index = (index + 2).min(bytes.len());
+            continue;
+        }
+
+        let closing = match byte {
+            b'\'' => Some(b'\''),
+            b'"' => Some(b'"'),
+            b'`' => Some(b'`'),
+            b'[' => Some(b']'),
+            _ => None,
+        };
+        if let Some(closing) = closing {
+            let start = index;
+            index += 1;
+            while index < bytes.len() {
+                if bytes[index] == closing {
+                    if closing != b']' && bytes.get(index + 1) == Some(&closing) {
+                        index += 2;
+                        continue;
+                    }
+                    break;
+                }
+                index += 1;
+            }
+            index = (index + 1).min(bytes.len());
+            tokens.push(Token {
+                kind: TokenKind::Quoted,
+                start,
+                end: index,
+            });
+            continue;
+        }
+
+        if is_word_byte(byte) {
+            let start = index;
+            while index < bytes.len() && is_word_byte(bytes[index]) {
+                index += 1;
+            }
+            tokens.push(Token {
+                kind: TokenKind::Word,
+                start,
+                end: index,
+            });
+            continue;
+        }
+
+        tokens.push(Token {
+            kind: TokenKind::Punct(byte),
+            start: index,
+            end: index + 1,
+        });
+        index += 1;
+    }
+
+    tokens
+}
+
+/// Whether `sql` names `name` as a bare or quoted identifier; string literals and
+/// comments do not count.
+pub(crate) fn mentions_identifier(sql: &str, name: &str) -> bool {
+    scan_tokens(sql).iter().any(|token| {
+        let text = token.text(sql);
+        match token.kind {
+            TokenKind::Word => text.eq_ignore_ascii_case(name),
+            TokenKind::Quoted if !text.starts_with('\'') && text.len() >= 2 => {
+                let closing = &text[text.len() - 1..];
+                text[1..text.len() - 1]
+                    .replace(&closing.repeat(2), closing)
+                    .eq_ignore_ascii_case(name)
+            }
+            _ => false,
+        }
+    })
+}
+
+/// Non-ASCII bytes count as word bytes so multi-byte characters are never split.
+pub(crate) fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'$' || !byte.is_ascii()
+}
@@ -1,9 +1,10 @@
 use std::fmt::Write as _;
 
 use stateql_core::{
-    BinaryOperator, CheckConstraint, ComparisonOp, DataType, Expr, ForeignKey, ForeignKeyAction,
-    GenerateError, Ident, IndexDef, IndexOwner, IsTest, Literal, SchemaObject, Trigger,
-    TriggerEvent, TriggerForEach, TriggerTiming, UnaryOperator, Value,
+    BinaryOperator, CheckConstraint, Column, ComparisonOp, DataType, Deferrable, Expr, ForeignKey,
+    ForeignKeyAction, GenerateError, Ident, IndexDef, IndexOwner, IsTest, Literal, Pragma,
+    QualifiedName, SchemaObject, Table, Trigger, TriggerEvent, TriggerForEach, TriggerTiming,
+    UnaryOperator, Value, extra_keys::sqlite as sqlite_keys,
 };
 
 use crate::extra_keys;
@@ -26,6 +27,8 @@ pub(crate) fn render_object(
             unsupported_variant_error(dialect_name, "MaterializedView")
         }
         SchemaObject::Sequence(_) => unsupported_variant_error(dialect_name, "Sequence"),
+        SchemaObject::Synonym(_) => unsupported_variant_error(dialect_name, "Synonym"),
+        SchemaObject::Event(_) => unsupported_variant_error(dialect_name, "Event"),
         SchemaObject::Function(_) => unsupported_variant_error(dialect_name, "Function"),
         SchemaObject::Type(_) => unsupported_variant_error(dialect_name, "Type"),
         SchemaObject::Domain(_) => unsupported_variant_error(dialect_name, "Domain"),
@@ -34,42 +37,80 @@ pub(crate) fn render_object(
         SchemaObject::Comment(_) => unsupported_variant_error(dialect_name, "Comment"),
         SchemaObject::Privilege(_) => unsupported_variant_error(dialect_name, "Privilege"),
         SchemaObject::Policy(_) => unsupported_variant_error(dialect_name, "Policy"),
+        SchemaObject::Pragma(pragma) => Ok(render_pragma(pragma)),
     }
 }
 
-fn render_table(dialect_name: &str, table: &stateql_core::Table) -> stateql_core::Result<String> {
-    if table.columns.is_empty()
-        && table.primary_key.is_none()
-        && table.foreign_keys.is_empty()
-        && table.checks.is_empty()
-    {
-        if let Some(source_hint) = source_table_sql_hint(table) {
-            return Ok(ensure_sql_terminated(source_hint));
+fn render_table(dialect_name: &str, table: &Table) -> stateql_core::Result<String> {
+    // The statement as written round-trips exactly; the IR rendering is the fallback.
+    if let Some(source_hint) = source_table_sql_hint(table) {
+        return Ok(ensure_sql_terminated(source_hint));
+    }
+
+    render_table_definition(dialect_name, table, &table.name, &[])
+}
+
+/// Renders `table` from its IR under `name`, which lets a rebuild create the shadow
+/// table with the target definition. `unique_constraints` are the indexes the table
+/// declares itself, rendered as `UNIQUE` table constraints.
+pub(crate) fn render_table_definition(
+    dialect_name: &str,
+    table: &Table,
+    name: &QualifiedName,
+    unique_constraints: &[IndexDef],
+) -> stateql_core::Result<String> {
+    if let Some(module) = string_option(table, sqlite_keys::TABLE_VIRTUAL_MODULE) {
+        let mut sql = format!(
+            "CREATE VIRTUAL TABLE {} USING {module}",
+            render_qualified_name(name)
+        );
+        if let Some(arguments) = string_option(table, sqlite_keys::TABLE_VIRTUAL_ARGUMENTS) {
+            write!(sql, "({arguments})").expect("writing to String should not fail");
         }
-        return unsupported_shape_error(dialect_name, UNSUPPORTED_TABLE_VARIANT);
+        sql.push(';');
+        return Ok(sql);
     }
 
-    if !table.exclusions.is_empty() || table.partition.is_some() {
+    if table.columns.is_empty() || !table.exclusions.is_empty() || table.partition.is_some() {
         return unsupported_shape_error(dialect_name, UNSUPPORTED_TABLE_VARIANT);
     }
 
-    let mut sql = String::new();
-    write!(sql, "CREATE TABLE {} (", render_qualified_name(&table.name))
-        .expect("writing to String should not fail");
+    let primary_key_conflict = string_option(table, sqlite_keys::TABLE_PRIMARY_KEY_CONFLICT);
+    let inline_primary_key = autoincrement_primary_key_column(table);
 
     let mut definitions = Vec::new();
     for column in &table.columns {
-        definitions.push(render_column(column));
+        let mut definition = render_column(column);
+        if inline_primary_key == Some(&column.name) {
+            definition.push_str(" PRIMARY KEY");
+            if let Some(conflict) = primary_key_conflict {
+                write!(definition, " ON CONFLICT {conflict}")
+                    .expect("writing to String should not fail");
+            }
+            definition.push_str(" AUTOINCREMENT");
+        }
+        definitions.push(definition);
     }
 
-    if let Some(primary_key) = &table.primary_key {
-        let columns = primary_key
-            .columns
-            .iter()
-            .map(render_ident)
-            .collect::<Vec<_>>()
-            .join(", ");
-        definitions.push(format!("PRIMARY KEY ({columns})"));
+    if let Some(primary_key) = &table.primary_key
+        && inline_primary_key.is_none()
+    {
+        let mut definition = constraint_name_prefix(primary_key.name.as_ref());
+        write!(
+            definition,
+            "PRIMARY KEY ({})",
+            render_ident_list(&primary_key.columns)
+        )
+        .expect("writing to String should not fail");
+        if let Some(conflict) = primary_key_conflict {
+            write!(definition, " ON CONFLICT {conflict}")
+                .expect("writing to String should not fail");
+        }
+        definitions.push(definition);
+    }
+
+    for index in unique_constraints {
+        definitions.push(render_unique_constraint(index));
     }
 
     for foreign_key in &table.foreign_keys {
@@ -80,56 +121,86 @@ fn render_table(dialect_name: &str, table: &stateql_core::Table) -> stateql_core
         definitions.push(render_check(check));
     }
 
-    sql.push_str(&definitions.join(", "));
-    sql.push(')');
+    let mut sql = format!(
+        "CREATE TABLE {} ({})",
+        render_qualified_name(name),
+        definitions.join(", ")
+    );
 
-    if table
-        .options
-        .extra
-        .get(extra_keys::TABLE_WITHOUT_ROWID)
-        .is_some_and(|value| matches!(value, Value::Bool(true)))
-    {
-        sql.push_str(" WITHOUT ROWID");
+    let mut table_options = Vec::new();
+    if bool_option(table, sqlite_keys::TABLE_WITHOUT_ROWID) {
+        table_options.push("WITHOUT ROWID");
     }
-    if table
-        .options
-        .extra
-        .get(extra_keys::TABLE_STRICT)
-        .is_some_and(|value| matches!(value, Value::Bool(true)))
-    {
-        sql.push_str(" STRICT");
+    if bool_option(table, sqlite_keys::TABLE_STRICT) {
+        table_options.push("STRICT");
+    }
+    if !table_options.is_empty() {
+        write!(sql, " {}", table_options.join(", ")).expect("writing to String should not fail");
     }
 
     sql.push(';');
     Ok(sql)
 }
 
-fn source_table_sql_hint(table: &stateql_core::Table) -> Option<&str> {
+/// SQLite only accepts `AUTOINCREMENT` inline on an `INTEGER PRIMARY KEY` column.
+fn autoincrement_primary_key_column(table: &Table) -> Option<&Ident> {
+    let primary_key = table.primary_key.as_ref()?;
+    let [column_name] = primary_key.columns.as_slice() else {
+        return None;
+    };
+    table
+        .columns
+        .iter()
+        .any(|column| &column.name == column_name && column.identity.is_some())
+        .then_some(column_name)
+}
+
+fn source_table_sql_hint(table: &Table) -> Option<&str> {
     match table.options.extra.get(extra_keys::TABLE_SOURCE_SQL) {
         Some(Value::String(value)) if !value.trim().is_empty() => Some(value.as_str()),
         _ => None,
     }
 }
 
-fn render_column(column: &stateql_core::Column) -> String {
-    let mut sql = format!(
-        "{} {}",
-        render_ident(&column.name),
-        render_data_type(&column.data_type)
-    );
+fn string_option<'a>(table: &'a Table, key: &str) -> Option<&'a str> {
+    match table.options.extra.get(key) {
+        Some(Value::String(value)) if !value.is_empty() => Some(value.as_str()),
+        _ => None,
+    }
+}
+
+fn bool_option(table: &Table, key: &str) -> bool {
+    matches!(table.options.extra.get(key), Some(Value::Bool(true)))
+}
+
+/// Renders a column definition without its primary key; the table carries that.
+/// The column type as written, falling back to the normalized one.
+pub(crate) fn declared_type(column: &Column) -> String {
+    match column.extra.get(extra_keys::COLUMN_DECLARED_TYPE) {
+        Some(Value::String(declared_type)) => declared_type.clone(),
+        _ => render_data_type(&column.data_type),
+    }
+}
+
+pub(crate) fn render_column(column: &Column) -> String {
+    let mut sql = render_ident(&column.name);
+    let declared_type = declared_type(column);
+    if !declared_type.is_empty() {
+        write!(sql, " {declared_type}").expect("writing to String should not fail");
+    }
 
     if column.not_null {
         sql.push_str(" NOT NULL");
+        if let Some(Value::String(conflict)) =
+            column.extra.get(extra_keys::COLUMN_NOT_NULL_CONFLICT)
+        {
+            write!(sql, " ON CONFLICT {conflict}").expect("writing to String should not fail");
+        }
     }
     if let Some(default) = &column.default {
         write!(sql, " DEFAULT {}", render_expr(default))
             .expect("writing to String should not fail");
     }
-    if let Some(identity) = &column.identity
-        && (identity.always || identity.start.is_some() || identity.increment.is_some())
-    {
-        sql.push_str(" GENERATED ALWAYS AS IDENTITY");
-    }
     if let Some(generated) = &column.generated {
         let storage = if generated.stored {
             "STORED"
@@ -156,22 +227,15 @@ fn render_column(column: &stateql_core::Column) -> String {
 }
 
 fn render_foreign_key(foreign_key: &ForeignKey) -> String {
-    let mut sql = format!(
+    let mut sql = constraint_name_prefix(foreign_key.name.as_ref());
+    write!(
+        sql,
         "FOREIGN KEY ({}) REFERENCES {} ({})",
-        foreign_key
-            .columns
-            .iter()
-            .map(render_ident)
-            .collect::<Vec<_>>()
-            .join(", "),
+        render_ident_list(&foreign_key.columns),
         render_qualified_name(&foreign_key.referenced_table),
-        foreign_key
-            .referenced_columns
-            .iter()
-            .map(render_ident)
-            .collect::<Vec<_>>()
-            .join(", ")
-    );
+        render_ident_list(&foreign_key.referenced_columns)
+    )
+    .expect("writing to String should not fail");
 
     if let Some(on_delete) = foreign_key.on_delete {
         write!(sql, " ON DELETE {}", render_fk_action(on_delete))
@@ -181,12 +245,83 @@ fn render_foreign_key(foreign_key: &ForeignKey) -> String {
         write!(sql, " ON UPDATE {}", render_fk_action(on_update))
             .expect("writing to String should not fail");
     }
+    match foreign_key.deferrable {
+        Some(Deferrable::Deferrable { initially_deferred }) => {
+            sql.push_str(" DEFERRABLE");
+            if initially_deferred {
+                sql.push_str(" INITIALLY DEFERRED");
+            }
+        }
+        Some(Deferrable::NotDeferrable) => sql.push_str(" NOT DEFERRABLE"),
+        None => {}
+    }
 
     sql
 }
 
+fn render_unique_constraint(index: &IndexDef) -> String {
+    let name = index.name.as_ref().filter(|_| {
+        !matches!(
+            index.extra.get(extra_keys::INDEX_UNNAMED_CONSTRAINT),
+            Some(Value::Bool(true))
+        )
+    });
+    let mut sql = constraint_name_prefix(name);
+    write!(
+        sql,
+        "UNIQUE ({})",
+        index
+            .columns
+            .iter()
+            .map(|column| render_expr(&column.expr))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+    .expect("writing to String should not fail");
+    if let Some(Value::String(conflict)) = index.extra.get(extra_keys::INDEX_CONFLICT) {
+        write!(sql, " ON CONFLICT {conflict}").expect("writing to String should not fail");
+    }
+    sql
+}
+
 fn render_check(check: &CheckConstraint) -> String {
-    format!("CHECK ({})", render_expr(&check.expr))
+    format!(
+        "{}CHECK ({})",
+        constraint_name_prefix(check.name.as_ref()),
+        render_expr(&check.expr)
+    )
+}
+
+fn constraint_name_prefix(name: Option<&Ident>) -> String {
+    name.map(|name| format!("CONSTRAINT {} ", render_ident(name)))
+        .unwrap_or_default()
+}
+
+fn render_ident_list(idents: &[Ident]) -> String {
+    idents
+        .iter()
+        .map(render_ident)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Pragma names and values are bare words; only an attached schema name is quoted.
+pub(crate) fn render_pragma(pragma: &Pragma) -> String {
+    let value = match &pragma.value {
+        Value::Integer(value) => value.to_string(),
+        Value::String(value) => value.clone(),
+        Value::Float(value) => value.to_string(),
+        Value::Bool(value) => i32::from(*value).to_string(),
+        Value::Null => "NULL".to_string(),
+    };
+    match &pragma.name.schema {
+        Some(schema) => format!(
+            "PRAGMA {}.{} = {value};",
+            render_ident(schema),
+            pragma.name.name.value
+        ),
+        None => format!("PRAGMA {} = {value};", pragma.name.name.value),
+    }
 }
 
 fn render_view(view: &stateql_core::View) -> stateql_core::Result<String> {
@@ -206,6 +341,11 @@ fn render_view(view: &stateql_core::View) -> stateql_core::Result<String> {
 }
 
 fn render_index(dialect_name: &str, index: &IndexDef) -> stateql_core::Result<String> {
+    // The `CREATE TABLE` that declares a `UNIQUE` constraint restates it.
+    if is_unique_constraint(index) {
+        return Ok(String::new());
+    }
+
     if index.concurrent {
         return unsupported_shape_error(dialect_name, UNSUPPORTED_INDEX_VARIANT);
     }
@@ -221,6 +361,11 @@ fn render_index(dialect_name: &str, index: &IndexDef) -> stateql_core::Result<St
         }
     };
 
+    // SQLite takes the schema on the index name and looks the table up in it.
+    let index_name = QualifiedName {
+        schema: table_name.schema.clone(),
+        name: name.clone(),
+    };
     let mut sql = String::from("CREATE ");
     if index.unique {
         sql.push_str("UNIQUE ");
@@ -228,8 +373,8 @@ fn render_index(dialect_name: &str, index: &IndexDef) -> stateql_core::Result<St
     write!(
         sql,
         "INDEX {} ON {} ({})",
-        render_ident(name),
-        render_qualified_name(table_name),
+        render_qualified_name(&index_name),
+        render_ident(&table_name.name),
         index
             .columns
             .iter()
@@ -248,6 +393,13 @@ fn render_index(dialect_name: &str, index: &IndexDef) -> stateql_core::Result<St
     Ok(sql)
 }
 
+pub(crate) fn is_unique_constraint(index: &IndexDef) -> bool {
+    matches!(
+        index.extra.get(sqlite_keys::INDEX_UNIQUE_CONSTRAINT),
+        Some(Value::Bool(true))
+    )
+}
+
 fn render_trigger(dialect_name: &str, trigger: &Trigger) -> stateql_core::Result<String> {
     if trigger.events.is_empty() {
         return unsupported_shape_error(dialect_name, UNSUPPORTED_TRIGGER_VARIANT);
@@ -257,25 +409,29 @@ fn render_trigger(dialect_name: &str, trigger: &Trigger) -> stateql_core::Result
         return unsupported_shape_error(dialect_name, UNSUPPORTED_TRIGGER_VARIANT);
     }
 
+    // Like an index, a trigger lives in the schema of its table, named on the trigger.
+    let trigger_name = QualifiedName {
+        schema: trigger
+            .name
+            .schema
+            .clone()
+            .or_else(|| trigger.table.schema.clone()),
+        name: trigger.name.name.clone(),
+    };
     let mut sql = format!(
         "CREATE TRIGGER {} {} {} ON {}",
-        render_qualified_name(&trigger.name),
+        render_qualified_name(&trigger_name),
         render_trigger_timing(trigger.timing),
-        trigger
-            .events
-            .iter()
-            .map(|event| render_trigger_event(*event))
-            .collect::<Vec<_>>()
-            .join(" OR "),
-        render_qualified_name(&trigger.table)
+        render_trigger_events(trigger),
+        render_ident(&trigger.table.name)
     );
 
+    sql.push_str(" FOR EACH ROW ");
     if let Some(when_clause) = &trigger.when_clause {
-        write!(sql, " WHEN {}", render_expr(when_clause))
+        write!(sql, "WHEN {} ", render_expr(when_clause))
             .expect("writing to String should not fail");
     }
 
-    sql.push_str(" FOR EACH ROW ");
     let body = trigger.body.trim().trim_end_matches(';').trim();
     if body.is_empty() {
         return unsupported_shape_error(dialect_name, UNSUPPORTED_TRIGGER_VARIANT);
@@ -291,6 +447,21 @@ fn render_trigger(dialect_name: &str, trigger: &Trigger) -> stateql_core::Result
     Ok(sql)
 }
 
+fn render_trigger_events(trigger: &Trigger) -> String {
+    trigger
+        .events
+        .iter()
+        .map(|event| {
+            if *event == TriggerEvent::Update && !trigger.update_columns.is_empty() {
+                format!("UPDATE OF {}", render_ident_list(&trigger.update_columns))
+            } else {
+                render_trigger_event(*event).to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" OR ")
+}
+
 fn render_trigger_timing(timing: TriggerTiming) -> &'static str {
     match timing {
         TriggerTiming::Before => "BEFORE",
@@ -322,12 +493,12 @@ fn render_data_type(data_type: &DataType) -> String {
         DataType::Blob => "BLOB".to_string(),
         DataType::Date | DataType::Time { .. } | DataType::Timestamp { .. } => "TEXT".to_string(),
         DataType::Json | DataType::Jsonb | DataType::Uuid => "TEXT".to_string(),
-        DataType::Array(_) => "TEXT".to_string(),
+        DataType::Array(_) | DataType::Enum(_) | DataType::Set(_) => "TEXT".to_string(),
         DataType::Custom(custom) => custom.trim().to_ascii_uppercase(),
     }
 }
 
-fn render_expr(expr: &Expr) -> String {
+pub(crate) fn render_expr(expr: &Expr) -> String {
     match expr {
         Expr::Literal(literal) => render_literal(literal),
         Expr::Ident(ident) => render_ident(ident),
@@ -591,7 +762,7 @@ fn render_fk_action(action: ForeignKeyAction) -> &'static str {
     }
 }
 
-fn render_qualified_name(name: &stateql_core::QualifiedName) -> String {
+fn render_qualified_name(name: &QualifiedName) -> String {
     match &name.schema {
         Some(schema) => format!("{}.{}", render_ident(schema), render_ident(&name.name)),
         None => render_ident(&name.name),
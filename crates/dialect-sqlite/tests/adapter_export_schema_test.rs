@@ -1,6 +1,8 @@
 use std::collections::BTreeMap;
 
-use stateql_core::{ConnectionConfig, Dialect};
+use stateql_core::{
+    ConnectionConfig, Dialect, DiffOp, Executor, Ident, QualifiedName, SchemaObject, Statement,
+};
 use stateql_dialect_sqlite::{SqliteDialect, table_names_query};
 
 #[test]
@@ -65,6 +67,174 @@ fn table_query_excludes_sqlite_internal_tables() {
     assert!(query.contains("tbl_name NOT LIKE 'sqlite_%'"));
 }
 
+#[test]
+fn export_schema_skips_virtual_table_shadow_tables() {
+    let dialect = SqliteDialect;
+    let adapter = dialect
+        .connect(&in_memory_connection())
+        .expect("connect should succeed");
+    for sql in [
+        "CREATE VIRTUAL TABLE notes USING fts5(title, body UNINDEXED, tokenize = 'porter');",
+        "CREATE VIRTUAL TABLE boxes USING rtree(id, min_x, max_x);",
+        "CREATE TABLE notes_archive (id INTEGER PRIMARY KEY);",
+    ] {
+        adapter
+            .execute(sql)
+            .expect("fixture statement should succeed");
+    }
+
+    let exported = adapter.export_schema().expect("export schema");
+
+    assert_eq!(
+        exported,
+        "PRAGMA application_id = 0;\n\n\
+         PRAGMA journal_mode = memory;\n\n\
+         PRAGMA user_version = 0;\n\n\
+         CREATE VIRTUAL TABLE boxes USING rtree(id, min_x, max_x);\n\n\
+         CREATE VIRTUAL TABLE notes USING fts5(title, body UNINDEXED, tokenize = 'porter');\n\n\
+         CREATE TABLE notes_archive (id INTEGER PRIMARY KEY);"
+    );
+    let objects = dialect
+        .parse(&exported)
+        .expect("exported schema parses back");
+    assert_eq!(objects.len(), 6);
+}
+
+#[test]
+fn declared_pragmas_run_outside_the_transaction_and_export_back() {
+    let dialect = SqliteDialect;
+    let mut adapter = dialect
+        .connect(&in_memory_connection())
+        .expect("connect should succeed");
+    let desired = dialect
+        .parse("PRAGMA application_id = 0x4D504250;\nPRAGMA user_version = 12;")
+        .expect("parse pragmas");
+    let ops = desired
+        .into_iter()
+        .map(|object| match object {
+            SchemaObject::Pragma(pragma) => DiffOp::SetPragma(pragma),
+            other => panic!("expected pragma, got {other:?}"),
+        })
+        .collect::<Vec<_>>();
+
+    let statements = dialect.generate_ddl(&ops).expect("generate pragmas");
+    assert_eq!(
+        statements,
+        vec![
+            Statement::Sql {
+                sql: "PRAGMA application_id = 1297105488;".to_string(),
+                transactional: false,
+                context: None,
+            },
+            Statement::Sql {
+                sql: "PRAGMA user_version = 12;".to_string(),
+                transactional: false,
+                context: None,
+            },
+        ]
+    );
+    Executor::new(adapter.as_mut())
+        .execute_plan(&statements)
+        .expect("pragmas should apply");
+
+    let exported = adapter.export_schema().expect("export schema");
+    assert!(
+        exported.starts_with(
+            "PRAGMA application_id = 1297105488;\n\n\
+             PRAGMA journal_mode = memory;\n\n\
+             PRAGMA user_version = 12;"
+        ),
+        "{exported}"
+    );
+}
+
+#[test]
+fn attached_databases_export_as_schemas() {
+    let dialect = SqliteDialect;
+    let mut connection = in_memory_connection();
+    connection
+        .extra
+        .insert("sqlite.attach.audit".to_string(), ":memory:".to_string());
+    let adapter = dialect
+        .connect(&connection)
+        .expect("connect should succeed");
+    for sql in [
+        "CREATE TABLE users (id INTEGER PRIMARY KEY);",
+        "CREATE TABLE audit.log (id INTEGER PRIMARY KEY, msg TEXT);",
+        "CREATE INDEX audit.log_msg ON log (msg);",
+        "CREATE TRIGGER audit.log_guard BEFORE DELETE ON log BEGIN SELECT RAISE(ABORT, 'append only'); END;",
+    ] {
+        adapter
+            .execute(sql)
+            .expect("fixture statement should succeed");
+    }
+
+    assert_eq!(
+        adapter.schema_search_path(),
+        vec!["main".to_string(), "audit".to_string()]
+    );
+    let exported = adapter.export_schema().expect("export schema");
+    for expected in [
+        "PRAGMA \"audit\".user_version = 0;",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY);",
+        "CREATE TABLE \"audit\".log (id INTEGER PRIMARY KEY, msg TEXT);",
+        "CREATE INDEX \"audit\".log_msg ON log (msg);",
+        "CREATE TRIGGER \"audit\".log_guard BEFORE DELETE ON log",
+    ] {
+        assert!(
+            exported.contains(expected),
+            "{expected} missing from {exported}"
+        );
+    }
+
+    let tables = dialect
+        .parse(&exported)
+        .expect("exported schema parses back")
+        .into_iter()
+        .filter_map(|object| match object {
+            SchemaObject::Table(table) => Some(table.name),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tables,
+        vec![
+            QualifiedName {
+                schema: None,
+                name: Ident::unquoted("users"),
+            },
+            QualifiedName {
+                schema: Some(Ident::quoted("audit")),
+                name: Ident::unquoted("log"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn attaching_under_a_reserved_schema_name_is_rejected() {
+    let mut connection = in_memory_connection();
+    connection
+        .extra
+        .insert("sqlite.attach.temp".to_string(), ":memory:".to_string());
+
+    let Err(error) = SqliteDialect.connect(&connection) else {
+        panic!("temp cannot be an attached schema");
+    };
+    let mut messages = Vec::new();
+    let mut source: Option<&dyn std::error::Error> = Some(&error);
+    while let Some(current) = source {
+        messages.push(current.to_string());
+        source = current.source();
+    }
+    assert!(
+        messages
+            .iter()
+            .any(|message| message.contains("sqlite.attach.temp")),
+        "error should name the config key: {messages:?}"
+    );
+}
+
 fn in_memory_connection() -> ConnectionConfig {
     ConnectionConfig {
         host: None,
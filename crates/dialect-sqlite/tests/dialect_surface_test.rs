@@ -1,11 +1,12 @@
 use std::collections::BTreeMap;
 
 use stateql_core::{
-    Column, Comment, CommentTarget, ConnectionConfig, DataType, Dialect, Domain, Error,
-    ExecutionError, Expr, Extension, Function, FunctionParam, FunctionSecurity, GenerateError,
-    Ident, IndexColumn, IndexDef, IndexOwner, Policy, PolicyCommand, Privilege, PrivilegeObject,
-    QualifiedName, SchemaDef, SchemaObject, Sequence, Table, Trigger, TriggerEvent, TriggerForEach,
-    TriggerTiming, TypeDef, TypeKind, View,
+    Column, Comment, CommentTarget, ConnectionConfig, DataType, Dialect, DiffConfig, DiffEngine,
+    DiffOp, Domain, Error, ExecutionError, Expr, Extension, Function, FunctionParam,
+    FunctionSecurity, GenerateError, Ident, IndexColumn, IndexDef, IndexOwner, Policy,
+    PolicyCommand, Pragma, Privilege, PrivilegeObject, QualifiedName, SchemaDef, SchemaObject,
+    Sequence, Table, Trigger, TriggerEvent, TriggerForEach, TriggerTiming, TypeDef, TypeKind,
+    Value, View,
 };
 use stateql_dialect_sqlite::SqliteDialect;
 
@@ -86,6 +87,54 @@ fn connect_rejects_sqlite_versions_below_3_35() {
     );
 }
 
+#[test]
+fn primary_and_foreign_keys_are_diffed() {
+    let dialect = SqliteDialect;
+    assert!(dialect.diffs_table_constraints());
+
+    let ops = diff_sql(
+        &dialect,
+        "CREATE TABLE teams (id INTEGER PRIMARY KEY);\n\
+         CREATE TABLE users (id INTEGER NOT NULL, team_id INTEGER, PRIMARY KEY (id), CONSTRAINT users_team_fk FOREIGN KEY (team_id) REFERENCES teams (id));",
+        "CREATE TABLE teams (id INTEGER PRIMARY KEY);\n\
+         CREATE TABLE users (id INTEGER NOT NULL, team_id INTEGER);",
+    );
+
+    assert!(
+        ops.iter()
+            .any(|op| matches!(op, DiffOp::SetPrimaryKey { .. })),
+        "{ops:?}"
+    );
+    assert!(
+        ops.iter()
+            .any(|op| matches!(op, DiffOp::AddForeignKey { .. })),
+        "{ops:?}"
+    );
+    dialect
+        .generate_ddl(&ops)
+        .expect("sqlite key changes should generate SQL");
+}
+
+/// Diffs two schemas with the settings the orchestrator derives from the dialect.
+fn diff_sql(dialect: &SqliteDialect, desired: &str, current: &str) -> Vec<DiffOp> {
+    let parse = |sql: &str| {
+        let mut objects = dialect.parse(sql).expect("schema should parse");
+        for object in &mut objects {
+            dialect.normalize(object);
+        }
+        objects
+    };
+    let config = DiffConfig {
+        emit_table_definitions: dialect.requires_table_definitions(),
+        diff_table_constraints: dialect.diffs_table_constraints(),
+        diffed_table_options: dialect.diffed_table_options(),
+        ..DiffConfig::default()
+    };
+    DiffEngine::new()
+        .diff(&parse(desired), &parse(current), &config)
+        .expect("diff should succeed")
+}
+
 fn canonical_export_sql(dialect: &SqliteDialect, sql: &str) -> String {
     let mut objects = dialect.parse(sql).expect("parse should succeed");
     for object in &mut objects {
@@ -160,6 +209,7 @@ fn supported_objects() -> Vec<SchemaObject> {
         table: qualified(None, "users"),
         timing: TriggerTiming::After,
         events: vec![TriggerEvent::Insert],
+        update_columns: Vec::new(),
         for_each: TriggerForEach::Row,
         when_clause: None,
         body: "BEGIN SELECT 1; END".to_string(),
@@ -170,6 +220,10 @@ fn supported_objects() -> Vec<SchemaObject> {
         SchemaObject::View(view),
         SchemaObject::Index(index),
         SchemaObject::Trigger(trigger),
+        SchemaObject::Pragma(Pragma {
+            name: qualified(None, "user_version"),
+            value: Value::Integer(7),
+        }),
     ]
 }
 
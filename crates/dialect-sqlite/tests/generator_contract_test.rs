@@ -59,25 +59,31 @@ fn is_supported_diffop(op: &DiffOp) -> bool {
         DiffOp::CreateTable(table) => table.exclusions.is_empty() && table.partition.is_none(),
         DiffOp::DropTable(_) => true,
         DiffOp::RenameTable { from, to } => from.schema == to.schema,
+        DiffOp::TableDefinition { target, .. } => {
+            target.exclusions.is_empty() && target.partition.is_none()
+        }
         DiffOp::AddColumn { position, .. } => position.is_none(),
-        DiffOp::DropColumn { .. } => true,
-        DiffOp::AlterColumn { changes, .. } => !changes.is_empty(),
+        // Table rebuilds are only generated alongside the table's `DiffOp::TableDefinition`.
+        DiffOp::DropColumn { .. } => false,
+        DiffOp::AlterColumn { .. } => false,
         DiffOp::RenameColumn { .. } => true,
         DiffOp::AddIndex(index) => {
             index.name.is_some() && matches!(index.owner, IndexOwner::Table(_))
         }
         DiffOp::DropIndex { owner, .. } => matches!(owner, IndexOwner::Table(_)),
         DiffOp::RenameIndex { .. } => false,
-        DiffOp::AddForeignKey { .. } => true,
-        DiffOp::DropForeignKey { .. } => true,
-        DiffOp::AddCheck { .. } => true,
-        DiffOp::DropCheck { .. } => true,
-        DiffOp::AddExclusion { .. } => true,
-        DiffOp::DropExclusion { .. } => true,
-        DiffOp::SetPrimaryKey { .. } => true,
-        DiffOp::DropPrimaryKey { .. } => true,
+        DiffOp::AddForeignKey { .. } => false,
+        DiffOp::DropForeignKey { .. } => false,
+        DiffOp::AddCheck { .. } => false,
+        DiffOp::DropCheck { .. } => false,
+        DiffOp::AddExclusion { .. } => false,
+        DiffOp::DropExclusion { .. } => false,
+        DiffOp::SetPrimaryKey { .. } => false,
+        DiffOp::DropPrimaryKey { .. } => false,
         DiffOp::AddPartition { .. } => false,
         DiffOp::DropPartition { .. } => false,
+        DiffOp::ReorganizePartition { .. } => false,
+        DiffOp::ChangePartitionCount { .. } => false,
         DiffOp::CreateView(_) => true,
         DiffOp::DropView(_) => true,
         DiffOp::CreateMaterializedView(_) => false,
@@ -85,10 +91,14 @@ fn is_supported_diffop(op: &DiffOp) -> bool {
         DiffOp::CreateSequence(_) => false,
         DiffOp::DropSequence(_) => false,
         DiffOp::AlterSequence { .. } => false,
+        DiffOp::CreateSynonym(_) => false,
+        DiffOp::DropSynonym(_) => false,
         DiffOp::CreateTrigger(trigger) => !trigger.events.is_empty(),
         DiffOp::DropTrigger { .. } => true,
         DiffOp::CreateFunction(_) => false,
-        DiffOp::DropFunction(_) => false,
+        DiffOp::DropFunction { .. } => false,
+        DiffOp::CreateEvent(_) => false,
+        DiffOp::DropEvent(_) => false,
         DiffOp::CreateType(_) => false,
         DiffOp::DropType(_) => false,
         DiffOp::AlterType { .. } => false,
@@ -105,6 +115,7 @@ fn is_supported_diffop(op: &DiffOp) -> bool {
         DiffOp::Revoke(_) => false,
         DiffOp::CreatePolicy(_) => false,
         DiffOp::DropPolicy { .. } => false,
+        DiffOp::SetPragma(_) => true,
         DiffOp::AlterTableOptions { .. } => false,
     }
 }
@@ -1,4 +1,8 @@
-use stateql_core::{Dialect, DiffError, Error, Ident, ParseError, SchemaObject};
+use stateql_core::{
+    DataType, Dialect, DiffError, Error, Expr, ForeignKeyAction, Ident, IndexOwner, ParseError,
+    QualifiedName, SchemaObject, TriggerEvent, TriggerForEach, TriggerTiming, Value,
+    extra_keys::sqlite as sqlite_keys,
+};
 use stateql_dialect_sqlite::SqliteDialect;
 
 #[test]
@@ -60,3 +64,487 @@ fn orphan_renamed_annotation_fails_fast() {
         other => panic!("expected orphan annotation mismatch, got {other:?}"),
     }
 }
+
+#[test]
+fn column_annotations_attach_renames_and_backfills() {
+    let dialect = SqliteDialect;
+    let sql = "CREATE TABLE users (\n  id integer,\n  full_name text, -- @renamed from=name\n  slug text NOT NULL -- @backfill 'user-' || id\n);";
+
+    let objects = dialect.parse(sql).expect("sqlite parse pipeline");
+
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    assert_eq!(table.renamed_from, None);
+    assert_eq!(table.columns[1].renamed_from, Some(Ident::unquoted("name")));
+    assert_eq!(
+        table.columns[2].extra.get("sqlite.backfill"),
+        Some(&Value::String("'user-' || id".to_string()))
+    );
+}
+
+#[test]
+fn orphan_backfill_annotation_fails_fast() {
+    let dialect = SqliteDialect;
+    let sql = "CREATE TABLE users (\n  id integer\n);\n-- @backfill 0\n";
+
+    let error = dialect
+        .parse(sql)
+        .expect_err("orphan backfill must not be silently ignored");
+
+    match error {
+        Error::Parse(ParseError::StatementConversion {
+            source_sql,
+            source_location,
+            source,
+            ..
+        }) => {
+            assert_eq!(source_sql, "-- @backfill 0");
+            assert_eq!(
+                source_location.as_ref().map(|location| location.line),
+                Some(4)
+            );
+            assert!(source.to_string().contains("not on a column definition"));
+        }
+        other => panic!("expected orphan annotation parse error, got {other:?}"),
+    }
+}
+
+#[test]
+fn virtual_table_keeps_module_and_arguments() {
+    let dialect = SqliteDialect;
+    let sql = "CREATE VIRTUAL TABLE notes USING FTS5(\n  title,\n  body UNINDEXED,\n  tokenize = 'porter unicode61'\n);";
+
+    let objects = parse_normalized(&dialect, sql);
+
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    assert_eq!(table.name.name, Ident::unquoted("notes"));
+    assert!(table.columns.is_empty());
+    assert_eq!(
+        table.options.extra.get(sqlite_keys::TABLE_VIRTUAL_MODULE),
+        Some(&Value::String("fts5".to_string()))
+    );
+    assert_eq!(
+        table
+            .options
+            .extra
+            .get(sqlite_keys::TABLE_VIRTUAL_ARGUMENTS),
+        Some(&Value::String(
+            "title, body UNINDEXED, tokenize = 'porter unicode61'".to_string()
+        ))
+    );
+}
+
+#[test]
+fn create_table_columns_are_converted() {
+    let dialect = SqliteDialect;
+    let sql = "CREATE TABLE users (\n  id INTEGER PRIMARY KEY AUTOINCREMENT,\n  email VARCHAR(255) NOT NULL ON CONFLICT REPLACE DEFAULT '' COLLATE NOCASE,\n  score,\n  email_domain TEXT GENERATED ALWAYS AS (substr(email, instr(email, '@') + 1)) STORED\n);";
+
+    let objects = dialect.parse(sql).expect("sqlite parse pipeline");
+
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    assert_eq!(
+        table.primary_key.as_ref().map(|pk| pk.columns.clone()),
+        Some(vec![Ident::unquoted("id")])
+    );
+    assert!(table.columns[0].identity.is_some());
+
+    let email = &table.columns[1];
+    assert_eq!(
+        email.data_type,
+        DataType::Custom("VARCHAR(255)".to_string())
+    );
+    assert_eq!(
+        email.extra.get("sqlite.declared_type"),
+        Some(&Value::String("VARCHAR(255)".to_string()))
+    );
+    assert!(email.not_null);
+    assert_eq!(
+        email.extra.get("sqlite.not_null_conflict"),
+        Some(&Value::String("REPLACE".to_string()))
+    );
+    assert_eq!(email.default, Some(Expr::Raw("''".to_string())));
+    assert_eq!(email.collation.as_deref(), Some("NOCASE"));
+
+    assert_eq!(table.columns[2].data_type, DataType::Custom(String::new()));
+
+    let generated = table.columns[3]
+        .generated
+        .as_ref()
+        .expect("generated column");
+    assert!(generated.stored);
+}
+
+#[test]
+fn table_constraints_and_conflict_clauses_are_converted() {
+    let dialect = SqliteDialect;
+    let sql = "CREATE TABLE memberships (\n  user_id INTEGER NOT NULL REFERENCES users (id) ON DELETE CASCADE,\n  team_id INTEGER NOT NULL,\n  role TEXT UNIQUE ON CONFLICT IGNORE,\n  PRIMARY KEY (user_id, team_id) ON CONFLICT FAIL,\n  CONSTRAINT memberships_team UNIQUE (team_id, role) ON CONFLICT REPLACE,\n  CHECK (role <> '')\n) WITHOUT ROWID, STRICT;";
+
+    let objects = dialect.parse(sql).expect("sqlite parse pipeline");
+
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    assert_eq!(
+        table.primary_key.as_ref().map(|pk| pk.columns.clone()),
+        Some(vec![Ident::unquoted("user_id"), Ident::unquoted("team_id")])
+    );
+    assert_eq!(
+        table
+            .options
+            .extra
+            .get(sqlite_keys::TABLE_PRIMARY_KEY_CONFLICT),
+        Some(&Value::String("FAIL".to_string()))
+    );
+    let [SchemaObject::Index(role), SchemaObject::Index(team)] = &objects[1..] else {
+        panic!("expected the UNIQUE constraints to follow their table as indexes");
+    };
+    assert!(role.unique && team.unique);
+    assert_eq!(role.owner, IndexOwner::Table(table.name.clone()));
+    assert_eq!(
+        role.name.as_ref().map(|name| name.value.as_str()),
+        Some("sqlite_unique_memberships_role")
+    );
+    assert_eq!(
+        role.extra.get("sqlite.unnamed_constraint"),
+        Some(&Value::Bool(true))
+    );
+    assert_eq!(
+        role.extra.get("sqlite.conflict"),
+        Some(&Value::String("IGNORE".to_string()))
+    );
+    assert_eq!(team.name, Some(Ident::unquoted("memberships_team")));
+    assert_eq!(
+        team.columns
+            .iter()
+            .map(|column| column.expr.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            Expr::Ident(Ident::unquoted("team_id")),
+            Expr::Ident(Ident::unquoted("role"))
+        ]
+    );
+    assert_eq!(team.extra.get("sqlite.unnamed_constraint"), None);
+    assert_eq!(
+        team.extra.get("sqlite.conflict"),
+        Some(&Value::String("REPLACE".to_string()))
+    );
+    for index in [role, team] {
+        assert_eq!(
+            index.extra.get(sqlite_keys::INDEX_UNIQUE_CONSTRAINT),
+            Some(&Value::Bool(true))
+        );
+    }
+    assert_eq!(
+        table.options.extra.get(sqlite_keys::TABLE_WITHOUT_ROWID),
+        Some(&Value::Bool(true))
+    );
+    assert_eq!(
+        table.options.extra.get(sqlite_keys::TABLE_STRICT),
+        Some(&Value::Bool(true))
+    );
+
+    let [foreign_key] = table.foreign_keys.as_slice() else {
+        panic!("expected one foreign key");
+    };
+    assert_eq!(foreign_key.columns, vec![Ident::unquoted("user_id")]);
+    assert_eq!(foreign_key.referenced_table.name, Ident::unquoted("users"));
+    assert_eq!(foreign_key.on_delete, Some(ForeignKeyAction::Cascade));
+
+    assert_eq!(
+        table.checks.first().map(|check| &check.expr),
+        Some(&Expr::Raw("role <> ''".to_string()))
+    );
+}
+
+#[test]
+fn exported_table_keeps_statement_text() {
+    let dialect = SqliteDialect;
+    let sql = "-- users\nCREATE TABLE users (id INTEGER, name TEXT) WITHOUT ROWID, STRICT;";
+
+    let objects = dialect.parse(sql).expect("sqlite parse pipeline");
+
+    assert_eq!(
+        dialect.to_sql(&objects[0]).expect("render table"),
+        "CREATE TABLE users (id INTEGER, name TEXT) WITHOUT ROWID, STRICT;"
+    );
+}
+
+#[test]
+fn partial_and_expression_indexes_are_converted() {
+    let dialect = SqliteDialect;
+    let sql = "CREATE TABLE users (id integer, email text, deleted_at text);\n\
+               CREATE UNIQUE INDEX users_email ON users (email) WHERE deleted_at IS NULL;\n\
+               CREATE INDEX users_email_lower ON users (lower(email), id DESC);";
+
+    let objects = dialect.parse(sql).expect("sqlite parse pipeline");
+
+    assert_eq!(objects.len(), 3);
+    let SchemaObject::Index(partial) = &objects[1] else {
+        panic!("expected index object, got {:?}", objects[1]);
+    };
+    assert_eq!(partial.name, Some(Ident::unquoted("users_email")));
+    assert!(partial.unique);
+    assert_eq!(
+        partial.owner,
+        IndexOwner::Table(QualifiedName {
+            schema: None,
+            name: Ident::unquoted("users"),
+        })
+    );
+    assert_eq!(
+        partial.columns[0].expr,
+        Expr::Ident(Ident::unquoted("email"))
+    );
+    assert_eq!(
+        partial.where_clause,
+        Some(Expr::Raw("deleted_at IS NULL".to_string()))
+    );
+
+    let SchemaObject::Index(expression) = &objects[2] else {
+        panic!("expected index object, got {:?}", objects[2]);
+    };
+    assert!(!expression.unique);
+    assert_eq!(
+        expression
+            .columns
+            .iter()
+            .map(|column| column.expr.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            Expr::Raw("lower(email)".to_string()),
+            Expr::Raw("id DESC".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn view_is_converted() {
+    let dialect = SqliteDialect;
+    let sql = "CREATE VIEW active_users (id, email) AS SELECT id, email FROM users WHERE active = 1; -- @renamed from=live_users\n";
+
+    let objects = dialect.parse(sql).expect("sqlite parse pipeline");
+
+    let [SchemaObject::View(view)] = objects.as_slice() else {
+        panic!("expected a single view, got {objects:?}");
+    };
+    assert_eq!(view.name.name, Ident::unquoted("active_users"));
+    assert_eq!(
+        view.columns,
+        vec![Ident::unquoted("id"), Ident::unquoted("email")]
+    );
+    assert_eq!(view.query, "SELECT id, email FROM users WHERE active = 1");
+    assert_eq!(view.renamed_from, Some(Ident::unquoted("live_users")));
+}
+
+#[test]
+fn trigger_body_semicolons_do_not_split_statements() {
+    let dialect = SqliteDialect;
+    let sql = "CREATE TRIGGER users_touch AFTER UPDATE OF name, email ON users\n\
+               FOR EACH ROW WHEN NEW.name <> OLD.name\n\
+               BEGIN\n\
+                 UPDATE users SET kind = CASE WHEN NEW.email IS NULL THEN 'anon' ELSE 'known' END WHERE id = NEW.id;\n\
+                 INSERT INTO audit (user_id) VALUES (NEW.id);\n\
+               END;\n\
+               CREATE TABLE audit (user_id integer);\n\
+               DROP TABLE audit;";
+
+    let error = dialect
+        .parse(sql)
+        .expect_err("the statement after the trigger keeps its own position");
+    match error {
+        Error::Parse(ParseError::StatementConversion {
+            statement_index,
+            source_sql,
+            ..
+        }) => {
+            assert_eq!(statement_index, 2);
+            assert_eq!(source_sql, "DROP TABLE audit;");
+        }
+        other => panic!("expected parse statement conversion error, got {other:?}"),
+    }
+
+    let objects = dialect
+        .parse(sql.trim_end_matches("DROP TABLE audit;"))
+        .expect("sqlite parse pipeline");
+    assert_eq!(objects.len(), 2);
+    let SchemaObject::Trigger(trigger) = &objects[0] else {
+        panic!("expected trigger object, got {:?}", objects[0]);
+    };
+    assert_eq!(trigger.name.name, Ident::unquoted("users_touch"));
+    assert_eq!(trigger.table.name, Ident::unquoted("users"));
+    assert_eq!(trigger.timing, TriggerTiming::After);
+    assert_eq!(trigger.events, vec![TriggerEvent::Update]);
+    assert_eq!(
+        trigger.update_columns,
+        vec![Ident::unquoted("name"), Ident::unquoted("email")]
+    );
+    assert_eq!(trigger.for_each, TriggerForEach::Row);
+    assert_eq!(
+        trigger.when_clause,
+        Some(Expr::Raw("NEW.name <> OLD.name".to_string()))
+    );
+    assert!(
+        trigger
+            .body
+            .starts_with("BEGIN UPDATE users SET kind = CASE")
+    );
+    assert!(
+        trigger
+            .body
+            .ends_with("INSERT INTO audit (user_id) VALUES (NEW.id); END")
+    );
+    assert!(matches!(objects[1], SchemaObject::Table(_)));
+}
+
+#[test]
+fn exported_indexes_views_and_triggers_parse_back() {
+    let dialect = SqliteDialect;
+    let sql = "CREATE TABLE users (id integer, email text);\n\
+               CREATE INDEX users_email ON users (lower(email)) WHERE email IS NOT NULL;\n\
+               CREATE VIEW emails AS SELECT email FROM users;\n\
+               CREATE TRIGGER users_guard BEFORE DELETE ON users\n\
+               BEGIN SELECT RAISE(ABORT, 'read only'); END;";
+
+    let objects = parse_normalized(&dialect, sql);
+    let exported = objects
+        .iter()
+        .map(|object| dialect.to_sql(object).expect("render object"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert_eq!(parse_normalized(&dialect, &exported), objects);
+}
+
+#[test]
+fn index_and_trigger_tables_take_the_schema_of_their_name() {
+    let dialect = SqliteDialect;
+    let sql = "CREATE TABLE audit.log (id integer, msg text);\n\
+               CREATE INDEX audit.log_msg ON log (msg);\n\
+               CREATE TRIGGER audit.log_guard BEFORE DELETE ON log\n\
+               BEGIN SELECT RAISE(ABORT, 'append only'); END;";
+
+    let objects = parse_normalized(&dialect, sql);
+    let audit_log = QualifiedName {
+        schema: Some(Ident::unquoted("audit")),
+        name: Ident::unquoted("log"),
+    };
+
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    assert_eq!(table.name, audit_log);
+    let SchemaObject::Index(index) = &objects[1] else {
+        panic!("expected index object");
+    };
+    assert_eq!(index.owner, IndexOwner::Table(audit_log.clone()));
+    let SchemaObject::Trigger(trigger) = &objects[2] else {
+        panic!("expected trigger object");
+    };
+    assert_eq!(trigger.table, audit_log);
+
+    let exported = objects
+        .iter()
+        .map(|object| dialect.to_sql(object).expect("render object"))
+        .collect::<Vec<_>>();
+    assert!(exported[1].starts_with("CREATE INDEX \"audit\".\"log_msg\" ON \"log\""));
+    assert!(
+        exported[2].starts_with("CREATE TRIGGER \"audit\".\"log_guard\" BEFORE DELETE ON \"log\"")
+    );
+    assert_eq!(parse_normalized(&dialect, &exported.join("\n")), objects);
+}
+
+#[test]
+fn managed_pragmas_are_parsed_beside_the_schema() {
+    let dialect = SqliteDialect;
+    let sql = "PRAGMA application_id = 0x4D504250;\n\
+               -- readers must not block the writer\n\
+               PRAGMA journal_mode = WAL;\n\
+               PRAGMA main.user_version(-3);\n\
+               CREATE TABLE users (id integer); -- @renamed from=people\n";
+
+    let objects = parse_normalized(&dialect, sql);
+
+    let SchemaObject::Table(table) = &objects[0] else {
+        panic!("expected table object");
+    };
+    assert_eq!(table.renamed_from, Some(Ident::unquoted("people")));
+    let pragmas = objects[1..]
+        .iter()
+        .map(|object| match object {
+            SchemaObject::Pragma(pragma) => (
+                pragma.name.schema.clone(),
+                pragma.name.name.value.as_str(),
+                pragma.value.clone(),
+            ),
+            other => panic!("expected pragma, got {other:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        pragmas,
+        vec![
+            (None, "application_id", Value::Integer(0x4D50_4250)),
+            (None, "journal_mode", Value::String("wal".to_string())),
+            (None, "user_version", Value::Integer(-3)),
+        ]
+    );
+}
+
+#[test]
+fn connection_pragmas_are_rejected() {
+    let dialect = SqliteDialect;
+    let sql = "CREATE TABLE users (id integer);\nPRAGMA foreign_keys = ON;";
+
+    let error = dialect
+        .parse(sql)
+        .expect_err("connection-scoped pragmas cannot be declared");
+
+    match error {
+        Error::Parse(ParseError::StatementConversion {
+            source_sql,
+            source_location,
+            ..
+        }) => {
+            assert_eq!(source_sql, "PRAGMA foreign_keys = ON;");
+            assert_eq!(
+                source_location.as_ref().map(|location| location.line),
+                Some(2)
+            );
+        }
+        other => panic!("expected parse statement conversion error, got {other:?}"),
+    }
+}
+
+#[test]
+fn statements_are_numbered_in_source_order_around_pragmas() {
+    let dialect = SqliteDialect;
+    let statement_index = |sql: &str| match dialect.parse(sql) {
+        Err(Error::Parse(ParseError::StatementConversion {
+            statement_index, ..
+        })) => statement_index,
+        other => panic!("expected parse statement conversion error, got {other:?}"),
+    };
+
+    assert_eq!(
+        statement_index(
+            "PRAGMA user_version = 3;\nCREATE TABLE users (id integer);\nPRAGMA foreign_keys = ON;"
+        ),
+        2
+    );
+    assert_eq!(
+        statement_index("PRAGMA user_version = 3;\nPRAGMA application_id = 7;\nDROP TABLE users;"),
+        2
+    );
+}
+
+fn parse_normalized(dialect: &SqliteDialect, sql: &str) -> Vec<SchemaObject> {
+    let mut objects = dialect.parse(sql).expect("sqlite parse pipeline");
+    for object in &mut objects {
+        dialect.normalize(object);
+    }
+    objects
+}
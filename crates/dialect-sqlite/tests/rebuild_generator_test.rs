@@ -1,8 +1,10 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, fs};
 
 use stateql_core::{
-    Column, ColumnChange, DataType, Dialect, DiffOp, Executor, Ident, QualifiedName,
-    SqliteRebuildStep, Statement, StatementContext,
+    Column, ColumnChange, ConnectionConfig, DataType, DatabaseAdapter, Dialect, DiffOp, Error,
+    ExecutionError, Executor, Expr, GenerateError, Ident, IndexDef, Mode, Orchestrator,
+    OrchestratorOptions, OrchestratorOutput, PrimaryKey, QualifiedName, SchemaObject,
+    SqliteRebuildStep, Statement, StatementContext, Table,
 };
 use stateql_dialect_sqlite::SqliteDialect;
 
@@ -11,7 +13,7 @@ mod sqlite_atomicity_fixture;
 
 use sqlite_atomicity_fixture::{
     assert_copy_step_failure, assert_rollback_left_original_table, prepare_users_with_null_age,
-    set_not_null_age_op,
+    set_not_null_age_ops,
 };
 
 #[test]
@@ -46,14 +48,9 @@ fn add_column_without_position_generates_simple_alter_statement() {
 fn alter_column_rewrites_to_sqlite_rebuild_steps_with_context() {
     let dialect = SqliteDialect;
     let table = qualified(None, "users");
-    let alter_column = DiffOp::AlterColumn {
-        table: table.clone(),
-        column: ident("age"),
-        changes: vec![ColumnChange::SetNotNull(true)],
-    };
 
     let statements = dialect
-        .generate_ddl(&[alter_column])
+        .generate_ddl(&set_not_null_age_ops(&dialect))
         .expect("sqlite rebuild should generate SQL");
 
     assert!(
@@ -71,7 +68,6 @@ fn alter_column_rewrites_to_sqlite_rebuild_steps_with_context() {
         else {
             panic!("sqlite generator should not emit BatchBoundary");
         };
-        assert!(transactional, "rebuild statements must be transactional");
         let Some(StatementContext::SqliteTableRebuild {
             table: context_table,
             step,
@@ -80,19 +76,50 @@ fn alter_column_rewrites_to_sqlite_rebuild_steps_with_context() {
             panic!("rebuild statements must include sqlite rebuild context");
         };
         assert_eq!(context_table, &table);
+        assert_eq!(
+            *transactional,
+            !matches!(
+                step,
+                SqliteRebuildStep::DisableForeignKeys | SqliteRebuildStep::RestoreForeignKeys
+            ),
+            "only the foreign key pragmas run outside the rebuild transaction"
+        );
         saw_copy_step |= *step == SqliteRebuildStep::CopyData;
     }
 
     assert!(saw_copy_step, "rebuild must include a copy-data step");
 }
 
+#[test]
+fn alter_column_without_table_definition_is_rejected() {
+    let dialect = SqliteDialect;
+    let alter_column = DiffOp::AlterColumn {
+        table: qualified(None, "users"),
+        column: ident("age"),
+        changes: vec![ColumnChange::SetNotNull(true)],
+    };
+
+    let error = dialect
+        .generate_ddl(&[alter_column])
+        .expect_err("a rebuild without the table definition cannot be generated");
+
+    assert!(
+        matches!(
+            error,
+            Error::Generate(GenerateError::UnsupportedDiffOp { ref diff_op, .. })
+                if diff_op == "AlterColumn"
+        ),
+        "unexpected error: {error:?}"
+    );
+}
+
 #[test]
 fn sqlite_rebuild_copy_failure_rolls_back_entire_transaction() {
     let dialect = SqliteDialect;
     let mut adapter = prepare_users_with_null_age(&dialect);
 
     let statements = dialect
-        .generate_ddl(&[set_not_null_age_op()])
+        .generate_ddl(&set_not_null_age_ops(&dialect))
         .expect("rebuild plan should generate");
 
     let mut executor = Executor::new(adapter.as_mut());
@@ -104,6 +131,762 @@ fn sqlite_rebuild_copy_failure_rolls_back_entire_transaction() {
     assert_rollback_left_original_table(adapter.as_ref());
 }
 
+#[test]
+fn table_definition_keeps_appendable_column_as_native_add_column() {
+    let dialect = SqliteDialect;
+    let current = users_table(vec![sample_column("id")]);
+    let target = users_table(vec![sample_column("id"), sample_column("nickname")]);
+
+    let statements = dialect
+        .generate_ddl(&[
+            DiffOp::AddColumn {
+                table: qualified(None, "users"),
+                column: Box::new(sample_column("nickname")),
+                position: None,
+            },
+            DiffOp::TableDefinition {
+                current: Box::new(current),
+                target: Box::new(target),
+                dependent_views: Vec::new(),
+                dependent_indexes: Vec::new(),
+                declared_indexes: Vec::new(),
+                dependent_triggers: Vec::new(),
+                referencing_foreign_keys: Vec::new(),
+            },
+        ])
+        .expect("sqlite add-column should generate SQL");
+
+    assert_eq!(
+        statement_sql(&statements),
+        vec!["ALTER TABLE \"users\" ADD COLUMN \"nickname\" TEXT;"]
+    );
+}
+
+#[test]
+fn table_definition_rebuilds_shadow_table_from_full_target() {
+    let dialect = SqliteDialect;
+    let table = qualified(None, "users");
+    let mut created_at = sample_column("created_at");
+    created_at.not_null = true;
+    created_at.default = Some(Expr::Raw("CURRENT_TIMESTAMP".to_string()));
+    let current = users_table(vec![sample_column("id"), sample_column("name")]);
+    let mut target = users_table(vec![
+        sample_column("id"),
+        sample_column("name"),
+        created_at.clone(),
+    ]);
+    target.primary_key = Some(PrimaryKey {
+        name: None,
+        columns: vec![ident("id")],
+    });
+
+    let statements = dialect
+        .generate_ddl(&[
+            DiffOp::AddColumn {
+                table: table.clone(),
+                column: Box::new(created_at),
+                position: None,
+            },
+            DiffOp::SetPrimaryKey {
+                table: table.clone(),
+                pk: target.primary_key.clone().expect("target primary key"),
+            },
+            DiffOp::TableDefinition {
+                current: Box::new(current),
+                target: Box::new(target),
+                dependent_views: Vec::new(),
+                dependent_indexes: Vec::new(),
+                declared_indexes: Vec::new(),
+                dependent_triggers: Vec::new(),
+                referencing_foreign_keys: Vec::new(),
+            },
+        ])
+        .expect("sqlite rebuild should generate SQL");
+
+    let sql = statement_sql(&statements);
+    assert_eq!(
+        sql[..5],
+        [
+            "PRAGMA foreign_keys = OFF;",
+            "CREATE TABLE \"__stateql_rebuild_users\" (\"id\" TEXT, \"name\" TEXT, \"created_at\" TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP, PRIMARY KEY (\"id\"));",
+            "INSERT INTO \"__stateql_rebuild_users\" (\"id\", \"name\") SELECT \"id\", \"name\" FROM \"users\";",
+            "DROP TABLE \"users\";",
+            "ALTER TABLE \"__stateql_rebuild_users\" RENAME TO \"users\";",
+        ]
+    );
+    assert_eq!(
+        sql[sql.len() - 2..],
+        ["PRAGMA foreign_key_check;", "PRAGMA foreign_keys = ON;"]
+    );
+    for statement in &statements {
+        let Statement::Sql { context, .. } = statement else {
+            panic!("sqlite generator should not emit BatchBoundary");
+        };
+        assert!(matches!(
+            context,
+            Some(StatementContext::SqliteTableRebuild { table: context_table, .. })
+                if context_table == &table
+        ));
+    }
+}
+
+#[test]
+fn rebuild_disables_foreign_keys_and_restores_them() {
+    let dialect = SqliteDialect;
+    let mut adapter = connect_with_orders(&dialect, &["INSERT INTO orders VALUES (1, 1);"]);
+    adapter
+        .execute("PRAGMA foreign_keys = ON;")
+        .expect("enable foreign keys");
+
+    let statements = dialect
+        .generate_ddl(&[users_name_not_null(&dialect)])
+        .expect("sqlite rebuild should generate SQL");
+    Executor::new(adapter.as_mut())
+        .execute_plan(&statements)
+        .expect("rebuild should succeed");
+
+    // Dropping the old table with enforcement on would have cascaded to this row.
+    adapter
+        .execute("INSERT INTO orders VALUES (1, 1);")
+        .expect_err("the referencing row must survive the rebuild");
+    adapter
+        .execute("INSERT INTO orders VALUES (2, 99);")
+        .expect_err("foreign key enforcement must be restored");
+}
+
+#[test]
+fn foreign_key_check_failure_reports_rows_and_rolls_back() {
+    let dialect = SqliteDialect;
+    let mut adapter = connect_with_orders(
+        &dialect,
+        &[
+            "PRAGMA foreign_keys = 0;",
+            "INSERT INTO orders VALUES (7, 99);",
+        ],
+    );
+
+    let statements = dialect
+        .generate_ddl(&[users_name_not_null(&dialect)])
+        .expect("sqlite rebuild should generate SQL");
+    let error = Executor::new(adapter.as_mut())
+        .execute_plan(&statements)
+        .expect_err("dangling reference must fail the rebuild");
+
+    let Error::Execute(ExecutionError::StatementFailed {
+        statement_context, ..
+    }) = &error
+    else {
+        panic!("expected execution-stage error, got {error:?}");
+    };
+    assert!(matches!(
+        statement_context.as_deref(),
+        Some(StatementContext::SqliteTableRebuild {
+            step: SqliteRebuildStep::CheckForeignKeys,
+            ..
+        })
+    ));
+    let mut messages = Vec::new();
+    let mut source: Option<&dyn std::error::Error> = Some(&error);
+    while let Some(current) = source {
+        messages.push(current.to_string());
+        source = current.source();
+    }
+    assert!(
+        messages
+            .iter()
+            .any(|message| message.contains("orders rowid 7 references missing users row")),
+        "error should name the offending row: {messages:?}"
+    );
+    adapter
+        .execute("INSERT INTO users VALUES (2, NULL);")
+        .expect("the original nullable table should remain after rollback");
+}
+
+#[test]
+fn foreign_key_pragmas_are_only_rebuild_steps_by_their_context() {
+    let dialect = SqliteDialect;
+    let adapter = connect_with_orders(
+        &dialect,
+        &[
+            "PRAGMA foreign_keys = 0;",
+            "INSERT INTO orders VALUES (7, 99);",
+        ],
+    );
+
+    adapter
+        .execute("PRAGMA foreign_key_check;")
+        .expect("a foreign key check outside a rebuild is plain SQL");
+    adapter
+        .execute_with_context(
+            "PRAGMA foreign_key_check;",
+            Some(&StatementContext::SqliteTableRebuild {
+                table: qualified(None, "users"),
+                step: SqliteRebuildStep::CheckForeignKeys,
+            }),
+        )
+        .expect_err("the rebuild's check step reports the dangling reference");
+}
+
+#[test]
+fn rebuild_drops_and_recreates_dependent_views() {
+    let dialect = SqliteDialect;
+    let view_sql = [
+        "CREATE VIEW named_users AS SELECT id, name FROM users;",
+        "CREATE VIEW user_count AS SELECT count(*) AS total FROM named_users;",
+    ];
+    let mut adapter = connect_with_orders(&dialect, &view_sql);
+    let mut op = users_name_not_null(&dialect);
+    let DiffOp::TableDefinition {
+        dependent_views, ..
+    } = &mut op
+    else {
+        unreachable!("users_name_not_null builds a table definition");
+    };
+    for sql in view_sql {
+        let Some(SchemaObject::View(view)) = dialect.parse(sql).expect("parse view").pop() else {
+            panic!("expected view object");
+        };
+        dependent_views.push(view);
+    }
+
+    let statements = dialect
+        .generate_ddl(&[op])
+        .expect("sqlite rebuild should generate SQL");
+    let steps = statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::Sql {
+                sql,
+                context: Some(StatementContext::SqliteTableRebuild { step, .. }),
+                ..
+            } => Some((step.clone(), sql.as_str())),
+            _ => None,
+        })
+        .filter(|(step, _)| {
+            matches!(
+                step,
+                SqliteRebuildStep::DropDependentViews
+                    | SqliteRebuildStep::DropOldTable
+                    | SqliteRebuildStep::RenameShadowTable
+                    | SqliteRebuildStep::RecreateDependentViews
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        steps,
+        vec![
+            (
+                SqliteRebuildStep::DropDependentViews,
+                "DROP VIEW \"user_count\";"
+            ),
+            (
+                SqliteRebuildStep::DropDependentViews,
+                "DROP VIEW \"named_users\";"
+            ),
+            (SqliteRebuildStep::DropOldTable, "DROP TABLE \"users\";"),
+            (
+                SqliteRebuildStep::RenameShadowTable,
+                "ALTER TABLE \"__stateql_rebuild_users\" RENAME TO \"users\";"
+            ),
+            (
+                SqliteRebuildStep::RecreateDependentViews,
+                "CREATE VIEW \"named_users\" AS SELECT id, name FROM users;"
+            ),
+            (
+                SqliteRebuildStep::RecreateDependentViews,
+                "CREATE VIEW \"user_count\" AS SELECT count(*) AS total FROM named_users;"
+            ),
+        ]
+    );
+
+    Executor::new(adapter.as_mut())
+        .execute_plan(&statements)
+        .expect("rebuild with dependent views should succeed");
+    let exported = adapter.export_schema().expect("export schema");
+    assert!(
+        exported.contains("CREATE VIEW \"user_count\""),
+        "{exported}"
+    );
+}
+
+#[test]
+fn rebuild_recreates_dependent_indexes_on_the_renamed_table() {
+    let dialect = SqliteDialect;
+    let index_sql = "CREATE INDEX users_name ON users (name);";
+    let mut adapter = connect_with_orders(&dialect, &[index_sql]);
+    let mut op = users_name_not_null(&dialect);
+    let DiffOp::TableDefinition {
+        dependent_indexes, ..
+    } = &mut op
+    else {
+        unreachable!("users_name_not_null builds a table definition");
+    };
+    let Some(SchemaObject::Index(index)) = dialect.parse(index_sql).expect("parse index").pop()
+    else {
+        panic!("expected index object");
+    };
+    dependent_indexes.push(index);
+
+    let statements = dialect
+        .generate_ddl(&[op])
+        .expect("sqlite rebuild should generate SQL");
+    let steps = statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::Sql {
+                sql,
+                context: Some(StatementContext::SqliteTableRebuild { step, .. }),
+                ..
+            } => Some((step.clone(), sql.as_str())),
+            _ => None,
+        })
+        .filter(|(step, _)| {
+            matches!(
+                step,
+                SqliteRebuildStep::RenameShadowTable | SqliteRebuildStep::RecreateIndexes
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        steps,
+        vec![
+            (
+                SqliteRebuildStep::RenameShadowTable,
+                "ALTER TABLE \"__stateql_rebuild_users\" RENAME TO \"users\";"
+            ),
+            (
+                SqliteRebuildStep::RecreateIndexes,
+                "CREATE INDEX \"users_name\" ON \"users\" (\"name\");"
+            ),
+        ]
+    );
+
+    Executor::new(adapter.as_mut())
+        .execute_plan(&statements)
+        .expect("rebuild with a dependent index should succeed");
+    let exported = adapter.export_schema().expect("export schema");
+    assert!(exported.contains("CREATE INDEX"), "{exported}");
+}
+
+#[test]
+fn rebuild_copy_maps_renamed_and_retyped_columns_and_backfills_new_ones() {
+    let dialect = SqliteDialect;
+    let table = |sql: &str| {
+        let mut objects = dialect.parse(sql).expect("sqlite parse pipeline");
+        let Some(SchemaObject::Table(table)) = objects.pop() else {
+            panic!("expected table object");
+        };
+        table
+    };
+    let current = table("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, score TEXT);");
+    let target = table(
+        "CREATE TABLE users (\n\
+         id INTEGER PRIMARY KEY,\n\
+         full_name TEXT, -- @renamed from=name\n\
+         score INTEGER,\n\
+         slug TEXT NOT NULL -- @backfill 'user-' || id\n\
+         );",
+    );
+    let users = qualified(None, "users");
+    let slug = target.columns[3].clone();
+
+    let statements = dialect
+        .generate_ddl(&[
+            DiffOp::RenameColumn {
+                table: users.clone(),
+                from: ident("name"),
+                to: ident("full_name"),
+            },
+            DiffOp::AlterColumn {
+                table: users.clone(),
+                column: ident("score"),
+                changes: vec![ColumnChange::SetType(DataType::Integer)],
+            },
+            DiffOp::AddColumn {
+                table: users,
+                column: Box::new(slug),
+                position: None,
+            },
+            DiffOp::TableDefinition {
+                current: Box::new(current),
+                target: Box::new(target),
+                dependent_views: Vec::new(),
+                dependent_indexes: Vec::new(),
+                declared_indexes: Vec::new(),
+                dependent_triggers: Vec::new(),
+                referencing_foreign_keys: Vec::new(),
+            },
+        ])
+        .expect("sqlite rebuild should generate SQL");
+
+    let sql = statement_sql(&statements);
+    assert!(
+        !sql.iter().any(|sql| sql.contains("RENAME COLUMN")),
+        "{sql:?}"
+    );
+    assert_eq!(
+        sql[2],
+        "INSERT INTO \"__stateql_rebuild_users\" (\"id\", \"full_name\", \"score\", \"slug\") SELECT \"id\", \"name\", CAST(\"score\" AS INTEGER), 'user-' || id FROM \"users\";"
+    );
+
+    let mut adapter = connect_with_orders(&dialect, &["ALTER TABLE users ADD COLUMN score TEXT;"]);
+    Executor::new(adapter.as_mut())
+        .execute_plan(&statements)
+        .expect("rebuild with backfilled NOT NULL column should succeed");
+}
+
+#[test]
+fn unconstrained_drops_and_renames_use_native_alter_table() {
+    let dialect = SqliteDialect;
+    let current = parse_table(
+        &dialect,
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, nickname TEXT);",
+    );
+    let target = parse_table(
+        &dialect,
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, full_name TEXT);",
+    );
+    let users = qualified(None, "users");
+
+    let statements = dialect
+        .generate_ddl(&[
+            DiffOp::RenameColumn {
+                table: users.clone(),
+                from: ident("name"),
+                to: ident("full_name"),
+            },
+            DiffOp::DropColumn {
+                table: users,
+                column: ident("nickname"),
+            },
+            DiffOp::TableDefinition {
+                current: Box::new(current),
+                target: Box::new(target),
+                dependent_views: Vec::new(),
+                dependent_indexes: Vec::new(),
+                declared_indexes: Vec::new(),
+                dependent_triggers: Vec::new(),
+                referencing_foreign_keys: Vec::new(),
+            },
+        ])
+        .expect("sqlite should generate native ALTER TABLE statements");
+
+    assert_eq!(
+        statement_sql(&statements),
+        [
+            "ALTER TABLE \"users\" RENAME COLUMN \"name\" TO \"full_name\";",
+            "ALTER TABLE \"users\" DROP COLUMN \"nickname\";",
+        ]
+    );
+    let mut adapter =
+        connect_with_orders(&dialect, &["ALTER TABLE users ADD COLUMN nickname TEXT;"]);
+    Executor::new(adapter.as_mut())
+        .execute_plan(&statements)
+        .expect("native drop and rename should succeed");
+}
+
+#[test]
+fn dropping_constrained_column_falls_back_to_rebuild() {
+    let dialect = SqliteDialect;
+    let target = parse_table(
+        &dialect,
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);",
+    );
+    for current_sql in [
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, email TEXT UNIQUE);",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, email TEXT, UNIQUE (name, email));",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, email TEXT REFERENCES accounts (email));",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, email TEXT CHECK (email LIKE '%@%'));",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, email TEXT GENERATED ALWAYS AS (name || '@example.com'));",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, email TEXT, domain TEXT GENERATED ALWAYS AS (substr(\"email\", 3)));",
+    ] {
+        let mut target = target.clone();
+        let (current, unique_constraints) = parse_declared(&dialect, current_sql);
+        if current_sql.contains("domain") {
+            target
+                .columns
+                .push(parse_table(&dialect, current_sql).columns[3].clone());
+        }
+        let statements = dialect
+            .generate_ddl(&[
+                DiffOp::DropColumn {
+                    table: qualified(None, "users"),
+                    column: ident("email"),
+                },
+                DiffOp::TableDefinition {
+                    current: Box::new(current),
+                    target: Box::new(target),
+                    dependent_views: Vec::new(),
+                    dependent_indexes: unique_constraints,
+                    declared_indexes: Vec::new(),
+                    dependent_triggers: Vec::new(),
+                    referencing_foreign_keys: Vec::new(),
+                },
+            ])
+            .expect("sqlite rebuild should generate SQL");
+
+        let sql = statement_sql(&statements);
+        assert!(
+            sql.iter()
+                .any(|sql| sql.starts_with("CREATE TABLE \"__stateql_rebuild_users\"")),
+            "{current_sql}: {sql:?}"
+        );
+        assert!(
+            !sql.iter().any(|sql| sql.contains("DROP COLUMN")),
+            "{current_sql}: {sql:?}"
+        );
+    }
+}
+
+fn parse_table(dialect: &SqliteDialect, sql: &str) -> Table {
+    parse_declared(dialect, sql).0
+}
+
+/// The table and the `UNIQUE` constraints it declares, which parse as indexes.
+fn parse_declared(dialect: &SqliteDialect, sql: &str) -> (Table, Vec<IndexDef>) {
+    let mut objects = dialect
+        .parse(sql)
+        .expect("sqlite parse pipeline")
+        .into_iter();
+    let Some(SchemaObject::Table(table)) = objects.next() else {
+        panic!("expected table object");
+    };
+    let indexes = objects
+        .map(|object| match object {
+            SchemaObject::Index(index) => index,
+            other => panic!("expected unique constraint, got {other:?}"),
+        })
+        .collect();
+    (table, indexes)
+}
+
+#[test]
+fn rebuild_through_the_orchestrator_keeps_indexes_and_triggers() {
+    let dialect = SqliteDialect;
+    let dir =
+        std::env::temp_dir().join(format!("stateql-rebuild-dependents-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("create scratch dir");
+    let config = ConnectionConfig {
+        host: None,
+        port: None,
+        user: None,
+        password: None,
+        database: dir.join("app.db").to_string_lossy().into_owned(),
+        socket: None,
+        extra: BTreeMap::new(),
+    };
+    let schema = |name_column: &str| {
+        format!(
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, {name_column});\n\
+             CREATE TABLE audit (user_id INTEGER PRIMARY KEY);\n\
+             CREATE INDEX users_name ON users (name);\n\
+             CREATE TRIGGER users_t AFTER INSERT ON users FOR EACH ROW BEGIN INSERT INTO audit (user_id) VALUES (NEW.id); END;\n"
+        )
+    };
+    let orchestrator = Orchestrator::new(&dialect);
+    let apply = |desired: &str| {
+        orchestrator.run(
+            &config,
+            desired,
+            OrchestratorOptions {
+                mode: Mode::Apply,
+                enable_drop: false,
+                enable_column_reorder: false,
+            },
+        )
+    };
+    apply(&schema("name TEXT")).expect("initial schema should apply");
+
+    let output = orchestrator
+        .run(
+            &config,
+            &schema("name TEXT NOT NULL DEFAULT ''"),
+            OrchestratorOptions {
+                mode: Mode::DryRun,
+                enable_drop: false,
+                enable_column_reorder: false,
+            },
+        )
+        .expect("dry run should succeed");
+    let OrchestratorOutput::DryRunSql(plan) = output else {
+        panic!("expected a dry-run plan, got {output:?}");
+    };
+    assert!(plan.contains("__stateql_rebuild_users"), "{plan}");
+
+    apply(&schema("name TEXT NOT NULL DEFAULT ''")).expect("rebuild should apply");
+    let adapter = dialect.connect(&config).expect("reconnect");
+    adapter
+        .execute("INSERT INTO users (id) VALUES (2);")
+        .expect("insert should use the new default");
+    let exported = adapter.export_schema().expect("export schema");
+    assert!(
+        exported.contains("CREATE INDEX \"users_name\""),
+        "{exported}"
+    );
+    assert!(
+        exported.contains("CREATE TRIGGER \"users_t\""),
+        "{exported}"
+    );
+    // The recreated trigger already audited user 2.
+    adapter
+        .execute("INSERT INTO audit (user_id) VALUES (2);")
+        .expect_err("the trigger must still fire after the rebuild");
+
+    fs::remove_dir_all(dir).expect("remove scratch dir");
+}
+
+#[test]
+fn unique_constraints_change_by_rebuilding_their_table() {
+    let dialect = SqliteDialect;
+    let dir = std::env::temp_dir().join(format!("stateql-rebuild-unique-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("create scratch dir");
+    let config = ConnectionConfig {
+        host: None,
+        port: None,
+        user: None,
+        password: None,
+        database: dir.join("app.db").to_string_lossy().into_owned(),
+        socket: None,
+        extra: BTreeMap::new(),
+    };
+    let orchestrator = Orchestrator::new(&dialect);
+    let run = |desired: &str, mode: Mode| {
+        orchestrator.run(
+            &config,
+            desired,
+            OrchestratorOptions {
+                mode,
+                enable_drop: true,
+                enable_column_reorder: false,
+            },
+        )
+    };
+    let plain = "CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT);\n";
+    let unique = "CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT, CONSTRAINT users_email UNIQUE (email) ON CONFLICT IGNORE);\n";
+    run(plain, Mode::Apply).expect("initial schema should apply");
+
+    let output = run(unique, Mode::DryRun).expect("dry run should succeed");
+    let OrchestratorOutput::DryRunSql(plan) = output else {
+        panic!("expected a dry-run plan, got {output:?}");
+    };
+    assert!(plan.contains("__stateql_rebuild_users"), "{plan}");
+    assert!(
+        plan.contains("CONSTRAINT \"users_email\" UNIQUE (\"email\") ON CONFLICT IGNORE"),
+        "{plan}"
+    );
+    assert!(!plan.contains("CREATE UNIQUE INDEX"), "{plan}");
+
+    run(unique, Mode::Apply).expect("adding the constraint should apply");
+    let output = run(unique, Mode::DryRun).expect("dry run should succeed");
+    let OrchestratorOutput::DryRunSql(plan) = output else {
+        panic!("expected a dry-run plan, got {output:?}");
+    };
+    assert!(!plan.contains("__stateql_rebuild_users"), "{plan}");
+    let adapter = dialect.connect(&config).expect("reconnect");
+    adapter
+        .execute("INSERT INTO users (id, email) VALUES (1, 'a@example.com'), (2, 'a@example.com');")
+        .expect("the conflict clause ignores the duplicate");
+    drop(adapter);
+
+    run(plain, Mode::Apply).expect("dropping the constraint should apply");
+    let adapter = dialect.connect(&config).expect("reconnect");
+    adapter
+        .execute("INSERT INTO users (id, email) VALUES (3, 'a@example.com');")
+        .expect("the constraint should be gone");
+    let exported = adapter.export_schema().expect("export schema");
+    assert!(!exported.contains("UNIQUE"), "{exported}");
+
+    fs::remove_dir_all(dir).expect("remove scratch dir");
+}
+
+#[test]
+fn dropping_a_column_of_a_unique_constraint_rebuilds_the_table() {
+    let dialect = SqliteDialect;
+    let (current, unique_constraints) = parse_declared(
+        &dialect,
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, email TEXT, CONSTRAINT users_contact UNIQUE (name, email));",
+    );
+    let (target, _) = parse_declared(
+        &dialect,
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);",
+    );
+    let statements = dialect
+        .generate_ddl(&[
+            DiffOp::DropColumn {
+                table: qualified(None, "users"),
+                column: ident("email"),
+            },
+            DiffOp::TableDefinition {
+                current: Box::new(current),
+                target: Box::new(target),
+                dependent_views: Vec::new(),
+                dependent_indexes: unique_constraints,
+                declared_indexes: Vec::new(),
+                dependent_triggers: Vec::new(),
+                referencing_foreign_keys: Vec::new(),
+            },
+        ])
+        .expect("sqlite rebuild should generate SQL");
+
+    let sql = statement_sql(&statements);
+    assert!(
+        sql.iter()
+            .any(|sql| sql.starts_with("CREATE TABLE \"__stateql_rebuild_users\"")),
+        "{sql:?}"
+    );
+    assert!(
+        !sql.iter().any(|sql| sql.contains("DROP COLUMN")),
+        "{sql:?}"
+    );
+    assert!(!sql.iter().any(|sql| sql.contains("UNIQUE")), "{sql:?}");
+}
+
+fn connect_with_orders(dialect: &SqliteDialect, seed: &[&str]) -> Box<dyn DatabaseAdapter> {
+    let adapter = dialect
+        .connect(&ConnectionConfig {
+            host: None,
+            port: None,
+            user: None,
+            password: None,
+            database: ":memory:".to_string(),
+            socket: None,
+            extra: BTreeMap::new(),
+        })
+        .expect("in-memory sqlite connection should succeed");
+    for sql in [
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);",
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, user_id INTEGER REFERENCES users (id) ON DELETE CASCADE);",
+        "INSERT INTO users VALUES (1, 'alice');",
+    ]
+    .into_iter()
+    .chain(seed.iter().copied())
+    {
+        adapter.execute(sql).expect("fixture statement should succeed");
+    }
+    adapter
+}
+
+fn users_name_not_null(dialect: &SqliteDialect) -> DiffOp {
+    let table = |sql: &str| {
+        let mut objects = dialect.parse(sql).expect("sqlite parse pipeline");
+        let Some(SchemaObject::Table(table)) = objects.pop() else {
+            panic!("expected table object");
+        };
+        Box::new(table)
+    };
+    DiffOp::TableDefinition {
+        current: table("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);"),
+        target: table("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL);"),
+        dependent_views: Vec::new(),
+        dependent_indexes: Vec::new(),
+        declared_indexes: Vec::new(),
+        dependent_triggers: Vec::new(),
+        referencing_foreign_keys: Vec::new(),
+    }
+}
+
 fn ident(value: &str) -> Ident {
     Ident::unquoted(value)
 }
@@ -129,3 +912,19 @@ fn sample_column(name: &str) -> Column {
         extra: BTreeMap::new(),
     }
 }
+
+fn users_table(columns: Vec<Column>) -> Table {
+    let mut table = Table::named("users");
+    table.columns = columns;
+    table
+}
+
+fn statement_sql(statements: &[Statement]) -> Vec<&str> {
+    statements
+        .iter()
+        .map(|statement| match statement {
+            Statement::Sql { sql, .. } => sql.as_str(),
+            Statement::BatchBoundary => panic!("sqlite generator should not emit BatchBoundary"),
+        })
+        .collect()
+}
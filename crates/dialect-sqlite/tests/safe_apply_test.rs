@@ -0,0 +1,192 @@
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+use stateql_core::{ConnectionConfig, DatabaseAdapter, Dialect, Executor, Statement};
+use stateql_dialect_sqlite::SqliteDialect;
+
+#[test]
+fn failed_plan_is_restored_from_the_reported_backup() {
+    let dir = scratch_dir("restore");
+    let mut adapter = connect(&dir, "backup");
+
+    let error = apply(adapter.as_mut(), &failing_plan()).expect_err("second statement fails");
+
+    let messages = error_messages(&error);
+    let backup = backups_in(&dir);
+    assert_eq!(backup.len(), 1, "the backup is kept: {backup:?}");
+    assert!(
+        messages
+            .iter()
+            .any(|message| message.contains(&format!("restored from {}", backup[0].display()))),
+        "error should report the backup: {messages:?}"
+    );
+    assert_eq!(table_names(adapter.as_ref()), vec!["users".to_string()]);
+
+    fs::remove_dir_all(dir).expect("remove scratch dir");
+}
+
+#[test]
+fn successful_plan_removes_its_backup() {
+    let dir = scratch_dir("success");
+    let mut adapter = connect(&dir, "backup");
+
+    apply(adapter.as_mut(), &failing_plan()[..1]).expect("plan should apply");
+
+    assert!(backups_in(&dir).is_empty());
+    assert_eq!(
+        table_names(adapter.as_ref()),
+        vec!["audit".to_string(), "users".to_string()]
+    );
+
+    fs::remove_dir_all(dir).expect("remove scratch dir");
+}
+
+/// A plan that switches the copies to `journal_mode = persist` leaves their `-journal`
+/// files behind, which go with them.
+#[test]
+fn rehearsal_failure_leaves_the_database_untouched() {
+    let dir = scratch_dir("rehearse");
+    let mut adapter = connect(&dir, "rehearse");
+    let mut plan = vec![Statement::Sql {
+        sql: "PRAGMA journal_mode = persist;".to_string(),
+        transactional: false,
+        context: None,
+    }];
+    plan.extend(failing_plan());
+
+    let error = apply(adapter.as_mut(), &plan).expect_err("rehearsal fails");
+
+    let messages = error_messages(&error);
+    assert!(
+        messages
+            .iter()
+            .any(|message| message.contains("failed against a temporary copy")),
+        "error should blame the rehearsal: {messages:?}"
+    );
+    assert!(backups_in(&dir).is_empty());
+    assert_eq!(rehearsal_files(), Vec::<PathBuf>::new());
+    assert_eq!(table_names(adapter.as_ref()), vec!["users".to_string()]);
+
+    fs::remove_dir_all(dir).expect("remove scratch dir");
+}
+
+#[test]
+fn unknown_safe_apply_mode_is_rejected() {
+    let dir = scratch_dir("unknown");
+    let mut config = file_connection(&dir);
+    config
+        .extra
+        .insert("sqlite.safe_apply".to_string(), "always".to_string());
+
+    let Err(error) = SqliteDialect.connect(&config) else {
+        panic!("unknown mode should be rejected");
+    };
+    assert!(
+        error_messages(&error)
+            .iter()
+            .any(|message| message.contains("`sqlite.safe_apply` must be")),
+    );
+
+    fs::remove_dir_all(dir).expect("remove scratch dir");
+}
+
+/// The first statement commits on its own, so only the restore can undo it.
+fn failing_plan() -> Vec<Statement> {
+    vec![
+        Statement::Sql {
+            sql: "CREATE TABLE audit (id INTEGER PRIMARY KEY);".to_string(),
+            transactional: false,
+            context: None,
+        },
+        Statement::Sql {
+            sql: "CREATE TABLE users (id INTEGER PRIMARY KEY);".to_string(),
+            transactional: true,
+            context: None,
+        },
+    ]
+}
+
+fn apply(adapter: &mut dyn DatabaseAdapter, statements: &[Statement]) -> stateql_core::Result<()> {
+    adapter.prepare_apply(statements)?;
+    let outcome = Executor::new(adapter).execute_plan(statements);
+    adapter.finish_apply(outcome)
+}
+
+fn connect(dir: &std::path::Path, mode: &str) -> Box<dyn DatabaseAdapter> {
+    let mut config = file_connection(dir);
+    config
+        .extra
+        .insert("sqlite.safe_apply".to_string(), mode.to_string());
+    let adapter = SqliteDialect
+        .connect(&config)
+        .expect("connect should succeed");
+    adapter
+        .execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);")
+        .expect("fixture table");
+    adapter
+}
+
+fn file_connection(dir: &std::path::Path) -> ConnectionConfig {
+    ConnectionConfig {
+        host: None,
+        port: None,
+        user: None,
+        password: None,
+        database: dir.join("app.db").to_string_lossy().into_owned(),
+        socket: None,
+        extra: BTreeMap::new(),
+    }
+}
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("stateql-safe-apply-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("create scratch dir");
+    dir
+}
+
+fn backups_in(dir: &std::path::Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .expect("read scratch dir")
+        .map(|entry| entry.expect("dir entry").path())
+        .filter(|path| path.to_string_lossy().contains(".stateql-backup-"))
+        .collect()
+}
+
+/// Rehearsal copies of this test process left in the temporary directory.
+fn rehearsal_files() -> Vec<PathBuf> {
+    let process = format!("-{}.db", std::process::id());
+    fs::read_dir(std::env::temp_dir())
+        .expect("read temp dir")
+        .map(|entry| entry.expect("dir entry").path())
+        .filter(|path| {
+            let name = path.to_string_lossy();
+            name.contains("stateql-rehearsal-") && name.contains(&process)
+        })
+        .collect()
+}
+
+fn table_names(adapter: &dyn DatabaseAdapter) -> Vec<String> {
+    let exported = adapter.export_schema().expect("export schema");
+    let mut names = SqliteDialect
+        .parse(&exported)
+        .expect("export parses")
+        .into_iter()
+        .filter_map(|object| match object {
+            stateql_core::SchemaObject::Table(table) => Some(table.name.name.value),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+fn error_messages(error: &stateql_core::Error) -> Vec<String> {
+    let mut messages = Vec::new();
+    let mut source: Option<&dyn std::error::Error> = Some(error);
+    while let Some(current) = source {
+        messages.push(current.to_string());
+        source = current.source();
+    }
+    messages
+}
@@ -6,7 +6,7 @@ mod sqlite_atomicity_fixture;
 
 use sqlite_atomicity_fixture::{
     assert_copy_step_failure, assert_rollback_left_original_table, prepare_users_with_null_age,
-    set_not_null_age_op,
+    set_not_null_age_ops,
 };
 
 #[test]
@@ -15,7 +15,7 @@ fn s10_sqlite_table_recreation_rolls_back_atomically_on_copy_failure() {
     let mut adapter = prepare_users_with_null_age(&dialect);
 
     let statements = dialect
-        .generate_ddl(&[set_not_null_age_op()])
+        .generate_ddl(&set_not_null_age_ops(&dialect))
         .expect("rebuild plan should generate");
 
     let mut executor = Executor::new(adapter.as_mut());
@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 
 use stateql_core::{
     ColumnChange, ConnectionConfig, DatabaseAdapter, Dialect, DiffOp, Error, ExecutionError, Ident,
-    QualifiedName, SqliteRebuildStep, StatementContext,
+    QualifiedName, SchemaObject, SqliteRebuildStep, StatementContext,
 };
 use stateql_dialect_sqlite::SqliteDialect;
 
@@ -19,12 +19,30 @@ pub fn prepare_users_with_null_age(dialect: &SqliteDialect) -> Box<dyn DatabaseA
     adapter
 }
 
-pub fn set_not_null_age_op() -> DiffOp {
-    DiffOp::AlterColumn {
-        table: users_table(),
-        column: ident("age"),
-        changes: vec![ColumnChange::SetNotNull(true)],
-    }
+pub fn set_not_null_age_ops(dialect: &SqliteDialect) -> Vec<DiffOp> {
+    let table = |sql: &str| {
+        let mut objects = dialect.parse(sql).expect("sqlite parse pipeline");
+        let Some(SchemaObject::Table(table)) = objects.pop() else {
+            panic!("expected table object");
+        };
+        Box::new(table)
+    };
+    vec![
+        DiffOp::TableDefinition {
+            current: table("CREATE TABLE users (age INTEGER);"),
+            target: table("CREATE TABLE users (age INTEGER NOT NULL);"),
+            dependent_views: Vec::new(),
+            dependent_indexes: Vec::new(),
+            declared_indexes: Vec::new(),
+            dependent_triggers: Vec::new(),
+            referencing_foreign_keys: Vec::new(),
+        },
+        DiffOp::AlterColumn {
+            table: users_table(),
+            column: ident("age"),
+            changes: vec![ColumnChange::SetNotNull(true)],
+        },
+    ]
 }
 
 pub fn assert_copy_step_failure(error: Error) {